@@ -22,7 +22,6 @@ pub mod frame_system;
 pub mod frame_system_extensions;
 pub mod inmemorydb_weights;
 pub mod pallet_ah_ops;
-pub mod pallet_asset_conversion;
 pub mod pallet_asset_conversion_ops;
 pub mod pallet_asset_conversion_tx_payment;
 pub mod pallet_asset_rate;
@@ -38,22 +37,17 @@ pub mod pallet_fast_unstake;
 pub mod pallet_indices;
 pub mod pallet_message_queue;
 pub mod pallet_migrations;
-pub mod pallet_multisig;
 pub mod pallet_nft_fractionalization;
-pub mod pallet_nfts;
 pub mod pallet_nomination_pools;
 pub mod pallet_preimage;
-pub mod pallet_proxy;
 pub mod pallet_referenda;
 pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_sudo;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
-pub mod pallet_treasury;
 pub mod pallet_uniques;
 pub mod pallet_utility;
-pub mod pallet_vesting;
 pub mod pallet_whitelist;
 pub mod pallet_xcm;
 pub mod pallet_xcm_bridge_hub_router;
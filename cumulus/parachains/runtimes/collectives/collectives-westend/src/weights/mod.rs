@@ -29,9 +29,7 @@ pub mod pallet_collective_content;
 pub mod pallet_core_fellowship_ambassador_core;
 pub mod pallet_core_fellowship_fellowship_core;
 pub mod pallet_message_queue;
-pub mod pallet_multisig;
 pub mod pallet_preimage;
-pub mod pallet_proxy;
 pub mod pallet_ranked_collective_ambassador_collective;
 pub mod pallet_ranked_collective_fellowship_collective;
 pub mod pallet_referenda_ambassador_referenda;
@@ -42,7 +40,6 @@ pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
-pub mod pallet_treasury;
 pub mod pallet_utility;
 pub mod pallet_xcm;
 pub mod paritydb_weights;
@@ -27,8 +27,6 @@ pub mod pallet_collator_selection;
 pub mod pallet_identity;
 pub mod pallet_message_queue;
 pub mod pallet_migrations;
-pub mod pallet_multisig;
-pub mod pallet_proxy;
 pub mod pallet_session;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
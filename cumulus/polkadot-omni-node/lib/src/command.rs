@@ -277,6 +277,9 @@ where
 					let runner = cli.create_runner(cmd)?;
 					runner.sync_run(|config| cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone()))
 				},
+				BenchmarkCmd::Compare(cmd) => cmd.run(),
+				BenchmarkCmd::Migrations(cmd) => cmd.run::<ReclaimHostFunctions>(),
+				BenchmarkCmd::StorageDiff(cmd) => cmd.run::<ReclaimHostFunctions>(),
 				#[allow(unreachable_patterns)]
 				_ => Err("Benchmarking sub-command unsupported or compilation feature missing. \
 					Make sure to compile omni-node with --features=runtime-benchmarks \
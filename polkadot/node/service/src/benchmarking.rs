@@ -156,6 +156,7 @@ fn westend_sign_call(
 		frame_system::CheckWeight::<runtime::Runtime>::new(),
 		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
 		frame_metadata_hash_extension::CheckMetadataHash::<runtime::Runtime>::new(false),
+		pallet_recovery::RecordActivity::<runtime::Runtime>::new(),
 		frame_system::WeightReclaim::<runtime::Runtime>::new(),
 	)
 		.into();
@@ -175,6 +176,7 @@ fn westend_sign_call(
 			(),
 			None,
 			(),
+			(),
 		),
 	);
 
@@ -215,6 +217,7 @@ fn rococo_sign_call(
 		frame_system::CheckWeight::<runtime::Runtime>::new(),
 		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
 		frame_metadata_hash_extension::CheckMetadataHash::<runtime::Runtime>::new(false),
+		pallet_recovery::RecordActivity::<runtime::Runtime>::new(),
 		frame_system::WeightReclaim::<runtime::Runtime>::new(),
 	)
 		.into();
@@ -234,6 +237,7 @@ fn rococo_sign_call(
 			(),
 			None,
 			(),
+			(),
 		),
 	);
 
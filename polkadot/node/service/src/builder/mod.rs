@@ -245,6 +245,11 @@ where
 		let shared_voter_state = rpc_setup;
 		let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
 		let auth_disc_public_addresses = config.network.public_addresses.clone();
+		let auth_disc_persisted_cache_file_path = config
+			.network
+			.net_config_path
+			.as_ref()
+			.map(|path| path.join(sc_authority_discovery::PERSISTED_CACHE_FILE_NAME));
 
 		let genesis_hash = client.chain_info().genesis_hash;
 		let peer_store_handle = net_config.peer_store_handle();
@@ -568,6 +573,7 @@ where
 					public_addresses: auth_disc_public_addresses,
 					// Require that authority discovery records are signed.
 					strict_record_validation: true,
+					persisted_cache_file_path: auth_disc_persisted_cache_file_path,
 					..Default::default()
 				},
 				client.clone(),
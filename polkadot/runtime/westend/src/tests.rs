@@ -129,7 +129,7 @@ mod remote_tests {
 		let mut ext = Builder::<Block>::default()
 			.mode(if let Some(state_snapshot) = maybe_state_snapshot {
 				Mode::OfflineOrElseOnline(
-					OfflineConfig { state_snapshot: state_snapshot.clone() },
+					OfflineConfig { state_snapshot: state_snapshot.clone(), ..Default::default() },
 					OnlineConfig {
 						transport,
 						state_snapshot: Some(state_snapshot),
@@ -172,7 +172,7 @@ mod remote_tests {
 		let mut ext = Builder::<Block>::default()
 			.mode(if let Some(state_snapshot) = maybe_state_snapshot {
 				Mode::OfflineOrElseOnline(
-					OfflineConfig { state_snapshot: state_snapshot.clone() },
+					OfflineConfig { state_snapshot: state_snapshot.clone(), ..Default::default() },
 					online_config,
 				)
 			} else {
@@ -276,7 +276,7 @@ mod remote_tests {
 		let mut ext = Builder::<Block>::default()
 			.mode(if let Some(state_snapshot) = maybe_state_snapshot {
 				Mode::OfflineOrElseOnline(
-					OfflineConfig { state_snapshot: state_snapshot.clone() },
+					OfflineConfig { state_snapshot: state_snapshot.clone(), ..Default::default() },
 					online_config,
 				)
 			} else {
@@ -31,11 +31,9 @@ pub mod pallet_message_queue;
 pub mod pallet_meta_tx;
 pub mod pallet_migrations;
 pub mod pallet_mmr;
-pub mod pallet_multisig;
 pub mod pallet_nomination_pools;
 pub mod pallet_parameters;
 pub mod pallet_preimage;
-pub mod pallet_proxy;
 pub mod pallet_referenda_referenda;
 pub mod pallet_scheduler;
 pub mod pallet_session;
@@ -43,10 +41,8 @@ pub mod pallet_staking;
 pub mod pallet_sudo;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
-pub mod pallet_treasury;
 pub mod pallet_utility;
 pub mod pallet_verify_signature;
-pub mod pallet_vesting;
 pub mod pallet_whitelist;
 pub mod pallet_xcm;
 pub mod polkadot_runtime_common_assigned_slots;
@@ -328,10 +328,13 @@ impl pallet_assets::Config for Test {
 	type Freezer = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type PermitSignature = sp_runtime::MultiSignature;
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = XcmBenchmarkHelper;
+	#[cfg(feature = "runtime-benchmarks")]
+	type PermitBenchmarkHelper = ();
 }
 
 #[derive_impl(pallet_revive::config_preludes::TestDefaultConfig)]
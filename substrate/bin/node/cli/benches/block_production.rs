@@ -75,6 +75,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		warm_up_trie_cache: None,
 		state_pruning: Some(PruningMode::ArchiveAll),
 		blocks_pruning: BlocksPruning::KeepAll,
+		event_topics_index: false,
 		chain_spec: spec,
 		executor: ExecutorConfiguration {
 			wasm_method: WasmExecutionMethod::Compiled {
@@ -116,6 +117,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		config,
 		None,
 		false,
+		node_cli::service::GRANDPA_JUSTIFICATION_PERIOD,
 		|_, _| (),
 	)
 	.expect("creating a full node doesn't fail")
@@ -66,6 +66,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 		warm_up_trie_cache: None,
 		state_pruning: Some(PruningMode::ArchiveAll),
 		blocks_pruning: BlocksPruning::KeepAll,
+		event_topics_index: false,
 		chain_spec: spec,
 		executor: ExecutorConfiguration::default(),
 		rpc: RpcConfiguration {
@@ -103,6 +104,7 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 			config,
 			None,
 			false,
+			node_cli::service::GRANDPA_JUSTIFICATION_PERIOD,
 			|_, _| (),
 		)
 		.expect("Creates node")
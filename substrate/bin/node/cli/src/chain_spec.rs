@@ -56,6 +56,22 @@ pub struct Extensions {
 	pub bad_blocks: sc_client_api::BadBlocks<Block>,
 	/// The light sync state extension used by the sync-state rpc.
 	pub light_sync_state: sc_sync_state_rpc::LightSyncStateExtension,
+	/// The finality proofs used to warp sync the chain.
+	#[serde(default)]
+	pub warp_sync_proofs: WarpSyncProofs,
+}
+
+/// The finality proofs served to and requested from peers when warp syncing.
+///
+/// All nodes of a chain must use the same kind of proofs.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WarpSyncProofs {
+	/// GRANDPA justifications of every authority set change.
+	#[default]
+	Grandpa,
+	/// BEEFY signed commitments and MMR proofs of every validator set change.
+	Beefy,
 }
 
 /// Specialized `ChainSpec`.
@@ -429,7 +445,7 @@ pub fn local_testnet_config() -> ChainSpec {
 #[cfg(test)]
 pub(crate) mod tests {
 	use super::*;
-	use crate::service::{new_full_base, NewFullBase};
+	use crate::service::{new_full_base, NewFullBase, GRANDPA_JUSTIFICATION_PERIOD};
 	use kitchensink_runtime::genesis_config_presets::well_known_including_eth_accounts;
 	use sc_service_test;
 	use sp_runtime::{AccountId32, BuildStorage};
@@ -468,7 +484,13 @@ pub(crate) mod tests {
 
 		sc_service_test::connectivity(integration_test_config_with_two_authorities(), |config| {
 			let NewFullBase { task_manager, client, network, sync, transaction_pool, .. } =
-				new_full_base::<sc_network::NetworkWorker<_, _>>(config, None, false, |_, _| ())?;
+				new_full_base::<sc_network::NetworkWorker<_, _>>(
+					config,
+					None,
+					false,
+					GRANDPA_JUSTIFICATION_PERIOD,
+					|_, _| (),
+				)?;
 			Ok(sc_service_test::TestNetComponents::new(
 				task_manager,
 				client,
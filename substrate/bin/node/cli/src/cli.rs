@@ -43,6 +43,14 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Store the GRANDPA justification of every finalized block.
+	///
+	/// By default justifications are only stored for authority set changes and periodically.
+	/// Storing all of them allows `grandpa_proveFinalityAt` to prove the finality of any
+	/// finalized block.
+	#[arg(long)]
+	pub grandpa_archive: bool,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub storage_monitor: sc_storage_monitor::StorageMonitorParams,
@@ -21,7 +21,7 @@ use polkadot_sdk::*;
 use super::benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder};
 use crate::{
 	chain_spec, service,
-	service::{new_partial, FullClient},
+	service::{new_partial, FullClient, GRANDPA_JUSTIFICATION_PERIOD},
 	Cli, Subcommand,
 };
 use frame_benchmarking_cli::*;
@@ -117,7 +117,7 @@ pub fn run() -> Result<()> {
 					},
 					BenchmarkCmd::Block(cmd) => {
 						// ensure that we keep the task manager alive
-						let partial = new_partial(&config, None)?;
+						let partial = new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 						cmd.run(partial.client)
 					},
 					#[cfg(not(feature = "runtime-benchmarks"))]
@@ -128,7 +128,7 @@ pub fn run() -> Result<()> {
 					#[cfg(feature = "runtime-benchmarks")]
 					BenchmarkCmd::Storage(cmd) => {
 						// ensure that we keep the task manager alive
-						let partial = new_partial(&config, None)?;
+						let partial = new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 						let db = partial.backend.expose_db();
 						let storage = partial.backend.expose_storage();
 						let shared_trie_cache = partial.backend.expose_shared_trie_cache();
@@ -137,7 +137,7 @@ pub fn run() -> Result<()> {
 					},
 					BenchmarkCmd::Overhead(cmd) => {
 						// ensure that we keep the task manager alive
-						let partial = new_partial(&config, None)?;
+						let partial = new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 						let ext_builder = RemarkBuilder::new(partial.client.clone());
 
 						cmd.run(
@@ -151,7 +151,7 @@ pub fn run() -> Result<()> {
 					},
 					BenchmarkCmd::Extrinsic(cmd) => {
 						// ensure that we keep the task manager alive
-						let partial = service::new_partial(&config, None)?;
+						let partial = service::new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 						// Register the *Remark* and *TKA* builders.
 						let ext_factory = ExtrinsicFactory(vec![
 							Box::new(RemarkBuilder::new(partial.client.clone())),
@@ -171,6 +171,11 @@ pub fn run() -> Result<()> {
 					},
 					BenchmarkCmd::Machine(cmd) =>
 						cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone()),
+					BenchmarkCmd::Compare(cmd) => cmd.run(),
+					BenchmarkCmd::Migrations(cmd) =>
+						cmd.run::<sp_statement_store::runtime_api::HostFunctions>(),
+					BenchmarkCmd::StorageDiff(cmd) =>
+						cmd.run::<sp_statement_store::runtime_api::HostFunctions>(),
 				}
 			})
 		},
@@ -187,21 +192,23 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let PartialComponents { client, task_manager, import_queue, .. } =
-					new_partial(&config, None)?;
+					new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 				Ok((cmd.run(client, import_queue), task_manager))
 			})
 		},
 		Some(Subcommand::ExportBlocks(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
-				let PartialComponents { client, task_manager, .. } = new_partial(&config, None)?;
+				let PartialComponents { client, task_manager, .. } =
+					new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 				Ok((cmd.run(client, config.database), task_manager))
 			})
 		},
 		Some(Subcommand::ExportState(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
-				let PartialComponents { client, task_manager, .. } = new_partial(&config, None)?;
+				let PartialComponents { client, task_manager, .. } =
+					new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 				Ok((cmd.run(client, config.chain_spec), task_manager))
 			})
 		},
@@ -209,7 +216,7 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let PartialComponents { client, task_manager, import_queue, .. } =
-					new_partial(&config, None)?;
+					new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 				Ok((cmd.run(client, import_queue), task_manager))
 			})
 		},
@@ -221,7 +228,7 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
 				let PartialComponents { client, task_manager, backend, .. } =
-					new_partial(&config, None)?;
+					new_partial(&config, None, GRANDPA_JUSTIFICATION_PERIOD)?;
 				let aux_revert = Box::new(|client: Arc<FullClient>, backend, blocks| {
 					sc_consensus_babe::revert(client.clone(), backend, blocks)?;
 					sc_consensus_grandpa::revert(client, blocks)?;
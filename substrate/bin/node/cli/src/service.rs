@@ -25,7 +25,7 @@ use polkadot_sdk::{
 	sp_consensus_beefy as beefy_primitives, *,
 };
 
-use crate::Cli;
+use crate::{chain_spec::WarpSyncProofs, Cli};
 use codec::Encode;
 use frame_benchmarking_cli::SUBSTRATE_REFERENCE_HARDWARE;
 use frame_system_rpc_runtime_api::AccountNonceApi;
@@ -37,7 +37,10 @@ use sc_consensus_babe::{self, SlotProportion};
 use sc_network::{
 	event::Event, service::traits::NetworkService, NetworkBackend, NetworkEventStream,
 };
-use sc_network_sync::{strategy::warp::WarpSyncConfig, SyncingService};
+use sc_network_sync::{
+	strategy::warp::{WarpSyncConfig, WarpSyncProvider},
+	SyncingService,
+};
 use sc_service::{config::Configuration, error::Error as ServiceError, RpcHandlers, TaskManager};
 use sc_statement_store::Store as StatementStore;
 use sc_telemetry::{Telemetry, TelemetryWorker};
@@ -84,7 +87,7 @@ pub type TransactionPool = sc_transaction_pool::TransactionPoolHandle<Block, Ful
 
 /// The minimum period of blocks on which justifications will be
 /// imported and generated.
-const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
+pub const GRANDPA_JUSTIFICATION_PERIOD: u32 = 512;
 
 /// Fetch the nonce of the given `account` from the chain state.
 ///
@@ -174,6 +177,7 @@ pub fn create_extrinsic(
 pub fn new_partial(
 	config: &Configuration,
 	mixnet_config: Option<&sc_mixnet::Config>,
+	justification_period: u32,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
@@ -244,7 +248,7 @@ pub fn new_partial(
 
 	let (grandpa_block_import, grandpa_link) = grandpa::block_import(
 		client.clone(),
-		GRANDPA_JUSTIFICATION_PERIOD,
+		justification_period,
 		&(client.clone() as Arc<_>),
 		select_chain.clone(),
 		telemetry.as_ref().map(|x| x.handle()),
@@ -403,6 +407,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	config: Configuration,
 	mixnet_config: Option<sc_mixnet::Config>,
 	disable_hardware_benchmarks: bool,
+	justification_period: u32,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<
 			Block,
@@ -441,7 +446,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 		transaction_pool,
 		other:
 			(rpc_builder, import_setup, rpc_setup, mut telemetry, statement_store, mixnet_api_backend),
-	} = new_partial(&config, mixnet_config.as_ref())?;
+	} = new_partial(&config, mixnet_config.as_ref(), justification_period)?;
 
 	let metrics = N::register_notification_metrics(
 		config.prometheus_config.as_ref().map(|cfg| &cfg.registry),
@@ -449,6 +454,11 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	let shared_voter_state = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
 	let auth_disc_public_addresses = config.network.public_addresses.clone();
+	let auth_disc_persisted_cache_file_path = config
+		.network
+		.net_config_path
+		.as_ref()
+		.map(|path| path.join(sc_authority_discovery::PERSISTED_CACHE_FILE_NAME));
 
 	let mut net_config = sc_network::config::FullNetworkConfiguration::<_, _, N>::new(
 		&config.network,
@@ -511,11 +521,24 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 		notification_service
 	});
 
-	let warp_sync = Arc::new(grandpa::warp_proof::NetworkProvider::new(
-		backend.clone(),
-		import_setup.1.shared_authority_set().clone(),
-		Vec::default(),
-	));
+	let warp_sync_proofs =
+		sc_chain_spec::get_extension::<WarpSyncProofs>(config.chain_spec.extensions())
+			.copied()
+			.unwrap_or_default();
+	let warp_sync: Arc<dyn WarpSyncProvider<Block>> = match warp_sync_proofs {
+		WarpSyncProofs::Grandpa => Arc::new(grandpa::warp_proof::NetworkProvider::new(
+			backend.clone(),
+			import_setup.1.shared_authority_set().clone(),
+			Vec::default(),
+		)),
+		WarpSyncProofs::Beefy => Arc::new(beefy::warp_proof::NetworkProvider::<
+			_,
+			_,
+			_,
+			beefy_primitives::ecdsa_crypto::AuthorityId,
+			pallet_beefy_mmr::BeefyEcdsaToEthereum,
+		>::new(backend.clone(), client.clone())),
+	};
 
 	let (network, system_rpc_tx, tx_handler_controller, sync_service) =
 		sc_service::build_network(sc_service::BuildNetworkParams {
@@ -543,6 +566,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 			Some(keystore_container.keystore()),
 			mixnet_notification_service
 				.expect("`NotificationService` exists since mixnet was enabled; qed"),
+			prometheus_registry.clone(),
 		);
 		task_manager.spawn_handle().spawn("mixnet", None, mixnet);
 	}
@@ -659,6 +683,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 				sc_authority_discovery::WorkerConfig {
 					publish_non_global_ips: auth_disc_publish_non_global_ips,
 					public_addresses: auth_disc_public_addresses,
+					persisted_cache_file_path: auth_disc_persisted_cache_file_path,
 					..Default::default()
 				},
 				client.clone(),
@@ -724,7 +749,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	let grandpa_config = grandpa::Config {
 		// FIXME #1578 make this available through chainspec
 		gossip_duration: std::time::Duration::from_millis(333),
-		justification_generation_period: GRANDPA_JUSTIFICATION_PERIOD,
+		justification_generation_period: justification_period,
 		name: Some(name),
 		observer_enabled: false,
 		keystore,
@@ -803,6 +828,26 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 			"offchain-work",
 			offchain_workers.run(client.clone(), task_manager.spawn_handle()).boxed(),
 		);
+
+		#[cfg(feature = "experimental")]
+		{
+			let task_executor =
+				sc_offchain::TaskExecutor::<_, _, kitchensink_runtime::RuntimeTask>::new(
+					sc_offchain::TaskExecutorOptions {
+						runtime_api_provider: client.clone(),
+						transaction_pool: OffchainTransactionPoolFactory::new(
+							transaction_pool.clone(),
+						),
+						max_tasks_per_block: 16,
+						resubmit_after: 8,
+					},
+				);
+			task_manager.spawn_handle().spawn_blocking(
+				"offchain-tasks-runner",
+				Some("offchain-work"),
+				task_executor.run(client.clone()).boxed(),
+			);
+		}
 	}
 
 	Ok(NewFullBase {
@@ -819,6 +864,11 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
 	let mixnet_config = cli.mixnet_params.config(config.role.is_authority());
 	let database_path = config.database.path().map(Path::to_path_buf);
+	let justification_period = if cli.grandpa_archive {
+		grandpa::ARCHIVE_JUSTIFICATION_PERIOD
+	} else {
+		GRANDPA_JUSTIFICATION_PERIOD
+	};
 
 	let task_manager = match config.network.network_backend {
 		sc_network::config::NetworkBackendType::Libp2p => {
@@ -826,6 +876,7 @@ pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceE
 				config,
 				mixnet_config,
 				cli.no_hardware_benchmarks,
+				justification_period,
 				|_, _| (),
 			)
 			.map(|NewFullBase { task_manager, .. }| task_manager)?;
@@ -836,6 +887,7 @@ pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceE
 				config,
 				mixnet_config,
 				cli.no_hardware_benchmarks,
+				justification_period,
 				|_, _| (),
 			)
 			.map(|NewFullBase { task_manager, .. }| task_manager)?;
@@ -857,7 +909,7 @@ pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceE
 
 #[cfg(test)]
 mod tests {
-	use crate::service::{new_full_base, NewFullBase};
+	use crate::service::{new_full_base, NewFullBase, GRANDPA_JUSTIFICATION_PERIOD};
 	use codec::Encode;
 	use kitchensink_runtime::{
 		constants::{currency::CENTS, time::SLOT_DURATION},
@@ -923,6 +975,7 @@ mod tests {
 						config,
 						None,
 						false,
+						GRANDPA_JUSTIFICATION_PERIOD,
 						|block_import: &sc_consensus_babe::BabeBlockImport<Block, _, _>,
 						 babe_link: &sc_consensus_babe::BabeLink<Block>| {
 							setup_handles = Some((block_import.clone(), babe_link.clone()));
@@ -1127,6 +1180,7 @@ mod tests {
 						config,
 						None,
 						false,
+						GRANDPA_JUSTIFICATION_PERIOD,
 						|_, _| (),
 					)?;
 				Ok(sc_service_test::TestNetComponents::new(
@@ -473,6 +473,55 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 	}
 }
 
+/// Determines the native or `Assets` asset spent by a call dispatched through a proxy.
+///
+/// `transfer_all` calls spend an unknown amount, so they are only allowed by an allowance of the
+/// maximum amount. Remarks and batches spend nothing themselves, as the calls they dispatch are
+/// inspected separately. Any other call may move funds in ways which are not accounted for, like
+/// approvals, vested transfers or swaps, so it is denied to proxies with a spending allowance.
+pub struct ProxySpendInspector;
+impl pallet_proxy::SpendInspector<RuntimeCall, NativeOrWithId<u32>, Balance>
+	for ProxySpendInspector
+{
+	fn spend(call: &RuntimeCall) -> pallet_proxy::CallSpend<NativeOrWithId<u32>, Balance> {
+		use pallet_proxy::CallSpend;
+		match call {
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+				value, ..
+			}) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) =>
+				CallSpend::Asset(NativeOrWithId::Native, *value),
+			RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) =>
+				CallSpend::Asset(NativeOrWithId::Native, Balance::MAX),
+			RuntimeCall::Assets(pallet_assets::Call::transfer { id, amount, .. }) |
+			RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive {
+				id, amount, ..
+			}) => CallSpend::Asset(NativeOrWithId::WithId(id.0), *amount),
+			RuntimeCall::Assets(pallet_assets::Call::transfer_all { id, .. }) =>
+				CallSpend::Asset(NativeOrWithId::WithId(id.0), Balance::MAX),
+			RuntimeCall::System(frame_system::Call::remark { .. }) |
+			RuntimeCall::System(frame_system::Call::remark_with_event { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) => CallSpend::Nothing,
+			_ => CallSpend::Unknown,
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ProxyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_proxy::BenchmarkHelper<NativeOrWithId<u32>> for ProxyBenchmarkHelper {
+	fn create_asset_kind(seed: u32) -> NativeOrWithId<u32> {
+		if seed == 0 {
+			NativeOrWithId::Native
+		} else {
+			NativeOrWithId::WithId(seed)
+		}
+	}
+}
+
 impl pallet_proxy::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -487,6 +536,11 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type AssetKind = NativeOrWithId<u32>;
+	type SpendInspector = ProxySpendInspector;
+	type MaxAllowances = ConstU32<16>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ProxyBenchmarkHelper;
 }
 
 parameter_types! {
@@ -1826,10 +1880,13 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type PermitSignature = Signature;
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type PermitBenchmarkHelper = ();
 }
 
 ord_parameter_types! {
@@ -1856,8 +1913,11 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type CallbackHandle = ();
+	type PermitSignature = Signature;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type PermitBenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -1901,6 +1961,9 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxPriceObservations = ConstU32<256>;
+	// `best_route` returns `None` once there are more pools than this.
+	type MaxRoutePools = ConstU32<128>;
 	type MintMinLiquidity = MintMinLiquidity;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -2030,6 +2093,7 @@ parameter_types! {
 	pub const ItemAttributesApprovalsLimit: u32 = 20;
 	pub const MaxTips: u32 = 10;
 	pub const MaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+	pub const AuctionExtensionPeriod: BlockNumber = 10 * MINUTES;
 }
 
 impl pallet_uniques::Config for Runtime {
@@ -2126,6 +2190,8 @@ impl pallet_nfts::Config for Runtime {
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type CollectionDeposit = CollectionDeposit;
 	type ItemDeposit = ItemDeposit;
@@ -2140,6 +2206,9 @@ impl pallet_nfts::Config for Runtime {
 	type MaxTips = MaxTips;
 	type MaxDeadlineDuration = MaxDeadlineDuration;
 	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type MaxRoyaltyRecipients = ConstU32<5>;
+	type MaxRoyalty = ConstU16<2_500>;
+	type AuctionExtensionPeriod = AuctionExtensionPeriod;
 	type Features = Features;
 	type OffchainSignature = Signature;
 	type OffchainPublic = <Signature as traits::Verify>::Signer;
@@ -3136,6 +3205,17 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	#[cfg(feature = "experimental")]
+	impl sp_offchain::TasksApi<Block, RuntimeTask> for Runtime {
+		fn valid_tasks(max: u32) -> Vec<RuntimeTask> {
+			System::valid_tasks(max)
+		}
+
+		fn task_extrinsic(task: RuntimeTask) -> <Block as BlockT>::Extrinsic {
+			generic::UncheckedExtrinsic::new_bare(frame_system::Call::do_task { task }.into()).into()
+		}
+	}
+
 	impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
 		fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
 			Grandpa::grandpa_authorities()
@@ -3393,6 +3473,7 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	#[api_version(3)]
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -3410,6 +3491,14 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		fn get_reserves(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>) -> Option<(Balance, Balance)> {
 			AssetConversion::get_reserves(asset1, asset2).ok()
 		}
+
+		fn twap(asset1: NativeOrWithId<u32>, asset2: NativeOrWithId<u32>, window: u32) -> Option<sp_runtime::FixedU128> {
+			AssetConversion::twap(asset1, asset2, window.into())
+		}
+
+		fn best_route(asset_in: NativeOrWithId<u32>, asset_out: NativeOrWithId<u32>, amount_in: Balance, max_hops: u32) -> Option<(Vec<NativeOrWithId<u32>>, Balance)> {
+			AssetConversion::find_best_route(asset_in, asset_out, amount_in, max_hops)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
@@ -3429,6 +3518,7 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
 		fn owner(collection: u32, item: u32) -> Option<AccountId> {
 			<Nfts as Inspect<AccountId>>::owner(&collection, &item)
@@ -3471,6 +3561,10 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		fn collection_attribute(collection: u32, key: Vec<u8>) -> Option<Vec<u8>> {
 			<Nfts as Inspect<AccountId>>::collection_attribute(&collection, &key)
 		}
+
+		fn collection_royalty(collection: u32) -> Vec<(AccountId, u16)> {
+			Nfts::collection_royalty(&collection)
+		}
 	}
 
 	#[api_version(5)]
@@ -3613,6 +3707,22 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl pallet_migrations::runtime_api::MigrationsApi<Block, BlockNumber> for Runtime {
+		fn migrations() -> Vec<pallet_migrations::runtime_api::MigrationInfo> {
+			MultiBlockMigrations::migrations()
+		}
+
+		fn dry_run_start() -> Option<pallet_migrations::MigrationCursor<Vec<u8>, BlockNumber>> {
+			MultiBlockMigrations::dry_run_start()
+		}
+
+		fn dry_run_step(
+			weight_limit: Option<Weight>,
+		) -> pallet_migrations::runtime_api::DryRunStep<BlockNumber> {
+			MultiBlockMigrations::dry_run_step(weight_limit)
+		}
+	}
+
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
@@ -3741,6 +3851,7 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use frame_support::{assert_ok, traits::fungibles::approvals::Inspect as _};
 	use frame_system::offchain::CreateSignedTransaction;
 
 	#[test]
@@ -3754,6 +3865,82 @@ mod tests {
 		is_submit_signed_transaction::<Runtime>();
 	}
 
+	#[test]
+	fn proxy_allowance_denies_unknown_spending() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			System::set_block_number(1);
+			let real = AccountId::from([1; 32]);
+			let delegate = AccountId::from([2; 32]);
+			let spender = AccountId::from([3; 32]);
+			assert_ok!(Balances::force_set_balance(
+				RuntimeOrigin::root(),
+				real.clone().into(),
+				1000 * DOLLARS
+			));
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				1.into(),
+				real.clone().into(),
+				true,
+				1
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(real.clone()),
+				1.into(),
+				real.clone().into(),
+				100
+			));
+			assert_ok!(Proxy::add_proxy(
+				RuntimeOrigin::signed(real.clone()),
+				delegate.clone().into(),
+				ProxyType::Any,
+				0
+			));
+			assert_ok!(Proxy::set_allowance(
+				RuntimeOrigin::signed(real.clone()),
+				delegate.clone().into(),
+				NativeOrWithId::WithId(1),
+				10,
+				0
+			));
+
+			// An approval could be used to spend the asset without touching the allowance.
+			let approve = RuntimeCall::Assets(pallet_assets::Call::approve_transfer {
+				id: 1.into(),
+				delegate: spender.clone().into(),
+				amount: 100,
+			});
+			assert_ok!(Proxy::proxy(
+				RuntimeOrigin::signed(delegate.clone()),
+				real.clone().into(),
+				None,
+				Box::new(approve)
+			));
+			System::assert_last_event(
+				pallet_proxy::Event::ProxyExecuted {
+					result: Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+				}
+				.into(),
+			);
+			assert_eq!(Assets::allowance(1, &real, &spender), 0);
+
+			// Transfers within the allowance are still possible.
+			let transfer = RuntimeCall::Assets(pallet_assets::Call::transfer {
+				id: 1.into(),
+				target: spender.clone().into(),
+				amount: 10,
+			});
+			assert_ok!(Proxy::proxy(
+				RuntimeOrigin::signed(delegate),
+				real.into(),
+				None,
+				Box::new(transfer)
+			));
+			System::assert_last_event(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }.into());
+			assert_eq!(Assets::balance(1, &spender), 10);
+		});
+	}
+
 	#[test]
 	fn call_size() {
 		let size = core::mem::size_of::<RuntimeCall>();
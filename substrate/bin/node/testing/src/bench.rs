@@ -389,6 +389,7 @@ impl BenchDb {
 			state_pruning: Some(PruningMode::ArchiveAll),
 			source: database_type.into_settings(dir.into()),
 			blocks_pruning: sc_client_db::BlocksPruning::KeepAll,
+			event_topics_index: false,
 			metrics_registry: None,
 		};
 		let task_executor = TaskExecutor::new();
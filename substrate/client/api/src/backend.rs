@@ -312,6 +312,22 @@ pub trait AuxStore {
 	fn get_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>>;
 }
 
+/// Provides access to an index of the event topics recorded by `frame_system`.
+///
+/// The index is optional, backends that don't maintain it return an error.
+pub trait EventTopicsIndex<Block: BlockT>: Send + Sync {
+	/// Returns the number, hash and event index of every event with `topic` in the canonical
+	/// blocks `from..=to`, in ascending order.
+	///
+	/// Blocks that were pruned are skipped.
+	fn events_by_topic(
+		&self,
+		topic: &[u8],
+		from: NumberFor<Block>,
+		to: NumberFor<Block>,
+	) -> sp_blockchain::Result<Vec<(NumberFor<Block>, Block::Hash, u32)>>;
+}
+
 /// An `Iterator` that iterates keys in a given block under a prefix.
 pub struct KeysIter<State, Block>
 where
@@ -81,4 +81,7 @@ pub enum Error {
 
 	#[error("Unknown authority.")]
 	UnknownAuthority,
+
+	#[error("Failed to access the persisted address cache: {0}")]
+	PersistedCache(#[from] std::io::Error),
 }
@@ -33,7 +33,7 @@ pub use crate::{
 	worker::{AuthorityDiscovery, NetworkProvider, Role, Worker},
 };
 
-use std::{collections::HashSet, sync::Arc, time::Duration};
+use std::{collections::HashSet, path::PathBuf, sync::Arc, time::Duration};
 
 use futures::{
 	channel::{mpsc, oneshot},
@@ -54,6 +54,10 @@ mod worker;
 #[cfg(test)]
 mod tests;
 
+/// Suggested file name for [`WorkerConfig::persisted_cache_file_path`], relative to the network
+/// configuration directory of the node.
+pub const PERSISTED_CACHE_FILE_NAME: &str = "authority_discovery_cache";
+
 /// Configuration of [`Worker`].
 pub struct WorkerConfig {
 	/// The maximum interval in which the node will publish its own address on the DHT.
@@ -88,6 +92,14 @@ pub struct WorkerConfig {
 	///
 	/// Defaults to `false` to provide compatibility with old versions
 	pub strict_record_validation: bool,
+
+	/// File in which the signed records of discovered authorities are persisted.
+	///
+	/// The records are verified again when loaded on startup, allowing the node to connect to the
+	/// authorities right away instead of waiting for the DHT lookups to complete.
+	///
+	/// Defaults to `None`, which disables persistence.
+	pub persisted_cache_file_path: Option<PathBuf>,
 }
 
 impl Default for WorkerConfig {
@@ -110,6 +122,7 @@ impl Default for WorkerConfig {
 			publish_non_global_ips: true,
 			public_addresses: Vec::new(),
 			strict_record_validation: false,
+			persisted_cache_file_path: None,
 		}
 	}
 }
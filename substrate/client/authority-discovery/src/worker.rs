@@ -25,6 +25,7 @@ use crate::{
 use std::{
 	collections::{HashMap, HashSet},
 	marker::PhantomData,
+	path::PathBuf,
 	sync::Arc,
 	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
@@ -58,6 +59,7 @@ use sp_keystore::{Keystore, KeystorePtr};
 use sp_runtime::traits::Block as BlockT;
 
 mod addr_cache;
+mod persisted_cache;
 /// Dht payload schemas generated from Protobuf definitions via Prost crate in build.rs.
 mod schema {
 	#[cfg(test)]
@@ -82,6 +84,10 @@ const MAX_ADDRESSES_TO_PUBLISH: usize = 32;
 /// Maximum number of in-flight DHT lookups at any given point in time.
 const MAX_IN_FLIGHT_LOOKUPS: usize = 8;
 
+/// Records older than this are not loaded from the persisted cache. Matches the time-to-live of
+/// Kademlia records, after which the record would have expired from the DHT as well.
+const MAX_PERSISTED_RECORD_AGE: Duration = Duration::from_secs(36 * 60 * 60);
+
 /// Role an authority discovery [`Worker`] can run as.
 pub enum Role {
 	/// Publish own addresses and discover addresses of others.
@@ -178,6 +184,9 @@ pub struct Worker<Client, Block: BlockT, DhtEventStream> {
 
 	addr_cache: addr_cache::AddrCache,
 
+	/// Same value as in the configuration.
+	persisted_cache_file_path: Option<PathBuf>,
+
 	metrics: Option<Metrics>,
 
 	/// Flag to ensure the warning about missing public addresses is only printed once.
@@ -303,6 +312,7 @@ where
 			in_flight_lookups: HashMap::new(),
 			known_lookups: HashMap::new(),
 			addr_cache,
+			persisted_cache_file_path: config.persisted_cache_file_path,
 			role,
 			metrics,
 			warn_public_addresses: false,
@@ -313,6 +323,8 @@ where
 
 	/// Start the worker
 	pub async fn run(mut self) {
+		self.load_persisted_cache();
+
 		loop {
 			self.start_new_lookups();
 
@@ -404,6 +416,9 @@ where
 			.peekable();
 
 		// Similar to listen addresses that takes into consideration `publish_non_global_ips`.
+		//
+		// These include the addresses observed by peers, so for a node behind a NAT they carry
+		// the public IP and the mapped ports of its QUIC listen addresses as well.
 		let mut external_addresses = self
 			.network
 			.external_addresses()
@@ -570,6 +585,7 @@ where
 		self.last_known_records.retain(|k, value| {
 			self.known_authorities.contains_key(k) && !value.record.is_expired(now)
 		});
+		self.persist_cache();
 
 		authorities.shuffle(&mut thread_rng());
 		self.pending_lookups = authorities;
@@ -813,13 +829,40 @@ where
 				return Err(Error::ReceivingUnexpectedRecord);
 			};
 
+		let (remote_addresses, records_creation_time) =
+			self.verify_authority_record(&authority_id, peer_record.record.value.as_slice())?;
+
+		let answering_peer_id = peer_record.peer.map(|peer| peer.into());
+
+		let addr_cache_needs_update = self.handle_new_record(
+			&authority_id,
+			remote_key.clone(),
+			RecordInfo {
+				creation_time: records_creation_time,
+				peers_with_record: answering_peer_id.into_iter().collect(),
+				record: peer_record.record,
+			},
+		);
+
+		if !remote_addresses.is_empty() && addr_cache_needs_update {
+			self.addr_cache.insert(authority_id, remote_addresses);
+			self.update_known_authorities_metric();
+		}
+		Ok(())
+	}
+
+	/// Check the signatures of a signed authority record found for `authority_id`.
+	///
+	/// Returns the addresses of the authority along with the record creation time.
+	fn verify_authority_record(
+		&self,
+		authority_id: &AuthorityId,
+		value: &[u8],
+	) -> Result<(Vec<Multiaddr>, u128)> {
 		let local_peer_id = self.network.local_peer_id();
 
 		let schema::SignedAuthorityRecord { record, peer_signature, .. } =
-			Self::check_record_signed_with_authority_id(
-				peer_record.record.value.as_slice(),
-				&authority_id,
-			)?;
+			Self::check_record_signed_with_authority_id(value, authority_id)?;
 
 		let authority_record =
 			schema::AuthorityRecord::decode(record.as_slice()).map_err(Error::DecodingProto)?;
@@ -862,33 +905,115 @@ where
 			&record,
 			peer_signature,
 			remote_peer_id,
-			&authority_id,
+			authority_id,
 		)?;
 
 		let remote_addresses: Vec<Multiaddr> =
 			addresses.into_iter().take(MAX_ADDRESSES_PER_AUTHORITY).collect();
 
-		let answering_peer_id = peer_record.peer.map(|peer| peer.into());
+		Ok((remote_addresses, records_creation_time))
+	}
 
-		let addr_cache_needs_update = self.handle_new_record(
-			&authority_id,
-			remote_key.clone(),
-			RecordInfo {
-				creation_time: records_creation_time,
-				peers_with_record: answering_peer_id.into_iter().collect(),
-				record: peer_record.record,
+	fn update_known_authorities_metric(&self) {
+		if let Some(metrics) = &self.metrics {
+			metrics
+				.known_authorities_count
+				.set(self.addr_cache.num_authority_ids().try_into().unwrap_or(std::u64::MAX));
+		}
+	}
+
+	/// Fill the address cache with the records persisted by a previous run, if any.
+	///
+	/// Every record is verified again, records that are invalid or too old are skipped.
+	fn load_persisted_cache(&mut self) {
+		let Some(path) = self.persisted_cache_file_path.clone() else { return };
+
+		let records = match persisted_cache::load(&path) {
+			Ok(records) => records,
+			Err(e) => {
+				error!(
+					target: LOG_TARGET,
+					"Failed to load persisted address cache from {}: {}",
+					path.display(),
+					e,
+				);
+				return
 			},
-		);
+		};
 
-		if !remote_addresses.is_empty() && addr_cache_needs_update {
-			self.addr_cache.insert(authority_id, remote_addresses);
-			if let Some(metrics) = &self.metrics {
-				metrics
-					.known_authorities_count
-					.set(self.addr_cache.num_authority_ids().try_into().unwrap_or(std::u64::MAX));
+		let now = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map(|time| time.as_nanos())
+			.unwrap_or_default();
+
+		for persisted_cache::PersistedRecord { authority_id, record } in records {
+			let (addresses, creation_time) =
+				match self.verify_authority_record(&authority_id, &record) {
+					Ok(verified) => verified,
+					Err(e) => {
+						debug!(
+							target: LOG_TARGET,
+							"Skipping invalid persisted record of {authority_id:?}: {e}",
+						);
+						continue
+					},
+				};
+
+			if now.saturating_sub(creation_time) > MAX_PERSISTED_RECORD_AGE.as_nanos() ||
+				addresses.is_empty()
+			{
+				debug!(target: LOG_TARGET, "Skipping stale persisted record of {authority_id:?}");
+				continue
 			}
+
+			let kademlia_key = hash_authority_id(authority_id.as_ref());
+			self.last_known_records.insert(
+				kademlia_key.clone(),
+				RecordInfo {
+					creation_time,
+					peers_with_record: HashSet::new(),
+					record: Record::new(kademlia_key, record),
+				},
+			);
+			self.addr_cache.insert(authority_id, addresses);
+		}
+
+		debug!(
+			target: LOG_TARGET,
+			"Loaded {} authorities from the persisted address cache",
+			self.addr_cache.num_authority_ids(),
+		);
+		self.update_known_authorities_metric();
+	}
+
+	/// Persist the records of the authorities currently in the address cache, if enabled.
+	fn persist_cache(&self) {
+		let Some(path) = &self.persisted_cache_file_path else { return };
+
+		let records = self
+			.known_authorities
+			.iter()
+			.filter(|(_, authority_id)| {
+				self.addr_cache.get_addresses_by_authority_id(authority_id).is_some()
+			})
+			.filter_map(|(kademlia_key, authority_id)| {
+				self.last_known_records.get(kademlia_key).map(|record_info| {
+					persisted_cache::PersistedRecord {
+						authority_id: authority_id.clone(),
+						record: record_info.record.value.clone(),
+					}
+				})
+			})
+			.collect::<Vec<_>>();
+
+		if let Err(e) = persisted_cache::store(path, &records) {
+			error!(
+				target: LOG_TARGET,
+				"Failed to persist address cache to {}: {}",
+				path.display(),
+				e,
+			);
 		}
-		Ok(())
 	}
 
 	// Handles receiving a new DHT record for the authorithy.
@@ -0,0 +1,110 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! On-disk cache of the signed authority discovery records backing the
+//! [`AddrCache`](super::addr_cache::AddrCache).
+//!
+//! Only the raw signed records are stored. They are verified again when loaded, so a tampered
+//! or stale file can never inject addresses that were not signed by the authority.
+
+use crate::error::Result;
+use codec::{Decode, Encode};
+use sp_authority_discovery::AuthorityId;
+use std::{
+	fs,
+	io::ErrorKind,
+	path::{Path, PathBuf},
+};
+
+/// A signed authority discovery record, as found on the DHT, for the given authority.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub(super) struct PersistedRecord {
+	/// The authority the record was looked up for.
+	pub authority_id: AuthorityId,
+	/// The encoded `SignedAuthorityRecord`.
+	pub record: Vec<u8>,
+}
+
+/// Load the records persisted at `path`.
+///
+/// A missing file is not an error, it just yields no records.
+pub(super) fn load(path: &Path) -> Result<Vec<PersistedRecord>> {
+	let encoded = match fs::read(path) {
+		Ok(encoded) => encoded,
+		Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+
+	Ok(Vec::<PersistedRecord>::decode(&mut &encoded[..])?)
+}
+
+/// Atomically replace the records persisted at `path` with `records`.
+pub(super) fn store(path: &Path, records: &[PersistedRecord]) -> Result<()> {
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+
+	let tmp_path = tmp_path(path);
+	fs::write(&tmp_path, records.encode())?;
+	fs::rename(&tmp_path, path)?;
+
+	Ok(())
+}
+
+fn tmp_path(path: &Path) -> PathBuf {
+	let mut tmp_path = path.as_os_str().to_owned();
+	tmp_path.push(".tmp");
+	tmp_path.into()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_authority_discovery::AuthorityPair;
+	use sp_core::crypto::Pair;
+
+	#[test]
+	fn store_and_load_roundtrip() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("nested").join("addr_cache");
+
+		assert_eq!(load(&path).unwrap(), Vec::new());
+
+		let records = (0..3u8)
+			.map(|i| PersistedRecord {
+				authority_id: AuthorityPair::from_seed_slice(&[i; 32]).unwrap().public(),
+				record: vec![i; 16],
+			})
+			.collect::<Vec<_>>();
+		store(&path, &records).unwrap();
+		assert_eq!(load(&path).unwrap(), records);
+
+		store(&path, &records[..1]).unwrap();
+		assert_eq!(load(&path).unwrap(), records[..1].to_vec());
+		assert!(!tmp_path(&path).exists());
+	}
+
+	#[test]
+	fn load_fails_on_corrupted_file() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("addr_cache");
+
+		fs::write(&path, [0xff; 3]).unwrap();
+		assert!(load(&path).is_err());
+	}
+}
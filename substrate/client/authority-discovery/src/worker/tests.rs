@@ -1358,3 +1358,114 @@ fn test_handle_put_record_request() {
 		.boxed_local(),
 	);
 }
+
+#[test]
+fn addr_cache_is_persisted_and_verified_on_load() {
+	let dir = tempfile::tempdir().unwrap();
+	let path = dir.path().join("authority_discovery_cache");
+	let remote_node_network = TestNetwork::default();
+	let remote_multiaddr = {
+		let address: Multiaddr = "/ip6/2001:db8:0:0:0:0:0:1/tcp/30333".parse().unwrap();
+
+		address.with(multiaddr::Protocol::P2p(remote_node_network.peer_id.into()))
+	};
+
+	let remote_key_store = MemoryKeystore::new();
+	let remote_public_keys: Vec<AuthorityId> = (0..2)
+		.map(|_| {
+			remote_key_store
+				.sr25519_generate_new(key_types::AUTHORITY_DISCOVERY, None)
+				.unwrap()
+				.into()
+		})
+		.collect();
+
+	let new_worker = || {
+		let (_dht_event_tx, dht_event_rx) = channel(1);
+		let (_to_worker, from_service) = mpsc::channel(0);
+		Worker::new(
+			from_service,
+			Arc::new(TestApi { authorities: remote_public_keys.clone() }),
+			Arc::new(TestNetwork::default()),
+			dht_event_rx.boxed(),
+			Role::Discover,
+			None,
+			WorkerConfig { persisted_cache_file_path: Some(path.clone()), ..Default::default() },
+		)
+	};
+
+	let mut worker = new_worker();
+	block_on(worker.refill_pending_lookups_queue()).unwrap();
+	worker.start_new_lookups();
+
+	let kv_pairs = build_dht_event(
+		vec![remote_multiaddr.clone()],
+		remote_public_keys[0].clone(),
+		&remote_key_store,
+		Some(&TestSigner { keypair: &remote_node_network.identity }),
+		Some(build_creation_time()),
+	);
+	for (key, value) in kv_pairs.clone() {
+		worker
+			.handle_dht_value_found_event(PeerRecord {
+				peer: None,
+				record: Record { key, value, publisher: None, expires: None },
+			})
+			.unwrap();
+	}
+
+	// Refilling the lookup queue persists the cache.
+	block_on(worker.refill_pending_lookups_queue()).unwrap();
+	drop(worker);
+
+	let mut worker = new_worker();
+	worker.load_persisted_cache();
+	assert_eq!(
+		Some(&HashSet::from([remote_multiaddr.clone()])),
+		worker.addr_cache.get_addresses_by_authority_id(&remote_public_keys[0]),
+	);
+
+	// A record attributed to an authority that did not sign it is rejected on load.
+	let (_, value) = kv_pairs.into_iter().next().unwrap();
+	persisted_cache::store(
+		&path,
+		&[persisted_cache::PersistedRecord {
+			authority_id: remote_public_keys[1].clone(),
+			record: value,
+		}],
+	)
+	.unwrap();
+
+	let mut worker = new_worker();
+	worker.load_persisted_cache();
+	assert_eq!(0, worker.addr_cache.num_authority_ids());
+}
+
+#[test]
+fn addresses_to_publish_includes_observed_quic_addresses() {
+	let (_dht_event_tx, dht_event_rx) = channel(1000);
+	let tcp_address: Multiaddr = "/ip4/1.2.3.4/tcp/30333".parse().unwrap();
+	// The port of the QUIC listen address as mapped by a NAT, observed by the peers of the node.
+	let quic_address: Multiaddr = "/ip4/1.2.3.4/udp/41234/quic-v1".parse().unwrap();
+	let network: Arc<TestNetwork> = Arc::new(TestNetwork {
+		external_addresses: vec![tcp_address.clone(), quic_address.clone()],
+		..Default::default()
+	});
+
+	let (_to_worker, from_service) = mpsc::channel(0);
+	let mut worker = Worker::new(
+		from_service,
+		Arc::new(TestApi { authorities: vec![] }),
+		network.clone(),
+		Box::pin(dht_event_rx),
+		Role::PublishAndDiscover(MemoryKeystore::new().into()),
+		Some(prometheus_endpoint::Registry::new()),
+		Default::default(),
+	);
+
+	let peer_id = multiaddr::Protocol::P2p(network.peer_id.into());
+	assert_eq!(
+		worker.addresses_to_publish().collect::<Vec<_>>(),
+		vec![tcp_address.with(peer_id.clone()), quic_address.with(peer_id)],
+	);
+}
@@ -219,6 +219,14 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(self.database_params().and_then(|x| x.database()))
 	}
 
+	/// Whether to index the event topics recorded by `frame_system`.
+	///
+	/// By default this is retrieved from `DatabaseParams` if it is available. Otherwise its
+	/// `false`.
+	fn event_topics_index(&self) -> Result<bool> {
+		Ok(self.database_params().map(|x| x.event_topics_index()).unwrap_or_default())
+	}
+
 	/// Get the database configuration object for the parameters provided
 	fn database_config(
 		&self,
@@ -541,6 +549,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			warm_up_trie_cache: self.warm_up_trie_cache()?,
 			state_pruning: self.state_pruning()?,
 			blocks_pruning: self.blocks_pruning()?,
+			event_topics_index: self.event_topics_index()?,
 			executor: ExecutorConfiguration {
 				wasm_method: self.wasm_method()?,
 				default_heap_pages: self.default_heap_pages()?,
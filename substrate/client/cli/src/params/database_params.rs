@@ -29,6 +29,13 @@ pub struct DatabaseParams {
 	/// Limit the memory the database cache can use.
 	#[arg(long = "db-cache", value_name = "MiB")]
 	pub database_cache_size: Option<usize>,
+
+	/// Index the event topics recorded by `frame_system` on import.
+	///
+	/// Makes the events of a topic queryable with the `system_eventsByTopic` RPC. Only blocks
+	/// imported while the index is enabled are indexed.
+	#[arg(long)]
+	pub event_topics_index: bool,
 }
 
 impl DatabaseParams {
@@ -41,4 +48,9 @@ impl DatabaseParams {
 	pub fn database_cache_size(&self) -> Option<usize> {
 		self.database_cache_size
 	}
+
+	/// Whether the event topics index is enabled.
+	pub fn event_topics_index(&self) -> bool {
+		self.event_topics_index
+	}
 }
@@ -18,16 +18,17 @@
 
 //! Schema for BEEFY state persisted in the aux-db.
 
-use crate::{error::Error, worker::PersistedState, LOG_TARGET};
+use crate::{error::Error, warp_proof::SetChanges, worker::PersistedState, LOG_TARGET};
 use codec::{Decode, Encode};
 use log::{debug, trace, warn};
 use sc_client_api::{backend::AuxStore, Backend};
 use sp_blockchain::{Error as ClientError, Result as ClientResult};
 use sp_consensus_beefy::AuthorityIdBound;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
 
 const VERSION_KEY: &[u8] = b"beefy_auxschema_version";
 const WORKER_STATE_KEY: &[u8] = b"beefy_voter_state";
+const WARP_SET_CHANGES_KEY: &[u8] = b"beefy_warp_set_changes";
 
 const CURRENT_VERSION: u32 = 4;
 
@@ -46,6 +47,22 @@ pub(crate) fn write_voter_state<B: BlockT, BE: AuxStore, AuthorityId: AuthorityI
 	AuxStore::insert_aux(backend, &[(WORKER_STATE_KEY, state.encode().as_slice())], &[])
 }
 
+/// The aux-db entry of the validator set changes indexed for warp sync proofs, to be written along
+/// with an imported block.
+pub(crate) fn warp_set_changes_aux<B: BlockT>(
+	set_changes: &SetChanges<NumberFor<B>, B::Hash>,
+) -> (Vec<u8>, Option<Vec<u8>>) {
+	trace!(target: LOG_TARGET, "🥩 persisting {:?}", set_changes);
+	(WARP_SET_CHANGES_KEY.to_vec(), Some(set_changes.encode()))
+}
+
+/// Load the validator set changes indexed for warp sync proofs.
+pub(crate) fn load_warp_set_changes<B: BlockT, BE: AuxStore>(
+	backend: &BE,
+) -> ClientResult<Option<SetChanges<NumberFor<B>, B::Hash>>> {
+	load_decode(backend, WARP_SET_CHANGES_KEY)
+}
+
 fn load_decode<BE: AuxStore, T: Decode>(backend: &BE, key: &[u8]) -> ClientResult<Option<T>> {
 	match backend.get_aux(key)? {
 		None => Ok(None),
@@ -32,6 +32,7 @@ use sc_client_api::{backend::Backend, TrieCacheContext};
 use sc_consensus::{BlockCheckParams, BlockImport, BlockImportParams, ImportResult};
 
 use crate::{
+	aux_schema,
 	communication::notification::BeefyVersionedFinalityProofSender,
 	justification::{decode_and_verify_finality_proof, BeefyVersionedFinalityProof},
 	metric_inc,
@@ -138,6 +139,17 @@ where
 		let hash = block.post_hash();
 		let number = *block.header.number();
 
+		// Index the validator set changes for warp sync proofs along with the block.
+		match crate::warp_proof::index_set_change::<Block, _, AuthorityId>(
+			&*self.backend,
+			&block.header,
+		) {
+			Ok(Some(set_changes)) =>
+				block.auxiliary.push(aux_schema::warp_set_changes_aux::<Block>(&set_changes)),
+			Ok(None) => {},
+			Err(e) => return Err(ConsensusError::ClientImport(e.to_string())),
+		}
+
 		let beefy_encoded = block.justifications.as_mut().and_then(|just| {
 			let encoded = just.get(BEEFY_ENGINE_ID).cloned();
 			// Remove BEEFY justification from the list before giving to `inner`; we send it to the
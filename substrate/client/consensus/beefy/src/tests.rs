@@ -352,7 +352,10 @@ fn add_mmr_digest(builder: &mut impl BlockBuilderExt, mmr_hash: MmrRootHash) {
 		.unwrap();
 }
 
-fn add_auth_change_digest(builder: &mut impl BlockBuilderExt, new_auth_set: BeefyValidatorSet) {
+pub(crate) fn add_auth_change_digest(
+	builder: &mut impl BlockBuilderExt,
+	new_auth_set: BeefyValidatorSet,
+) {
 	builder
 		.push_deposit_log_digest_item(DigestItem::Consensus(
 			BEEFY_ENGINE_ID,
@@ -0,0 +1,966 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Utilities for generating and verifying BEEFY warp sync proofs.
+//!
+//! Unlike GRANDPA warp proofs, which need to walk through every authority set change, a BEEFY
+//! warp proof hands over trust from one validator set to the next using the
+//! `beefy_next_authority_set` commitment stored in the MMR leaves built by `pallet-beefy-mmr`.
+//!
+//! Every [`WarpSyncFragment`] contains a BEEFY justification signed by the currently trusted
+//! validator set, an MMR leaf proven against the MMR root from the signed commitment payload and
+//! the header of the leaf's parent block. When the leaf commits to the next validator set, the
+//! fragment also carries the full list of next validator keys, which is checked against the
+//! leaf's `keyset_commitment`.
+//!
+//! The blocks enacting a validator set change are indexed by the
+//! [`BeefyBlockImport`](crate::import::BeefyBlockImport) as they are imported, so generating a
+//! proof doesn't need to walk through the headers of the chain.
+
+use codec::{Decode, DecodeAll, Encode};
+use parking_lot::Mutex;
+use sc_client_api::Backend as ClientBackend;
+use sc_network_sync::strategy::warp::{
+	AuthorityList, EncodedProof, SetId, VerificationResult, WarpSyncProvider,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::{Backend as BlockchainBackend, HeaderBackend};
+use sp_consensus_beefy::{
+	known_payloads::MMR_ROOT_ID,
+	mmr::{BeefyNextAuthoritySet, MmrLeaf},
+	AuthorityIdBound, BeefyApi, MmrRootHash, ValidatorSet, ValidatorSetId, VersionedFinalityProof,
+	BEEFY_ENGINE_ID,
+};
+use sp_consensus_grandpa::GrandpaApi;
+use sp_mmr_primitives::{
+	mmr_lib, utils::NodesUtils, DataOrHash, EncodableOpaqueLeaf, LeafProof, MmrApi, OpaqueLeaf,
+};
+use sp_runtime::traits::{
+	Block as BlockT, Convert, Hash as HashT, Header as HeaderT, Keccak256, NumberFor, One,
+	Saturating,
+};
+use std::{marker::PhantomData, sync::Arc};
+
+use crate::{
+	aux_schema, find_authorities_change,
+	justification::{verify_with_validator_set, BeefyVersionedFinalityProof},
+	LOG_TARGET,
+};
+
+/// Warp proof processing error.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Decoding error.
+	#[error("Failed to decode: {0}.")]
+	DecodeScale(#[from] codec::Error),
+	/// Client backend error.
+	#[error("{0}")]
+	Client(#[from] sp_blockchain::Error),
+	/// Runtime API error.
+	#[error("Runtime api error: {0}")]
+	RuntimeApi(#[from] sp_api::ApiError),
+	/// Invalid request data.
+	#[error("{0}")]
+	InvalidRequest(String),
+	/// Invalid warp proof.
+	#[error("{0}")]
+	InvalidProof(String),
+	/// Missing header, justification or MMR data.
+	#[error("Missing required data to be able to answer request.")]
+	MissingData,
+	/// Blocks following the start block are not indexed, because they were imported before the
+	/// index was started or have not been downloaded yet after a warp sync.
+	#[error("Missing the history following the start block.")]
+	MissingHistory,
+}
+
+/// The maximum size in bytes of the `WarpSyncProof`.
+pub(crate) const MAX_WARP_SYNC_PROOF_SIZE: usize = 8 * 1024 * 1024;
+
+/// Prefix of an MMR leaf built by `pallet-beefy-mmr`.
+///
+/// The chain specific `leaf_extra` data is not needed for warp syncing, so it is skipped when
+/// decoding. The leaf hash is always computed over the full opaque leaf.
+type LeafPrefix<Block> = MmrLeaf<NumberFor<Block>, <Block as BlockT>::Hash, MmrRootHash, ()>;
+
+/// MMR node hashing, matching the `Keccak256` based MMR used alongside BEEFY.
+type MmrNode = DataOrHash<Keccak256, OpaqueLeaf>;
+
+struct MmrHasher;
+
+impl mmr_lib::Merge for MmrHasher {
+	type Item = MmrNode;
+
+	fn merge(left: &Self::Item, right: &Self::Item) -> mmr_lib::Result<Self::Item> {
+		let mut concat = left.hash().as_ref().to_vec();
+		concat.extend_from_slice(right.hash().as_ref());
+
+		Ok(MmrNode::Hash(Keccak256::hash(&concat)))
+	}
+}
+
+/// A proof of BEEFY finality for a single header, optionally handing over to the next validator
+/// set.
+#[derive(Decode, Encode)]
+pub struct WarpSyncFragment<Block: BlockT, AuthorityId: AuthorityIdBound> {
+	/// The header of the block committed to by `leaf` as its parent.
+	pub header: Block::Header,
+	/// A BEEFY justification signed by the validator set the verifier currently trusts. Its
+	/// payload must contain the MMR root `leaf_proof` is checked against.
+	pub justification: BeefyVersionedFinalityProof<Block, AuthorityId>,
+	/// The MMR leaf added in the justified block.
+	pub leaf: EncodableOpaqueLeaf,
+	/// Proof of `leaf` against the MMR root signed in `justification`.
+	pub leaf_proof: LeafProof<MmrRootHash>,
+	/// The keys of the next validator set, if `leaf` commits to a validator set change.
+	pub next_validators: Option<Vec<AuthorityId>>,
+}
+
+/// An accumulated proof of multiple BEEFY validator set changes.
+#[derive(Decode, Encode)]
+pub struct WarpSyncProof<Block: BlockT, AuthorityId: AuthorityIdBound> {
+	proofs: Vec<WarpSyncFragment<Block, AuthorityId>>,
+	is_finished: bool,
+}
+
+/// Index of the blocks enacting a BEEFY validator set change, persisted in the aux-db.
+///
+/// Every imported block is indexed, so blocks of abandoned forks are kept in the index and have
+/// to be checked against the canonical chain.
+#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+pub(crate) struct SetChanges<N, H> {
+	/// The blocks imported before this one were not indexed.
+	indexed_from: N,
+	/// Blocks enacting a validator set change, in ascending order of their numbers.
+	blocks: Vec<(N, H)>,
+}
+
+impl<Block: BlockT, AuthorityId: AuthorityIdBound> WarpSyncProof<Block, AuthorityId> {
+	/// Generates a warp sync proof starting at the given block. It will generate validator set
+	/// handover proofs for all BEEFY sessions that started after `begin`, followed by a proof of
+	/// the latest BEEFY justification (capped by MAX_WARP_SYNC_PROOF_SIZE).
+	///
+	/// The validator set changes are taken from `set_changes`, which has to index every block
+	/// following `begin`.
+	fn generate<Backend, Runtime>(
+		backend: &Backend,
+		runtime: &Runtime,
+		begin: Block::Hash,
+		set_changes: &SetChanges<NumberFor<Block>, Block::Hash>,
+	) -> Result<Self, Error>
+	where
+		Backend: ClientBackend<Block>,
+		Runtime: ProvideRuntimeApi<Block>,
+		Runtime::Api: MmrApi<Block, MmrRootHash, NumberFor<Block>>,
+	{
+		let blockchain = backend.blockchain();
+		let info = blockchain.info();
+		let (finalized_number, finalized_hash) = (info.finalized_number, info.finalized_hash);
+
+		let begin_number = blockchain
+			.number(begin)?
+			.ok_or_else(|| Error::InvalidRequest("Missing start block".to_string()))?;
+
+		if begin_number > finalized_number {
+			return Err(Error::InvalidRequest("Start block is not finalized".to_string()))
+		}
+
+		if blockchain.hash(begin_number)? != Some(begin) {
+			return Err(Error::InvalidRequest(
+				"Start block is not in the finalized chain".to_string(),
+			))
+		}
+
+		if begin_number.saturating_add(One::one()) < set_changes.indexed_from ||
+			info.block_gap.is_some_and(|gap| gap.end > begin_number)
+		{
+			return Err(Error::MissingHistory)
+		}
+
+		// The header proven by a fragment is the parent of the justified block, so a
+		// justification for the block following `begin` has already been consumed.
+		let mut lower_bound = begin_number.saturating_add(One::one()).saturating_add(One::one());
+
+		let mut proofs = Vec::new();
+		let mut proofs_encoded_len = 0;
+		let mut proof_limit_reached = false;
+
+		let first_change = set_changes.blocks.partition_point(|(number, _)| *number < lower_bound);
+		for &(number, hash) in &set_changes.blocks[first_change..] {
+			if number > finalized_number {
+				break
+			}
+			if blockchain.hash(number)? != Some(hash) {
+				// The block is part of an abandoned fork.
+				continue
+			}
+			let header = expect_header::<Block, _>(blockchain, number)?;
+			let next_set =
+				find_authorities_change::<Block, AuthorityId>(&header).ok_or(Error::MissingData)?;
+
+			// Hand over to `next_set` using the last justification of the previous session.
+			if let Some(justified) =
+				last_justified_in::<Block, _, AuthorityId>(blockchain, lower_bound, number)?
+			{
+				let proof = Self::fragment(
+					blockchain,
+					runtime,
+					finalized_hash,
+					justified,
+					Some(next_set.validators().to_vec()),
+				)?;
+				let proof_size = proof.encoded_size();
+
+				// Check for the limit. We remove some bytes from the maximum size, because we're
+				// only counting the size of the `WarpSyncFragment`s. The extra margin is here to
+				// leave room for rest of the data (the size of the `Vec` and the boolean).
+				if proofs_encoded_len + proof_size >= MAX_WARP_SYNC_PROOF_SIZE - 50 {
+					proof_limit_reached = true;
+					break
+				}
+
+				proofs_encoded_len += proof_size;
+				proofs.push(proof);
+			}
+			lower_bound = number;
+		}
+
+		let is_finished = if proof_limit_reached {
+			false
+		} else if let Some(justified) = last_justified_in::<Block, _, AuthorityId>(
+			blockchain,
+			lower_bound,
+			finalized_number.saturating_add(One::one()),
+		)? {
+			let proof = Self::fragment(blockchain, runtime, finalized_hash, justified, None)?;
+
+			// Check for the limit. We remove some bytes from the maximum size, because we're only
+			// counting the size of the `WarpSyncFragment`s. The extra margin is here to leave
+			// room for rest of the data (the size of the `Vec` and the boolean).
+			if proofs_encoded_len + proof.encoded_size() >= MAX_WARP_SYNC_PROOF_SIZE - 50 {
+				false
+			} else {
+				proofs.push(proof);
+				true
+			}
+		} else {
+			true
+		};
+
+		let final_outcome = WarpSyncProof { proofs, is_finished };
+		debug_assert!(final_outcome.encoded_size() <= MAX_WARP_SYNC_PROOF_SIZE);
+		Ok(final_outcome)
+	}
+
+	/// Build a fragment proving the parent of the `justified` block.
+	fn fragment<Blockchain, Runtime>(
+		blockchain: &Blockchain,
+		runtime: &Runtime,
+		at: Block::Hash,
+		(justified_number, justification): (
+			NumberFor<Block>,
+			BeefyVersionedFinalityProof<Block, AuthorityId>,
+		),
+		next_validators: Option<Vec<AuthorityId>>,
+	) -> Result<WarpSyncFragment<Block, AuthorityId>, Error>
+	where
+		Blockchain: BlockchainBackend<Block>,
+		Runtime: ProvideRuntimeApi<Block>,
+		Runtime::Api: MmrApi<Block, MmrRootHash, NumberFor<Block>>,
+	{
+		let (mut leaves, leaf_proof) = runtime
+			.runtime_api()
+			.generate_proof(at, vec![justified_number], Some(justified_number))?
+			.map_err(|_| Error::MissingData)?;
+		let leaf = leaves.pop().ok_or(Error::MissingData)?;
+		let header =
+			expect_header::<Block, _>(blockchain, justified_number.saturating_sub(One::one()))?;
+
+		Ok(WarpSyncFragment { header, justification, leaf, leaf_proof, next_validators })
+	}
+
+	/// Verifies the warp sync proof starting at the given validator set. Verification stops when
+	/// either the proof is exhausted or finality for the target header can be proven. If the proof
+	/// is valid the new validator set is returned.
+	fn verify<AuthorityToMerkleLeaf>(
+		&self,
+		validator_set: ValidatorSet<AuthorityId>,
+	) -> Result<ValidatorSet<AuthorityId>, Error>
+	where
+		AuthorityToMerkleLeaf: Convert<AuthorityId, Vec<u8>>,
+	{
+		let mut current = validator_set;
+
+		for (fragment_num, proof) in self.proofs.iter().enumerate() {
+			let commitment = match proof.justification {
+				VersionedFinalityProof::V1(ref signed_commitment) => &signed_commitment.commitment,
+			};
+			if commitment.validator_set_id != current.id() {
+				return Err(Error::InvalidProof("Unexpected validator set id".to_string()))
+			}
+			verify_with_validator_set::<Block, AuthorityId>(
+				commitment.block_number,
+				&current,
+				&proof.justification,
+			)
+			.map_err(|(err, _)| Error::InvalidProof(err.to_string()))?;
+
+			let mmr_root = commitment
+				.payload
+				.get_decoded::<MmrRootHash>(&MMR_ROOT_ID)
+				.ok_or_else(|| Error::InvalidProof("Missing MMR root in payload".to_string()))?;
+			verify_leaf_proof(mmr_root, &proof.leaf, proof.leaf_proof.clone())?;
+
+			let leaf = LeafPrefix::<Block>::decode(&mut &proof.leaf.0[..])?;
+			let (parent_number, parent_hash) = leaf.parent_number_and_hash;
+			if parent_hash != proof.header.hash() ||
+				parent_number != *proof.header.number() ||
+				parent_number.saturating_add(One::one()) != commitment.block_number
+			{
+				return Err(Error::InvalidProof("Mismatch between header and MMR leaf".to_string()))
+			}
+
+			if let Some(ref next_validators) = proof.next_validators {
+				current = next_validator_set::<AuthorityId, AuthorityToMerkleLeaf>(
+					&leaf.beefy_next_authority_set,
+					current.id(),
+					next_validators,
+				)?;
+			} else if fragment_num != self.proofs.len() - 1 || !self.is_finished {
+				// Only the last fragment of the last proof message is allowed to be missing the
+				// validator set handover.
+				return Err(Error::InvalidProof(
+					"Fragment is missing validator set handover".to_string(),
+				))
+			}
+		}
+		Ok(current)
+	}
+}
+
+/// Index the block with `header` if it enacts a validator set change.
+///
+/// Returns the index to persist along with the block, if it changed. The index is started with the
+/// first imported block: if its parent is known, the blocks before it were imported without being
+/// indexed. Otherwise the block is the target of a warp sync and the blocks before it are indexed
+/// as they are downloaded.
+pub(crate) fn index_set_change<Block, Backend, AuthorityId>(
+	backend: &Backend,
+	header: &Block::Header,
+) -> Result<Option<SetChanges<NumberFor<Block>, Block::Hash>>, Error>
+where
+	Block: BlockT,
+	Backend: ClientBackend<Block>,
+	AuthorityId: AuthorityIdBound,
+{
+	let number = *header.number();
+	let is_change = find_authorities_change::<Block, AuthorityId>(header).is_some();
+	let mut set_changes = match aux_schema::load_warp_set_changes::<Block, _>(backend)? {
+		Some(_) if !is_change => return Ok(None),
+		Some(set_changes) => set_changes,
+		None => {
+			let parent_known = backend.blockchain().header(*header.parent_hash())?.is_some();
+			let indexed_from = if parent_known { number } else { One::one() };
+			SetChanges { indexed_from, blocks: Vec::new() }
+		},
+	};
+
+	if is_change {
+		let hash = header.hash();
+		let index = set_changes.blocks.partition_point(|(indexed, _)| *indexed < number);
+		if !set_changes.blocks[index..]
+			.iter()
+			.take_while(|(n, _)| *n == number)
+			.any(|b| b.1 == hash)
+		{
+			set_changes.blocks.insert(index, (number, hash));
+		}
+	}
+	Ok(Some(set_changes))
+}
+
+/// Read the canonical header at `number`.
+fn expect_header<Block: BlockT, Blockchain: BlockchainBackend<Block>>(
+	blockchain: &Blockchain,
+	number: NumberFor<Block>,
+) -> Result<Block::Header, Error> {
+	let hash = blockchain.hash(number)?.ok_or(Error::MissingData)?;
+	blockchain.header(hash)?.ok_or(Error::MissingData)
+}
+
+/// Find the highest block in `[from, to)` that has a BEEFY justification.
+fn last_justified_in<Block, Blockchain, AuthorityId>(
+	blockchain: &Blockchain,
+	from: NumberFor<Block>,
+	to: NumberFor<Block>,
+) -> Result<Option<(NumberFor<Block>, BeefyVersionedFinalityProof<Block, AuthorityId>)>, Error>
+where
+	Block: BlockT,
+	Blockchain: BlockchainBackend<Block>,
+	AuthorityId: AuthorityIdBound,
+{
+	let mut number = to;
+	while number > from {
+		number -= One::one();
+		let hash = blockchain.hash(number)?.ok_or(Error::MissingData)?;
+		if let Some(encoded) = blockchain
+			.justifications(hash)?
+			.and_then(|justifs| justifs.into_justification(BEEFY_ENGINE_ID))
+		{
+			let justification =
+				BeefyVersionedFinalityProof::<Block, AuthorityId>::decode_all(&mut &encoded[..])?;
+			return Ok(Some((number, justification)))
+		}
+	}
+	Ok(None)
+}
+
+/// Verify the MMR `leaf` against `root`.
+fn verify_leaf_proof(
+	root: MmrRootHash,
+	leaf: &EncodableOpaqueLeaf,
+	proof: LeafProof<MmrRootHash>,
+) -> Result<(), Error> {
+	let [leaf_index] = proof.leaf_indices[..] else {
+		return Err(Error::InvalidProof("Expected a single leaf proof".to_string()))
+	};
+	let size = NodesUtils::new(proof.leaf_count).size();
+	let leaf = MmrNode::Data(leaf.clone().into_opaque_leaf());
+
+	let merkle_proof = mmr_lib::MerkleProof::<MmrNode, MmrHasher>::new(
+		size,
+		proof.items.into_iter().map(MmrNode::Hash).collect(),
+	);
+	match merkle_proof
+		.verify(MmrNode::Hash(root), vec![(mmr_lib::leaf_index_to_pos(leaf_index), leaf)])
+	{
+		Ok(true) => Ok(()),
+		_ => Err(Error::InvalidProof("Invalid MMR leaf proof".to_string())),
+	}
+}
+
+/// Check `next_validators` against the commitment from the MMR leaf and build the next set.
+fn next_validator_set<AuthorityId, AuthorityToMerkleLeaf>(
+	committed: &BeefyNextAuthoritySet<MmrRootHash>,
+	current_id: ValidatorSetId,
+	next_validators: &[AuthorityId],
+) -> Result<ValidatorSet<AuthorityId>, Error>
+where
+	AuthorityId: AuthorityIdBound,
+	AuthorityToMerkleLeaf: Convert<AuthorityId, Vec<u8>>,
+{
+	if committed.id != current_id + 1 || committed.len as usize != next_validators.len() {
+		return Err(Error::InvalidProof("Unexpected next validator set".to_string()))
+	}
+
+	let keyset_commitment = binary_merkle_tree::merkle_root::<Keccak256, _>(
+		next_validators.iter().cloned().map(AuthorityToMerkleLeaf::convert),
+	);
+	if keyset_commitment != committed.keyset_commitment {
+		return Err(Error::InvalidProof("Next validator set keys mismatch".to_string()))
+	}
+
+	ValidatorSet::new(next_validators.to_vec(), committed.id)
+		.ok_or_else(|| Error::InvalidProof("Empty next validator set".to_string()))
+}
+
+/// Implements network API for warp sync using BEEFY finality proofs.
+///
+/// `AuthorityToMerkleLeaf` must match the conversion configured for `pallet-beefy-mmr` in the
+/// runtime (e.g. `pallet_beefy_mmr::BeefyEcdsaToEthereum`).
+///
+/// The BEEFY validator set trusted while verifying a proof is tracked by the provider itself, the
+/// GRANDPA `SetId` passed around by the syncing strategy mirrors its id. The GRANDPA authorities
+/// of the syncing strategy start with the genesis authorities and are handed back unchanged.
+///
+/// Proofs are generated from the index of validator set changes built by the
+/// [`BeefyBlockImport`](crate::import::BeefyBlockImport), so a node serves proofs starting at the
+/// blocks that were imported with BEEFY enabled.
+pub struct NetworkProvider<Block, Backend, Runtime, AuthorityId, AuthorityToMerkleLeaf>
+where
+	Block: BlockT,
+	AuthorityId: AuthorityIdBound,
+{
+	backend: Arc<Backend>,
+	runtime: Arc<Runtime>,
+	validator_set: Mutex<Option<ValidatorSet<AuthorityId>>>,
+	_phantom: PhantomData<(Block, AuthorityToMerkleLeaf)>,
+}
+
+impl<Block, Backend, Runtime, AuthorityId, AuthorityToMerkleLeaf>
+	NetworkProvider<Block, Backend, Runtime, AuthorityId, AuthorityToMerkleLeaf>
+where
+	Block: BlockT,
+	AuthorityId: AuthorityIdBound,
+{
+	/// Create a new instance for a given backend and runtime.
+	pub fn new(backend: Arc<Backend>, runtime: Arc<Runtime>) -> Self {
+		NetworkProvider { backend, runtime, validator_set: Mutex::new(None), _phantom: PhantomData }
+	}
+
+	/// The BEEFY validator set trusted after the last verified proof.
+	pub fn validator_set(&self) -> Option<ValidatorSet<AuthorityId>> {
+		self.validator_set.lock().clone()
+	}
+}
+
+impl<Block, Backend, Runtime, AuthorityId, AuthorityToMerkleLeaf>
+	NetworkProvider<Block, Backend, Runtime, AuthorityId, AuthorityToMerkleLeaf>
+where
+	Block: BlockT,
+	Backend: ClientBackend<Block>,
+	Runtime: ProvideRuntimeApi<Block>,
+	Runtime::Api: BeefyApi<Block, AuthorityId>,
+	AuthorityId: AuthorityIdBound,
+{
+	/// The validator set of the genesis block, where every warp sync starts.
+	fn genesis_validator_set(&self) -> Result<Option<ValidatorSet<AuthorityId>>, Error> {
+		let genesis_hash = self.backend.blockchain().info().genesis_hash;
+		Ok(self.runtime.runtime_api().validator_set(genesis_hash)?)
+	}
+}
+
+impl<Block, Backend, Runtime, AuthorityId, AuthorityToMerkleLeaf> WarpSyncProvider<Block>
+	for NetworkProvider<Block, Backend, Runtime, AuthorityId, AuthorityToMerkleLeaf>
+where
+	Block: BlockT,
+	Backend: ClientBackend<Block>,
+	Runtime: ProvideRuntimeApi<Block> + Send + Sync,
+	Runtime::Api: BeefyApi<Block, AuthorityId>
+		+ MmrApi<Block, MmrRootHash, NumberFor<Block>>
+		+ GrandpaApi<Block>,
+	AuthorityId: AuthorityIdBound,
+	AuthorityToMerkleLeaf: Convert<AuthorityId, Vec<u8>> + Send + Sync,
+{
+	fn generate(
+		&self,
+		start: Block::Hash,
+	) -> Result<EncodedProof, Box<dyn std::error::Error + Send + Sync>> {
+		let set_changes = aux_schema::load_warp_set_changes::<Block, _>(&*self.backend)?
+			.ok_or(Error::MissingHistory)
+			.map_err(Box::new)?;
+		let proof = WarpSyncProof::<Block, AuthorityId>::generate(
+			&*self.backend,
+			&*self.runtime,
+			start,
+			&set_changes,
+		)
+		.map_err(Box::new)?;
+		Ok(EncodedProof(proof.encode()))
+	}
+
+	fn verify(
+		&self,
+		proof: &EncodedProof,
+		set_id: SetId,
+		authorities: AuthorityList,
+	) -> Result<VerificationResult<Block>, Box<dyn std::error::Error + Send + Sync>> {
+		let EncodedProof(proof) = proof;
+		let proof = WarpSyncProof::<Block, AuthorityId>::decode_all(&mut proof.as_slice())
+			.map_err(|e| format!("Proof decoding error: {:?}", e))?;
+		let last_header = proof
+			.proofs
+			.last()
+			.map(|p| p.header.clone())
+			.ok_or_else(|| "Empty proof".to_string())?;
+
+		let mut validator_set = self.validator_set.lock();
+		let current = match validator_set.clone().filter(|current| current.id() == set_id) {
+			Some(current) => current,
+			// A new warp sync starts from the genesis validator set.
+			None => self
+				.genesis_validator_set()
+				.map_err(Box::new)?
+				.filter(|genesis| genesis.id() == set_id)
+				.ok_or_else(|| format!("Unknown BEEFY validator set {set_id}"))?,
+		};
+		let next = proof.verify::<AuthorityToMerkleLeaf>(current).map_err(Box::new)?;
+		let next_set_id = next.id();
+		*validator_set = Some(next);
+
+		if proof.is_finished {
+			Ok(VerificationResult::<Block>::Complete(next_set_id, authorities, last_header))
+		} else {
+			Ok(VerificationResult::<Block>::Partial(next_set_id, authorities, last_header.hash()))
+		}
+	}
+
+	/// The GRANDPA authorities of the genesis block. BEEFY keys can't be expressed as GRANDPA
+	/// authorities, the trusted validator set is returned by [`NetworkProvider::validator_set`].
+	fn current_authorities(&self) -> AuthorityList {
+		let genesis_hash = self.backend.blockchain().info().genesis_hash;
+		self.runtime
+			.runtime_api()
+			.grandpa_authorities(genesis_hash)
+			.unwrap_or_else(|e| {
+				log::warn!(target: LOG_TARGET, "🥩 Failed to read the genesis GRANDPA authorities: {e}");
+				Vec::new()
+			})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{add_auth_change_digest, make_beefy_ids, BeefyTestNet};
+	use codec::Encode;
+	use sc_client_api::AuxStore;
+	use sc_network_test::TestNetFactory;
+	use sp_api::ApiRef;
+	use sp_consensus::BlockOrigin;
+	use sp_consensus_beefy::{
+		ecdsa_crypto::AuthorityId, mmr::MmrLeafVersion, test_utils::Keyring, Commitment, Payload,
+		SignedCommitment,
+	};
+	use sp_consensus_grandpa::{OpaqueKeyOwnershipProof, SetId as GrandpaSetId};
+	use sp_core::{ed25519, H256};
+	use sp_mmr_primitives::Error as MmrError;
+	use substrate_test_runtime_client::{runtime::Block, Backend, ClientExt};
+
+	struct EncodeAuthority;
+
+	impl Convert<AuthorityId, Vec<u8>> for EncodeAuthority {
+		fn convert(authority: AuthorityId) -> Vec<u8> {
+			authority.encode()
+		}
+	}
+
+	fn commit_to(
+		validators: &[AuthorityId],
+		id: ValidatorSetId,
+	) -> BeefyNextAuthoritySet<MmrRootHash> {
+		BeefyNextAuthoritySet {
+			id,
+			len: validators.len() as u32,
+			keyset_commitment: binary_merkle_tree::merkle_root::<Keccak256, _>(
+				validators.iter().map(|v| v.encode()),
+			),
+		}
+	}
+
+	#[test]
+	fn next_validator_set_matches_keyset_commitment() {
+		let next = make_beefy_ids(&[Keyring::Alice, Keyring::Bob, Keyring::Charlie]);
+		let committed = commit_to(&next, 6);
+
+		let validator_set =
+			next_validator_set::<AuthorityId, EncodeAuthority>(&committed, 5, &next).unwrap();
+		assert_eq!(validator_set.id(), 6);
+		assert_eq!(validator_set.validators(), &next[..]);
+	}
+
+	#[test]
+	fn next_validator_set_rejects_mismatches() {
+		let next = make_beefy_ids(&[Keyring::Alice, Keyring::Bob, Keyring::Charlie]);
+		let committed = commit_to(&next, 6);
+
+		// wrong set id
+		assert!(next_validator_set::<AuthorityId, EncodeAuthority>(&committed, 6, &next).is_err());
+
+		// different keys with the same length
+		let other = make_beefy_ids(&[Keyring::Alice, Keyring::Bob, Keyring::Dave]);
+		assert!(next_validator_set::<AuthorityId, EncodeAuthority>(&committed, 5, &other).is_err());
+
+		// missing key
+		assert!(
+			next_validator_set::<AuthorityId, EncodeAuthority>(&committed, 5, &next[..2]).is_err()
+		);
+	}
+
+	#[derive(Clone)]
+	struct TestApi {
+		genesis_set: ValidatorSet<AuthorityId>,
+		leaves: Vec<EncodableOpaqueLeaf>,
+	}
+
+	fn grandpa_authorities() -> AuthorityList {
+		vec![(ed25519::Public::from_raw([1; 32]).into(), 1)]
+	}
+
+	// compiler gets confused and warns us about unused inner
+	#[allow(dead_code)]
+	struct RuntimeApi {
+		inner: TestApi,
+	}
+
+	impl ProvideRuntimeApi<Block> for TestApi {
+		type Api = RuntimeApi;
+		fn runtime_api(&self) -> ApiRef<'_, Self::Api> {
+			RuntimeApi { inner: self.clone() }.into()
+		}
+	}
+
+	sp_api::mock_impl_runtime_apis! {
+		impl BeefyApi<Block, AuthorityId> for RuntimeApi {
+			fn validator_set() -> Option<ValidatorSet<AuthorityId>> {
+				Some(self.inner.genesis_set.clone())
+			}
+		}
+
+		impl MmrApi<Block, MmrRootHash, NumberFor<Block>> for RuntimeApi {
+			fn generate_proof(
+				block_numbers: Vec<NumberFor<Block>>,
+				best_known_block_number: Option<NumberFor<Block>>,
+			) -> Result<(Vec<EncodableOpaqueLeaf>, LeafProof<MmrRootHash>), MmrError> {
+				let [number] = block_numbers[..] else { return Err(MmrError::GenerateProof) };
+				let leaf_count = best_known_block_number.ok_or(MmrError::GenerateProof)?;
+				let (_, proof) = mmr_root_and_proof(&self.inner.leaves[..leaf_count as usize], number);
+				Ok((vec![self.inner.leaves[number as usize - 1].clone()], proof))
+			}
+		}
+
+		impl GrandpaApi<Block> for RuntimeApi {
+			fn grandpa_authorities() -> AuthorityList {
+				grandpa_authorities()
+			}
+
+			fn submit_report_equivocation_unsigned_extrinsic(
+				_: sp_consensus_grandpa::EquivocationProof<H256, u64>,
+				_: OpaqueKeyOwnershipProof,
+			) -> Option<()> {
+				None
+			}
+
+			fn generate_key_ownership_proof(
+				_: GrandpaSetId,
+				_: sp_consensus_grandpa::AuthorityId,
+			) -> Option<OpaqueKeyOwnershipProof> {
+				None
+			}
+
+			fn current_set_id() -> GrandpaSetId {
+				0
+			}
+		}
+	}
+
+	/// Root of the MMR over `leaves` (the first one added in block 1) and proof of the leaf added
+	/// in block `number`.
+	fn mmr_root_and_proof(
+		leaves: &[EncodableOpaqueLeaf],
+		number: NumberFor<Block>,
+	) -> (MmrRootHash, LeafProof<MmrRootHash>) {
+		let store = mmr_lib::util::MemStore::default();
+		let mut mmr = mmr_lib::util::MemMMR::<MmrNode, MmrHasher>::new(0, &store);
+		for leaf in leaves {
+			mmr.push(MmrNode::Data(leaf.clone().into_opaque_leaf())).unwrap();
+		}
+		let leaf_index = number - 1;
+		let proof = mmr.gen_proof(vec![mmr_lib::leaf_index_to_pos(leaf_index)]).unwrap();
+		let proof = LeafProof {
+			leaf_indices: vec![leaf_index],
+			leaf_count: leaves.len() as u64,
+			items: proof.proof_items().iter().map(|item| item.hash()).collect(),
+		};
+		(mmr.get_root().unwrap().hash(), proof)
+	}
+
+	/// Sign a commitment to the MMR root at `number` by all `keys` of `validator_set`.
+	fn justification(
+		leaves: &[EncodableOpaqueLeaf],
+		number: NumberFor<Block>,
+		validator_set: &ValidatorSet<AuthorityId>,
+		keys: &[Keyring<AuthorityId>],
+	) -> BeefyVersionedFinalityProof<Block, AuthorityId> {
+		let (mmr_root, _) = mmr_root_and_proof(&leaves[..number as usize], number);
+		let commitment = Commitment {
+			payload: Payload::from_single_entry(MMR_ROOT_ID, mmr_root.encode()),
+			block_number: number,
+			validator_set_id: validator_set.id(),
+		};
+		let message = commitment.encode();
+		let signatures = keys.iter().map(|key| Some(key.sign(&message))).collect();
+		VersionedFinalityProof::V1(SignedCommitment { commitment, signatures })
+	}
+
+	/// Build and finalize 29 blocks with validator set changes enacted in blocks 10 and 20 and
+	/// BEEFY justifications for blocks 8, 15 and 25.
+	fn build_chain() -> (Arc<Backend>, TestApi, Vec<ValidatorSet<AuthorityId>>) {
+		let keys = [
+			vec![Keyring::Alice, Keyring::Bob],
+			vec![Keyring::Charlie, Keyring::Dave],
+			vec![Keyring::Eve, Keyring::Ferdie],
+			vec![Keyring::One],
+		];
+		let sets: Vec<_> = keys
+			.iter()
+			.enumerate()
+			.map(|(id, keys)| ValidatorSet::new(make_beefy_ids(keys), id as u64).unwrap())
+			.collect();
+		let session = |number: u64| number as usize / 10;
+
+		let mut net = BeefyTestNet::new(1);
+		let client = net.peer(0).client().as_client();
+		let mut number = 0;
+		net.peer(0).generate_blocks(29, BlockOrigin::File, |mut builder| {
+			number += 1;
+			if number % 10 == 0 {
+				add_auth_change_digest(&mut builder, sets[session(number)].clone());
+			}
+			builder.build().unwrap().block
+		});
+
+		let leaves: Vec<_> = (1..=29)
+			.map(|number| {
+				let parent_hash = client.hash(number - 1).unwrap().unwrap();
+				let next_set = &sets[session(number) + 1];
+				EncodableOpaqueLeaf::from_leaf(&MmrLeaf {
+					version: MmrLeafVersion::new(0, 0),
+					parent_number_and_hash: (number - 1, parent_hash),
+					beefy_next_authority_set: commit_to(next_set.validators(), next_set.id()),
+					leaf_extra: vec![42u8],
+				})
+			})
+			.collect();
+
+		for number in [8, 15, 25] {
+			let hash = client.hash(number).unwrap().unwrap();
+			let set = session(number);
+			let justification = justification(&leaves, number, &sets[set], &keys[set]);
+			client
+				.finalize_block(hash, Some((BEEFY_ENGINE_ID, justification.encode())))
+				.unwrap();
+		}
+		client.finalize_block(client.hash(29).unwrap().unwrap(), None).unwrap();
+
+		let api = TestApi { genesis_set: sets[0].clone(), leaves };
+		(net.peer(0).client().as_backend(), api, sets)
+	}
+
+	#[tokio::test]
+	async fn warp_proof_round_trip_works() {
+		let (backend, api, sets) = build_chain();
+		let genesis_hash = backend.blockchain().info().genesis_hash;
+		let provider = NetworkProvider::<_, _, _, AuthorityId, EncodeAuthority>::new(
+			backend.clone(),
+			Arc::new(api),
+		);
+
+		let proof = provider.generate(genesis_hash).unwrap();
+		let decoded = WarpSyncProof::<Block, AuthorityId>::decode_all(&mut &proof.0[..]).unwrap();
+		assert!(decoded.is_finished);
+		assert_eq!(
+			decoded.proofs.iter().map(|p| *p.header.number()).collect::<Vec<_>>(),
+			vec![7, 14, 24],
+		);
+
+		match provider.verify(&proof, 0, provider.current_authorities()).unwrap() {
+			VerificationResult::Complete(set_id, authorities, header) => {
+				assert_eq!(set_id, 2);
+				assert_eq!(authorities, grandpa_authorities());
+				assert_eq!(header.hash(), backend.blockchain().hash(24).unwrap().unwrap());
+			},
+			VerificationResult::Partial(..) => panic!("proof is expected to be complete"),
+		}
+		assert_eq!(provider.validator_set(), Some(sets[2].clone()));
+
+		// querying the authorities doesn't reset the tracked validator set
+		assert_eq!(provider.current_authorities(), grandpa_authorities());
+		assert_eq!(provider.validator_set(), Some(sets[2].clone()));
+
+		// the validator set changes are indexed on import
+		let set_changes =
+			aux_schema::load_warp_set_changes::<Block, _>(&*backend).unwrap().unwrap();
+		let hash = |number| backend.blockchain().hash(number).unwrap().unwrap();
+		assert_eq!(
+			set_changes,
+			SetChanges { indexed_from: 1, blocks: vec![(10, hash(10)), (20, hash(20))] }
+		);
+	}
+
+	#[tokio::test]
+	async fn warp_proof_needs_indexed_history() {
+		let (backend, api, sets) = build_chain();
+		let hash = |number| backend.blockchain().hash(number).unwrap().unwrap();
+		let provider = NetworkProvider::<_, _, _, AuthorityId, EncodeAuthority>::new(
+			backend.clone(),
+			Arc::new(api),
+		);
+
+		// blocks before 12 were imported before the index was started, and a validator set change
+		// in block 15 was imported on a fork.
+		let set_changes = SetChanges {
+			indexed_from: 12,
+			blocks: vec![(10, hash(10)), (15, H256::random()), (20, hash(20))],
+		};
+		let (key, value) = aux_schema::warp_set_changes_aux::<Block>(&set_changes);
+		AuxStore::insert_aux(&*backend, &[(&key[..], &value.unwrap()[..])], &[]).unwrap();
+
+		let error = WarpSyncProof::<Block, AuthorityId>::generate(
+			&*backend,
+			&*provider.runtime,
+			hash(10),
+			&set_changes,
+		)
+		.err()
+		.unwrap();
+		assert!(matches!(error, Error::MissingHistory));
+		assert!(provider.generate(hash(10)).is_err());
+
+		let proof = provider.generate(hash(11)).unwrap();
+		let decoded = WarpSyncProof::<Block, AuthorityId>::decode_all(&mut &proof.0[..]).unwrap();
+		assert_eq!(
+			decoded.proofs.iter().map(|p| *p.header.number()).collect::<Vec<_>>(),
+			vec![14, 24],
+		);
+		assert_eq!(decoded.verify::<EncodeAuthority>(sets[1].clone()).unwrap(), sets[2]);
+	}
+
+	#[tokio::test]
+	async fn warp_proof_from_later_block_works() {
+		let (backend, api, sets) = build_chain();
+		let start = backend.blockchain().hash(12).unwrap().unwrap();
+		let provider = NetworkProvider::<_, _, _, AuthorityId, EncodeAuthority>::new(
+			backend.clone(),
+			Arc::new(api),
+		);
+
+		let proof = provider.generate(start).unwrap();
+		let decoded = WarpSyncProof::<Block, AuthorityId>::decode_all(&mut &proof.0[..]).unwrap();
+		assert_eq!(
+			decoded.proofs.iter().map(|p| *p.header.number()).collect::<Vec<_>>(),
+			vec![14, 24],
+		);
+
+		// the proof starts at the second validator set
+		assert_eq!(decoded.verify::<EncodeAuthority>(sets[1].clone()).unwrap(), sets[2]);
+		assert!(decoded.verify::<EncodeAuthority>(sets[0].clone()).is_err());
+
+		// which is not known to a provider that has not verified the previous sets
+		assert!(provider.verify(&proof, 1, grandpa_authorities()).is_err());
+		assert_eq!(provider.validator_set(), None);
+	}
+
+	#[tokio::test]
+	async fn warp_proof_with_tampered_handover_fails() {
+		let (backend, api, _) = build_chain();
+		let genesis_hash = backend.blockchain().info().genesis_hash;
+		let provider = NetworkProvider::<_, _, _, AuthorityId, EncodeAuthority>::new(
+			backend.clone(),
+			Arc::new(api),
+		);
+
+		let proof = provider.generate(genesis_hash).unwrap();
+		let mut decoded =
+			WarpSyncProof::<Block, AuthorityId>::decode_all(&mut &proof.0[..]).unwrap();
+		decoded.proofs[0].next_validators = Some(make_beefy_ids(&[Keyring::Eve, Keyring::Dave]));
+
+		assert!(provider.verify(&EncodedProof(decoded.encode()), 0, Vec::new()).is_err());
+		assert_eq!(provider.validator_set(), None);
+	}
+}
@@ -32,6 +32,13 @@ pub trait RpcFinalityProofProvider<Block: BlockT> {
 		&self,
 		block: NumberFor<Block>,
 	) -> Result<Option<EncodedFinalityProof>, sc_consensus_grandpa::FinalityProofError>;
+
+	/// Prove finality for the given block number by returning the earliest stored Justification
+	/// for the block or one of its descendants.
+	fn rpc_prove_finality_at(
+		&self,
+		block: NumberFor<Block>,
+	) -> Result<Option<EncodedFinalityProof>, sc_consensus_grandpa::FinalityProofError>;
 }
 
 impl<B, Block> RpcFinalityProofProvider<Block> for FinalityProofProvider<B, Block>
@@ -46,4 +53,11 @@ where
 	) -> Result<Option<EncodedFinalityProof>, sc_consensus_grandpa::FinalityProofError> {
 		self.prove_finality(block).map(|x| x.map(|y| EncodedFinalityProof(y.into())))
 	}
+
+	fn rpc_prove_finality_at(
+		&self,
+		block: NumberFor<Block>,
+	) -> Result<Option<EncodedFinalityProof>, sc_consensus_grandpa::FinalityProofError> {
+		self.prove_finality_at(block).map(|x| x.map(|y| EncodedFinalityProof(y.into())))
+	}
 }
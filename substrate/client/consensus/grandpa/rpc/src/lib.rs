@@ -65,6 +65,14 @@ pub trait GrandpaApi<Notification, Hash, Number> {
 	/// in the set and all the intermediary headers to link them together.
 	#[method(name = "grandpa_proveFinality")]
 	async fn prove_finality(&self, block: Number) -> Result<Option<EncodedFinalityProof>, Error>;
+
+	/// Prove finality for the given block number by returning the earliest stored Justification
+	/// for it or one of its descendants and all the intermediary headers to link them together.
+	///
+	/// Any finalized block can be proven when the node stores every justification.
+	#[method(name = "grandpa_proveFinalityAt")]
+	async fn prove_finality_at(&self, block: Number)
+		-> Result<Option<EncodedFinalityProof>, Error>;
 }
 
 /// Provides RPC methods for interacting with GRANDPA.
@@ -126,6 +134,16 @@ where
 			error::Error::ProveFinalityFailed(e)
 		})
 	}
+
+	async fn prove_finality_at(
+		&self,
+		block: NumberFor<Block>,
+	) -> Result<Option<EncodedFinalityProof>, Error> {
+		self.finality_proof_provider.rpc_prove_finality_at(block).map_err(|e| {
+			warn!("Error proving finality: {}", e);
+			error::Error::ProveFinalityFailed(e)
+		})
+	}
 }
 
 #[cfg(test)]
@@ -143,7 +161,7 @@ mod tests {
 	use sp_blockchain::HeaderBackend;
 	use sp_core::crypto::ByteArray;
 	use sp_keyring::Ed25519Keyring;
-	use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+	use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
 	use substrate_test_runtime_client::{
 		runtime::{Block, Header, H256},
 		DefaultTestClientBuilderExt, TestClientBuilder, TestClientBuilderExt,
@@ -203,6 +221,20 @@ mod tests {
 					.into(),
 			)))
 		}
+
+		fn rpc_prove_finality_at(
+			&self,
+			block: NumberFor<Block>,
+		) -> Result<Option<EncodedFinalityProof>, sc_consensus_grandpa::FinalityProofError> {
+			let block: u64 = block.unique_saturated_into();
+			let mut finality_proof = self
+				.finality_proof
+				.clone()
+				.expect("Don't call rpc_prove_finality_at without setting the FinalityProof");
+			// Only the headers following the requested block link it to the justified block.
+			finality_proof.unknown_headers.retain(|header| *header.number() > block);
+			Ok(Some(EncodedFinalityProof(finality_proof.encode().into())))
+		}
 	}
 
 	impl ReportVoterState for TestVoterState {
@@ -412,4 +444,29 @@ mod tests {
 		let finality_proof_rpc: FinalityProof<Header> = Decode::decode(&mut &bytes[..]).unwrap();
 		assert_eq!(finality_proof_rpc, finality_proof);
 	}
+
+	#[tokio::test]
+	async fn prove_finality_at_with_test_finality_proof_provider() {
+		let finality_proof = FinalityProof {
+			block: header(42).hash(),
+			justification: create_justification().encode(),
+			unknown_headers: vec![header(40), header(41), header(42)],
+		};
+		let (rpc, _) =
+			setup_io_handler_with_finality_proofs(TestVoterState, Some(finality_proof.clone()));
+
+		let bytes: sp_core::Bytes = rpc.call("grandpa_proveFinalityAt", [40]).await.unwrap();
+		let finality_proof_rpc: FinalityProof<Header> = Decode::decode(&mut &bytes[..]).unwrap();
+		assert_eq!(
+			finality_proof_rpc,
+			FinalityProof {
+				unknown_headers: vec![header(41), header(42)],
+				..finality_proof.clone()
+			},
+		);
+
+		let bytes: sp_core::Bytes = rpc.call("grandpa_proveFinalityAt", [42]).await.unwrap();
+		let finality_proof_rpc: FinalityProof<Header> = Decode::decode(&mut &bytes[..]).unwrap();
+		assert_eq!(finality_proof_rpc, FinalityProof { unknown_headers: vec![], ..finality_proof });
+	}
 }
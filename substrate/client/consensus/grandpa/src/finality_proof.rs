@@ -46,6 +46,7 @@ use sp_consensus_grandpa::GRANDPA_ENGINE_ID;
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, Header as HeaderT, NumberFor, One},
+	SaturatedConversion,
 };
 
 use crate::{
@@ -130,6 +131,19 @@ where
 
 		prove_finality(&*self.backend, authority_set_changes, block, collect_unknown_headers)
 	}
+
+	/// Prove finality for the given block number by returning the earliest stored justification
+	/// for it or one of its descendants, together with the headers linking them.
+	///
+	/// Unlike [`Self::prove_finality`], this does not depend on authority set changes and can
+	/// prove any finalized block, as long as a justification is stored close enough to it (see
+	/// [`crate::ARCHIVE_JUSTIFICATION_PERIOD`]).
+	pub fn prove_finality_at(
+		&self,
+		block: NumberFor<Block>,
+	) -> Result<Option<Vec<u8>>, FinalityProofError> {
+		Ok(prove_finality_at(&*self.backend, block)?.map(|proof| proof.encode()))
+	}
 }
 
 /// Finality for block B is proved by providing:
@@ -256,6 +270,82 @@ where
 	}))
 }
 
+/// Prove finality for the given block number by returning the earliest justification stored for
+/// the block itself or any of its finalized descendants, falling back to the best justification.
+///
+/// All headers from the requested block until the block the justification refers to are included
+/// in the proof.
+fn prove_finality_at<Block, B>(
+	backend: &B,
+	block: NumberFor<Block>,
+) -> Result<Option<FinalityProof<Block::Header>>, FinalityProofError>
+where
+	Block: BlockT,
+	B: Backend<Block>,
+{
+	let blockchain = backend.blockchain();
+	let finalized_number = blockchain.info().finalized_number;
+	if finalized_number < block {
+		trace!(
+			target: LOG_TARGET,
+			"Requested finality proof at #{} while we only have finalized #{}.",
+			block,
+			finalized_number,
+		);
+		return Err(FinalityProofError::BlockNotYetFinalized)
+	}
+
+	let mut headers = Vec::new();
+	let mut current = block;
+	loop {
+		let hash = blockchain.expect_block_hash_from_id(&BlockId::Number(current))?;
+		if current != block {
+			headers.push(blockchain.expect_header(hash)?);
+		}
+
+		if let Some(justification) = blockchain
+			.justifications(hash)?
+			.and_then(|justifications| justifications.into_justification(GRANDPA_ENGINE_ID))
+		{
+			return Ok(Some(FinalityProof { block: hash, justification, unknown_headers: headers }))
+		}
+
+		if current >= finalized_number || headers.len() >= MAX_UNKNOWN_HEADERS {
+			break
+		}
+		current += One::one();
+	}
+
+	// None of the blocks we went through have a justification stored, the best justification can
+	// still be used if it is close enough to the requested block.
+	let Some(justification) = best_justification::<_, Block>(backend)? else {
+		trace!(
+			target: LOG_TARGET,
+			"No justification found when making finality proof at {}. Returning empty proof.",
+			block,
+		);
+		return Ok(None)
+	};
+
+	let (just_number, just_hash) = justification.target();
+	if just_number < block || just_number > current {
+		trace!(
+			target: LOG_TARGET,
+			"Best justification for #{} does not cover {}. Returning empty proof.",
+			just_number,
+			block,
+		);
+		return Ok(None)
+	}
+
+	headers.truncate((just_number - block).saturated_into());
+	Ok(Some(FinalityProof {
+		block: just_hash,
+		justification: justification.encode(),
+		unknown_headers: headers,
+	}))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -595,4 +685,68 @@ mod tests {
 			}
 		);
 	}
+
+	#[test]
+	fn finality_proof_at_uses_earliest_stored_justification() {
+		let (client, backend, blocks) = test_blockchain(8, &[4, 5]);
+		let block7 = &blocks[6];
+		let block8 = &blocks[7];
+
+		let commit = create_commit(block7.clone(), 7, 1, &[Ed25519Keyring::Alice]);
+		let grandpa_just7 = GrandpaJustification::from_commit(&client, 7, commit).unwrap();
+		client.finalize_block(block7.hash(), Some((ID, grandpa_just7.encode()))).unwrap();
+
+		let commit = create_commit(block8.clone(), 8, 1, &[Ed25519Keyring::Alice]);
+		let grandpa_just8 = GrandpaJustification::from_commit(&client, 8, commit).unwrap();
+		client.finalize_block(block8.hash(), Some((ID, grandpa_just8.encode()))).unwrap();
+
+		let proof_of_6: FinalityProof = prove_finality_at(&*backend, 6).unwrap().unwrap();
+		assert_eq!(
+			proof_of_6,
+			FinalityProof {
+				block: block7.hash(),
+				justification: grandpa_just7.encode(),
+				unknown_headers: vec![block7.header().clone()],
+			},
+		);
+
+		let proof_of_8: FinalityProof = prove_finality_at(&*backend, 8).unwrap().unwrap();
+		assert_eq!(
+			proof_of_8,
+			FinalityProof {
+				block: block8.hash(),
+				justification: grandpa_just8.encode(),
+				unknown_headers: vec![],
+			},
+		);
+
+		assert!(matches!(
+			prove_finality_at(&*backend, 9),
+			Err(FinalityProofError::BlockNotYetFinalized)
+		));
+	}
+
+	#[test]
+	fn finality_proof_at_falls_back_to_best_justification() {
+		let (client, backend, blocks) = test_blockchain(8, &[4, 5, 8]);
+		let block7 = &blocks[6];
+		let block8 = &blocks[7];
+
+		// Nothing stored and no best justification.
+		assert!(matches!(prove_finality_at(&*backend, 6), Ok(None)));
+
+		let commit = create_commit(block8.clone(), 8, 1, &[Ed25519Keyring::Alice]);
+		let grandpa_just8 = GrandpaJustification::from_commit(&client, 8, commit).unwrap();
+		store_best_justification(&client, &grandpa_just8);
+
+		let proof_of_6: FinalityProof = prove_finality_at(&*backend, 6).unwrap().unwrap();
+		assert_eq!(
+			proof_of_6,
+			FinalityProof {
+				block: block8.hash(),
+				justification: grandpa_just8.encode(),
+				unknown_headers: vec![block7.header().clone(), block8.header().clone()],
+			},
+		);
+	}
 }
@@ -209,6 +209,14 @@ impl Clone for SharedVoterState {
 	}
 }
 
+/// Justification period which makes GRANDPA store a justification for every block it finalizes.
+///
+/// This is an opt-in archive mode: use it both as the `justification_import_period` given to
+/// [`block_import`] and as [`Config::justification_generation_period`]. Together with
+/// [`FinalityProofProvider::prove_finality_at`] it allows serving finality proofs for any
+/// finalized block, at the expense of storing one justification per GRANDPA round.
+pub const ARCHIVE_JUSTIFICATION_PERIOD: u32 = 1;
+
 /// Configuration for the GRANDPA service
 #[derive(Clone)]
 pub struct Config {
@@ -216,7 +224,8 @@ pub struct Config {
 	pub gossip_duration: Duration,
 	/// Justification generation period (in blocks). GRANDPA will try to generate
 	/// justifications at least every justification_generation_period blocks. There
-	/// are some other events which might cause justification generation.
+	/// are some other events which might cause justification generation. Set it to
+	/// [`ARCHIVE_JUSTIFICATION_PERIOD`] to store every justification.
 	pub justification_generation_period: u32,
 	/// Whether the GRANDPA observer protocol is live on the network and thereby
 	/// a full-node not running as a validator is running the GRANDPA observer
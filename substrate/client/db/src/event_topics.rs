@@ -0,0 +1,145 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Index of the event topics recorded by `frame_system`.
+//!
+//! `frame_system` keeps the indices of the events of the current block per topic in its
+//! `EventTopics` map, which is cleared at the start of every block. On import, the entries of this
+//! map are taken from the storage changes of the block and stored in the `EVENT_TOPICS` column:
+//!
+//! - `lookup_key ++ topic` maps to the indices of the events with `topic` in the block.
+//! - `lookup_key` maps to the topics of the block, to remove its entries when it is pruned.
+//!
+//! The pallet is expected to be named `System` in the runtime.
+
+use crate::{columns, DbHash};
+use codec::{Decode, Encode};
+use sp_core::hashing::twox_128;
+use sp_database::{Database, Transaction};
+use sp_state_machine::StorageCollection;
+
+/// The topics of a block with the indices of their events.
+pub(crate) type BlockTopics = Vec<(Vec<u8>, Vec<u32>)>;
+
+/// The storage key prefix of `frame_system::EventTopics`.
+fn storage_prefix() -> Vec<u8> {
+	[twox_128(b"System"), twox_128(b"EventTopics")].concat()
+}
+
+fn topic_key(lookup_key: &[u8], topic: &[u8]) -> Vec<u8> {
+	[lookup_key, topic].concat()
+}
+
+/// Extract the topics of the block `number` from its storage changes.
+pub(crate) fn topics_from_changes<N: Decode + PartialEq>(
+	changes: &StorageCollection,
+	number: N,
+) -> BlockTopics {
+	let prefix = storage_prefix();
+	changes
+		.iter()
+		.filter_map(|(key, value)| {
+			// `EventTopics` is a `Blake2_128Concat` map.
+			let topic = key.strip_prefix(&prefix[..])?.get(16..)?;
+			let records = Vec::<(N, u32)>::decode(&mut &value.as_ref()?[..]).ok()?;
+			let indices = records
+				.into_iter()
+				.filter(|(n, _)| *n == number)
+				.map(|(_, index)| index)
+				.collect::<Vec<_>>();
+			(!indices.is_empty()).then(|| (topic.to_vec(), indices))
+		})
+		.collect()
+}
+
+/// Add the `topics` of the block with `lookup_key` to the index.
+pub(crate) fn insert(
+	transaction: &mut Transaction<DbHash>,
+	lookup_key: &[u8],
+	topics: BlockTopics,
+) {
+	if topics.is_empty() {
+		return
+	}
+
+	let mut block_topics = Vec::with_capacity(topics.len());
+	for (topic, indices) in topics {
+		transaction.set_from_vec(
+			columns::EVENT_TOPICS,
+			&topic_key(lookup_key, &topic),
+			indices.encode(),
+		);
+		block_topics.push(topic);
+	}
+	transaction.set_from_vec(columns::EVENT_TOPICS, lookup_key, block_topics.encode());
+}
+
+/// Remove the topics of the block with `lookup_key` from the index.
+pub(crate) fn remove(
+	transaction: &mut Transaction<DbHash>,
+	db: &dyn Database<DbHash>,
+	lookup_key: &[u8],
+) -> sp_blockchain::Result<()> {
+	let Some(topics) = db.get(columns::EVENT_TOPICS, lookup_key) else { return Ok(()) };
+	let topics = Vec::<Vec<u8>>::decode(&mut &topics[..]).map_err(|err| {
+		sp_blockchain::Error::Backend(format!("Error decoding event topics: {err}"))
+	})?;
+	for topic in topics {
+		transaction.remove(columns::EVENT_TOPICS, &topic_key(lookup_key, &topic));
+	}
+	transaction.remove(columns::EVENT_TOPICS, lookup_key);
+	Ok(())
+}
+
+/// The indices of the events with `topic` in the block with `lookup_key`.
+pub(crate) fn read(
+	db: &dyn Database<DbHash>,
+	lookup_key: &[u8],
+	topic: &[u8],
+) -> sp_blockchain::Result<Vec<u32>> {
+	match db.get(columns::EVENT_TOPICS, &topic_key(lookup_key, topic)) {
+		Some(indices) => Vec::<u32>::decode(&mut &indices[..]).map_err(|err| {
+			sp_blockchain::Error::Backend(format!("Error decoding event indices: {err}"))
+		}),
+		None => Ok(Vec::new()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn topic_storage_key(topic: &[u8]) -> Vec<u8> {
+		[storage_prefix(), sp_core::hashing::blake2_128(topic).to_vec(), topic.to_vec()].concat()
+	}
+
+	#[test]
+	fn topics_from_changes_works() {
+		let changes = vec![
+			(topic_storage_key(b"a"), Some(vec![(7u64, 0u32), (7, 3)].encode())),
+			// removed topics of the previous block.
+			(topic_storage_key(b"b"), None),
+			// records of another block are ignored.
+			(topic_storage_key(b"c"), Some(vec![(6u64, 1u32)].encode())),
+			// other storage is ignored.
+			(b"other".to_vec(), Some(vec![(7u64, 1u32)].encode())),
+		];
+
+		assert_eq!(topics_from_changes(&changes, 7u64), vec![(b"a".to_vec(), vec![0, 3])]);
+	}
+}
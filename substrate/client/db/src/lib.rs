@@ -33,6 +33,7 @@ pub mod offchain;
 pub mod bench;
 
 mod children;
+mod event_topics;
 mod parity_db;
 mod pinned_blocks_cache;
 mod record_stats_state;
@@ -313,6 +314,10 @@ pub struct DatabaseSettings {
 	///
 	/// NOTE: only finalized blocks are subject for removal!
 	pub blocks_pruning: BlocksPruning,
+	/// Index the event topics recorded by `frame_system` on import.
+	///
+	/// See [`sc_client_api::backend::EventTopicsIndex`].
+	pub event_topics_index: bool,
 
 	/// Prometheus metrics registry.
 	pub metrics_registry: Option<Registry>,
@@ -442,6 +447,8 @@ pub(crate) mod columns {
 	/// Transactions
 	pub const TRANSACTION: u32 = 11;
 	pub const BODY_INDEX: u32 = 12;
+	/// Event topics index
+	pub const EVENT_TOPICS: u32 = 13;
 }
 
 struct PendingBlock<Block: BlockT> {
@@ -1115,6 +1122,7 @@ pub struct Backend<Block: BlockT> {
 	import_lock: Arc<RwLock<()>>,
 	is_archive: bool,
 	blocks_pruning: BlocksPruning,
+	event_topics_index: bool,
 	io_stats: FrozenForDuration<(kvdb::IoStats, StateUsageInfo)>,
 	state_usage: Arc<StateUsageStats>,
 	genesis_state: RwLock<Option<Arc<DbGenesisStorage<Block>>>>,
@@ -1175,6 +1183,7 @@ impl<Block: BlockT> Backend<Block> {
 			state_pruning: Some(state_pruning),
 			source: DatabaseSource::Custom { db, require_create_flag: true },
 			blocks_pruning,
+			event_topics_index: false,
 			metrics_registry: None,
 		};
 
@@ -1265,6 +1274,7 @@ impl<Block: BlockT> Backend<Block> {
 			io_stats: FrozenForDuration::new(std::time::Duration::from_secs(1)),
 			state_usage: Arc::new(StateUsageStats::new()),
 			blocks_pruning: config.blocks_pruning,
+			event_topics_index: config.event_topics_index,
 			genesis_state: RwLock::new(None),
 			shared_trie_cache,
 		};
@@ -1548,6 +1558,10 @@ impl<Block: BlockT> Backend<Block> {
 					justifications.encode(),
 				);
 			}
+			if self.event_topics_index {
+				let topics = event_topics::topics_from_changes(&operation.storage_updates, number);
+				event_topics::insert(&mut transaction, &lookup_key, topics);
+			}
 
 			if number.is_zero() {
 				transaction.set(columns::META, meta_keys::GENESIS_HASH, hash.as_ref());
@@ -2001,6 +2015,11 @@ impl<Block: BlockT> Backend<Block> {
 					))),
 			}
 		}
+		if let Some(lookup_key) =
+			utils::block_id_to_lookup_key(&*self.storage.db, columns::KEY_LOOKUP, id)?
+		{
+			event_topics::remove(transaction, &*self.storage.db, &lookup_key)?;
+		}
 		Ok(())
 	}
 
@@ -2129,6 +2148,43 @@ where
 	}
 }
 
+impl<Block: BlockT> sc_client_api::backend::EventTopicsIndex<Block> for Backend<Block> {
+	fn events_by_topic(
+		&self,
+		topic: &[u8],
+		from: NumberFor<Block>,
+		to: NumberFor<Block>,
+	) -> ClientResult<Vec<(NumberFor<Block>, Block::Hash, u32)>> {
+		if !self.event_topics_index {
+			return Err(sp_blockchain::Error::Backend("Event topics index is not enabled".into()))
+		}
+
+		let mut events = Vec::new();
+		let (from, to): (u64, u64) = (from.saturated_into(), to.saturated_into());
+		for number in from..=to {
+			let number = NumberFor::<Block>::saturated_from(number);
+			let Some(lookup_key) = utils::block_id_to_lookup_key::<Block>(
+				&*self.storage.db,
+				columns::KEY_LOOKUP,
+				BlockId::Number(number),
+			)?
+			else {
+				continue
+			};
+			let indices = event_topics::read(&*self.storage.db, &lookup_key, topic)?;
+			if indices.is_empty() {
+				continue
+			}
+			// lookup keys are the big-endian `u32` block number followed by the block hash.
+			let hash = Block::Hash::decode(&mut &lookup_key[4..]).map_err(|err| {
+				sp_blockchain::Error::Backend(format!("Error decoding block hash: {err}"))
+			})?;
+			events.extend(indices.into_iter().map(|index| (number, hash, index)));
+		}
+		Ok(events)
+	}
+}
+
 impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 	type BlockImportOperation = BlockImportOperation<Block>;
 	type Blockchain = BlockchainDb<Block>;
@@ -2381,6 +2437,11 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 							}
 						}
 						transaction.set_from_vec(columns::META, meta_keys::BEST_BLOCK, key);
+						event_topics::remove(
+							&mut transaction,
+							&*self.storage.db,
+							&utils::number_and_hash_to_lookup_key(*removed.number(), removed_hash)?,
+						)?;
 						transaction.remove(columns::KEY_LOOKUP, removed.hash().as_ref());
 						children::remove_children(
 							&mut transaction,
@@ -2450,6 +2511,11 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 		if let Some(commit) = self.storage.state_db.remove(&hash) {
 			apply_state_commit(&mut transaction, commit);
 		}
+		event_topics::remove(
+			&mut transaction,
+			&*self.storage.db,
+			&utils::number_and_hash_to_lookup_key(hdr.number, hash)?,
+		)?;
 		transaction.remove(columns::KEY_LOOKUP, hash.as_ref());
 
 		let children: Vec<_> = self
@@ -2813,6 +2879,7 @@ pub(crate) mod tests {
 				state_pruning: Some(PruningMode::blocks_pruning(1)),
 				source: DatabaseSource::Custom { db: backing, require_create_flag: false },
 				blocks_pruning: BlocksPruning::KeepFinalized,
+				event_topics_index: false,
 				metrics_registry: None,
 			},
 			0,
@@ -3812,6 +3879,140 @@ pub(crate) mod tests {
 		}
 	}
 
+	fn event_topics_backend(blocks_pruning: u32) -> Backend<Block> {
+		Backend::<Block>::new(
+			DatabaseSettings {
+				trie_cache_maximum_size: None,
+				state_pruning: Some(PruningMode::blocks_pruning(blocks_pruning)),
+				source: DatabaseSource::Custom {
+					db: sp_database::as_database(kvdb_memorydb::create(crate::utils::NUM_COLUMNS)),
+					require_create_flag: true,
+				},
+				blocks_pruning: BlocksPruning::Some(blocks_pruning),
+				event_topics_index: true,
+				metrics_registry: None,
+			},
+			0,
+		)
+		.unwrap()
+	}
+
+	/// Import a block which emits the events at `indices` with the topic `a`.
+	fn insert_block_with_event_topics(
+		backend: &Backend<Block>,
+		number: u64,
+		parent_hash: H256,
+		indices: &[u32],
+		state: NewBlockState,
+	) -> H256 {
+		let topic_key = [
+			sp_core::hashing::twox_128(b"System").to_vec(),
+			sp_core::hashing::twox_128(b"EventTopics").to_vec(),
+			sp_core::hashing::blake2_128(b"a").to_vec(),
+			b"a".to_vec(),
+		]
+		.concat();
+
+		let mut op = backend.begin_operation().unwrap();
+		let state_hash = if number == 0 { Default::default() } else { parent_hash };
+		backend.begin_state_operation(&mut op, state_hash).unwrap();
+		let (root, overlay) = op.old_state.storage_root(
+			vec![(state_hash.as_ref(), Some(state_hash.as_ref()))].into_iter(),
+			StateVersion::V1,
+		);
+		op.update_db_storage(overlay).unwrap();
+		let records = indices.iter().map(|index| (number, *index)).collect::<Vec<_>>();
+		op.update_storage(vec![(topic_key, Some(records.encode()))], Vec::new())
+			.unwrap();
+
+		let header = Header {
+			number,
+			parent_hash,
+			state_root: root,
+			digest: Default::default(),
+			// Forks only differ by their events.
+			extrinsics_root: sp_core::hashing::blake2_256(&records.encode()).into(),
+		};
+		op.set_block_data(header.clone(), Some(Vec::new()), None, None, state).unwrap();
+		backend.commit_operation(op).unwrap();
+		header.hash()
+	}
+
+	#[test]
+	fn event_topics_are_indexed_and_pruned() {
+		use sc_client_api::backend::EventTopicsIndex;
+
+		let backend = event_topics_backend(2);
+
+		let mut blocks = Vec::new();
+		let mut prev_hash = Default::default();
+		for number in 0..5u64 {
+			// Every odd block emits the events `0` and `2` with topic `a`.
+			let indices: &[u32] = if number % 2 == 1 { &[0, 2] } else { &[] };
+			prev_hash = insert_block_with_event_topics(
+				&backend,
+				number,
+				prev_hash,
+				indices,
+				NewBlockState::Best,
+			);
+			blocks.push(prev_hash);
+		}
+
+		assert_eq!(
+			backend.events_by_topic(b"a", 0, 10).unwrap(),
+			vec![(1, blocks[1], 0), (1, blocks[1], 2), (3, blocks[3], 0), (3, blocks[3], 2)],
+		);
+		assert_eq!(
+			backend.events_by_topic(b"a", 2, 3).unwrap(),
+			vec![(3, blocks[3], 0), (3, blocks[3], 2)]
+		);
+		assert!(backend.events_by_topic(b"b", 0, 10).unwrap().is_empty());
+
+		let mut op = backend.begin_operation().unwrap();
+		backend.begin_state_operation(&mut op, blocks[4]).unwrap();
+		for hash in &blocks[1..] {
+			op.mark_finalized(*hash, None).unwrap();
+		}
+		backend.commit_operation(op).unwrap();
+
+		assert_eq!(
+			backend.events_by_topic(b"a", 0, 10).unwrap(),
+			vec![(3, blocks[3], 0), (3, blocks[3], 2)]
+		);
+	}
+
+	#[test]
+	fn event_topics_are_removed_with_reverted_and_removed_leaf_blocks() {
+		let backend = event_topics_backend(10);
+		let indexed = |number: u64, hash: H256| {
+			let lookup_key = utils::number_and_hash_to_lookup_key(number, hash).unwrap();
+			backend.storage.db.get(columns::EVENT_TOPICS, &lookup_key).is_some()
+		};
+
+		let genesis = insert_block_with_event_topics(
+			&backend,
+			0,
+			Default::default(),
+			&[],
+			NewBlockState::Best,
+		);
+		let block1 =
+			insert_block_with_event_topics(&backend, 1, genesis, &[0], NewBlockState::Best);
+		let block2 = insert_block_with_event_topics(&backend, 2, block1, &[0], NewBlockState::Best);
+		let fork2 =
+			insert_block_with_event_topics(&backend, 2, block1, &[1], NewBlockState::Normal);
+		assert!(indexed(2, block2));
+		assert!(indexed(2, fork2));
+
+		backend.remove_leaf_block(fork2).unwrap();
+		assert!(!indexed(2, fork2));
+
+		assert_eq!(backend.revert(1, false).unwrap().0, 1);
+		assert!(!indexed(2, block2));
+		assert!(indexed(1, block1));
+	}
+
 	#[test]
 	fn prune_blocks_on_finalize_with_fork() {
 		sp_tracing::try_init_simple();
@@ -16,7 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use super::{config::Config, error::Error, request::Request};
+use super::{config::Config, error::Error, request::Request, status::Status};
 use futures::{
 	channel::{mpsc, oneshot},
 	SinkExt,
@@ -27,19 +27,22 @@ use std::future::Future;
 /// The other end of an [`Api`]. This should be passed to [`run`](super::run::run).
 pub struct ApiBackend {
 	pub(super) request_receiver: mpsc::Receiver<Request>,
+	pub(super) status_receiver: mpsc::Receiver<oneshot::Sender<Status>>,
 }
 
 /// Interface to the mixnet service.
 #[derive(Clone)]
 pub struct Api {
 	request_sender: mpsc::Sender<Request>,
+	status_sender: mpsc::Sender<oneshot::Sender<Status>>,
 }
 
 impl Api {
 	/// Create a new `Api`. The [`ApiBackend`] should be passed to [`run`](super::run::run).
 	pub fn new(config: &Config) -> (Self, ApiBackend) {
 		let (request_sender, request_receiver) = mpsc::channel(config.substrate.request_buffer);
-		(Self { request_sender }, ApiBackend { request_receiver })
+		let (status_sender, status_receiver) = mpsc::channel(config.substrate.request_buffer);
+		(Self { request_sender, status_sender }, ApiBackend { request_receiver, status_receiver })
 	}
 
 	/// Submit an extrinsic via the mixnet.
@@ -66,4 +69,14 @@ impl Api {
 			reply_receiver.await.map_err(|_| Error::ServiceUnavailable)?
 		}
 	}
+
+	/// Get the health and topology of the mixnet service.
+	pub async fn status(&mut self) -> Result<Status, Error> {
+		let (reply_sender, reply_receiver) = oneshot::channel();
+		self.status_sender
+			.send(reply_sender)
+			.await
+			.map_err(|_| Error::ServiceUnavailable)?;
+		reply_receiver.await.map_err(|_| Error::ServiceUnavailable)
+	}
 }
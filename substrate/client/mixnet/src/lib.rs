@@ -27,11 +27,13 @@ mod config;
 mod error;
 mod extrinsic_queue;
 mod maybe_inf_delay;
+mod metrics;
 mod packet_dispatcher;
 mod peer_id;
 mod protocol;
 mod request;
 mod run;
+mod status;
 mod sync_with_runtime;
 
 pub use self::{
@@ -40,5 +42,8 @@ pub use self::{
 	error::{Error, RemoteErr},
 	protocol::{peers_set_config, protocol_name},
 	run::run,
+	status::{
+		CoverTrafficStatus, MixnodeStatus, PeerPacketStats, SessionPhase, SessionTopology, Status,
+	},
 };
 pub use mixnet::core::{KxSecret, PostErr, TopologyErr};
@@ -0,0 +1,115 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prometheus metrics of the mixnet service.
+
+use prometheus_endpoint::{
+	register, Counter, CounterVec, Gauge, Opts, PrometheusError, Registry, U64,
+};
+
+/// Reason for dropping a packet that should have been sent to a peer.
+#[derive(Clone, Copy)]
+pub enum DropReason {
+	/// The peer is not connected.
+	NotConnected,
+	/// The queue of packets waiting to be sent to the peer is full.
+	QueueFull,
+	/// Sending to the peer failed.
+	SendFailed,
+	/// The mixnet peer ID of the peer could not be converted to a libp2p peer ID.
+	InvalidPeerId,
+}
+
+impl DropReason {
+	fn as_str(&self) -> &'static str {
+		match self {
+			Self::NotConnected => "not_connected",
+			Self::QueueFull => "queue_full",
+			Self::SendFailed => "send_failed",
+			Self::InvalidPeerId => "invalid_peer_id",
+		}
+	}
+}
+
+#[derive(Clone)]
+pub struct Metrics {
+	pub packets_received: Counter<U64>,
+	pub packets_sent: Counter<U64>,
+	packets_dropped: CounterVec<U64>,
+	pub authored_packets: Counter<U64>,
+	pub connected_peers: Gauge<U64>,
+	pub session_index: Gauge<U64>,
+	pub connected_mixnodes: Gauge<U64>,
+}
+
+impl Metrics {
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			packets_received: register(
+				Counter::new("substrate_mixnet_packets_received", "Number of packets received")?,
+				registry,
+			)?,
+			packets_sent: register(
+				Counter::new("substrate_mixnet_packets_sent", "Number of packets sent to peers")?,
+				registry,
+			)?,
+			packets_dropped: register(
+				CounterVec::new(
+					Opts::new(
+						"substrate_mixnet_packets_dropped",
+						"Number of packets dropped instead of being sent to a peer",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
+			authored_packets: register(
+				Counter::new(
+					"substrate_mixnet_authored_packets",
+					"Number of packets authored by the local node, including cover packets",
+				)?,
+				registry,
+			)?,
+			connected_peers: register(
+				Gauge::new(
+					"substrate_mixnet_connected_peers",
+					"Number of peers with an open mixnet substream",
+				)?,
+				registry,
+			)?,
+			session_index: register(
+				Gauge::new(
+					"substrate_mixnet_session_index",
+					"Index of the current mixnet session",
+				)?,
+				registry,
+			)?,
+			connected_mixnodes: register(
+				Gauge::new(
+					"substrate_mixnet_connected_mixnodes",
+					"Number of mixnodes of the current session the local node is connected to",
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	pub fn dropped(&self, reason: DropReason, num_packets: u64) {
+		self.packets_dropped.with_label_values(&[reason.as_str()]).inc_by(num_packets);
+	}
+}
@@ -18,33 +18,57 @@
 
 //! [`AddressedPacket`] dispatching.
 
-use super::peer_id::{from_core_peer_id, to_core_peer_id};
+use super::{
+	metrics::{DropReason, Metrics},
+	peer_id::{from_core_peer_id, to_core_peer_id},
+	status::PeerPacketStats,
+};
 use arrayvec::ArrayVec;
 use log::{debug, warn};
 use mixnet::core::{AddressedPacket, NetworkStatus, Packet, PeerId as CorePeerId};
 use parking_lot::Mutex;
 use sc_network::NotificationService;
 use sc_network_types::PeerId;
-use std::{collections::HashMap, future::Future, sync::Arc};
+use std::{
+	collections::HashMap,
+	future::Future,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+};
 
 const LOG_TARGET: &str = "mixnet";
 
-/// Packet queue for a peer.
+/// Packet queue for a peer, along with packet statistics for the peer.
 ///
 /// Ideally we would use `Rc<RefCell<_>>`, but that would prevent the top-level future from being
 /// automatically marked `Send`. I believe it would be safe to manually mark it `Send`, but using
 /// `Arc<Mutex<_>>` here is not really a big deal.
-struct PeerQueue(Mutex<ArrayVec<Box<Packet>, 2>>);
+struct PeerQueue {
+	packets: Mutex<ArrayVec<Box<Packet>, 2>>,
+	/// Number of packets received from the peer.
+	received: AtomicU64,
+	/// Number of packets successfully sent to the peer.
+	sent: AtomicU64,
+	/// Number of packets to the peer that were dropped.
+	dropped: AtomicU64,
+}
 
 impl PeerQueue {
 	fn new() -> Self {
-		Self(Mutex::new(ArrayVec::new()))
+		Self {
+			packets: Mutex::new(ArrayVec::new()),
+			received: AtomicU64::new(0),
+			sent: AtomicU64::new(0),
+			dropped: AtomicU64::new(0),
+		}
 	}
 
 	/// Push `packet` onto the queue. Returns `true` if the queue was previously empty. Fails if
 	/// the queue is full.
 	fn push(&self, packet: Box<Packet>) -> Result<bool, ()> {
-		let mut queue = self.0.lock();
+		let mut queue = self.packets.lock();
 		if queue.is_full() {
 			Err(())
 		} else {
@@ -54,19 +78,26 @@ impl PeerQueue {
 		}
 	}
 
-	/// Drop all packets from the queue.
-	fn clear(&self) {
-		let mut queue = self.0.lock();
+	/// Drop all packets from the queue. Returns the number of dropped packets.
+	fn clear(&self) -> u64 {
+		let mut queue = self.packets.lock();
+		let num_packets = queue.len() as u64;
 		queue.clear();
+		self.dropped.fetch_add(num_packets, Ordering::Relaxed);
+		num_packets
 	}
 
 	/// Pop the packet at the head of the queue and return it, or, if the queue is empty, return
 	/// `None`. Also returns `true` if there are more packets in the queue.
 	fn pop(&self) -> (Option<Box<Packet>>, bool) {
-		let mut queue = self.0.lock();
+		let mut queue = self.packets.lock();
 		let packet = queue.pop();
 		(packet, !queue.is_empty())
 	}
+
+	fn len(&self) -> usize {
+		self.packets.lock().len()
+	}
 }
 
 /// A peer which has packets ready to send but is not currently being serviced.
@@ -74,6 +105,7 @@ pub struct ReadyPeer {
 	id: PeerId,
 	/// The peer's packet queue. Not empty.
 	queue: Arc<PeerQueue>,
+	metrics: Option<Metrics>,
 }
 
 impl ReadyPeer {
@@ -91,7 +123,7 @@ impl ReadyPeer {
 					target: LOG_TARGET,
 					"Failed to get message sink for peer ID {}", self.id,
 				);
-				self.queue.clear();
+				self.drop_packets(DropReason::NotConnected, 0);
 				None
 			},
 			Some(sink) => Some(async move {
@@ -99,19 +131,36 @@ impl ReadyPeer {
 				let packet = packet.expect("Should only be called if there is a packet to send");
 
 				match sink.send_async_notification((packet as Box<[_]>).into()).await {
-					Ok(_) => more_packets.then_some(self),
+					Ok(_) => {
+						self.queue.sent.fetch_add(1, Ordering::Relaxed);
+						if let Some(metrics) = &self.metrics {
+							metrics.packets_sent.inc();
+						}
+						more_packets.then_some(self)
+					},
 					Err(err) => {
 						debug!(
 							target: LOG_TARGET,
 							"Failed to send packet to peer ID {}: {err}", self.id,
 						);
-						self.queue.clear();
+						// The popped packet is lost as well
+						self.drop_packets(DropReason::SendFailed, 1);
 						None
 					},
 				}
 			}),
 		}
 	}
+
+	/// Drop all packets queued for the peer, along with `num_popped` packets already taken from
+	/// the queue, for the given `reason`.
+	fn drop_packets(&self, reason: DropReason, num_popped: u64) {
+		self.queue.dropped.fetch_add(num_popped, Ordering::Relaxed);
+		let num_packets = self.queue.clear() + num_popped;
+		if let Some(metrics) = &self.metrics {
+			metrics.dropped(reason, num_packets);
+		}
+	}
 }
 
 pub struct PacketDispatcher {
@@ -120,11 +169,12 @@ pub struct PacketDispatcher {
 	/// Packet queue for each connected peer. These queues are very short and only exist to give
 	/// packets somewhere to sit while waiting for notification senders to be ready.
 	peer_queues: HashMap<CorePeerId, Arc<PeerQueue>>,
+	metrics: Option<Metrics>,
 }
 
 impl PacketDispatcher {
-	pub fn new(local_peer_id: &CorePeerId) -> Self {
-		Self { local_peer_id: *local_peer_id, peer_queues: HashMap::new() }
+	pub fn new(local_peer_id: &CorePeerId, metrics: Option<Metrics>) -> Self {
+		Self { local_peer_id: *local_peer_id, peer_queues: HashMap::new(), metrics }
 	}
 
 	pub fn add_peer(&mut self, id: &PeerId) {
@@ -136,6 +186,7 @@ impl PacketDispatcher {
 		if self.peer_queues.insert(core_id, Arc::new(PeerQueue::new())).is_some() {
 			warn!(target: LOG_TARGET, "Two stream opened notifications for peer ID {id}");
 		}
+		self.update_connected_peers_metric();
 	}
 
 	pub fn remove_peer(&mut self, id: &PeerId) {
@@ -147,6 +198,39 @@ impl PacketDispatcher {
 		if self.peer_queues.remove(&core_id).is_none() {
 			warn!(target: LOG_TARGET, "Stream closed notification for unknown peer ID {id}");
 		}
+		self.update_connected_peers_metric();
+	}
+
+	/// Note that a packet was received from the given peer.
+	pub fn note_received(&self, id: &PeerId) {
+		if let Some(metrics) = &self.metrics {
+			metrics.packets_received.inc();
+		}
+		if let Some(queue) = to_core_peer_id(id).and_then(|core_id| self.peer_queues.get(&core_id))
+		{
+			queue.received.fetch_add(1, Ordering::Relaxed);
+		}
+	}
+
+	/// Returns the packet statistics of every connected peer.
+	pub fn peer_stats(&self) -> Vec<PeerPacketStats> {
+		self.peer_queues
+			.iter()
+			.map(|(core_id, queue)| PeerPacketStats {
+				peer_id: from_core_peer_id(core_id)
+					.map_or_else(|| array_bytes::bytes2hex("0x", core_id), |id| id.to_string()),
+				packets_received: queue.received.load(Ordering::Relaxed),
+				packets_sent: queue.sent.load(Ordering::Relaxed),
+				packets_dropped: queue.dropped.load(Ordering::Relaxed),
+				queued_packets: queue.len(),
+			})
+			.collect()
+	}
+
+	fn update_connected_peers_metric(&self) {
+		if let Some(metrics) = &self.metrics {
+			metrics.connected_peers.set(self.peer_queues.len() as u64);
+		}
 	}
 
 	/// If the peer is not connected or the peer's packet queue is full, the packet is dropped.
@@ -156,6 +240,9 @@ impl PacketDispatcher {
 		let Some(queue) = self.peer_queues.get_mut(&packet.peer_id) else {
 			debug!(target: LOG_TARGET, "Dropped packet to mixnet peer ID {:x?}; not connected",
 				packet.peer_id);
+			if let Some(metrics) = &self.metrics {
+				metrics.dropped(DropReason::NotConnected, 1);
+			}
 			return None
 		};
 
@@ -165,6 +252,10 @@ impl PacketDispatcher {
 					target: LOG_TARGET,
 					"Dropped packet to mixnet peer ID {:x?}; peer queue full", packet.peer_id
 				);
+				queue.dropped.fetch_add(1, Ordering::Relaxed);
+				if let Some(metrics) = &self.metrics {
+					metrics.dropped(DropReason::QueueFull, 1);
+				}
 				None
 			},
 			Ok(true) => {
@@ -173,10 +264,13 @@ impl PacketDispatcher {
 					debug!(target: LOG_TARGET, "Cannot send packet; \
 						failed to convert mixnet peer ID {:x?} to libp2p peer ID",
 						packet.peer_id);
-					queue.clear();
+					let num_packets = queue.clear();
+					if let Some(metrics) = &self.metrics {
+						metrics.dropped(DropReason::InvalidPeerId, num_packets);
+					}
 					return None
 				};
-				Some(ReadyPeer { id, queue: queue.clone() })
+				Some(ReadyPeer { id, queue: queue.clone(), metrics: self.metrics.clone() })
 			},
 			Ok(false) => None, // Queue was not empty
 		}
@@ -24,9 +24,11 @@ use super::{
 	error::RemoteErr,
 	extrinsic_queue::ExtrinsicQueue,
 	maybe_inf_delay::MaybeInfDelay,
+	metrics::Metrics,
 	packet_dispatcher::PacketDispatcher,
 	peer_id::to_core_peer_id,
 	request::{extrinsic_delay, Request, SUBMIT_EXTRINSIC},
+	status::{status, SessionMixnodes},
 	sync_with_runtime::sync_with_runtime,
 };
 use bytes::Bytes;
@@ -42,6 +44,7 @@ use mixnet::{
 	reply_manager::{ReplyContext, ReplyManager},
 	request_manager::RequestManager,
 };
+use prometheus_endpoint::Registry;
 use sc_client_api::{BlockchainEvents, HeaderBackend};
 use sc_network::{
 	service::traits::{NetworkService, NotificationEvent, ValidationResult},
@@ -156,6 +159,7 @@ pub async fn run<B, C, S, P>(
 	transaction_pool: Arc<P>,
 	keystore: Option<KeystorePtr>,
 	mut notification_service: Box<dyn NotificationService>,
+	prometheus_registry: Option<Registry>,
 ) where
 	B: Block,
 	C: BlockchainEvents<B> + ProvideRuntimeApi<B> + HeaderBackend<B>,
@@ -171,14 +175,24 @@ pub async fn run<B, C, S, P>(
 		return
 	};
 
+	let metrics = match prometheus_registry.as_ref().map(Metrics::register).transpose() {
+		Ok(metrics) => metrics,
+		Err(err) => {
+			error!(target: LOG_TARGET, "Failed to register metrics: {err}");
+			None
+		},
+	};
+
 	let offchain_transaction_pool_factory =
 		OffchainTransactionPoolFactory::new(transaction_pool.clone());
 
-	let mut mixnet = Mixnet::new(config.core);
+	let mut mixnet = Mixnet::new(config.core.clone());
 	// It would make sense to reset this to 0 when the session changes, but registrations aren't
 	// allowed at the start of a session anyway, so it doesn't really matter
 	let mut min_register_block = 0u32.into();
-	let mut packet_dispatcher = PacketDispatcher::new(&local_peer_id);
+	let mut packet_dispatcher = PacketDispatcher::new(&local_peer_id, metrics.clone());
+	let mut session_mixnodes = SessionMixnodes::default();
+	let mut authored_packets = 0u64;
 	let mut request_manager = RequestManager::new(config.request_manager);
 	let mut reply_manager = ReplyManager::new(config.reply_manager);
 	let mut extrinsic_queue = ExtrinsicQueue::new(config.substrate.extrinsic_queue_capacity);
@@ -213,14 +227,28 @@ pub async fn run<B, C, S, P>(
 			request = next_request =>
 				request_manager.insert(request, &mut mixnet, &packet_dispatcher, &config.substrate),
 
+			reply_sender = api_backend.status_receiver.select_next_some() => {
+				let status = status(mixnet.session_status(), &session_mixnodes, &config.core,
+					&packet_dispatcher, authored_packets);
+				if reply_sender.send(status).is_err() {
+					debug!(target: LOG_TARGET, "Failed to send status to requester");
+				}
+			}
+
 			notification = finality_notifications.select_next_some() => {
 				// To avoid trying to connect to old mixnodes, ignore finality notifications while
 				// offline or major syncing. This is a bit racy but should be good enough.
 				if !sync.is_offline() && !sync.is_major_syncing() {
 					let api = client.runtime_api();
-					sync_with_runtime(&mut mixnet, api, notification.hash);
+					sync_with_runtime(&mut mixnet, &mut session_mixnodes, api, notification.hash);
 					request_manager.update_session_status(
 						&mut mixnet, &packet_dispatcher, &config.substrate);
+					if let Some(metrics) = &metrics {
+						let current_index = mixnet.session_status().current_index;
+						metrics.session_index.set(current_index.into());
+						metrics.connected_mixnodes.set(
+							session_mixnodes.num_connected(current_index, &packet_dispatcher) as u64);
+					}
 				}
 			}
 
@@ -261,6 +289,7 @@ pub async fn run<B, C, S, P>(
 				},
 				Some(NotificationEvent::NotificationReceived { peer, notification }) => {
 					let notification: Bytes = notification.into();
+					packet_dispatcher.note_received(&peer);
 
 					match notification.as_ref().try_into() {
 						Ok(packet) => handle_packet(packet,
@@ -290,6 +319,10 @@ pub async fn run<B, C, S, P>(
 
 			_ = next_authored_packet_delay => {
 				if let Some(packet) = mixnet.pop_next_authored_packet(&packet_dispatcher) {
+					authored_packets += 1;
+					if let Some(metrics) = &metrics {
+						metrics.authored_packets.inc();
+					}
 					if let Some(ready_peer) = packet_dispatcher.dispatch(packet) {
 						if let Some(fut) = ready_peer.send_packet(&notification_service) {
 							ready_peers.push(fut);
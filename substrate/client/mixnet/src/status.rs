@@ -0,0 +1,278 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Health and topology of the mixnet service, as returned by
+//! [`Api::status`](super::api::Api::status).
+
+use super::{config::CoreConfig, packet_dispatcher::PacketDispatcher, peer_id::from_core_peer_id};
+use mixnet::core::{
+	NetworkStatus, PeerId as CorePeerId, SessionIndex, SessionPhase as CoreSessionPhase,
+	SessionStatus as CoreSessionStatus,
+};
+use sc_network_types::multiaddr::Multiaddr;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Phase of the current session. See [`mixnet::core::SessionPhase`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SessionPhase {
+	/// Generate cover traffic to the current session's mixnode set.
+	CoverToCurrent,
+	/// Build requests using the current session's mixnode set.
+	RequestsToCurrent,
+	/// Only send cover (and forwarded) traffic to the previous session's mixnode set.
+	CoverToPrev,
+	/// Disconnect the previous session's mixnode set.
+	DisconnectFromPrev,
+}
+
+impl From<CoreSessionPhase> for SessionPhase {
+	fn from(phase: CoreSessionPhase) -> Self {
+		match phase {
+			CoreSessionPhase::CoverToCurrent => Self::CoverToCurrent,
+			CoreSessionPhase::RequestsToCurrent => Self::RequestsToCurrent,
+			CoreSessionPhase::CoverToPrev => Self::CoverToPrev,
+			CoreSessionPhase::DisconnectFromPrev => Self::DisconnectFromPrev,
+		}
+	}
+}
+
+/// A mixnode in the topology of a session.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MixnodeStatus {
+	/// Peer ID of the mixnode.
+	pub peer_id: String,
+	/// External addresses of the mixnode, as registered on chain.
+	pub external_addresses: Vec<String>,
+	/// Is there currently a mixnet connection to the mixnode?
+	pub connected: bool,
+}
+
+/// Topology of a session, as retrieved from the runtime.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionTopology {
+	/// Index of the session.
+	pub session_index: SessionIndex,
+	/// Is the local node one of the mixnodes of the session?
+	pub local_is_mixnode: bool,
+	/// Mean number of packets (including cover packets) the local node is configured to author
+	/// per second in this session. This is a target, not a measurement, see
+	/// [`CoverTrafficStatus::authored_packets`] for the packets actually authored. [`None`] if the
+	/// local node does not participate in the session.
+	pub target_authored_packets_per_second: Option<f64>,
+	/// The mixnodes of the session.
+	pub mixnodes: Vec<MixnodeStatus>,
+}
+
+/// Packet statistics for a peer with an open mixnet substream.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerPacketStats {
+	/// Peer ID of the peer.
+	pub peer_id: String,
+	/// Number of packets received from the peer.
+	pub packets_received: u64,
+	/// Number of packets successfully sent to the peer.
+	pub packets_sent: u64,
+	/// Number of packets to the peer that were dropped, either because the peer queue was full or
+	/// because sending failed.
+	pub packets_dropped: u64,
+	/// Number of packets currently waiting to be sent to the peer.
+	pub queued_packets: usize,
+}
+
+/// Cover traffic generated by the local node.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverTrafficStatus {
+	/// Are cover packets generated? Only disabled for testing purposes.
+	pub enabled: bool,
+	/// Proportion of authored packets which are loop cover packets.
+	pub loop_cover_proportion: f64,
+	/// Number of packets (including cover packets) authored since the service was started.
+	pub authored_packets: u64,
+}
+
+/// Health and topology of the mixnet service.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+	/// Index of the current session.
+	pub session_index: SessionIndex,
+	/// Phase of the current session.
+	pub session_phase: SessionPhase,
+	/// Topology of the previous session, if known.
+	pub prev_session: Option<SessionTopology>,
+	/// Topology of the current session, if known.
+	pub current_session: Option<SessionTopology>,
+	/// Statistics for every peer with an open mixnet substream.
+	pub peers: Vec<PeerPacketStats>,
+	/// Cover traffic generated by the local node.
+	pub cover_traffic: CoverTrafficStatus,
+}
+
+/// Mixnodes of the sessions the mixnet core knows about, kept for status reporting as the core
+/// does not expose its topology.
+#[derive(Default)]
+pub struct SessionMixnodes(BTreeMap<SessionIndex, Vec<(CorePeerId, Vec<Multiaddr>)>>);
+
+impl SessionMixnodes {
+	/// Record the mixnodes of the given session.
+	pub fn insert(
+		&mut self,
+		session_index: SessionIndex,
+		mixnodes: Vec<(CorePeerId, Vec<Multiaddr>)>,
+	) {
+		self.0.insert(session_index, mixnodes);
+	}
+
+	/// Forget about sessions before the previous session.
+	pub fn prune(&mut self, current_index: SessionIndex) {
+		let prev_index = current_index.saturating_sub(1);
+		self.0 = self.0.split_off(&prev_index);
+	}
+
+	/// Returns the number of mixnodes of the given session the local node is connected to.
+	pub fn num_connected(
+		&self,
+		session_index: SessionIndex,
+		packet_dispatcher: &PacketDispatcher,
+	) -> usize {
+		self.0.get(&session_index).map_or(0, |mixnodes| {
+			mixnodes
+				.iter()
+				.filter(|(peer_id, _)| packet_dispatcher.is_connected(peer_id))
+				.count()
+		})
+	}
+
+	fn topology(
+		&self,
+		session_index: SessionIndex,
+		local_peer_id: &CorePeerId,
+		config: &CoreConfig,
+		packet_dispatcher: &PacketDispatcher,
+	) -> Option<SessionTopology> {
+		let mixnodes = self.0.get(&session_index)?;
+		let local_is_mixnode = mixnodes.iter().any(|(peer_id, _)| peer_id == local_peer_id);
+		let session_config = if local_is_mixnode {
+			Some(&config.mixnode_session)
+		} else {
+			config.non_mixnode_session.as_ref()
+		};
+
+		Some(SessionTopology {
+			session_index,
+			local_is_mixnode,
+			target_authored_packets_per_second: session_config
+				.map(|config| 1.0 / config.mean_authored_packet_period.as_secs_f64()),
+			mixnodes: mixnodes
+				.iter()
+				.map(|(peer_id, external_addresses)| MixnodeStatus {
+					peer_id: from_core_peer_id(peer_id)
+						.map_or_else(|| array_bytes::bytes2hex("0x", peer_id), |id| id.to_string()),
+					external_addresses: external_addresses.iter().map(|a| a.to_string()).collect(),
+					connected: packet_dispatcher.is_connected(peer_id),
+				})
+				.collect(),
+		})
+	}
+}
+
+/// Build the [`Status`] of the mixnet service.
+pub fn status(
+	session_status: CoreSessionStatus,
+	session_mixnodes: &SessionMixnodes,
+	config: &CoreConfig,
+	packet_dispatcher: &PacketDispatcher,
+	authored_packets: u64,
+) -> Status {
+	let local_peer_id = packet_dispatcher.local_peer_id();
+	let current_index = session_status.current_index;
+	let topology = |session_index| {
+		session_mixnodes.topology(session_index, &local_peer_id, config, packet_dispatcher)
+	};
+
+	Status {
+		session_index: current_index,
+		session_phase: session_status.phase.into(),
+		prev_session: current_index.checked_sub(1).and_then(topology),
+		current_session: topology(current_index),
+		peers: packet_dispatcher.peer_stats(),
+		cover_traffic: CoverTrafficStatus {
+			enabled: config.gen_cover_packets,
+			loop_cover_proportion: config.loop_cover_proportion,
+			authored_packets,
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn session_mixnodes_keeps_current_and_prev_sessions() {
+		let mut session_mixnodes = SessionMixnodes::default();
+		for session_index in 0..4 {
+			session_mixnodes.insert(session_index, vec![([session_index as u8; 32], Vec::new())]);
+		}
+
+		session_mixnodes.prune(2);
+		assert_eq!(session_mixnodes.0.keys().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+		session_mixnodes.prune(3);
+		assert_eq!(session_mixnodes.0.keys().copied().collect::<Vec<_>>(), vec![2, 3]);
+	}
+
+	#[test]
+	fn topology_reports_connectivity_and_rates() {
+		let local_peer_id = [1; 32];
+		let remote_peer_id = [2; 32];
+		let config = CoreConfig::default();
+		let packet_dispatcher = PacketDispatcher::new(&local_peer_id, None);
+
+		let mut session_mixnodes = SessionMixnodes::default();
+		session_mixnodes.insert(5, vec![(remote_peer_id, Vec::new())]);
+
+		let status = status(
+			CoreSessionStatus { current_index: 5, phase: CoreSessionPhase::RequestsToCurrent },
+			&session_mixnodes,
+			&config,
+			&packet_dispatcher,
+			3,
+		);
+
+		assert_eq!(status.session_phase, SessionPhase::RequestsToCurrent);
+		assert_eq!(status.prev_session, None);
+		let current_session = status.current_session.unwrap();
+		assert!(!current_session.local_is_mixnode);
+		assert_eq!(
+			current_session.target_authored_packets_per_second,
+			config
+				.non_mixnode_session
+				.map(|config| 1.0 / config.mean_authored_packet_period.as_secs_f64()),
+		);
+		assert_eq!(current_session.mixnodes.len(), 1);
+		assert!(!current_session.mixnodes[0].connected);
+		assert_eq!(status.cover_traffic.authored_packets, 3);
+	}
+}
@@ -19,7 +19,7 @@
 //! [`sync_with_runtime`] synchronises the session status and mixnode sets from the blockchain
 //! runtime to the core mixnet state. It is called every time a block is finalised.
 
-use super::peer_id::from_core_peer_id;
+use super::{peer_id::from_core_peer_id, status::SessionMixnodes};
 use log::{debug, info};
 use mixnet::core::{
 	Mixnet, Mixnode as CoreMixnode, MixnodesErr as CoreMixnodesErr, RelSessionIndex,
@@ -152,6 +152,7 @@ fn into_core_mixnode(mixnode: RuntimeMixnode) -> CoreMixnode<Vec<Multiaddr>> {
 
 fn maybe_set_mixnodes(
 	mixnet: &mut Mixnet<Vec<Multiaddr>>,
+	session_mixnodes: &mut SessionMixnodes,
 	rel_session_index: RelSessionIndex,
 	mixnodes: &dyn Fn() -> Result<Result<Vec<RuntimeMixnode>, RuntimeMixnodesErr>, ApiError>,
 ) {
@@ -161,7 +162,17 @@ fn maybe_set_mixnodes(
 		// that case so we are fine. Do not move this out of the closure!
 		let session_index = rel_session_index + current_session_index;
 		match mixnodes() {
-			Ok(Ok(mixnodes)) => Ok(mixnodes.into_iter().map(into_core_mixnode).collect()),
+			Ok(Ok(mixnodes)) => {
+				let mixnodes: Vec<_> = mixnodes.into_iter().map(into_core_mixnode).collect();
+				session_mixnodes.insert(
+					session_index,
+					mixnodes
+						.iter()
+						.map(|mixnode| (mixnode.peer_id, mixnode.extra.clone()))
+						.collect(),
+				);
+				Ok(mixnodes)
+			},
 			Ok(Err(err)) => {
 				info!(target: LOG_TARGET, "Session {session_index}: Mixnet disabled: {err}");
 				Err(CoreMixnodesErr::Permanent) // Disable the session slot
@@ -177,8 +188,12 @@ fn maybe_set_mixnodes(
 	});
 }
 
-pub fn sync_with_runtime<B, A>(mixnet: &mut Mixnet<Vec<Multiaddr>>, api: ApiRef<A>, hash: B::Hash)
-where
+pub fn sync_with_runtime<B, A>(
+	mixnet: &mut Mixnet<Vec<Multiaddr>>,
+	session_mixnodes: &mut SessionMixnodes,
+	api: ApiRef<A>,
+	hash: B::Hash,
+) where
 	B: Block,
 	A: MixnetApi<B>,
 {
@@ -189,10 +204,16 @@ where
 			return
 		},
 	};
-	mixnet.set_session_status(to_core_session_status(session_status));
+	let session_status = to_core_session_status(session_status);
+	mixnet.set_session_status(session_status);
+	session_mixnodes.prune(session_status.current_index);
 
-	maybe_set_mixnodes(mixnet, RelSessionIndex::Prev, &|| api.prev_mixnodes(hash));
-	maybe_set_mixnodes(mixnet, RelSessionIndex::Current, &|| api.current_mixnodes(hash));
+	maybe_set_mixnodes(mixnet, session_mixnodes, RelSessionIndex::Prev, &|| {
+		api.prev_mixnodes(hash)
+	});
+	maybe_set_mixnodes(mixnet, session_mixnodes, RelSessionIndex::Current, &|| {
+		api.current_mixnodes(hash)
+	});
 }
 
 #[cfg(test)]
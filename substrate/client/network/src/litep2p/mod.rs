@@ -62,7 +62,7 @@ use litep2p::{
 		request_response::ConfigBuilder as RequestResponseConfigBuilder,
 	},
 	transport::{
-		tcp::config::Config as TcpTransportConfig,
+		quic::config::Config as QuicTransportConfig, tcp::config::Config as TcpTransportConfig,
 		websocket::config::Config as WebSocketTransportConfig, ConnectionLimitsConfig, Endpoint,
 	},
 	types::{
@@ -268,74 +268,107 @@ impl Litep2pNetworkBackend {
 		Ok((local_identity, local_peer_id))
 	}
 
-	/// Configure transport protocols for `Litep2pNetworkBackend`.
-	fn configure_transport<B: BlockT + 'static, H: ExHashT>(
-		config: &FullNetworkConfiguration<B, H, Self>,
-	) -> ConfigBuilder {
-		let _ = match config.network_config.transport {
-			TransportConfig::MemoryOnly => panic!("memory transport not supported"),
-			TransportConfig::Normal { .. } => false,
-		};
-		let config_builder = ConfigBuilder::new();
-
-		let (tcp, websocket): (Vec<Option<_>>, Vec<Option<_>>) = config
-			.network_config
-			.listen_addresses
-			.iter()
-			.filter_map(|address| {
-				use sc_network_types::multiaddr::Protocol;
-
-				let mut iter = address.iter();
+	/// Group listen addresses by the transport protocol which should listen on them.
+	///
+	/// Returns the TCP, WebSocket and QUIC listen addresses, in that order. Addresses which are not
+	/// supported by any of the enabled transports are logged and ignored.
+	fn listen_addresses_by_transport(
+		listen_addresses: &[sc_network_types::multiaddr::Multiaddr],
+	) -> (Vec<Multiaddr>, Vec<Multiaddr>, Vec<Multiaddr>) {
+		use sc_network_types::multiaddr::Protocol;
+
+		let mut tcp = Vec::new();
+		let mut websocket = Vec::new();
+		let mut quic = Vec::new();
+
+		for address in listen_addresses {
+			let mut iter = address.iter();
+
+			match iter.next() {
+				Some(Protocol::Ip4(_) | Protocol::Ip6(_)) => {},
+				protocol => {
+					log::error!(
+						target: LOG_TARGET,
+						"unknown protocol {protocol:?}, ignoring {address:?}",
+					);
+					continue
+				},
+			}
 
-				match iter.next() {
-					Some(Protocol::Ip4(_) | Protocol::Ip6(_)) => {},
+			let transport = match iter.next() {
+				Some(Protocol::Tcp(_)) => match iter.next() {
+					Some(Protocol::Ws(_) | Protocol::Wss(_)) => Some(&mut websocket),
+					Some(Protocol::P2p(_)) | None => Some(&mut tcp),
 					protocol => {
 						log::error!(
 							target: LOG_TARGET,
 							"unknown protocol {protocol:?}, ignoring {address:?}",
 						);
-
-						return None
-					},
-				}
-
-				match iter.next() {
-					Some(Protocol::Tcp(_)) => match iter.next() {
-						Some(Protocol::Ws(_) | Protocol::Wss(_)) =>
-							Some((None, Some(address.clone()))),
-						Some(Protocol::P2p(_)) | None => Some((Some(address.clone()), None)),
-						protocol => {
-							log::error!(
-								target: LOG_TARGET,
-								"unknown protocol {protocol:?}, ignoring {address:?}",
-							);
-							None
-						},
+						None
 					},
-					protocol => {
+				},
+				Some(Protocol::Udp(_)) => match (iter.next(), iter.next()) {
+					(Some(Protocol::QuicV1), Some(Protocol::P2p(_)) | None) => Some(&mut quic),
+					(protocol, _) => {
+						// WebTransport and WebRTC listeners are not supported by `litep2p` yet.
 						log::error!(
 							target: LOG_TARGET,
-							"unknown protocol {protocol:?}, ignoring {address:?}",
+							"unsupported UDP transport {protocol:?}, ignoring {address:?}",
 						);
 						None
 					},
-				}
-			})
-			.unzip();
+				},
+				protocol => {
+					log::error!(
+						target: LOG_TARGET,
+						"unknown protocol {protocol:?}, ignoring {address:?}",
+					);
+					None
+				},
+			};
 
-		config_builder
+			if let Some(transport) = transport {
+				transport.push(address.clone().into());
+			}
+		}
+
+		(tcp, websocket, quic)
+	}
+
+	/// Configure transport protocols for `Litep2pNetworkBackend`.
+	///
+	/// QUIC is only enabled if at least one QUIC listen address is configured. Its TLS certificate
+	/// is generated by `litep2p` from the node key, so no certificate needs to be provided.
+	fn configure_transport<B: BlockT + 'static, H: ExHashT>(
+		config: &FullNetworkConfiguration<B, H, Self>,
+	) -> ConfigBuilder {
+		let _ = match config.network_config.transport {
+			TransportConfig::MemoryOnly => panic!("memory transport not supported"),
+			TransportConfig::Normal { .. } => false,
+		};
+		let (tcp, websocket, quic) =
+			Self::listen_addresses_by_transport(&config.network_config.listen_addresses);
+
+		let config_builder = ConfigBuilder::new()
 			.with_websocket(WebSocketTransportConfig {
-				listen_addresses: websocket.into_iter().flatten().map(Into::into).collect(),
+				listen_addresses: websocket,
 				yamux_config: litep2p::yamux::Config::default(),
 				nodelay: true,
 				..Default::default()
 			})
 			.with_tcp(TcpTransportConfig {
-				listen_addresses: tcp.into_iter().flatten().map(Into::into).collect(),
+				listen_addresses: tcp,
 				yamux_config: litep2p::yamux::Config::default(),
 				nodelay: true,
 				..Default::default()
-			})
+			});
+
+		if quic.is_empty() {
+			return config_builder
+		}
+
+		config_builder
+			.with_quic(QuicTransportConfig { listen_addresses: quic, ..Default::default() })
 	}
 }
 
@@ -485,8 +518,9 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkBackend<B, H> for Litep2pNetworkBac
 				use sc_network_types::multiaddr::Protocol;
 
 				let address = match address.iter().last() {
-					Some(Protocol::Ws(_) | Protocol::Wss(_) | Protocol::Tcp(_)) =>
-						address.with(Protocol::P2p(peer.into())),
+					Some(
+						Protocol::Ws(_) | Protocol::Wss(_) | Protocol::Tcp(_) | Protocol::QuicV1,
+					) => address.with(Protocol::P2p(peer.into())),
 					Some(Protocol::P2p(_)) => address,
 					_ => return acc,
 				};
@@ -1193,6 +1227,7 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkBackend<B, H> for Litep2pNetworkBac
 									NegotiationError::ParseError(_) => "parse-error",
 									NegotiationError::IoError(_) => "io-error",
 									NegotiationError::WebSocket(_) => "webscoket-error",
+									NegotiationError::Quic(_) => "quic-error",
 									NegotiationError::BadSignature => "bad-signature",
 								}
 							};
@@ -1222,3 +1257,35 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkBackend<B, H> for Litep2pNetworkBac
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn listen_addresses_grouped_by_transport() {
+		let addresses = [
+			"/ip4/0.0.0.0/tcp/30333",
+			"/ip6/::/tcp/30333",
+			"/ip4/0.0.0.0/tcp/30334/ws",
+			"/ip4/0.0.0.0/udp/30333/quic-v1",
+			"/ip6/::/udp/30333/quic-v1",
+			// Not supported by any transport.
+			"/ip4/0.0.0.0/udp/30335",
+			"/dns/example.com/tcp/30333",
+		]
+		.into_iter()
+		.map(|address| address.parse().unwrap())
+		.collect::<Vec<sc_network_types::multiaddr::Multiaddr>>();
+
+		let (tcp, websocket, quic) =
+			Litep2pNetworkBackend::listen_addresses_by_transport(&addresses);
+
+		let to_litep2p = |indices: &[usize]| {
+			indices.iter().map(|i| addresses[*i].clone().into()).collect::<Vec<Multiaddr>>()
+		};
+		assert_eq!(tcp, to_litep2p(&[0, 1]));
+		assert_eq!(websocket, to_litep2p(&[2]));
+		assert_eq!(quic, to_litep2p(&[3, 4]));
+	}
+}
@@ -53,9 +53,11 @@ use sp_runtime::traits::{self, Header};
 use threadpool::ThreadPool;
 
 mod api;
+mod tasks;
 
 pub use sp_core::offchain::storage::OffchainDb;
-pub use sp_offchain::{OffchainWorkerApi, STORAGE_PREFIX};
+pub use sp_offchain::{OffchainWorkerApi, TasksApi, STORAGE_PREFIX};
+pub use tasks::{TaskExecutor, TaskExecutorOptions};
 
 const LOG_TARGET: &str = "offchain-worker";
 
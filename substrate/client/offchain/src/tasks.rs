@@ -0,0 +1,256 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Background execution of the tasks of the runtime.
+//!
+//! Pallets declare tasks with `#[pallet::tasks_experimental]`. On every new best block the
+//! [`TaskExecutor`] asks the runtime for the currently valid tasks through [`TasksApi`] and submits
+//! them to the transaction pool as unsigned transactions, so no pallet has to enumerate and submit
+//! its own tasks from an offchain worker.
+
+use codec::{Codec, Encode};
+use futures::prelude::*;
+use sc_client_api::BlockchainEvents;
+use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_offchain::TasksApi;
+use sp_runtime::traits::{self, Header, NumberFor, One, Saturating};
+use std::{
+	collections::{BTreeMap, HashMap},
+	marker::PhantomData,
+	sync::Arc,
+};
+
+const LOG_TARGET: &str = "offchain-tasks";
+
+/// Options for [`TaskExecutor`].
+pub struct TaskExecutorOptions<RA, Block: traits::Block> {
+	/// Provides access to the runtime api.
+	pub runtime_api_provider: Arc<RA>,
+	/// The transaction pool the tasks are submitted to.
+	pub transaction_pool: OffchainTransactionPoolFactory<Block>,
+	/// Maximum number of tasks submitted per new best block.
+	pub max_tasks_per_block: u32,
+	/// Number of blocks after which a task that is still valid is submitted again.
+	///
+	/// A submitted task usually stops being valid once it was executed. If it is still valid
+	/// after this many blocks, its transaction is assumed to have been dropped.
+	pub resubmit_after: u32,
+}
+
+/// Submits the valid tasks of the runtime as unsigned transactions.
+pub struct TaskExecutor<RA, Block: traits::Block, RuntimeTask> {
+	runtime_api_provider: Arc<RA>,
+	transaction_pool: OffchainTransactionPoolFactory<Block>,
+	max_tasks_per_block: u32,
+	submitted: SubmittedTasks<NumberFor<Block>>,
+	_phantom: PhantomData<RuntimeTask>,
+}
+
+impl<RA, Block: traits::Block, RuntimeTask> TaskExecutor<RA, Block, RuntimeTask> {
+	/// Creates a new instance.
+	pub fn new(
+		TaskExecutorOptions {
+			runtime_api_provider,
+			transaction_pool,
+			max_tasks_per_block,
+			resubmit_after,
+		}: TaskExecutorOptions<RA, Block>,
+	) -> Self {
+		Self {
+			runtime_api_provider,
+			transaction_pool,
+			max_tasks_per_block,
+			submitted: SubmittedTasks::new(resubmit_after.into()),
+			_phantom: PhantomData,
+		}
+	}
+}
+
+impl<RA, Block, RuntimeTask> TaskExecutor<RA, Block, RuntimeTask>
+where
+	Block: traits::Block,
+	RA: ProvideRuntimeApi<Block> + Send + Sync + 'static,
+	RA::Api: TasksApi<Block, RuntimeTask>,
+	RuntimeTask: Codec,
+{
+	/// Run the task executor.
+	///
+	/// Submits tasks on every new best block reported by `import_events`.
+	pub async fn run<BE: BlockchainEvents<Block>>(mut self, import_events: Arc<BE>) {
+		let mut import_notifications = import_events.import_notification_stream();
+
+		while let Some(notification) = import_notifications.next().await {
+			if notification.is_new_best {
+				self.on_new_best_block(&notification.header);
+			} else {
+				tracing::debug!(
+					target: LOG_TARGET,
+					"Skipping tasks for non-canon block: {:?}",
+					notification.header,
+				)
+			}
+		}
+	}
+
+	fn on_new_best_block(&mut self, header: &Block::Header) {
+		let hash = header.hash();
+		let runtime = self.runtime_api_provider.runtime_api();
+
+		match runtime.has_api::<dyn TasksApi<Block, RuntimeTask>>(hash) {
+			Ok(true) => {},
+			Ok(false) => {
+				tracing::trace!(target: LOG_TARGET, "Runtime at {hash:?} has no tasks api");
+				return
+			},
+			Err(e) => {
+				tracing::error!(target: LOG_TARGET, "Failed to check tasks api at {hash:?}: {e}");
+				return
+			},
+		}
+
+		self.submitted.prune(*header.number());
+
+		// Tasks submitted at an earlier block may still be valid, fetch enough to skip them.
+		let max_tasks = self.max_tasks_per_block.saturating_add(self.submitted.len() as u32);
+		let tasks = match runtime.valid_tasks(hash, max_tasks) {
+			Ok(tasks) => tasks,
+			Err(e) => {
+				tracing::error!(target: LOG_TARGET, "Failed to fetch valid tasks at {hash:?}: {e}");
+				return
+			},
+		};
+
+		let mut pool = self.transaction_pool.offchain_transaction_pool(hash);
+		let mut num_submitted = 0;
+		for task in tasks {
+			if num_submitted >= self.max_tasks_per_block {
+				break
+			}
+
+			let encoded_task = task.encode();
+			if self.submitted.contains(&encoded_task) {
+				continue
+			}
+
+			let extrinsic = match runtime.task_extrinsic(hash, task) {
+				Ok(extrinsic) => extrinsic,
+				Err(e) => {
+					tracing::error!(
+						target: LOG_TARGET,
+						"Failed to build task extrinsic at {hash:?}: {e}",
+					);
+					return
+				},
+			};
+
+			// Submission errors are logged by the pool.
+			if pool.submit_transaction(extrinsic.encode()).is_ok() {
+				self.submitted.insert(encoded_task, *header.number());
+				num_submitted += 1;
+			}
+		}
+
+		if num_submitted > 0 {
+			tracing::debug!(target: LOG_TARGET, "Submitted {num_submitted} tasks at {hash:?}");
+		}
+	}
+}
+
+/// Encoded tasks which were submitted recently, indexed by the block number from which they may be
+/// submitted again.
+struct SubmittedTasks<N> {
+	tasks: HashMap<Vec<u8>, N>,
+	by_expiry: BTreeMap<N, Vec<Vec<u8>>>,
+	resubmit_after: N,
+}
+
+impl<N: Copy + Ord + One + Saturating> SubmittedTasks<N> {
+	fn new(resubmit_after: N) -> Self {
+		Self { tasks: HashMap::new(), by_expiry: BTreeMap::new(), resubmit_after }
+	}
+
+	/// Forget about the tasks which may be submitted again at block `number`.
+	///
+	/// Only the tasks which expired are visited.
+	fn prune(&mut self, number: N) {
+		let not_expired = self.by_expiry.split_off(&number.saturating_add(N::one()));
+		for (expiry, tasks) in core::mem::replace(&mut self.by_expiry, not_expired) {
+			for task in tasks {
+				// The task may have been submitted again with a later expiry.
+				if self.tasks.get(&task) == Some(&expiry) {
+					self.tasks.remove(&task);
+				}
+			}
+		}
+	}
+
+	fn contains(&self, task: &[u8]) -> bool {
+		self.tasks.contains_key(task)
+	}
+
+	fn insert(&mut self, task: Vec<u8>, number: N) {
+		let expiry = number.saturating_add(self.resubmit_after);
+		self.by_expiry.entry(expiry).or_default().push(task.clone());
+		self.tasks.insert(task, expiry);
+	}
+
+	fn len(&self) -> usize {
+		self.tasks.len()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn submitted_tasks_are_pruned_after_resubmit_period() {
+		let mut submitted = SubmittedTasks::<u64>::new(3);
+		submitted.insert(vec![1], 10);
+		submitted.insert(vec![2], 11);
+
+		submitted.prune(12);
+		assert!(submitted.contains(&[1]));
+		assert!(submitted.contains(&[2]));
+
+		submitted.prune(13);
+		assert!(!submitted.contains(&[1]));
+		assert!(submitted.contains(&[2]));
+		assert_eq!(submitted.len(), 1);
+
+		submitted.prune(14);
+		assert_eq!(submitted.len(), 0);
+		assert!(submitted.by_expiry.is_empty());
+	}
+
+	#[test]
+	fn resubmitted_tasks_are_pruned_after_their_latest_submission() {
+		let mut submitted = SubmittedTasks::<u64>::new(3);
+		submitted.insert(vec![1], 10);
+		submitted.insert(vec![1], 12);
+
+		submitted.prune(13);
+		assert!(submitted.contains(&[1]));
+		assert_eq!(submitted.by_expiry.len(), 1);
+
+		submitted.prune(15);
+		assert!(!submitted.contains(&[1]));
+		assert!(submitted.by_expiry.is_empty());
+	}
+}
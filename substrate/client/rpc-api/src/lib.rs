@@ -36,3 +36,4 @@ pub mod offchain;
 pub mod state;
 pub mod statement;
 pub mod system;
+pub mod view;
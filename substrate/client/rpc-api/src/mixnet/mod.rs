@@ -21,7 +21,8 @@
 pub mod error;
 
 use error::Error;
-use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_mixnet::Status;
 use sp_core::Bytes;
 
 #[rpc(client, server)]
@@ -29,4 +30,11 @@ pub trait MixnetApi {
 	/// Submit encoded extrinsic over the mixnet for inclusion in block.
 	#[method(name = "mixnet_submitExtrinsic")]
 	async fn submit_extrinsic(&self, extrinsic: Bytes) -> Result<(), Error>;
+
+	/// Get the health and topology of the mixnet service: mixnodes of the current and previous
+	/// sessions, per-peer packet statistics and cover traffic.
+	///
+	/// This method is unsafe, the peer statistics reveal who the node is exchanging packets with.
+	#[method(name = "mixnet_status", with_extensions)]
+	async fn status(&self) -> RpcResult<Status>;
 }
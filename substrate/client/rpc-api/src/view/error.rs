@@ -0,0 +1,77 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! View functions RPC errors.
+
+use jsonrpsee::types::error::{ErrorObject, ErrorObjectOwned};
+
+/// View functions RPC Result type.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// View functions RPC errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Client error.
+	#[error("Client error: {}", .0)]
+	Client(#[from] Box<dyn std::error::Error + Send + Sync>),
+	/// The runtime does not provide metadata with view functions.
+	#[error("Metadata v16 is not available: {}", .0)]
+	MetadataUnavailable(String),
+	/// No pallet with the given name exists.
+	#[error("Pallet `{}` not found", .0)]
+	PalletNotFound(String),
+	/// The pallet has no view function with the given name.
+	#[error("View function `{}::{}` not found", .pallet, .function)]
+	ViewFunctionNotFound {
+		/// Name of the pallet.
+		pallet: String,
+		/// Name of the view function.
+		function: String,
+	},
+	/// The arguments could not be encoded.
+	#[error("Invalid arguments: {}", .0)]
+	InvalidArgs(String),
+	/// The runtime failed to execute the view function.
+	#[error("View function execution failed: {}", .0)]
+	Execution(String),
+	/// The return value could not be decoded.
+	#[error("Failed to decode the return value: {}", .0)]
+	InvalidOutput(String),
+	/// The method is marked as unsafe but unsafe flag wasn't supplied on the CLI.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] crate::policy::UnsafeRpcError),
+}
+
+/// Base code for all view functions errors.
+const BASE_ERROR: i32 = crate::error::base::VIEW;
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(e: Error) -> ErrorObjectOwned {
+		let code = match e {
+			Error::Client(_) => BASE_ERROR + 1,
+			Error::MetadataUnavailable(_) => BASE_ERROR + 2,
+			Error::PalletNotFound(_) => BASE_ERROR + 3,
+			Error::ViewFunctionNotFound { .. } => BASE_ERROR + 4,
+			Error::InvalidArgs(_) => BASE_ERROR + 5,
+			Error::Execution(_) => BASE_ERROR + 6,
+			Error::InvalidOutput(_) => BASE_ERROR + 7,
+			Error::UnsafeRpcCalled(e) => return e.into(),
+		};
+		ErrorObject::owned(code, e.to_string(), None::<()>)
+	}
+}
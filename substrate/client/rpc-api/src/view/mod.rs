@@ -0,0 +1,72 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate view functions API.
+
+use jsonrpsee::proc_macros::rpc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use sp_core::Bytes;
+
+pub mod error;
+
+pub use error::Error;
+
+/// Arguments of a view function call.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ViewFunctionArgs {
+	/// The SCALE encoded arguments, concatenated in declaration order.
+	Scale(Bytes),
+	/// One JSON value per argument, in declaration order.
+	///
+	/// The values are encoded according to the argument types found in the metadata.
+	Json(Vec<JsonValue>),
+}
+
+/// Substrate view functions API.
+#[rpc(client, server)]
+pub trait ViewApi<Hash> {
+	/// Call the view function `function` of the pallet `pallet` at a block's state.
+	///
+	/// This method is unsafe, as view functions may run arbitrarily long.
+	///
+	/// The view function and its argument and return types are resolved from the metadata v16
+	/// of the runtime. Returns the decoded return value.
+	#[method(name = "view_call", blocking, with_extensions)]
+	fn call(
+		&self,
+		pallet: String,
+		function: String,
+		args: ViewFunctionArgs,
+		hash: Option<Hash>,
+	) -> Result<JsonValue, Error>;
+
+	/// Subscribe to the result of a view function call, re-evaluated on every new best block.
+	///
+	/// This method is unsafe, as view functions may run arbitrarily long.
+	///
+	/// A result is only sent when it differs from the previously sent one.
+	#[subscription(
+		name = "view_subscribe" => "view_result",
+		unsubscribe = "view_unsubscribe",
+		item = JsonValue,
+		with_extensions,
+	)]
+	fn subscribe_call(&self, pallet: String, function: String, args: ViewFunctionArgs);
+}
@@ -18,10 +18,16 @@
 
 //! Substrate mixnet API.
 
-use jsonrpsee::core::async_trait;
-use sc_mixnet::Api;
-use sc_rpc_api::mixnet::error::Error;
+#[cfg(test)]
+mod tests;
+
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	Extensions,
+};
+use sc_mixnet::{Api, Status};
 pub use sc_rpc_api::mixnet::MixnetApiServer;
+use sc_rpc_api::{check_if_safe, mixnet::error::Error};
 use sp_core::Bytes;
 
 /// Mixnet API.
@@ -44,4 +50,12 @@ impl MixnetApiServer for Mixnet {
 		};
 		Ok(fut.await.map_err(Error)?)
 	}
+
+	async fn status(&self, ext: &Extensions) -> RpcResult<Status> {
+		check_if_safe(ext)?;
+
+		// Work on a copy of the API so the lock is not held while waiting for the reply
+		let mut api = self.0.lock().await.clone();
+		Ok(api.status().await.map_err(Error)?)
+	}
 }
@@ -0,0 +1,35 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::testing::{allow_unsafe, deny_unsafe};
+use sc_mixnet::Config;
+
+#[tokio::test]
+async fn status_is_unsafe() {
+	let (api, api_backend) = Api::new(&Config::default());
+	let mixnet = Mixnet::new(api);
+
+	let err = mixnet.status(&deny_unsafe()).await.unwrap_err();
+	assert_eq!(err.message(), "RPC call is unsafe to be called externally");
+
+	// The call goes through to the mixnet service, which is not running
+	drop(api_backend);
+	let err = mixnet.status(&allow_unsafe()).await.unwrap_err();
+	assert_eq!(err.message(), sc_mixnet::Error::ServiceUnavailable.to_string());
+}
@@ -0,0 +1,377 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate view functions API.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+	utils::{spawn_subscription_task, BoundedVecDeque, PendingSubscription},
+	SubscriptionTaskExecutor,
+};
+use codec::{Decode, Encode};
+use frame_metadata::{v16::RuntimeMetadataV16, RuntimeMetadata, RuntimeMetadataPrefixed};
+use futures::{future, stream, FutureExt, StreamExt};
+use jsonrpsee::{Extensions, PendingSubscriptionSink};
+use parking_lot::Mutex;
+use sc_client_api::{BlockchainEvents, CallExecutor, ExecutorProvider};
+use sc_rpc_api::{
+	check_if_safe,
+	view::{
+		error::{Error, Result},
+		ViewFunctionArgs,
+	},
+};
+use scale_info::PortableRegistry;
+use serde_json::Value as JsonValue;
+use sp_blockchain::HeaderBackend;
+use sp_core::{hexdisplay::HexDisplay, traits::CallContext};
+use sp_runtime::traits::Block as BlockT;
+use std::{marker::PhantomData, sync::Arc};
+
+pub use sc_rpc_api::view::ViewApiServer;
+
+/// The metadata version which describes view functions.
+const METADATA_VERSION: u32 = 16;
+
+/// The runtime api executing view functions.
+const VIEW_FUNCTION_API: &str = "RuntimeViewFunction";
+
+/// The method of [`VIEW_FUNCTION_API`] executing a view function.
+const VIEW_FUNCTION_METHOD: &str = "execute_view_function";
+
+/// View functions API.
+pub struct View<Block: BlockT, Client> {
+	client: Arc<Client>,
+	executor: SubscriptionTaskExecutor,
+	view_functions: Arc<ViewFunctions<Block, Client>>,
+}
+
+impl<Block: BlockT, Client> View<Block, Client> {
+	/// Create new View functions API RPC handler.
+	pub fn new(client: Arc<Client>, executor: SubscriptionTaskExecutor) -> Self {
+		Self {
+			view_functions: Arc::new(ViewFunctions {
+				client: client.clone(),
+				metadata: Mutex::new(None),
+				_phantom: PhantomData,
+			}),
+			client,
+			executor,
+		}
+	}
+}
+
+impl<Block, Client> ViewApiServer<Block::Hash> for View<Block, Client>
+where
+	Block: BlockT + 'static,
+	Client: ExecutorProvider<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	fn call(
+		&self,
+		ext: &Extensions,
+		pallet: String,
+		function: String,
+		args: ViewFunctionArgs,
+		hash: Option<Block::Hash>,
+	) -> Result<JsonValue> {
+		check_if_safe(ext)?;
+
+		let hash = hash.unwrap_or_else(|| self.client.info().best_hash);
+		self.view_functions.call(hash, &pallet, &function, args)
+	}
+
+	fn subscribe_call(
+		&self,
+		pending: PendingSubscriptionSink,
+		ext: &Extensions,
+		pallet: String,
+		function: String,
+		args: ViewFunctionArgs,
+	) {
+		if let Err(e) = check_if_safe(ext) {
+			spawn_subscription_task(&self.executor, pending.reject(Error::from(e)));
+			return
+		}
+
+		let best_hash = self.client.info().best_hash;
+		let import_notifications = self
+			.client
+			.import_notification_stream()
+			.filter(|n| future::ready(n.is_new_best));
+		let view_functions = self.view_functions.clone();
+
+		// View functions are executed by the runtime, keep them off the async executor.
+		let fut = async move {
+			let initial = match view_functions.call(best_hash, &pallet, &function, args.clone()) {
+				Ok(initial) => initial,
+				Err(e) => {
+					pending.reject(e).await;
+					return
+				},
+			};
+
+			let mut previous_result = initial.clone();
+
+			// A stream of new results
+			let result_stream = import_notifications.filter_map(move |n| {
+				let result = view_functions.call(n.hash, &pallet, &function, args.clone());
+
+				match result {
+					Ok(result) if result != previous_result => {
+						previous_result = result.clone();
+						future::ready(Some(result))
+					},
+					Ok(_) => future::ready(None),
+					Err(e) => {
+						log::debug!("Failed to call view function at {:?}: {}", n.hash, e);
+						future::ready(None)
+					},
+				}
+			});
+
+			let stream = stream::once(future::ready(initial)).chain(result_stream);
+			PendingSubscription::from(pending)
+				.pipe_from_stream(stream, BoundedVecDeque::default())
+				.await;
+		};
+
+		self.executor
+			.spawn_blocking("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+/// Executes view functions, caching the metadata of the latest runtime.
+struct ViewFunctions<Block, Client> {
+	client: Arc<Client>,
+	/// The metadata along with the `spec_version` of the runtime it was fetched from.
+	metadata: Mutex<Option<(u32, Arc<RuntimeMetadataV16>)>>,
+	_phantom: PhantomData<Block>,
+}
+
+impl<Block, Client> ViewFunctions<Block, Client>
+where
+	Block: BlockT,
+	Client: ExecutorProvider<Block>,
+{
+	fn call(
+		&self,
+		hash: Block::Hash,
+		pallet: &str,
+		function: &str,
+		args: ViewFunctionArgs,
+	) -> Result<JsonValue> {
+		let metadata = self.metadata(hash)?;
+		let view_function = ResolvedViewFunction::resolve(&metadata, pallet, function)?;
+		let input = view_function.encode_args(&metadata.types, args)?;
+
+		// `ViewFunctionId` encodes to the 32 bytes of the id.
+		let result = self
+			.client
+			.executor()
+			.call(
+				hash,
+				&format!("{VIEW_FUNCTION_API}_{VIEW_FUNCTION_METHOD}"),
+				&(view_function.id, input).encode(),
+				CallContext::Offchain,
+			)
+			.map_err(client_err)?;
+
+		match std::result::Result::<Vec<u8>, ()>::decode(&mut &result[..]) {
+			Ok(Ok(output)) => view_function.decode_output(&metadata.types, &output),
+			_ => Err(Error::Execution(describe_failed_call(&metadata, &result))),
+		}
+	}
+
+	/// Returns the metadata of the runtime at `hash`.
+	fn metadata(&self, hash: Block::Hash) -> Result<Arc<RuntimeMetadataV16>> {
+		let executor = self.client.executor();
+		let spec_version = executor.runtime_version(hash).map_err(client_err)?.spec_version;
+		if let Some((version, metadata)) = &*self.metadata.lock() {
+			if *version == spec_version {
+				return Ok(metadata.clone())
+			}
+		}
+
+		let encoded = executor
+			.call(
+				hash,
+				"Metadata_metadata_at_version",
+				&METADATA_VERSION.encode(),
+				CallContext::Offchain,
+			)
+			.map_err(client_err)?;
+		let opaque = Option::<Vec<u8>>::decode(&mut &encoded[..])
+			.map_err(|e| Error::MetadataUnavailable(e.to_string()))?
+			.ok_or_else(|| Error::MetadataUnavailable("not supported by the runtime".into()))?;
+		let metadata = match RuntimeMetadataPrefixed::decode(&mut &opaque[..])
+			.map_err(|e| Error::MetadataUnavailable(e.to_string()))?
+			.1
+		{
+			RuntimeMetadata::V16(metadata) => Arc::new(metadata),
+			other =>
+				return Err(Error::MetadataUnavailable(format!(
+					"runtime returned version {}",
+					other.version()
+				))),
+		};
+
+		*self.metadata.lock() = Some((spec_version, metadata.clone()));
+		Ok(metadata)
+	}
+}
+
+/// A view function, as described by the metadata.
+struct ResolvedViewFunction {
+	id: [u8; 32],
+	inputs: Vec<u32>,
+	output: u32,
+}
+
+impl ResolvedViewFunction {
+	fn resolve(metadata: &RuntimeMetadataV16, pallet: &str, function: &str) -> Result<Self> {
+		let view_function = metadata
+			.pallets
+			.iter()
+			.find(|p| p.name == pallet)
+			.ok_or_else(|| Error::PalletNotFound(pallet.into()))?
+			.view_functions
+			.iter()
+			.find(|f| f.name == function)
+			.ok_or_else(|| Error::ViewFunctionNotFound {
+				pallet: pallet.into(),
+				function: function.into(),
+			})?;
+
+		Ok(Self {
+			id: view_function.id,
+			inputs: view_function.inputs.iter().map(|input| input.ty.id).collect(),
+			output: view_function.output.id,
+		})
+	}
+
+	/// Encode `args` into the input of the view function.
+	fn encode_args(&self, types: &PortableRegistry, args: ViewFunctionArgs) -> Result<Vec<u8>> {
+		let values = match args {
+			ViewFunctionArgs::Scale(input) => return Ok(input.0),
+			ViewFunctionArgs::Json(values) => values,
+		};
+
+		if values.len() != self.inputs.len() {
+			return Err(Error::InvalidArgs(format!(
+				"expected {} arguments, got {}",
+				self.inputs.len(),
+				values.len()
+			)))
+		}
+
+		let mut input = Vec::new();
+		for (index, (value, ty)) in values.iter().zip(&self.inputs).enumerate() {
+			let value = json_to_value(value)
+				.map_err(|e| Error::InvalidArgs(format!("argument {index}: {e}")))?;
+			scale_value::scale::encode_as_type(&value, *ty, types, &mut input)
+				.map_err(|e| Error::InvalidArgs(format!("argument {index}: {e}")))?;
+		}
+		Ok(input)
+	}
+
+	/// Decode the SCALE encoded return value of the view function.
+	fn decode_output(&self, types: &PortableRegistry, mut output: &[u8]) -> Result<JsonValue> {
+		let value = scale_value::scale::decode_as_type(&mut output, self.output, types)
+			.map_err(|e| Error::InvalidOutput(e.to_string()))?;
+		if !output.is_empty() {
+			return Err(Error::InvalidOutput(format!("{} trailing bytes", output.len())))
+		}
+		serde_json::to_value(&value).map_err(|e| Error::InvalidOutput(e.to_string()))
+	}
+}
+
+/// Convert a JSON argument to a [`scale_value::Value`] which can be encoded using the metadata.
+///
+/// Objects with exactly a `name` and a `values` field are variants, the same shape output values
+/// of enum types are returned in.
+fn json_to_value(value: &JsonValue) -> std::result::Result<scale_value::Value, String> {
+	use scale_value::Value;
+
+	Ok(match value {
+		JsonValue::Null => Value::unnamed_composite(Vec::new()),
+		JsonValue::Bool(value) => Value::bool(*value),
+		JsonValue::Number(number) =>
+			if let Some(number) = number.as_u64() {
+				Value::u128(number.into())
+			} else if let Some(number) = number.as_i64() {
+				Value::i128(number.into())
+			} else {
+				return Err(format!("unsupported number {number}"))
+			},
+		JsonValue::String(value) => Value::string(value.clone()),
+		JsonValue::Array(values) => Value::unnamed_composite(
+			values.iter().map(json_to_value).collect::<std::result::Result<Vec<_>, _>>()?,
+		),
+		JsonValue::Object(fields) => match (fields.len(), fields.get("name"), fields.get("values"))
+		{
+			(2, Some(JsonValue::String(name)), Some(JsonValue::Array(values))) =>
+				Value::unnamed_variant(
+					name.clone(),
+					values.iter().map(json_to_value).collect::<std::result::Result<Vec<_>, _>>()?,
+				),
+			(2, Some(JsonValue::String(name)), Some(JsonValue::Object(fields))) =>
+				Value::named_variant(
+					name.clone(),
+					fields
+						.iter()
+						.map(|(name, value)| Ok((name.clone(), json_to_value(value)?)))
+						.collect::<std::result::Result<Vec<_>, String>>()?,
+				),
+			_ => Value::named_composite(
+				fields
+					.iter()
+					.map(|(name, value)| Ok((name.clone(), json_to_value(value)?)))
+					.collect::<std::result::Result<Vec<_>, String>>()?,
+			),
+		},
+	})
+}
+
+/// Describe the result of a failed view function call, using the return type of the runtime api
+/// if it is part of the metadata.
+fn describe_failed_call(metadata: &RuntimeMetadataV16, result: &[u8]) -> String {
+	let output_ty = metadata
+		.apis
+		.iter()
+		.find(|api| api.name == VIEW_FUNCTION_API)
+		.and_then(|api| api.methods.iter().find(|method| method.name == VIEW_FUNCTION_METHOD))
+		.map(|method| method.output.id);
+
+	output_ty
+		.and_then(|ty| {
+			scale_value::scale::decode_as_type(&mut &result[..], ty, &metadata.types).ok()
+		})
+		.and_then(|value| serde_json::to_string(&value).ok())
+		.unwrap_or_else(|| format!("0x{}", HexDisplay::from(&result)))
+}
+
+fn client_err(err: sp_blockchain::Error) -> Error {
+	Error::Client(Box::new(err))
+}
@@ -0,0 +1,115 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::testing::{allow_unsafe, deny_unsafe, test_executor};
+use assert_matches::assert_matches;
+use scale_info::{meta_type, Registry};
+use serde_json::json;
+use substrate_test_runtime_client::runtime::Block;
+
+fn test_view_function() -> (ResolvedViewFunction, PortableRegistry) {
+	let mut registry = Registry::new();
+	let inputs = vec![
+		registry.register_type(&meta_type::<u32>()).id,
+		registry.register_type(&meta_type::<Vec<u16>>()).id,
+	];
+	let output = registry.register_type(&meta_type::<(u64, Option<u32>)>()).id;
+
+	(ResolvedViewFunction { id: [0; 32], inputs, output }, registry.into())
+}
+
+#[test]
+fn json_args_are_encoded_using_metadata_types() {
+	let (view_function, types) = test_view_function();
+
+	let input = view_function
+		.encode_args(&types, ViewFunctionArgs::Json(vec![json!(7), json!([1, 2])]))
+		.unwrap();
+	assert_eq!(input, (7u32, vec![1u16, 2]).encode());
+
+	assert_matches!(
+		view_function.encode_args(&types, ViewFunctionArgs::Json(vec![json!(7)])),
+		Err(Error::InvalidArgs(_))
+	);
+	assert_matches!(
+		view_function.encode_args(&types, ViewFunctionArgs::Json(vec![json!(-1), json!([])])),
+		Err(Error::InvalidArgs(_))
+	);
+}
+
+#[test]
+fn scale_args_are_passed_through() {
+	let (view_function, types) = test_view_function();
+
+	let input = (7u32, vec![1u16, 2]).encode();
+	assert_eq!(
+		view_function
+			.encode_args(&types, ViewFunctionArgs::Scale(input.clone().into()))
+			.unwrap(),
+		input,
+	);
+}
+
+#[test]
+fn output_is_decoded_using_metadata_types() {
+	let (view_function, types) = test_view_function();
+
+	assert_eq!(
+		view_function.decode_output(&types, &(5u64, Some(3u32)).encode()).unwrap(),
+		json!([5, { "name": "Some", "values": [3] }]),
+	);
+
+	let mut output = (5u64, None::<u32>).encode();
+	output.push(0);
+	assert_matches!(view_function.decode_output(&types, &output), Err(Error::InvalidOutput(_)));
+}
+
+#[tokio::test]
+async fn unknown_view_functions_are_reported() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let api = View::<Block, _>::new(client, test_executor());
+	let ext = allow_unsafe();
+
+	assert_matches!(
+		api.call(&ext, "Unknown".into(), "get".into(), ViewFunctionArgs::Json(vec![]), None),
+		Err(Error::PalletNotFound(pallet)) if pallet == "Unknown"
+	);
+	assert_matches!(
+		api.call(&ext, "System".into(), "unknown".into(), ViewFunctionArgs::Json(vec![]), None),
+		Err(Error::ViewFunctionNotFound { pallet, function })
+			if pallet == "System" && function == "unknown"
+	);
+}
+
+#[tokio::test]
+async fn view_functions_are_unsafe() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let api = View::<Block, _>::new(client, test_executor());
+
+	assert_matches!(
+		api.call(
+			&deny_unsafe(),
+			"System".into(),
+			"get".into(),
+			ViewFunctionArgs::Json(vec![]),
+			None
+		),
+		Err(Error::UnsafeRpcCalled(_))
+	);
+}
@@ -72,6 +72,7 @@ use sc_rpc::{
 	offchain::OffchainApiServer,
 	state::{ChildStateApiServer, StateApiServer},
 	system::SystemApiServer,
+	view::ViewApiServer,
 	DenyUnsafe, SubscriptionTaskExecutor,
 };
 use sc_rpc_spec_v2::{
@@ -869,6 +870,8 @@ where
 
 	let system = sc_rpc::system::System::new(system_info, system_rpc_tx).into_rpc();
 
+	let view = sc_rpc::view::View::new(client.clone(), task_executor.clone()).into_rpc();
+
 	if let Some(storage) = backend.offchain_storage() {
 		let offchain = sc_rpc::offchain::Offchain::new(storage).into_rpc();
 
@@ -889,6 +892,7 @@ where
 	rpc_api.merge(system).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(state).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(child_state).map_err(|e| Error::Application(e.into()))?;
+	rpc_api.merge(view).map_err(|e| Error::Application(e.into()))?;
 	// Additional [`RpcModule`]s defined in the node to fit the specific blockchain
 	let extra_rpcs = rpc_builder(task_executor.clone())?;
 	rpc_api.merge(extra_rpcs).map_err(|e| Error::Application(e.into()))?;
@@ -78,6 +78,8 @@ pub struct Configuration {
 	///
 	/// NOTE: only finalized blocks are subject for removal!
 	pub blocks_pruning: BlocksPruning,
+	/// Index the event topics recorded by `frame_system` on import.
+	pub event_topics_index: bool,
 	/// Chain configuration.
 	pub chain_spec: Box<dyn ChainSpec>,
 	/// Runtime executor configuration.
@@ -235,6 +237,7 @@ impl Configuration {
 			state_pruning: self.state_pruning.clone(),
 			source: self.database.clone(),
 			blocks_pruning: self.blocks_pruning,
+			event_topics_index: self.event_topics_index,
 			metrics_registry: self.prometheus_registry().cloned(),
 		}
 	}
@@ -1486,6 +1486,7 @@ fn doesnt_import_blocks_that_revert_finality() {
 				state_pruning: Some(PruningMode::ArchiveAll),
 				blocks_pruning: BlocksPruning::KeepAll,
 				source: DatabaseSource::RocksDb { path: tmp.path().into(), cache_size: 1024 },
+				event_topics_index: false,
 				metrics_registry: None,
 			},
 			u64::MAX,
@@ -1763,6 +1764,7 @@ fn returns_status_for_pruned_blocks() {
 				state_pruning: Some(PruningMode::blocks_pruning(1)),
 				blocks_pruning: BlocksPruning::KeepFinalized,
 				source: DatabaseSource::RocksDb { path: tmp.path().into(), cache_size: 1024 },
+				event_topics_index: false,
 				metrics_registry: None,
 			},
 			u64::MAX,
@@ -166,6 +166,32 @@ where
 	(lp_token, liquidity1, liquidity2)
 }
 
+/// Fill the ring buffer of price observations of the pool of `asset1` and `asset2` and move to
+/// the next block, so that the next change of the pool records a new observation.
+fn fill_price_oracle<T: Config>(asset1: &T::AssetKind, asset2: &T::AssetKind) {
+	let max_observations = T::MaxPriceObservations::get();
+	if max_observations.is_zero() {
+		return
+	}
+	let pool_id = T::PoolLocator::pool_id(asset1, asset2).unwrap();
+	let now = frame_system::Pallet::<T>::block_number();
+	let latest = PriceObservation { block: now, ..Default::default() };
+	for index in 0..max_observations {
+		PriceObservations::<T>::insert(&pool_id, index, latest);
+	}
+	PriceOracles::<T>::insert(
+		&pool_id,
+		PriceOracleState {
+			latest,
+			index: max_observations - 1,
+			count: max_observations,
+			reserve1: 1,
+			reserve2: 1,
+		},
+	);
+	frame_system::Pallet::<T>::set_block_number(now + One::one());
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -174,7 +200,11 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	assert_eq!(event, &system_event);
 }
 
-#[benchmarks(where T::Assets: Create<T::AccountId> + Mutate<T::AccountId>, T::PoolAssetId: Into<u32>,)]
+#[benchmarks(where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+	T::PoolAssetId: Into<u32>,
+	T::PoolId: Into<(T::AssetKind, T::AssetKind)>,
+)]
 mod benchmarks {
 	use super::*;
 
@@ -207,6 +237,7 @@ mod benchmarks {
 		create_fee_asset::<T>(&caller);
 		let (lp_token, liquidity1, liquidity2) =
 			create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+		fill_price_oracle::<T>(&asset1, &asset2);
 
 		#[extrinsic_call]
 		_(
@@ -249,6 +280,7 @@ mod benchmarks {
 			T::Balance::zero(),
 			caller.clone(),
 		));
+		fill_price_oracle::<T>(&asset1, &asset2);
 		let total_supply =
 			<T::PoolAssets as Inspect<T::AccountId>>::total_issuance(lp_token.clone());
 
@@ -295,6 +327,7 @@ mod benchmarks {
 				T::Balance::zero(),
 				caller.clone(),
 			));
+			fill_price_oracle::<T>(&asset1, &asset2);
 		}
 
 		let asset_in = *path.first().unwrap().clone();
@@ -347,6 +380,7 @@ mod benchmarks {
 				T::Balance::zero(),
 				caller.clone(),
 			));
+			fill_price_oracle::<T>(&asset1, &asset2);
 		}
 
 		let asset_in = *path.first().unwrap().clone();
@@ -410,5 +444,46 @@ mod benchmarks {
 		assert_last_event::<T>(Event::Touched { pool_id, who: caller }.into());
 	}
 
+	#[benchmark]
+	fn find_best_route(n: Linear<1, { T::MaxRoutePools::get() }>) {
+		// The cost of the search is the number of paths in the frontier of a hop times the number
+		// of pools, so the worst case is a star, whose leaves are all reached in the first hop.
+		let (first, second) = T::BenchmarkHelper::create_pair(0, 1);
+		let (third, fourth) = T::BenchmarkHelper::create_pair(0, 2);
+		let center = if first == third || first == fourth { first } else { second };
+		let mut amount_in = T::Balance::one();
+		for n in 1..=n {
+			// A provider for each pool, so that no account holds too many assets.
+			let caller: T::AccountId = account("caller", n, 0);
+			create_fee_asset::<T>(&caller);
+			let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, n);
+			let (_, liquidity1, liquidity2) = create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+			assert_ok!(AssetConversion::<T>::add_liquidity(
+				SystemOrigin::Signed(caller.clone()).into(),
+				Box::new(asset1),
+				Box::new(asset2),
+				liquidity1,
+				liquidity2,
+				T::Balance::one(),
+				T::Balance::zero(),
+				caller.clone(),
+			));
+			amount_in = liquidity1;
+		}
+		// An asset without a pool, so that every leaf stays in the frontier.
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, n + 1);
+		let asset_out = if asset1 == center { asset2 } else { asset1 };
+
+		#[block]
+		{
+			AssetConversion::<T>::find_best_route(
+				center,
+				asset_out,
+				amount_in,
+				T::MaxSwapPathLength::get(),
+			);
+		}
+	}
+
 	impl_benchmark_test_suite!(AssetConversion, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -34,6 +34,10 @@
 //!    a runtime call endpoint
 //!  - [query the size of a liquidity pool](`AssetConversionApi::get_reserves`) via a runtime api
 //!    endpoint.
+//!  - [query the time-weighted average price](`AssetConversionApi::twap`) of a pool over a window
+//!    of blocks via a runtime api endpoint, see the [`oracle`] module.
+//!  - [find the best route](`AssetConversionApi::best_route`) for a swap between two assets via a
+//!    runtime api endpoint, see the [`route`] module.
 //!
 //! The `quote_price_exact_tokens_for_tokens` and `quote_price_tokens_for_exact_tokens` functions
 //! both take a path parameter of the route to take. If you want to swap from native asset to
@@ -58,6 +62,8 @@
 mod benchmarking;
 #[cfg(test)]
 mod mock;
+pub mod oracle;
+pub mod route;
 mod swap;
 #[cfg(test)]
 mod tests;
@@ -65,7 +71,9 @@ mod types;
 pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::{BenchmarkHelper, NativeOrWithIdFactory};
+pub use oracle::PriceOracle;
 pub use pallet::*;
+pub use route::FindRoute;
 pub use swap::*;
 pub use types::*;
 pub use weights::WeightInfo;
@@ -186,6 +194,20 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSwapPathLength: Get<u32>;
 
+		/// The max number of price observations kept per pool for the time-weighted average
+		/// price oracle. Limits the longest window the average price can be queried for to the
+		/// last `MaxPriceObservations` blocks with a swap or a liquidity change in the pool.
+		///
+		/// Zero disables the oracle.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
+		/// The max number of pools for which the best route of a swap is searched, no route is
+		/// found beyond it. Bounds the weight of the search, see the [`route`](crate::route)
+		/// module.
+		#[pallet::constant]
+		type MaxRoutePools: Get<u32>;
+
 		/// The pallet's id, used for deriving its sovereign account ID.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -209,6 +231,28 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextPoolAssetId<T: Config> = StorageValue<_, T::PoolAssetId, OptionQuery>;
 
+	/// The state of the price oracle of a pool, see [`crate::oracle`].
+	#[pallet::storage]
+	pub type PriceOracles<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		PriceOracleState<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The ring buffer of price observations of a pool, indexed by the position in the buffer.
+	#[pallet::storage]
+	pub type PriceObservations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Twox64Concat,
+		u32,
+		PriceObservation<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	// Pallet's events.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -505,8 +549,9 @@ pub mod pallet {
 				Error::<T>::AmountTwoLessThanMinimal
 			);
 
-			T::Assets::transfer(*asset1, &sender, &pool_account, amount1, Preserve)?;
-			T::Assets::transfer(*asset2, &sender, &pool_account, amount2, Preserve)?;
+			T::Assets::transfer(*asset1.clone(), &sender, &pool_account, amount1, Preserve)?;
+			T::Assets::transfer(*asset2.clone(), &sender, &pool_account, amount2, Preserve)?;
+			Self::update_price_oracle(&asset1, &asset2);
 
 			let total_supply = T::PoolAssets::total_issuance(pool.lp_token.clone());
 
@@ -608,8 +653,9 @@ pub mod pallet {
 				Polite,
 			)?;
 
-			T::Assets::transfer(*asset1, &pool_account, &withdraw_to, amount1, Expendable)?;
-			T::Assets::transfer(*asset2, &pool_account, &withdraw_to, amount2, Expendable)?;
+			T::Assets::transfer(*asset1.clone(), &pool_account, &withdraw_to, amount1, Expendable)?;
+			T::Assets::transfer(*asset2.clone(), &pool_account, &withdraw_to, amount2, Expendable)?;
+			Self::update_price_oracle(&asset1, &asset2);
 
 			Self::deposit_event(Event::LiquidityRemoved {
 				who: sender,
@@ -1000,6 +1046,10 @@ pub mod pallet {
 			T::Assets::resolve(&pool_to, credit_in)
 				.map_err(|c| (c, Error::<T>::BelowMinimum.into()))?;
 
+			for pair in path.windows(2) {
+				Self::update_price_oracle(&pair[0].0, &pair[1].0);
+			}
+
 			Ok(credit_out)
 		}
 
@@ -1025,7 +1075,7 @@ pub mod pallet {
 
 		/// Get the `owner`'s balance of `asset`, which could be the chain's native asset or another
 		/// fungible. Returns a value in the form of an `Balance`.
-		fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
+		pub(crate) fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
 			T::Assets::reducible_balance(asset, owner, Expendable, Polite)
 		}
 
@@ -1323,6 +1373,29 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Returns the time-weighted average price of `asset1` in terms of `asset2` over the
+		/// last `window` blocks.
+		///
+		/// Returns `None` if the pool does not exist or the oracle does not cover the window.
+		#[api_version(2)]
+		fn twap(asset1: AssetId, asset2: AssetId, window: u32) -> Option<sp_runtime::FixedU128>;
+
+		/// Returns the path of at most `max_hops` pools that yields the largest amount of
+		/// `asset_out` for exactly `amount_in` of `asset_in`, along with that amount.
+		///
+		/// Returns `None` if there are more pools than the route search of the pallet is bounded
+		/// to, see `Config::MaxRoutePools`.
+		///
+		/// Note that the price may have changed by the time the transaction is executed.
+		/// (Use `amount_out_min` of [`Pallet::swap_exact_tokens_for_tokens`] to control slippage.)
+		#[api_version(3)]
+		fn best_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			max_hops: u32,
+		) -> Option<(Vec<AssetId>, Balance)>;
 	}
 }
 
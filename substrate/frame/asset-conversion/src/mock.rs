@@ -88,8 +88,10 @@ impl pallet_assets::Config<Instance1> for Test {
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type PermitSignature = pallet_verify_signature::testing::TestSignature<u128>;
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
+		type PermitBenchmarkHelper = ();
 	}
 }
 
@@ -114,8 +116,10 @@ impl pallet_assets::Config<Instance2> for Test {
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type PermitSignature = pallet_verify_signature::testing::TestSignature<u128>;
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
+		type PermitBenchmarkHelper = ();
 	}
 }
 
@@ -123,6 +127,7 @@ parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
 	pub storage LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub storage MaxRoutePools: u32 = 100;
 }
 
 ord_parameter_types! {
@@ -154,6 +159,8 @@ impl Config for Test {
 	type LPFee = ConstU32<3>; // means 0.3%
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxPriceObservations = ConstU32<10>;
+	type MaxRoutePools = MaxRoutePools;
 	type MintMinLiquidity = ConstU128<100>; // 100 is good enough when the main currency has 12 decimals.
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
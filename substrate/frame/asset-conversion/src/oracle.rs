@@ -0,0 +1,290 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time-weighted average prices of the pools.
+//!
+//! Every pool accumulates the price of each of its assets in terms of the other one per block,
+//! following the [Uniswap V2](https://docs.uniswap.org/contracts/v2/concepts/core-concepts/oracles)
+//! oracle design. The accumulators are updated with the reserves recorded after the latest swap
+//! or liquidity change of the pool when it changes for the first time in a block, so only the
+//! price at the end of a block counts, which makes them expensive to manipulate. Balances
+//! transferred to the pool without a swap do not move the price until they are part of the
+//! reserves recorded by the next change. A snapshot of the accumulators is kept per block with a
+//! reserve change in a ring buffer of [`Config::MaxPriceObservations`] entries, from which the
+//! average price over a window of blocks is derived.
+//!
+//! The assets of a pool are ordered by their SCALE encoding for the accumulators.
+
+use super::*;
+use codec::Encode;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, traits::UniqueSaturatedInto,
+	FixedPointNumber, FixedU128, Rounding,
+};
+
+/// Trait for providing time-weighted average prices of the pools.
+pub trait PriceOracle {
+	/// Measurement units of the asset classes for pricing.
+	type Balance: Balance;
+	/// Type representing the kind of assets for which the price is being quoted.
+	type AssetKind;
+	/// Type measuring the length of the averaging window.
+	type BlockNumber;
+
+	/// The average price of `asset1` in terms of `asset2` over the last `window` blocks.
+	///
+	/// Returns `None` if the pool does not exist or the stored observations do not cover the
+	/// window.
+	fn twap(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		window: Self::BlockNumber,
+	) -> Option<FixedU128>;
+
+	/// Quotes the amount of `asset1` required to obtain the exact `amount` of `asset2` at the
+	/// average price over the last `window` blocks.
+	///
+	/// If `include_fee` is set to `true`, the price will include the pool's fee.
+	fn quote_twap_tokens_for_exact_tokens(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		amount: Self::Balance,
+		window: Self::BlockNumber,
+		include_fee: bool,
+	) -> Option<Self::Balance>;
+
+	/// Quotes the amount of `asset2` resulting from swapping the exact `amount` of `asset1` at
+	/// the average price over the last `window` blocks.
+	///
+	/// If `include_fee` is set to `true`, the price will include the pool's fee.
+	fn quote_twap_exact_tokens_for_tokens(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		amount: Self::Balance,
+		window: Self::BlockNumber,
+		include_fee: bool,
+	) -> Option<Self::Balance>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Order the assets of a pool for its price accumulators.
+	///
+	/// Returns whether the assets were swapped.
+	fn oracle_order(
+		asset1: T::AssetKind,
+		asset2: T::AssetKind,
+	) -> (T::AssetKind, T::AssetKind, bool) {
+		if asset1.encode() <= asset2.encode() {
+			(asset1, asset2, false)
+		} else {
+			(asset2, asset1, true)
+		}
+	}
+
+	/// The reserves of the pool at `pool_account`, as `u128`.
+	fn oracle_reserves(
+		pool_account: &T::AccountId,
+		asset1: &T::AssetKind,
+		asset2: &T::AssetKind,
+	) -> (u128, u128) {
+		(
+			Self::get_balance(pool_account, asset1.clone()).unique_saturated_into(),
+			Self::get_balance(pool_account, asset2.clone()).unique_saturated_into(),
+		)
+	}
+
+	/// Update the price accumulators of the pool of `asset1` and `asset2`, and record its
+	/// reserves.
+	///
+	/// Must be called after the reserves of the pool changed.
+	pub(crate) fn update_price_oracle(asset1: &T::AssetKind, asset2: &T::AssetKind) {
+		let max_observations = T::MaxPriceObservations::get();
+		if max_observations.is_zero() {
+			return
+		}
+		let (asset1, asset2, _) = Self::oracle_order(asset1.clone(), asset2.clone());
+		let Ok(pool_id) = T::PoolLocator::pool_id(&asset1, &asset2) else { return };
+		let Ok(pool_account) = T::PoolLocator::address(&pool_id) else { return };
+		let (reserve1, reserve2) = Self::oracle_reserves(&pool_account, &asset1, &asset2);
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let state = match PriceOracles::<T>::get(&pool_id) {
+			// The accumulators are only updated once per block.
+			Some(state) if state.latest.block >= now => {
+				PriceOracles::<T>::insert(
+					&pool_id,
+					PriceOracleState { reserve1, reserve2, ..state },
+				);
+				return
+			},
+			// The price since the latest observation is the one of the recorded reserves.
+			Some(state) => PriceOracleState {
+				latest: state.latest.accumulate(state.reserve1, state.reserve2, now),
+				index: (state.index + 1) % max_observations,
+				count: state.count.saturating_add(1).min(max_observations),
+				reserve1,
+				reserve2,
+			},
+			None => PriceOracleState {
+				latest: PriceObservation { block: now, ..Default::default() },
+				index: 0,
+				count: 1,
+				reserve1,
+				reserve2,
+			},
+		};
+
+		PriceObservations::<T>::insert(&pool_id, state.index, state.latest);
+		PriceOracles::<T>::insert(&pool_id, state);
+	}
+
+	/// The average price of `asset1` in terms of `asset2` over the last `window` blocks.
+	pub fn twap(
+		asset1: T::AssetKind,
+		asset2: T::AssetKind,
+		window: BlockNumberFor<T>,
+	) -> Option<FixedU128> {
+		if window.is_zero() {
+			return None
+		}
+		let (asset1, asset2, swapped) = Self::oracle_order(asset1, asset2);
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).ok()?;
+		let state = PriceOracles::<T>::get(&pool_id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let start = now.checked_sub(&window)?;
+
+		// The recorded reserves determine the price since the latest observation.
+		let (reserve1, reserve2) = (state.reserve1, state.reserve2);
+		let current = state.latest.accumulate(reserve1, reserve2, now);
+		let past = if start >= state.latest.block {
+			state.latest.accumulate(reserve1, reserve2, start)
+		} else {
+			Self::observation_at(&pool_id, &state, start)?
+		};
+
+		let (current, past) = if swapped {
+			(current.price2_cumulative, past.price2_cumulative)
+		} else {
+			(current.price1_cumulative, past.price1_cumulative)
+		};
+		let window: u128 = window.unique_saturated_into();
+		Some(FixedU128::from_inner(current.wrapping_sub(past) / window))
+	}
+
+	/// The accumulators at block `at`, interpolated from the stored observations.
+	///
+	/// `at` must be before the latest observation.
+	fn observation_at(
+		pool_id: &T::PoolId,
+		state: &PriceOracleState<BlockNumberFor<T>>,
+		at: BlockNumberFor<T>,
+	) -> Option<PriceObservation<BlockNumberFor<T>>> {
+		let max_observations = T::MaxPriceObservations::get().max(state.count).max(1);
+		let count = state.count.min(max_observations);
+		let oldest = (state.index + max_observations + 1 - count) % max_observations;
+		let get = |position: u32| {
+			PriceObservations::<T>::get(pool_id, (oldest + position) % max_observations)
+		};
+
+		// Find the latest observation at or before `at`.
+		let (mut low, mut high) = (0, count);
+		while low < high {
+			let mid = low + (high - low) / 2;
+			if get(mid)?.block <= at {
+				low = mid + 1;
+			} else {
+				high = mid;
+			}
+		}
+		let before = get(low.checked_sub(1)?)?;
+		let after = get(low)?;
+
+		// The price was constant between both observations.
+		let span: u128 = after.block.saturating_sub(before.block).unique_saturated_into();
+		let elapsed: u128 = at.saturating_sub(before.block).unique_saturated_into();
+		let interpolate = |before: u128, after: u128| {
+			let price = after.wrapping_sub(before) / span.max(1);
+			before.wrapping_add(price.wrapping_mul(elapsed))
+		};
+		Some(PriceObservation {
+			block: at,
+			price1_cumulative: interpolate(before.price1_cumulative, after.price1_cumulative),
+			price2_cumulative: interpolate(before.price2_cumulative, after.price2_cumulative),
+		})
+	}
+
+	/// Convert `amount` with `price`, optionally accounting for the pool's fee.
+	fn twap_convert(
+		amount: T::Balance,
+		price: FixedU128,
+		include_fee: bool,
+		rounding: Rounding,
+	) -> Option<T::Balance> {
+		let amount =
+			price.checked_mul_int(UniqueSaturatedInto::<u128>::unique_saturated_into(amount))?;
+		let amount = if include_fee {
+			let (fee_free, total) = (1000u128.saturating_sub(T::LPFee::get().into()), 1000);
+			match rounding {
+				// The fee is paid on top of the amount required.
+				Rounding::Up =>
+					multiply_by_rational_with_rounding(amount, total, fee_free, rounding)?,
+				// The fee is deducted from the amount received.
+				_ => multiply_by_rational_with_rounding(amount, fee_free, total, rounding)?,
+			}
+		} else {
+			amount
+		};
+		T::Balance::try_from(amount).ok()
+	}
+}
+
+impl<T: Config> PriceOracle for Pallet<T> {
+	type Balance = T::Balance;
+	type AssetKind = T::AssetKind;
+	type BlockNumber = BlockNumberFor<T>;
+
+	fn twap(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		window: Self::BlockNumber,
+	) -> Option<FixedU128> {
+		Self::twap(asset1, asset2, window)
+	}
+
+	fn quote_twap_tokens_for_exact_tokens(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		amount: Self::Balance,
+		window: Self::BlockNumber,
+		include_fee: bool,
+	) -> Option<Self::Balance> {
+		let price = Self::twap(asset2, asset1, window)?;
+		Self::twap_convert(amount, price, include_fee, Rounding::Up)
+	}
+
+	fn quote_twap_exact_tokens_for_tokens(
+		asset1: Self::AssetKind,
+		asset2: Self::AssetKind,
+		amount: Self::Balance,
+		window: Self::BlockNumber,
+		include_fee: bool,
+	) -> Option<Self::Balance> {
+		let price = Self::twap(asset1, asset2, window)?;
+		Self::twap_convert(amount, price, include_fee, Rounding::Down)
+	}
+}
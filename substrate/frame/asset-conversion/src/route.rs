@@ -0,0 +1,164 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Discovery of the best route for a swap.
+//!
+//! The pools are searched breadth-first from the asset in, one hop at a time, extending the path
+//! with the largest amount out reaching each asset, until `max_hops` or
+//! [`Config::MaxSwapPathLength`] is reached. The amounts are computed like the swap computes them,
+//! with [`Pallet::get_amount_out`] over the current reserves.
+//!
+//! The search is refused, and no route is found, when there are more than
+//! [`Config::MaxRoutePools`] pools, which bounds the weight of the search. Searching a subset of
+//! the pools instead would make the route depend on the order in which the pools are stored.
+
+use super::*;
+use alloc::vec;
+use frame_support::weights::Weight;
+
+/// Trait for finding the best route to swap an asset for another.
+pub trait FindRoute {
+	/// Measurement units of the asset classes for swapping.
+	type Balance: Balance;
+	/// Kind of assets that are going to be swapped.
+	type AssetKind;
+
+	/// Returns the upper limit on the weight of [`Self::find_best_route`].
+	fn find_best_route_weight() -> Weight;
+
+	/// Finds the path of at most `max_hops` pools that yields the largest amount of `asset_out`
+	/// for exactly `amount_in` of `asset_in`.
+	///
+	/// Returns the path, including `asset_in` and `asset_out`, and the amount out, or `None` if
+	/// the assets are not connected by pools or if there are too many pools to search.
+	fn find_best_route(
+		asset_in: Self::AssetKind,
+		asset_out: Self::AssetKind,
+		amount_in: Self::Balance,
+		max_hops: u32,
+	) -> Option<(Vec<Self::AssetKind>, Self::Balance)>;
+}
+
+impl<T: Config> Pallet<T>
+where
+	T::PoolId: Into<(T::AssetKind, T::AssetKind)>,
+{
+	/// The upper limit on the weight of [`Self::find_best_route`].
+	pub fn find_best_route_weight() -> Weight {
+		T::WeightInfo::find_best_route(T::MaxRoutePools::get())
+	}
+
+	/// Finds the path of at most `max_hops` pools that yields the largest amount of `asset_out`
+	/// for exactly `amount_in` of `asset_in`, see the [`route`](crate::route) module.
+	pub fn find_best_route(
+		asset_in: T::AssetKind,
+		asset_out: T::AssetKind,
+		amount_in: T::Balance,
+		max_hops: u32,
+	) -> Option<(Vec<T::AssetKind>, T::Balance)> {
+		let max_hops = max_hops.min(T::MaxSwapPathLength::get().saturating_sub(1));
+		if asset_in == asset_out || max_hops.is_zero() || amount_in.is_zero() {
+			return None
+		}
+
+		let max_pools = T::MaxRoutePools::get() as usize;
+		let pool_ids: Vec<_> = Pools::<T>::iter_keys().take(max_pools.saturating_add(1)).collect();
+		if pool_ids.len() > max_pools {
+			return None
+		}
+
+		let pools: Vec<_> = pool_ids
+			.into_iter()
+			.filter_map(|pool_id| {
+				let (asset1, asset2) = pool_id.into();
+				let (reserve1, reserve2) =
+					Self::get_reserves(asset1.clone(), asset2.clone()).ok()?;
+				Some((asset1, asset2, reserve1, reserve2))
+			})
+			.collect();
+
+		let mut best: Option<(Vec<T::AssetKind>, T::Balance)> = None;
+		// The paths with the largest amount out reaching each asset in the last hop.
+		let mut frontier = vec![(vec![asset_in], amount_in)];
+		for _ in 0..max_hops {
+			let mut next: Vec<(Vec<T::AssetKind>, T::Balance)> = Vec::new();
+			for (path, amount) in frontier {
+				let Some(last) = path.last() else { continue };
+				for (asset1, asset2, reserve1, reserve2) in &pools {
+					let (asset, reserve_in, reserve_out) = if asset1 == last {
+						(asset2, reserve1, reserve2)
+					} else if asset2 == last {
+						(asset1, reserve2, reserve1)
+					} else {
+						continue
+					};
+					// Every pool is used at most once if every asset is.
+					if path.contains(asset) {
+						continue
+					}
+					let Ok(amount_out) = Self::get_amount_out(&amount, reserve_in, reserve_out)
+					else {
+						continue
+					};
+					if amount_out.is_zero() {
+						continue
+					}
+
+					let mut new_path = path.clone();
+					new_path.push(asset.clone());
+					// A shorter path is preferred at the same amount out.
+					if *asset == asset_out {
+						if best.as_ref().map_or(true, |(_, best_amount)| amount_out > *best_amount)
+						{
+							best = Some((new_path, amount_out));
+						}
+					} else {
+						match next.iter_mut().find(|(path, _)| path.last() == Some(asset)) {
+							Some((_, best_amount)) if *best_amount >= amount_out => {},
+							Some(entry) => *entry = (new_path, amount_out),
+							None => next.push((new_path, amount_out)),
+						}
+					}
+				}
+			}
+			frontier = next;
+		}
+
+		best
+	}
+}
+
+impl<T: Config> FindRoute for Pallet<T>
+where
+	T::PoolId: Into<(T::AssetKind, T::AssetKind)>,
+{
+	type Balance = T::Balance;
+	type AssetKind = T::AssetKind;
+
+	fn find_best_route_weight() -> Weight {
+		Self::find_best_route_weight()
+	}
+
+	fn find_best_route(
+		asset_in: Self::AssetKind,
+		asset_out: Self::AssetKind,
+		amount_in: Self::Balance,
+		max_hops: u32,
+	) -> Option<(Vec<Self::AssetKind>, Self::Balance)> {
+		Self::find_best_route(asset_in, asset_out, amount_in, max_hops)
+	}
+}
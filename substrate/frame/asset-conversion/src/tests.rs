@@ -28,7 +28,7 @@ use frame_support::{
 	},
 };
 use sp_arithmetic::Permill;
-use sp_runtime::{DispatchError, TokenError};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128, TokenError};
 
 fn events() -> Vec<Event<Test>> {
 	let result = System::events()
@@ -2493,3 +2493,360 @@ fn swap_credit_invalid_path() {
 		assert_eq!(error, (expected_credit_in, Error::<Test>::InvalidPath.into()));
 	});
 }
+
+fn setup_oracle_pool(user: u128) -> (NativeOrWithId<u32>, NativeOrWithId<u32>) {
+	let token_1 = NativeOrWithId::Native;
+	let token_2 = NativeOrWithId::WithId(2);
+
+	create_tokens(user, vec![token_2.clone()]);
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone())
+	));
+	assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 10000));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone()),
+		10000,
+		200,
+		1,
+		1,
+		user,
+	));
+	(token_1, token_2)
+}
+
+fn oracle_swap(user: u128, path: Vec<NativeOrWithId<u32>>, amount: u128) {
+	assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+		RuntimeOrigin::signed(user),
+		path.into_iter().map(Box::new).collect(),
+		amount,
+		1,
+		user,
+		false,
+	));
+}
+
+#[test]
+fn twap_tracks_pool_price() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = setup_oracle_pool(user);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		// the oracle starts at the first liquidity provision.
+		let state = PriceOracles::<Test>::get(&pool_id).unwrap();
+		assert_eq!((state.latest.block, state.index, state.count), (1, 0, 1));
+
+		// no blocks passed yet.
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 1), None);
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 0), None);
+
+		System::set_block_number(11);
+		let price1 = FixedU128::saturating_from_rational(200, 10000);
+		let price2 = FixedU128::saturating_from_rational(10000, 200);
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 10), Some(price1));
+		assert_eq!(AssetConversion::twap(token_2.clone(), token_1.clone(), 5), Some(price2));
+		// the window is not covered by the oracle.
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 11), None);
+
+		oracle_swap(user, vec![token_2.clone(), token_1.clone()], 50);
+		let (reserve1, reserve2) =
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap();
+		let new_price1 = FixedU128::saturating_from_rational(reserve2, reserve1);
+		assert_ne!(new_price1, price1);
+
+		// the price of the block of the swap is only observed in the next block.
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 10), Some(price1));
+
+		System::set_block_number(21);
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 10), Some(new_price1));
+		let average = |blocks: u128, new_blocks: u128| {
+			FixedU128::from_inner(
+				(price1.into_inner() * blocks + new_price1.into_inner() * new_blocks) /
+					(blocks + new_blocks),
+			)
+		};
+		assert_eq!(
+			AssetConversion::twap(token_1.clone(), token_2.clone(), 20),
+			Some(average(10, 10))
+		);
+		// the window starts in between two observations.
+		assert_eq!(
+			AssetConversion::twap(token_1.clone(), token_2.clone(), 15),
+			Some(average(5, 10))
+		);
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 21), None);
+
+		// a pool without liquidity changes has no oracle.
+		let token_3 = NativeOrWithId::WithId(3);
+		create_tokens(user, vec![token_3.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone())
+		));
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_3.clone(), 1), None);
+	});
+}
+
+#[test]
+fn price_oracle_observes_once_per_block() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = setup_oracle_pool(user);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		System::set_block_number(2);
+		oracle_swap(user, vec![token_2.clone(), token_1.clone()], 10);
+		let state = PriceOracles::<Test>::get(&pool_id).unwrap();
+		assert_eq!((state.latest.block, state.index, state.count), (2, 1, 2));
+
+		// a manipulation within the same block is not observed.
+		oracle_swap(user, vec![token_2.clone(), token_1.clone()], 100);
+		oracle_swap(user, vec![token_1.clone(), token_2.clone()], 5000);
+		let new_state = PriceOracles::<Test>::get(&pool_id).unwrap();
+		assert_eq!((new_state.latest, new_state.index, new_state.count), (state.latest, 1, 2));
+		// only the reserves at the end of the block are recorded.
+		assert_eq!(
+			(new_state.reserve1, new_state.reserve2),
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap()
+		);
+
+		// the observations are kept in a ring buffer.
+		let max = <Test as Config>::MaxPriceObservations::get();
+		for block in 3..(max as u64 + 5) {
+			System::set_block_number(block);
+			oracle_swap(user, vec![token_2.clone(), token_1.clone()], 1);
+		}
+		let state = PriceOracles::<Test>::get(&pool_id).unwrap();
+		assert_eq!(state.latest.block, max as u64 + 4);
+		assert_eq!((state.index, state.count), ((max + 3) % max, max));
+		assert_eq!(PriceObservations::<Test>::iter_prefix(&pool_id).count(), max as usize);
+
+		// the oldest observation is from `max - 1` blocks before the latest.
+		System::set_block_number(max as u64 + 5);
+		assert!(AssetConversion::twap(token_1.clone(), token_2.clone(), max as u64).is_some());
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), max as u64 + 1), None);
+
+		// swapping through the pool updates its oracle.
+		let token_3 = NativeOrWithId::WithId(3);
+		create_tokens(user, vec![token_3.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone())
+		));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 10000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_3.clone()),
+			10000,
+			1000,
+			1,
+			1,
+			user,
+		));
+		oracle_swap(user, vec![token_2.clone(), token_1.clone(), token_3.clone()], 10);
+		let state = PriceOracles::<Test>::get(&pool_id).unwrap();
+		assert_eq!(state.latest.block, max as u64 + 5);
+	});
+}
+
+#[test]
+fn twap_ignores_transfers_to_pool() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = setup_oracle_pool(user);
+		let pool_id = (token_1.clone(), token_2.clone());
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+		let price1 = FixedU128::saturating_from_rational(200, 10000);
+
+		// a transfer into the pool does not move the price, even if followed by a swap.
+		System::set_block_number(11);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(user), pool_account, 5000));
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 10), Some(price1));
+		oracle_swap(user, vec![token_2.clone(), token_1.clone()], 1);
+		assert_eq!(AssetConversion::twap(token_1.clone(), token_2.clone(), 10), Some(price1));
+	});
+}
+
+#[test]
+fn can_quote_twap() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let (token_1, token_2) = setup_oracle_pool(user);
+
+		System::set_block_number(11);
+		assert_eq!(
+			AssetConversion::quote_twap_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_1.clone(),
+				100,
+				10,
+				false
+			),
+			Some(5000)
+		);
+		assert_eq!(
+			AssetConversion::quote_twap_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_1.clone(),
+				100,
+				10,
+				true
+			),
+			Some(4985)
+		);
+		assert_eq!(
+			AssetConversion::quote_twap_tokens_for_exact_tokens(
+				token_2.clone(),
+				token_1.clone(),
+				5000,
+				10,
+				false
+			),
+			Some(100)
+		);
+		assert_eq!(
+			AssetConversion::quote_twap_tokens_for_exact_tokens(
+				token_2.clone(),
+				token_1.clone(),
+				5000,
+				10,
+				true
+			),
+			Some(101)
+		);
+		assert_eq!(
+			AssetConversion::quote_twap_tokens_for_exact_tokens(
+				token_2.clone(),
+				token_1.clone(),
+				5000,
+				11,
+				true
+			),
+			None
+		);
+	});
+}
+
+fn setup_route_pool(
+	user: u128,
+	asset1: NativeOrWithId<u32>,
+	asset2: NativeOrWithId<u32>,
+	amount1: u128,
+	amount2: u128,
+) {
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(asset1.clone()),
+		Box::new(asset2.clone())
+	));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(asset1),
+		Box::new(asset2),
+		amount1,
+		amount2,
+		1,
+		1,
+		user,
+	));
+}
+
+#[test]
+fn can_find_best_route() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let token_4 = NativeOrWithId::WithId(4);
+		create_tokens(user, vec![token_2.clone(), token_3.clone(), token_4.clone()]);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 1_000_000));
+		for id in 2..=4 {
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(user), id, user, 1_000_000));
+		}
+
+		setup_route_pool(user, token_1.clone(), token_2.clone(), 10_000, 10_000);
+		setup_route_pool(user, token_1.clone(), token_3.clone(), 10_000, 10_000);
+		// the direct pool is shallow.
+		setup_route_pool(user, token_2.clone(), token_3.clone(), 1_000, 1_000);
+
+		// the route through the native asset yields more than the direct pool.
+		let amount_in = 500;
+		let direct = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_2.clone(),
+			token_3.clone(),
+			amount_in,
+			true,
+		)
+		.unwrap();
+		let path = vec![token_2.clone(), token_1.clone(), token_3.clone()];
+		let via_native = AssetConversion::balance_path_from_amount_in(amount_in, path.clone())
+			.unwrap()
+			.last()
+			.unwrap()
+			.1;
+		assert!(via_native > direct);
+		assert_eq!(
+			AssetConversion::find_best_route(token_2.clone(), token_3.clone(), amount_in, 2),
+			Some((path.clone(), via_native))
+		);
+
+		// the route is limited by the number of hops.
+		assert_eq!(
+			AssetConversion::find_best_route(token_2.clone(), token_3.clone(), amount_in, 1),
+			Some((vec![token_2.clone(), token_3.clone()], direct))
+		);
+
+		// a small amount is better swapped through the direct pool.
+		let direct = AssetConversion::quote_price_exact_tokens_for_tokens(
+			token_2.clone(),
+			token_3.clone(),
+			10,
+			true,
+		)
+		.unwrap();
+		assert_eq!(
+			AssetConversion::find_best_route(token_2.clone(), token_3.clone(), 10, 2),
+			Some((vec![token_2.clone(), token_3.clone()], direct))
+		);
+
+		// the found route can be swapped.
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			path.into_iter().map(Box::new).collect(),
+			amount_in,
+			via_native,
+			user,
+			false,
+		));
+
+		// no route without pools.
+		assert_eq!(AssetConversion::find_best_route(token_2.clone(), token_4.clone(), 10, 3), None);
+		setup_route_pool(user, token_3.clone(), token_4.clone(), 10_000, 10_000);
+		assert_eq!(
+			AssetConversion::find_best_route(token_1.clone(), token_4.clone(), 10, 2)
+				.map(|(path, _)| path),
+			Some(vec![token_1.clone(), token_3.clone(), token_4.clone()])
+		);
+		// the hops are limited by the max swap path length.
+		assert_eq!(AssetConversion::find_best_route(token_2.clone(), token_4.clone(), 10, 0), None);
+		assert!(
+			AssetConversion::find_best_route(token_2.clone(), token_4.clone(), 10, 10).is_some()
+		);
+		assert_eq!(AssetConversion::find_best_route(token_2.clone(), token_2.clone(), 10, 2), None);
+
+		// no route is searched beyond the max number of pools.
+		MaxRoutePools::set(&3);
+		assert_eq!(AssetConversion::find_best_route(token_1.clone(), token_4.clone(), 10, 2), None);
+		MaxRoutePools::set(&4);
+		assert!(AssetConversion::find_best_route(token_1.clone(), token_4.clone(), 10, 2).is_some());
+	});
+}
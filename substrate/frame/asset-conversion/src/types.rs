@@ -19,7 +19,10 @@ use super::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
-use sp_runtime::traits::TryConvert;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, TryConvert},
+	FixedPointNumber, FixedU128,
+};
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
 /// be deposited to get the following asset's amount withdrawn (this is inclusive of fees).
@@ -41,6 +44,59 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// A snapshot of the cumulative prices of a pool, see [`PriceOracle`].
+#[derive(Decode, Encode, Clone, Copy, Default, PartialEq, Eq, Debug, MaxEncodedLen, TypeInfo)]
+pub struct PriceObservation<BlockNumber> {
+	/// The block the snapshot was taken at.
+	pub block: BlockNumber,
+	/// The price of the first asset of the pool in terms of the second one, summed up per block.
+	///
+	/// The inner value of a [`FixedU128`]; it is expected to wrap around on overflow.
+	pub price1_cumulative: u128,
+	/// The price of the second asset of the pool in terms of the first one, summed up per block.
+	///
+	/// The inner value of a [`FixedU128`]; it is expected to wrap around on overflow.
+	pub price2_cumulative: u128,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> PriceObservation<BlockNumber> {
+	/// The observation at block `now`, given that the reserves of the pool did not change since
+	/// [`Self::block`].
+	pub fn accumulate(&self, reserve1: u128, reserve2: u128, now: BlockNumber) -> Self {
+		let elapsed: u128 = now.saturating_sub(self.block).unique_saturated_into();
+		let accumulate = |cumulative: u128, price: Option<FixedU128>| {
+			let price = price.unwrap_or_default().into_inner();
+			cumulative.wrapping_add(price.wrapping_mul(elapsed))
+		};
+		Self {
+			block: now,
+			price1_cumulative: accumulate(
+				self.price1_cumulative,
+				FixedU128::checked_from_rational(reserve2, reserve1),
+			),
+			price2_cumulative: accumulate(
+				self.price2_cumulative,
+				FixedU128::checked_from_rational(reserve1, reserve2),
+			),
+		}
+	}
+}
+
+/// The state of the price oracle of a pool.
+#[derive(Decode, Encode, Clone, Copy, Default, PartialEq, Eq, Debug, MaxEncodedLen, TypeInfo)]
+pub struct PriceOracleState<BlockNumber> {
+	/// The latest observation of the pool.
+	pub latest: PriceObservation<BlockNumber>,
+	/// The index of the latest observation in the ring buffer of observations.
+	pub index: u32,
+	/// The number of observations in the ring buffer of observations.
+	pub count: u32,
+	/// The reserve of the first asset of the pool after its latest swap or liquidity change.
+	pub reserve1: u128,
+	/// The reserve of the second asset of the pool after its latest swap or liquidity change.
+	pub reserve2: u128,
+}
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
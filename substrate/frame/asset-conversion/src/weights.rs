@@ -35,21 +35,21 @@
 //! Autogenerated weights for `pallet_asset_conversion`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `4563561839a5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
-// frame-omni-bencher
+// ./target/release/frame-omni-bencher
 // v1
 // benchmark
 // pallet
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_asset_conversion
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/asset-conversion/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/asset-conversion/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -59,7 +59,6 @@
 // --no-min-squares
 // --no-median-slopes
 // --genesis-builder-policy=none
-// --exclude-pallets=pallet_xcm,pallet_xcm_benchmarks::fungible,pallet_xcm_benchmarks::generic,pallet_nomination_pools,pallet_remark,pallet_transaction_storage,pallet_election_provider_multi_block,pallet_election_provider_multi_block::signed,pallet_election_provider_multi_block::unsigned,pallet_election_provider_multi_block::verifier
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -78,6 +77,7 @@ pub trait WeightInfo {
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight;
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight;
 	fn touch(n: u32, ) -> Weight;
+	fn find_best_route(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_asset_conversion` using the Substrate node and recommended hardware.
@@ -101,8 +101,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `476`
 		//  Estimated: `6360`
-		// Minimum execution time: 81_898_000 picoseconds.
-		Weight::from_parts(83_910_000, 6360)
+		// Minimum execution time: 200_010_000 picoseconds.
+		Weight::from_parts(204_558_000, 6360)
 			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
@@ -114,18 +114,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:2 w:2)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceObservations` (r:0 w:1)
+	/// Proof: `AssetConversion::PriceObservations` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
 	fn add_liquidity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1090`
+		//  Measured:  `1253`
 		//  Estimated: `11426`
-		// Minimum execution time: 138_751_000 picoseconds.
-		Weight::from_parts(141_390_000, 11426)
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+		// Minimum execution time: 362_224_000 picoseconds.
+		Weight::from_parts(416_217_000, 11426)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
@@ -137,47 +141,63 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:1 w:1)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceObservations` (r:0 w:1)
+	/// Proof: `AssetConversion::PriceObservations` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1233`
+		//  Measured:  `1396`
 		//  Estimated: `11426`
-		// Minimum execution time: 124_722_000 picoseconds.
-		Weight::from_parts(128_644_000, 11426)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+		// Minimum execution time: 334_506_000 picoseconds.
+		Weight::from_parts(358_131_000, 11426)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceObservations` (r:0 w:3)
+	/// Proof: `AssetConversion::PriceObservations` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (419 ±0)`
-		//  Estimated: `990 + n * (5218 ±0)`
-		// Minimum execution time: 88_884_000 picoseconds.
-		Weight::from_parts(91_036_000, 990)
-			// Standard Error: 337_841
-			.saturating_add(Weight::from_parts(11_478_919, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+		//  Measured:  `0 + n * (532 ±0)`
+		//  Estimated: `3567 + n * (5218 ±0)`
+		// Minimum execution time: 222_860_000 picoseconds.
+		Weight::from_parts(261_596_000, 3567)
+			// Standard Error: 1_072_879
+			.saturating_add(Weight::from_parts(39_355_889, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceObservations` (r:0 w:3)
+	/// Proof: `AssetConversion::PriceObservations` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (419 ±0)`
-		//  Estimated: `990 + n * (5218 ±0)`
-		// Minimum execution time: 89_080_000 picoseconds.
-		Weight::from_parts(90_913_000, 990)
-			// Standard Error: 340_609
-			.saturating_add(Weight::from_parts(11_562_623, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+		//  Measured:  `0 + n * (532 ±0)`
+		//  Estimated: `3567 + n * (5218 ±0)`
+		// Minimum execution time: 209_654_000 picoseconds.
+		Weight::from_parts(31_573_883, 3567)
+			// Standard Error: 2_294_119
+			.saturating_add(Weight::from_parts(122_427_830, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
@@ -197,13 +217,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `1154`
 		//  Estimated: `6360`
-		// Minimum execution time: 43_815_000 picoseconds.
-		Weight::from_parts(46_005_208, 6360)
-			// Standard Error: 68_937
-			.saturating_add(Weight::from_parts(19_974_807, 0).saturating_mul(n.into()))
+		// Minimum execution time: 53_159_000 picoseconds.
+		Weight::from_parts(75_145_377, 6360)
+			// Standard Error: 937_913
+			.saturating_add(Weight::from_parts(23_469_421, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
+	/// Storage: `AssetConversion::Pools` (r:129 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:129 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:256 w:0)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn find_best_route(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464 + n * (484 ±0)`
+		//  Estimated: `3675 + n * (5218 ±0)`
+		// Minimum execution time: 34_105_000 picoseconds.
+		Weight::from_parts(169_102_069, 3675)
+			// Standard Error: 503_484
+			.saturating_add(Weight::from_parts(25_776_334, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -226,8 +265,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `476`
 		//  Estimated: `6360`
-		// Minimum execution time: 81_898_000 picoseconds.
-		Weight::from_parts(83_910_000, 6360)
+		// Minimum execution time: 200_010_000 picoseconds.
+		Weight::from_parts(204_558_000, 6360)
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
@@ -239,18 +278,22 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:2 w:2)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceObservations` (r:0 w:1)
+	/// Proof: `AssetConversion::PriceObservations` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
 	fn add_liquidity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1090`
+		//  Measured:  `1253`
 		//  Estimated: `11426`
-		// Minimum execution time: 138_751_000 picoseconds.
-		Weight::from_parts(141_390_000, 11426)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+		// Minimum execution time: 362_224_000 picoseconds.
+		Weight::from_parts(416_217_000, 11426)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
@@ -262,47 +305,63 @@ impl WeightInfo for () {
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:1 w:1)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceObservations` (r:0 w:1)
+	/// Proof: `AssetConversion::PriceObservations` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1233`
+		//  Measured:  `1396`
 		//  Estimated: `11426`
-		// Minimum execution time: 124_722_000 picoseconds.
-		Weight::from_parts(128_644_000, 11426)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		// Minimum execution time: 334_506_000 picoseconds.
+		Weight::from_parts(358_131_000, 11426)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceObservations` (r:0 w:3)
+	/// Proof: `AssetConversion::PriceObservations` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (419 ±0)`
-		//  Estimated: `990 + n * (5218 ±0)`
-		// Minimum execution time: 88_884_000 picoseconds.
-		Weight::from_parts(91_036_000, 990)
-			// Standard Error: 337_841
-			.saturating_add(Weight::from_parts(11_478_919, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+		//  Measured:  `0 + n * (532 ±0)`
+		//  Estimated: `3567 + n * (5218 ±0)`
+		// Minimum execution time: 222_860_000 picoseconds.
+		Weight::from_parts(261_596_000, 3567)
+			// Standard Error: 1_072_879
+			.saturating_add(Weight::from_parts(39_355_889, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceObservations` (r:0 w:3)
+	/// Proof: `AssetConversion::PriceObservations` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (419 ±0)`
-		//  Estimated: `990 + n * (5218 ±0)`
-		// Minimum execution time: 89_080_000 picoseconds.
-		Weight::from_parts(90_913_000, 990)
-			// Standard Error: 340_609
-			.saturating_add(Weight::from_parts(11_562_623, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+		//  Measured:  `0 + n * (532 ±0)`
+		//  Estimated: `3567 + n * (5218 ±0)`
+		// Minimum execution time: 209_654_000 picoseconds.
+		Weight::from_parts(31_573_883, 3567)
+			// Standard Error: 2_294_119
+			.saturating_add(Weight::from_parts(122_427_830, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
@@ -322,11 +381,30 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `1154`
 		//  Estimated: `6360`
-		// Minimum execution time: 43_815_000 picoseconds.
-		Weight::from_parts(46_005_208, 6360)
-			// Standard Error: 68_937
-			.saturating_add(Weight::from_parts(19_974_807, 0).saturating_mul(n.into()))
+		// Minimum execution time: 53_159_000 picoseconds.
+		Weight::from_parts(75_145_377, 6360)
+			// Standard Error: 937_913
+			.saturating_add(Weight::from_parts(23_469_421, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
+	/// Storage: `AssetConversion::Pools` (r:129 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:129 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:256 w:0)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn find_best_route(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `464 + n * (484 ±0)`
+		//  Estimated: `3675 + n * (5218 ±0)`
+		// Minimum execution time: 34_105_000 picoseconds.
+		Weight::from_parts(169_102_069, 3675)
+			// Standard Error: 503_484
+			.saturating_add(Weight::from_parts(25_776_334, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
 }
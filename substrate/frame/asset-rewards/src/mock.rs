@@ -94,8 +94,10 @@ impl pallet_assets::Config<Instance1> for MockRuntime {
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type PermitSignature = pallet_verify_signature::testing::TestSignature<u128>;
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
+		type PermitBenchmarkHelper = ();
 	}
 }
 
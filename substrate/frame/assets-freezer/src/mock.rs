@@ -103,6 +103,7 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<10>;
 	type CallbackHandle = ();
+	type PermitSignature = frame::deps::sp_runtime::testing::TestSignature;
 	type Currency = Balances;
 	type Holder = ();
 	type Freezer = AssetsFreezer;
@@ -110,6 +111,8 @@ impl pallet_assets::Config for Test {
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type PermitBenchmarkHelper = ();
 }
 
 #[derive(
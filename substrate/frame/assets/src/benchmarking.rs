@@ -26,6 +26,7 @@ use frame_benchmarking::v1::{
 };
 use frame_support::traits::{EnsureOrigin, Get, UnfilteredDispatchable};
 use frame_system::RawOrigin as SystemOrigin;
+use pallet_verify_signature::BenchmarkHelper as _;
 use sp_runtime::traits::Bounded;
 
 use crate::Pallet as Assets;
@@ -431,6 +432,28 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ApprovedTransfer { asset_id: asset_id.into(), source: caller, delegate, amount }.into());
 	}
 
+	approve_transfer_with_permit {
+		let (asset_id, _, _) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let spender: T::AccountId = account("delegate", 0, SEED);
+		let amount = 100u32.into();
+		let permit = Permit {
+			asset_id: asset_id.clone().into(),
+			spender: spender.clone(),
+			amount,
+			nonce: 0,
+			deadline: frame_system::Pallet::<T>::block_number(),
+		};
+		let payload = Assets::<T, I>::permit_signing_payload(&permit);
+		let (signature, owner) = T::PermitBenchmarkHelper::create_signature(&[], &payload[..]);
+		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T, I>::max_value());
+		let owner_lookup = T::Lookup::unlookup(owner.clone());
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(SystemOrigin::Signed(caller), owner_lookup, permit, signature)
+	verify {
+		assert_eq!(PermitNonces::<T, I>::get(&owner), 1);
+		assert_last_event::<T, I>(Event::ApprovedTransfer { asset_id: asset_id.into(), source: owner, delegate: spender, amount }.into());
+	}
+
 	transfer_approved {
 		let (asset_id, owner, owner_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
 		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T, I>::max_value());
@@ -19,7 +19,12 @@
 
 use super::*;
 use alloc::vec;
-use frame_support::{defensive, traits::Get, BoundedVec};
+use frame_support::{
+	defensive,
+	traits::{Get, PalletInfoAccess},
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
 
 #[must_use]
 pub(super) enum DeadConsequence {
@@ -962,6 +967,42 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// The message a permit must be signed over for [`Pallet::approve_transfer_with_permit`].
+	///
+	/// Binds the permit to this chain, by its genesis hash, and to this instance of the pallet, by
+	/// its index in the runtime. The message is built from these like the payload of the
+	/// `pallet-verify-signature` extension, see [`pallet_verify_signature::signing_message`].
+	pub fn permit_signing_payload(permit: &PermitOf<T, I>) -> [u8; 32] {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		let pallet_index = <Self as PalletInfoAccess>::index() as u32;
+		pallet_verify_signature::signing_message(&(
+			PERMIT_CONTEXT,
+			genesis_hash,
+			pallet_index,
+			permit,
+		))
+	}
+
+	/// Creates an approval from `owner` as described by `permit`, if `signature` is a valid
+	/// signature of it by `owner`, and consumes the nonce of the permit.
+	pub(super) fn do_approve_transfer_with_permit(
+		owner: &T::AccountId,
+		permit: PermitOf<T, I>,
+		signature: T::PermitSignature,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now <= permit.deadline, Error::<T, I>::PermitExpired);
+		let nonce = PermitNonces::<T, I>::get(owner);
+		ensure!(permit.nonce == nonce, Error::<T, I>::BadPermitNonce);
+		ensure!(
+			signature.verify(&Self::permit_signing_payload(&permit)[..], owner),
+			Error::<T, I>::BadPermitSignature
+		);
+
+		PermitNonces::<T, I>::insert(owner, nonce.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+		Self::do_approve_transfer(permit.asset_id, owner, &permit.spender, permit.amount)
+	}
+
 	/// Reduces the asset `id` balance of `owner` by some `amount` and increases the balance of
 	/// `dest` by (similar) amount, checking that 'delegate' has an existing approval from `owner`
 	/// to spend`amount`.
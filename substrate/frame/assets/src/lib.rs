@@ -172,7 +172,10 @@ extern crate alloc;
 
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedSub, Saturating, StaticLookup, Zero},
+	traits::{
+		AtLeast32BitUnsigned, CheckedAdd, CheckedSub, IdentifyAccount, Saturating, StaticLookup,
+		Verify, Zero,
+	},
 	ArithmeticError, DispatchError, TokenError,
 };
 
@@ -200,6 +203,8 @@ pub use weights::WeightInfo;
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 const LOG_TARGET: &str = "runtime::assets";
+/// The context of the payload signed by permits, see [`Pallet::permit_signing_payload`].
+const PERMIT_CONTEXT: &[u8] = b"pallet-assets/permit";
 
 /// Trait with callbacks that are executed after successful asset creation or destruction.
 pub trait AssetsCallback<AssetId, AccountId> {
@@ -301,9 +306,12 @@ pub mod pallet {
 			type Holder = ();
 			type Extra = ();
 			type CallbackHandle = ();
+			type PermitSignature = pallet_verify_signature::testing::TestSignature<u64>;
 			type WeightInfo = ();
 			#[cfg(feature = "runtime-benchmarks")]
 			type BenchmarkHelper = ();
+			#[cfg(feature = "runtime-benchmarks")]
+			type PermitBenchmarkHelper = ();
 		}
 	}
 
@@ -413,12 +421,27 @@ pub mod pallet {
 		/// used to set up auto-incrementing asset IDs for this collection.
 		type CallbackHandle: AssetsCallback<Self::AssetId, Self::AccountId>;
 
+		/// The signature of the permits accepted by [`Pallet::approve_transfer_with_permit`],
+		/// usually the `Signature` of `pallet-verify-signature`, over the message built by
+		/// [`pallet_verify_signature::signing_message`].
+		#[pallet::no_default_bounds]
+		type PermitSignature: Verify<Signer: IdentifyAccount<AccountId = Self::AccountId>>
+			+ Parameter;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
 		/// Helper trait for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<Self::AssetIdParameter>;
+
+		/// Helper to sign permits in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		#[pallet::no_default_bounds]
+		type PermitBenchmarkHelper: pallet_verify_signature::BenchmarkHelper<
+			Self::PermitSignature,
+			Self::AccountId,
+		>;
 	}
 
 	#[pallet::storage]
@@ -477,6 +500,12 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextAssetId<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AssetId, OptionQuery>;
 
+	/// The nonce the next permit of an account must have to be used, see
+	/// [`Pallet::approve_transfer_with_permit`].
+	#[pallet::storage]
+	pub type PermitNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -701,6 +730,12 @@ pub mod pallet {
 		ContainsFreezes,
 		/// The asset cannot be destroyed because some accounts for this asset contain holds.
 		ContainsHolds,
+		/// The permit is past its deadline.
+		PermitExpired,
+		/// The permit nonce is not the next nonce of the owner.
+		BadPermitNonce,
+		/// The permit is not signed by the owner.
+		BadPermitSignature,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -1820,6 +1855,36 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Approve an amount of asset for transfer by a delegated third-party account, with a
+		/// permit signed off-chain by the owner of the funds.
+		///
+		/// Origin must be Signed, but can be any account, e.g. the spender or a relayer.
+		///
+		/// Works like `approve_transfer` on behalf of `owner`, including the reserve of the
+		/// `ApprovalDeposit` from `owner`. The signature must be made by `owner` over the
+		/// [`permit_signing_payload`](Pallet::permit_signing_payload) of the `permit`, and the
+		/// permit nonce must be the next one of `owner`, which is then incremented so that the
+		/// permit cannot be replayed.
+		///
+		/// - `owner`: The account approving the transfer of its assets.
+		/// - `permit`: The asset, spender, amount, nonce and deadline of the approval.
+		/// - `signature`: The signature of the permit by `owner`.
+		///
+		/// Emits `ApprovedTransfer` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(33)]
+		pub fn approve_transfer_with_permit(
+			origin: OriginFor<T>,
+			owner: AccountIdLookupOf<T>,
+			permit: PermitOf<T, I>,
+			signature: T::PermitSignature,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			Self::do_approve_transfer_with_permit(&owner, permit, signature)
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -366,6 +366,97 @@ fn transfer_approved_all_funds() {
 	});
 }
 
+fn signed_permit(
+	owner: u64,
+	spender: u64,
+	amount: u64,
+	nonce: u32,
+	deadline: u64,
+) -> (PermitOf<Test, ()>, pallet_verify_signature::testing::TestSignature<u64>) {
+	let permit = Permit { asset_id: 0, spender, amount, nonce, deadline };
+	let payload = Assets::permit_signing_payload(&permit);
+	(permit, pallet_verify_signature::testing::TestSignature(owner, payload.to_vec()))
+}
+
+#[test]
+fn approve_transfer_with_permit_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 2);
+		Balances::make_free_balance_be(&3, 1);
+		// anyone may submit the owner's permit on their behalf.
+		let (permit, signature) = signed_permit(1, 2, 50, 0, 10);
+		assert_ok!(Assets::approve_transfer_with_permit(
+			RuntimeOrigin::signed(3),
+			1,
+			permit.clone(),
+			signature.clone()
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ApprovedTransfer {
+			asset_id: 0,
+			source: 1,
+			delegate: 2,
+			amount: 50,
+		}));
+		assert_eq!(PermitNonces::<Test>::get(1), 1);
+		assert_eq!(Asset::<Test>::get(0).unwrap().approvals, 1);
+		// the deposit is taken from the owner, not the submitter.
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Balances::reserved_balance(&3), 0);
+		// the same permit cannot be replayed.
+		assert_noop!(
+			Assets::approve_transfer_with_permit(RuntimeOrigin::signed(3), 1, permit, signature),
+			Error::<Test>::BadPermitNonce
+		);
+		assert_ok!(Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 4, 40));
+		assert_eq!(Assets::balance(0, 1), 60);
+		assert_eq!(Assets::balance(0, 4), 40);
+	});
+}
+
+#[test]
+fn approve_transfer_with_permit_checks_permit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 2);
+		System::set_block_number(5);
+		// expired deadline.
+		let (permit, signature) = signed_permit(1, 2, 50, 0, 4);
+		assert_noop!(
+			Assets::approve_transfer_with_permit(RuntimeOrigin::signed(3), 1, permit, signature),
+			Error::<Test>::PermitExpired
+		);
+		// nonce from the future.
+		let (permit, signature) = signed_permit(1, 2, 50, 1, 5);
+		assert_noop!(
+			Assets::approve_transfer_with_permit(RuntimeOrigin::signed(3), 1, permit, signature),
+			Error::<Test>::BadPermitNonce
+		);
+		// signed by someone other than the owner.
+		let (permit, signature) = signed_permit(2, 2, 50, 0, 5);
+		assert_noop!(
+			Assets::approve_transfer_with_permit(RuntimeOrigin::signed(3), 1, permit, signature),
+			Error::<Test>::BadPermitSignature
+		);
+		// terms altered after signing.
+		let (mut permit, signature) = signed_permit(1, 2, 50, 0, 5);
+		permit.amount = 100;
+		assert_noop!(
+			Assets::approve_transfer_with_permit(RuntimeOrigin::signed(3), 1, permit, signature),
+			Error::<Test>::BadPermitSignature
+		);
+		let (permit, signature) = signed_permit(1, 2, 50, 0, 5);
+		assert_ok!(Assets::approve_transfer_with_permit(
+			RuntimeOrigin::signed(3),
+			1,
+			permit,
+			signature
+		));
+	});
+}
+
 #[test]
 fn approval_deposits_work() {
 	new_test_ext().execute_with(|| {
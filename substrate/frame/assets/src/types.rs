@@ -87,6 +87,39 @@ pub struct Approval<Balance, DepositBalance> {
 	pub deposit: DepositBalance,
 }
 
+pub type PermitOf<T, I = ()> = Permit<
+	<T as Config<I>>::AssetId,
+	<T as SystemConfig>::AccountId,
+	<T as Config<I>>::Balance,
+	frame_system::pallet_prelude::BlockNumberFor<T>,
+>;
+
+/// An approval of a balance transfer signed off-chain by the owner of the funds, see
+/// [`Pallet::approve_transfer_with_permit`].
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct Permit<AssetId, AccountId, Balance, BlockNumber> {
+	/// The identifier of the asset.
+	pub asset_id: AssetId,
+	/// The account allowed to transfer the funds of the owner.
+	pub spender: AccountId,
+	/// The amount of funds approved for the balance transfer.
+	pub amount: Balance,
+	/// The permit nonce of the owner, see [`PermitNonces`].
+	pub nonce: u32,
+	/// The last block in which the permit can be used.
+	pub deadline: BlockNumber,
+}
+
 #[test]
 fn ensure_bool_decodes_to_consumer_or_sufficient() {
 	assert_eq!(false.encode(), ExistenceReason::<(), ()>::Consumer.encode());
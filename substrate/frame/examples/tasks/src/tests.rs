@@ -135,6 +135,76 @@ fn task_execution_fails_for_invalid_task() {
 	});
 }
 
+#[cfg(feature = "experimental")]
+#[test]
+fn valid_tasks_works() {
+	new_test_ext().execute_with(|| {
+		Numbers::<Runtime>::insert(0, 1);
+		Numbers::<Runtime>::insert(1, 4);
+		Numbers::<Runtime>::insert(2, 9);
+
+		assert_eq!(System::valid_tasks(5).len(), 3);
+		assert_eq!(System::valid_tasks(2).len(), 2);
+		assert!(System::valid_tasks(0).is_empty());
+	});
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn valid_tasks_are_accepted_as_unsigned_transactions() {
+	use sp_runtime::{
+		traits::ValidateUnsigned,
+		transaction_validity::{InvalidTransaction, TransactionSource},
+	};
+
+	new_test_ext().execute_with(|| {
+		Numbers::<Runtime>::insert(0, 1);
+		Numbers::<Runtime>::insert(1, 4);
+
+		for task in System::valid_tasks(5) {
+			assert_ok!(System::validate_unsigned(
+				TransactionSource::Local,
+				&frame_system::Call::do_task { task },
+			));
+		}
+
+		let missing_number =
+			<Runtime as frame_system::Config>::RuntimeTask::TasksExample(crate::pallet::Task::<
+				Runtime,
+			>::AddNumberIntoTotal {
+				i: 2u32,
+			});
+		assert_eq!(
+			System::validate_unsigned(
+				TransactionSource::Local,
+				&frame_system::Call::do_task { task: missing_number },
+			),
+			Err(InvalidTransaction::Call.into()),
+		);
+	});
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn valid_tasks_can_be_executed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Numbers::<Runtime>::insert(0, 1);
+		Numbers::<Runtime>::insert(1, 4);
+		Numbers::<Runtime>::insert(2, 9);
+
+		for task in System::valid_tasks(5) {
+			assert_ok!(System::do_task(RuntimeOrigin::none(), task.clone()));
+			System::assert_last_event(
+				frame_system::Event::<Runtime>::TaskCompleted { task }.into(),
+			);
+		}
+
+		assert_eq!(crate::Total::<Runtime>::get(), (3, 14));
+		assert!(System::valid_tasks(5).is_empty());
+	});
+}
+
 #[cfg(feature = "experimental")]
 #[test]
 fn task_with_offchain_worker() {
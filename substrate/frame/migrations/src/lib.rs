@@ -49,6 +49,10 @@
 //! The API contains some calls for emergency management. They are all prefixed with `force_` and
 //! should normally not be needed. Pay special attention prior to using them.
 //!
+//! The [`runtime_api::MigrationsApi`] allows to dry-run the migrations against a state snapshot
+//! without producing blocks. It is used by the `benchmark migrations` command of
+//! `frame-benchmarking-cli`.
+//!
 //! ### Design Goals
 //!
 //! 1. Must automatically execute migrations over multiple blocks.
@@ -148,6 +152,7 @@ mod benchmarking;
 pub mod migrations;
 mod mock;
 pub mod mock_helpers;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
@@ -671,7 +676,12 @@ impl<T: Config> Pallet<T> {
 
 	/// Tries to make progress on the Multi-Block-Migrations process.
 	fn progress_mbms(n: BlockNumberFor<T>) -> Weight {
-		let mut meter = WeightMeter::with_limit(T::MaxServiceWeight::get());
+		Self::progress_mbms_with_limit(n, T::MaxServiceWeight::get())
+	}
+
+	/// Tries to make progress on the Multi-Block-Migrations process without exceeding `limit`.
+	fn progress_mbms_with_limit(n: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
 		meter.consume(T::WeightInfo::progress_mbms_none());
 
 		let mut cursor = match Cursor::<T>::get() {
@@ -848,6 +858,100 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The migrations that are configured in [`Config::Migrations`].
+	pub fn migrations() -> Vec<runtime_api::MigrationInfo> {
+		(0..T::Migrations::len())
+			.map(|index| {
+				let id = T::Migrations::nth_id(index).unwrap_or_default();
+				let historic =
+					IdentifierOf::<T>::try_from(id.clone()).is_ok_and(Historic::<T>::contains_key);
+				runtime_api::MigrationInfo {
+					index,
+					id,
+					max_steps: T::Migrations::nth_max_steps(index).flatten(),
+					historic,
+				}
+			})
+			.collect()
+	}
+
+	/// Start the migrations like a runtime upgrade would, unless they are already ongoing.
+	///
+	/// Only meant to be used by [`runtime_api::MigrationsApi::dry_run_start`].
+	pub fn dry_run_start() -> Option<MigrationCursor<Vec<u8>, BlockNumberFor<T>>> {
+		if !Self::ongoing() {
+			Self::onboard_new_mbms();
+		}
+
+		Cursor::<T>::get().map(Self::raw_cursor)
+	}
+
+	/// Advance the block number by one and progress the migrations with at most `weight_limit`.
+	///
+	/// Only meant to be used by [`runtime_api::MigrationsApi::dry_run_step`]. Initializes the next
+	/// block and removes the events of the previous one to determine the outcome.
+	pub fn dry_run_step(weight_limit: Option<Weight>) -> runtime_api::DryRunStep<BlockNumberFor<T>>
+	where
+		<T as frame_system::Config>::RuntimeEvent: TryInto<Event<T>>,
+	{
+		use runtime_api::DryRunStatus;
+
+		let block = System::<T>::block_number().saturating_add(sp_runtime::traits::One::one());
+		System::<T>::reset_events();
+		System::<T>::initialize(&block, &System::<T>::parent_hash(), &Default::default());
+
+		let was_stuck = matches!(Cursor::<T>::get(), Some(MigrationCursor::Stuck));
+		let weight_limit = weight_limit.unwrap_or_else(T::MaxServiceWeight::get);
+		let weight_used = Self::progress_mbms_with_limit(block, weight_limit);
+
+		let mut completed = Vec::new();
+		let mut skipped = Vec::new();
+		let mut failed = None;
+		let mut status = None;
+		for record in System::<T>::read_events_no_consensus() {
+			let Ok(event) = record.event.try_into() else { continue };
+			match event {
+				Event::MigrationCompleted { index, .. } => completed.push(index),
+				Event::MigrationSkipped { index } => skipped.push(index),
+				Event::MigrationFailed { index, .. } => failed = Some(index),
+				Event::UpgradeCompleted => status = Some(DryRunStatus::Completed),
+				Event::UpgradeFailed => status = Some(DryRunStatus::Failed { migration: failed }),
+				_ => {},
+			}
+		}
+
+		let cursor = Cursor::<T>::get();
+		let status = status.unwrap_or(match cursor {
+			None => DryRunStatus::Idle,
+			Some(MigrationCursor::Active(_)) => DryRunStatus::Ongoing,
+			Some(MigrationCursor::Stuck) if was_stuck => DryRunStatus::Stuck,
+			Some(MigrationCursor::Stuck) => DryRunStatus::Failed { migration: failed },
+		});
+
+		runtime_api::DryRunStep {
+			block,
+			weight_limit,
+			weight_used,
+			cursor: cursor.map(Self::raw_cursor),
+			completed,
+			skipped,
+			status,
+		}
+	}
+
+	/// Convert a cursor into one with an unbounded inner cursor.
+	fn raw_cursor(cursor: CursorOf<T>) -> MigrationCursor<Vec<u8>, BlockNumberFor<T>> {
+		match cursor {
+			MigrationCursor::Active(ActiveCursor { index, inner_cursor, started_at }) =>
+				MigrationCursor::Active(ActiveCursor {
+					index,
+					inner_cursor: inner_cursor.map(BoundedVec::into_inner),
+					started_at,
+				}),
+			MigrationCursor::Stuck => MigrationCursor::Stuck,
+		}
+	}
+
 	/// The maximal weight of calling the private `Self::exec_migration` function.
 	pub fn exec_migration_max_weight() -> Weight {
 		T::WeightInfo::exec_migration_complete()
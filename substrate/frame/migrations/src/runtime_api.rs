@@ -0,0 +1,100 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API to dry-run the multi-block migrations of a runtime.
+//!
+//! The calls of [`MigrationsApi`] modify the state in the same way as the migrations would when
+//! executed by block production. They are meant to be called on a throw-away state, for example
+//! by the `benchmark migrations` command of `frame-benchmarking-cli`.
+
+use crate::MigrationCursor;
+use alloc::vec::Vec;
+use codec::{Codec, Decode, Encode};
+use frame_support::weights::Weight;
+use scale_info::TypeInfo;
+
+/// Information about one migration of [`Config::Migrations`](crate::Config::Migrations).
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct MigrationInfo {
+	/// The index of the migration within the migrations tuple.
+	pub index: u32,
+	/// The identifier of the migration.
+	pub id: Vec<u8>,
+	/// The maximal number of blocks that the migration may take.
+	pub max_steps: Option<u32>,
+	/// Whether the migration was executed in the past and will therefore be skipped.
+	pub historic: bool,
+}
+
+/// The outcome of dry-running the migrations for one block.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub enum DryRunStatus {
+	/// No migrations are ongoing.
+	Idle,
+	/// The migrations advanced and continue in the next block.
+	Ongoing,
+	/// The last migration completed in this block.
+	Completed,
+	/// The runtime upgrade failed in this block.
+	Failed {
+		/// The index of the migration that failed, if known.
+		migration: Option<u32>,
+	},
+	/// The migrations are stuck since an earlier failure.
+	Stuck,
+}
+
+/// The result of dry-running the migrations for one block.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct DryRunStep<BlockNumber> {
+	/// The block number that was simulated.
+	pub block: BlockNumber,
+	/// The weight that was available to the migrations in this block.
+	pub weight_limit: Weight,
+	/// The weight that the migrations consumed in this block.
+	pub weight_used: Weight,
+	/// The cursor at the end of the block.
+	pub cursor: Option<MigrationCursor<Vec<u8>, BlockNumber>>,
+	/// The indices of the migrations that completed in this block.
+	pub completed: Vec<u32>,
+	/// The indices of the migrations that were skipped in this block since they are historic.
+	pub skipped: Vec<u32>,
+	/// The status of the migrations at the end of the block.
+	pub status: DryRunStatus,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API to dry-run the multi-block migrations of the runtime.
+	pub trait MigrationsApi<BlockNumber>
+	where
+		BlockNumber: Codec
+	{
+		/// The migrations that are configured in the runtime.
+		fn migrations() -> Vec<MigrationInfo>;
+
+		/// Start the migrations in the same way as a runtime upgrade.
+		///
+		/// Does nothing if migrations are already ongoing. Returns the cursor after starting.
+		fn dry_run_start() -> Option<MigrationCursor<Vec<u8>, BlockNumber>>;
+
+		/// Advance the block number by one and progress the migrations.
+		///
+		/// At most `weight_limit` is spent on the migrations. Defaults to the `MaxServiceWeight`
+		/// of the pallet.
+		fn dry_run_step(weight_limit: Option<Weight>) -> DryRunStep<BlockNumber>;
+	}
+}
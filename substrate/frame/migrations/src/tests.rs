@@ -17,12 +17,14 @@
 
 #![cfg(test)]
 
+use codec::Encode;
 use frame_support::{pallet_prelude::Weight, traits::OnRuntimeUpgrade};
 
 use crate::{
 	mock::{Test as T, *},
 	mock_helpers::{MockedMigrationKind::*, *},
-	Cursor, Event, FailedMigrationHandling, MigrationCursor,
+	runtime_api::{DryRunStatus, MigrationInfo},
+	ActiveCursor, Cursor, Event, FailedMigrationHandling, Historic, MigrationCursor,
 };
 
 #[docify::export]
@@ -454,3 +456,86 @@ fn try_runtime_no_migrations() {
 		assert_eq!(System::events().len(), 0);
 	});
 }
+
+#[test]
+fn dry_run_works() {
+	test_closure(|| {
+		MockedMigrations::set(vec![(SucceedAfter, 0), (SucceedAfter, 1)]);
+		Historic::<T>::insert(mocked_id(SucceedAfter, 0), ());
+		System::set_block_number(1);
+
+		assert_eq!(
+			Migrations::migrations(),
+			vec![
+				MigrationInfo {
+					index: 0,
+					id: mocked_id(SucceedAfter, 0).into_inner(),
+					max_steps: Some(0),
+					historic: true,
+				},
+				MigrationInfo {
+					index: 1,
+					id: mocked_id(SucceedAfter, 1).into_inner(),
+					max_steps: Some(1),
+					historic: false,
+				},
+			]
+		);
+
+		let active =
+			MigrationCursor::Active(ActiveCursor { index: 0, inner_cursor: None, started_at: 1 });
+		assert_eq!(Migrations::dry_run_start(), Some(active.clone()));
+		// Starting again does not reset the cursor.
+		assert_eq!(Migrations::dry_run_start(), Some(active));
+
+		let limit = Weight::from_parts(1_000_000_000, 1_000_000);
+		let step = Migrations::dry_run_step(Some(limit));
+		assert_eq!(step.block, 2);
+		assert_eq!(step.status, DryRunStatus::Ongoing);
+		assert_eq!(step.skipped, vec![0]);
+		assert!(step.completed.is_empty());
+		assert_eq!(
+			step.cursor,
+			Some(MigrationCursor::Active(ActiveCursor {
+				index: 1,
+				inner_cursor: Some(1u32.encode()),
+				started_at: 2,
+			}))
+		);
+		assert_eq!(step.weight_limit, limit);
+		assert!(step.weight_used.all_lte(limit));
+		assert!(step.weight_used.any_gt(Weight::zero()));
+
+		let step = Migrations::dry_run_step(Some(limit));
+		assert_eq!(step.status, DryRunStatus::Completed);
+		assert_eq!(step.completed, vec![1]);
+		assert_eq!(step.cursor, None);
+
+		let step = Migrations::dry_run_step(None);
+		assert_eq!(step.block, 4);
+		assert_eq!(step.status, DryRunStatus::Idle);
+		assert_eq!(step.weight_limit, <T as crate::Config>::MaxServiceWeight::get());
+		assert_eq!(historic(), vec![mocked_id(SucceedAfter, 0), mocked_id(SucceedAfter, 1)]);
+	});
+}
+
+#[test]
+#[cfg_attr(feature = "try-runtime", should_panic)]
+fn dry_run_reports_failure() {
+	test_closure(|| {
+		FailedUpgradeResponse::set(FailedMigrationHandling::KeepStuck);
+		MockedMigrations::set(vec![(FailAfter, 1)]);
+		System::set_block_number(1);
+		Migrations::dry_run_start();
+
+		assert_eq!(Migrations::dry_run_step(None).status, DryRunStatus::Ongoing);
+
+		let step = Migrations::dry_run_step(None);
+		assert_eq!(step.status, DryRunStatus::Failed { migration: Some(0) });
+		assert_eq!(step.cursor, Some(MigrationCursor::Stuck));
+
+		// Stays stuck without making progress.
+		assert_eq!(Migrations::dry_run_step(None).status, DryRunStatus::Stuck);
+		assert_eq!(upgrades_started_completed_failed(), (1, 0, 1));
+	});
+}
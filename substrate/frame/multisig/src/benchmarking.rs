@@ -359,5 +359,78 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// `s`: Members, need at least 2 people
+	#[benchmark]
+	fn create_stored_multisig(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let members = signatories.into_iter().map(|who| (who, 1)).collect::<Vec<_>>();
+		let caller = members[0].0.clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), members, s as u16, 0);
+
+		assert_eq!(StoredMultisigs::<T>::iter_keys().count(), 1);
+
+		Ok(())
+	}
+
+	/// `s`: Members, need at least 2 people
+	#[benchmark]
+	fn set_stored_multisig(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, _) = setup_multi::<T>(T::MaxSignatories::get(), 0)?;
+		let members = signatories.into_iter().map(|who| (who, 1)).collect::<Vec<_>>();
+		let caller = members[0].0.clone();
+		let multi_account_id = Multisig::<T>::stored_multisig_account_id(&caller, 0, None);
+		// Worst case: the deposit of the creator is raised.
+		Multisig::<T>::create_stored_multisig(
+			RawOrigin::Signed(caller.clone()).into(),
+			members.iter().take(2).cloned().collect(),
+			2,
+			0,
+		)?;
+		let new_members = members.into_iter().take(s as usize).collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multi_account_id.clone()), new_members, s as u16);
+
+		let multisig =
+			StoredMultisigs::<T>::get(&multi_account_id).ok_or("multisig not created")?;
+		assert_eq!(multisig.depositor, caller);
+		assert_eq!(multisig.members.len(), s as usize);
+
+		Ok(())
+	}
+
+	/// `s`: Members, need at least 2 people
+	#[benchmark]
+	fn destroy_stored_multisig(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let members = signatories.into_iter().map(|who| (who, 1)).collect::<Vec<_>>();
+		let caller = members[0].0.clone();
+		let multi_account_id = Multisig::<T>::stored_multisig_account_id(&caller, 0, None);
+		Multisig::<T>::create_stored_multisig(
+			RawOrigin::Signed(caller).into(),
+			members,
+			s as u16,
+			0,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(multi_account_id.clone()));
+
+		assert!(!StoredMultisigs::<T>::contains_key(multi_account_id));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -39,6 +39,20 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `create_stored_multisig` - Create a multisig account whose members, their weights and the
+//!   threshold are kept in storage.
+//! * `set_stored_multisig` - Change the members and threshold of a stored multisig account.
+//! * `destroy_stored_multisig` - Remove a stored multisig account.
+//! * `as_multi_stored`, `approve_as_multi_stored` and `cancel_as_multi_stored` - The same as their
+//!   counterparts above, but for a stored multisig account.
+//!
+//! ### Stored multisig accounts
+//!
+//! The account of a regular multisig is derived from its signatories and threshold, so changing
+//! any of them results in a different account. A stored multisig account is derived from its
+//! creator instead and keeps its members in storage. Each member has a weight and a call is
+//! dispatched once the weights of its approvals add up to the threshold. The multisig itself can
+//! change its members and threshold with `set_stored_multisig`.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -120,8 +134,78 @@ where
 	pub approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// A multisig account whose members and threshold are kept in storage.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxMembers))]
+pub struct StoredMultisig<Balance, AccountId, MaxMembers>
+where
+	MaxMembers: Get<u32>,
+{
+	/// The members with the weight of their approvals. Always sorted by account.
+	pub members: BoundedVec<(AccountId, u16), MaxMembers>,
+	/// The total weight of approvals needed to dispatch a call.
+	pub threshold: u16,
+	/// The account who holds the deposit for storing the multisig.
+	pub depositor: AccountId,
+	/// The amount held in reserve of the `depositor`, to be returned once the multisig is
+	/// destroyed.
+	pub deposit: Balance,
+}
+
+impl<Balance, AccountId: Ord, MaxMembers: Get<u32>> StoredMultisig<Balance, AccountId, MaxMembers> {
+	/// The weight of the approval of `who`, zero if it is not a member.
+	pub fn weight_of(&self, who: &AccountId) -> u32 {
+		self.members
+			.binary_search_by(|(member, _)| member.cmp(who))
+			.map_or(0, |index| self.members[index].1.into())
+	}
+}
+
+type StoredMultisigOf<T> = StoredMultisig<
+	BalanceOf<T>,
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::MaxSignatories,
+>;
+
 type CallHash = [u8; 32];
 
+/// How the approvals of a multisig operation are weighed against its threshold.
+enum Quorum<'a, T: Config> {
+	/// Each signatory of a regular multisig counts once.
+	Signatories(u16),
+	/// The members of a stored multisig count with their weight.
+	Stored(&'a StoredMultisigOf<T>),
+}
+
+impl<'a, T: Config> Quorum<'a, T> {
+	fn threshold(&self) -> u32 {
+		match self {
+			Self::Signatories(threshold) => (*threshold).into(),
+			Self::Stored(multisig) => multisig.threshold.into(),
+		}
+	}
+
+	fn weight_of(&self, who: &T::AccountId) -> u32 {
+		match self {
+			Self::Signatories(_) => 1,
+			Self::Stored(multisig) => multisig.weight_of(who),
+		}
+	}
+
+	/// The total weight of `approvals`.
+	fn approved(&self, approvals: &[T::AccountId]) -> u32 {
+		approvals.iter().map(|who| self.weight_of(who)).fold(0, u32::saturating_add)
+	}
+
+	/// The deposit for an operation of the multisig.
+	fn deposit(&self) -> BalanceOf<T> {
+		match self {
+			Self::Signatories(threshold) => Pallet::<T>::deposit(*threshold),
+			Self::Stored(multisig) => Pallet::<T>::deposit(multisig.members.len() as u16),
+		}
+	}
+}
+
 enum CallOrHash<T: Config> {
 	Call(<T as Config>::RuntimeCall),
 	Hash([u8; 32]),
@@ -211,6 +295,11 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The multisig accounts whose members and threshold are kept in storage.
+	#[pallet::storage]
+	pub type StoredMultisigs<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, StoredMultisigOf<T>>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -242,6 +331,16 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The account is not a stored multisig account.
+		NotStoredMultisig,
+		/// The sender is not a member of the stored multisig account.
+		NotMember,
+		/// A member of a stored multisig account has a weight of zero.
+		ZeroWeight,
+		/// The weights of all members together do not reach the threshold.
+		ThresholdUnreachable,
+		/// The stored multisig account still holds funds or is referenced by other pallets.
+		NotEmpty,
 	}
 
 	#[pallet::event]
@@ -278,6 +377,12 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// A stored multisig account has been created.
+		StoredMultisigCreated { multisig: T::AccountId, depositor: T::AccountId, threshold: u16 },
+		/// The members or the threshold of a stored multisig account have been changed.
+		StoredMultisigUpdated { multisig: T::AccountId, threshold: u16 },
+		/// A stored multisig account has been destroyed.
+		StoredMultisigDestroyed { multisig: T::AccountId },
 	}
 
 	#[pallet::hooks]
@@ -517,22 +622,7 @@ pub mod pallet {
 			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
 
 			let id = Self::multi_account_id(&signatories, threshold);
-
-			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
-			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
-			ensure!(m.depositor == who, Error::<T>::NotOwner);
-
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
-			debug_assert!(err_amount.is_zero());
-			<Multisigs<T>>::remove(&id, &call_hash);
-
-			Self::deposit_event(Event::MultisigCancelled {
-				cancelling: who,
-				timepoint,
-				multisig: id,
-				call_hash,
-			});
-			Ok(())
+			Self::cancel(who, id, timepoint, call_hash)
 		}
 
 		/// Poke the deposit reserved for an existing multisig operation.
@@ -612,6 +702,251 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Create a multisig account whose members and threshold are kept in storage.
+		///
+		/// Unlike the account of a regular multisig, the account is derived from the sender and
+		/// stays the same when the members or the threshold change.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` per member will be reserved. It is returned
+		/// once the multisig is destroyed.
+		///
+		/// The dispatch origin for this call must be _Signed_. The sender does not have to be a
+		/// member.
+		///
+		/// - `members`: The members with the weight of their approvals, sorted by account. At least
+		///   two, at most `MaxSignatories`.
+		/// - `threshold`: The total weight of approvals needed to dispatch a call.
+		/// - `index`: A disambiguation index, in case this is called multiple times in the same
+		///   transaction.
+		///
+		/// Emits `StoredMultisigCreated` with the account of the multisig.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::create_stored_multisig(members.len() as u32))]
+		pub fn create_stored_multisig(
+			origin: OriginFor<T>,
+			members: Vec<(T::AccountId, u16)>,
+			threshold: u16,
+			index: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let members = Self::ensure_valid_members(members, threshold)?;
+
+			let id = Self::stored_multisig_account_id(&who, index, None);
+			ensure!(!StoredMultisigs::<T>::contains_key(&id), Error::<T>::AlreadyStored);
+
+			let deposit = Self::deposit(members.len() as u16);
+			T::Currency::reserve(&who, deposit)?;
+			StoredMultisigs::<T>::insert(
+				&id,
+				StoredMultisig { members, threshold, depositor: who.clone(), deposit },
+			);
+
+			Self::deposit_event(Event::StoredMultisigCreated {
+				multisig: id,
+				depositor: who,
+				threshold,
+			});
+			Ok(())
+		}
+
+		/// Change the members and the threshold of a stored multisig account.
+		///
+		/// Operations that are underway continue with the new members and threshold. Approvals of
+		/// removed members no longer count.
+		///
+		/// The deposit stays with the depositor and is adjusted to the new number of members, up to
+		/// `DepositBase` plus `DepositFactor` times `MaxSignatories`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stored multisig account.
+		///
+		/// - `members`: The new members with the weight of their approvals, sorted by account.
+		/// - `threshold`: The new total weight of approvals needed to dispatch a call.
+		///
+		/// Emits `StoredMultisigUpdated`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_stored_multisig(members.len() as u32))]
+		pub fn set_stored_multisig(
+			origin: OriginFor<T>,
+			members: Vec<(T::AccountId, u16)>,
+			threshold: u16,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let members = Self::ensure_valid_members(members, threshold)?;
+
+			StoredMultisigs::<T>::try_mutate(&id, |maybe_multisig| -> DispatchResult {
+				let multisig = maybe_multisig.as_mut().ok_or(Error::<T>::NotStoredMultisig)?;
+
+				let deposit = Self::deposit(members.len() as u16);
+				if deposit > multisig.deposit {
+					T::Currency::reserve(
+						&multisig.depositor,
+						deposit.saturating_sub(multisig.deposit),
+					)?;
+				} else {
+					let err_amount = T::Currency::unreserve(
+						&multisig.depositor,
+						multisig.deposit.saturating_sub(deposit),
+					);
+					debug_assert!(err_amount.is_zero());
+				}
+
+				multisig.members = members;
+				multisig.threshold = threshold;
+				multisig.deposit = deposit;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::StoredMultisigUpdated { multisig: id, threshold });
+			Ok(())
+		}
+
+		/// Destroy a stored multisig account and return its deposit.
+		///
+		/// Operations that are underway can no longer be approved, but their depositors can still
+		/// cancel them with `cancel_as_multi_stored`.
+		///
+		/// Since nobody is able to control the account afterwards, it must not hold any funds
+		/// above the existential deposit nor have any consumer references.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the stored multisig account.
+		///
+		/// Emits `StoredMultisigDestroyed`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::destroy_stored_multisig(T::MaxSignatories::get()))]
+		pub fn destroy_stored_multisig(origin: OriginFor<T>) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			let multisig = StoredMultisigs::<T>::take(&id).ok_or(Error::<T>::NotStoredMultisig)?;
+			let balance = T::Currency::total_balance(&id);
+			ensure!(
+				(balance.is_zero() || balance < T::Currency::minimum_balance()) &&
+					frame_system::Pallet::<T>::consumers(&id).is_zero(),
+				Error::<T>::NotEmpty
+			);
+
+			let err_amount = T::Currency::unreserve(&multisig.depositor, multisig.deposit);
+			debug_assert!(err_amount.is_zero());
+
+			Self::deposit_event(Event::StoredMultisigDestroyed { multisig: id });
+			Ok(())
+		}
+
+		/// Register approval for a dispatch to be made from a stored multisig account.
+		///
+		/// If the weights of the approvals reach the threshold of the multisig, then dispatch the
+		/// call.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `DepositFactor` per member of the multisig. It is returned once this dispatch happens
+		/// or is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a member of the multisig.
+		///
+		/// - `multisig`: The stored multisig account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call`: The call to be executed.
+		///
+		/// NOTE: Unless this is the final approval, you will generally want to use
+		/// `approve_as_multi_stored` instead, since it only requires a hash of the call.
+		///
+		/// ## Complexity
+		/// The same as `as_multi` with `S` being `MaxSignatories`, plus one read of the stored
+		/// multisig.
+		#[pallet::call_index(8)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_create(s, z)
+			.max(T::WeightInfo::as_multi_approve(s, z))
+			.max(T::WeightInfo::as_multi_complete(s, z))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(*max_weight)
+		})]
+		pub fn as_multi_stored(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_stored(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Call(*call),
+				max_weight,
+			)
+		}
+
+		/// Register approval for a dispatch to be made from a stored multisig account.
+		///
+		/// Payment: `DepositBase` will be reserved if this is the first approval, plus
+		/// `DepositFactor` per member of the multisig. It is returned once this dispatch happens
+		/// or is cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a member of the multisig.
+		///
+		/// - `multisig`: The stored multisig account.
+		/// - `maybe_timepoint`: If this is the first approval, then this must be `None`. If it is
+		/// not the first approval, then it must be `Some`, with the timepoint (block number and
+		/// transaction index) of the first approval transaction.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// NOTE: If this is the final approval, you will want to use `as_multi_stored` instead.
+		///
+		/// ## Complexity
+		/// The same as `approve_as_multi` with `S` being `MaxSignatories`, plus one read of the
+		/// stored multisig.
+		#[pallet::call_index(9)]
+		#[pallet::weight({
+			let s = T::MaxSignatories::get();
+
+			T::WeightInfo::approve_as_multi_create(s)
+				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_add(*max_weight)
+		})]
+		pub fn approve_as_multi_stored(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+			call_hash: [u8; 32],
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::operate_stored(
+				who,
+				multisig,
+				maybe_timepoint,
+				CallOrHash::Hash(call_hash),
+				max_weight,
+			)
+		}
+
+		/// Cancel a pre-existing, on-going operation of a stored multisig account. Any deposit
+		/// reserved previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the depositor of the operation.
+		///
+		/// - `multisig`: The stored multisig account.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::cancel_as_multi(T::MaxSignatories::get()))]
+		pub fn cancel_as_multi_stored(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::cancel(who, multisig, timepoint, call_hash)
+		}
 	}
 }
 
@@ -626,6 +961,26 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
+	/// Derive the account of a stored multisig created by `who`.
+	///
+	/// `maybe_when` is the block height and extrinsic index of the creation, which defaults to
+	/// the current ones.
+	pub fn stored_multisig_account_id(
+		who: &T::AccountId,
+		index: u16,
+		maybe_when: Option<(BlockNumberFor<T>, u32)>,
+	) -> T::AccountId {
+		let (height, ext_index) = maybe_when.unwrap_or_else(|| {
+			let Timepoint { height, index } = Self::timepoint();
+			(height, index)
+		});
+
+		let entropy =
+			(b"modlpy/multisto_", who, height, ext_index, index).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
 	fn operate(
 		who: T::AccountId,
 		threshold: u16,
@@ -643,6 +998,52 @@ impl<T: Config> Pallet<T> {
 
 		let id = Self::multi_account_id(&signatories, threshold);
 
+		Self::operate_on(
+			who,
+			id,
+			Quorum::Signatories(threshold),
+			other_signatories_len as u32,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	fn operate_stored(
+		who: T::AccountId,
+		id: T::AccountId,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let multisig = StoredMultisigs::<T>::get(&id).ok_or(Error::<T>::NotStoredMultisig)?;
+		ensure!(multisig.weight_of(&who) > 0, Error::<T>::NotMember);
+
+		Self::operate_on(
+			who,
+			id,
+			Quorum::Stored(&multisig),
+			multisig.members.len() as u32,
+			maybe_timepoint,
+			call_or_hash,
+			max_weight,
+		)
+	}
+
+	/// Approve and possibly dispatch a call from the multisig account `id`.
+	///
+	/// `s` is the number of signatories, used to calculate the weight.
+	fn operate_on(
+		who: T::AccountId,
+		id: T::AccountId,
+		quorum: Quorum<T>,
+		s: u32,
+		maybe_timepoint: Option<Timepoint<BlockNumberFor<T>>>,
+		call_or_hash: CallOrHash<T>,
+		max_weight: Weight,
+	) -> DispatchResultWithPostInfo {
+		let threshold = quorum.threshold();
+
 		// Threshold > 1; this means it's a multi-step operation. We extract the `call_hash`.
 		let (call_hash, call_len, maybe_call) = match call_or_hash {
 			CallOrHash::Call(call) => {
@@ -659,12 +1060,12 @@ impl<T: Config> Pallet<T> {
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
 
 			// Ensure that either we have not yet signed or that it is at threshold.
-			let mut approvals = m.approvals.len() as u16;
+			let mut approvals = quorum.approved(&m.approvals);
 			// We only bother with the approval if we're below threshold.
 			let maybe_pos = m.approvals.binary_search(&who).err().filter(|_| approvals < threshold);
 			// Bump approvals if not yet voted and the vote is needed.
 			if maybe_pos.is_some() {
-				approvals += 1;
+				approvals = approvals.saturating_add(quorum.weight_of(&who));
 			}
 
 			// We only bother fetching/decoding call if we know that we're ready to execute.
@@ -680,23 +1081,7 @@ impl<T: Config> Pallet<T> {
 				<Multisigs<T>>::remove(&id, call_hash);
 				T::Currency::unreserve(&m.depositor, m.deposit);
 
-				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
-				Self::deposit_event(Event::MultisigExecuted {
-					approving: who,
-					timepoint,
-					multisig: id,
-					call_hash,
-					result: result.map(|_| ()).map_err(|e| e.error),
-				});
-				Ok(get_result_weight(result)
-					.map(|actual_weight| {
-						T::WeightInfo::as_multi_complete(
-							other_signatories_len as u32,
-							call_len as u32,
-						)
-						.saturating_add(actual_weight)
-					})
-					.into())
+				Ok(Self::execute(who, id, timepoint, call_hash, call, s, call_len as u32))
 			} else {
 				// We cannot dispatch the call now; either it isn't available, or it is, but we
 				// don't have threshold approvals even with our signature.
@@ -719,8 +1104,7 @@ impl<T: Config> Pallet<T> {
 					Err(Error::<T>::AlreadyApproved)?
 				}
 
-				let final_weight =
-					T::WeightInfo::as_multi_approve(other_signatories_len as u32, call_len as u32);
+				let final_weight = T::WeightInfo::as_multi_approve(s, call_len as u32);
 				// Call is not made, so the actual weight does not include call
 				Ok(Some(final_weight).into())
 			}
@@ -728,8 +1112,18 @@ impl<T: Config> Pallet<T> {
 			// Not yet started; there should be no timepoint given.
 			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
 
+			// A member of a stored multisig may reach the threshold on its own.
+			if let Some(call) = maybe_call.filter(|_| quorum.weight_of(&who) >= threshold) {
+				ensure!(
+					call.get_dispatch_info().call_weight.all_lte(max_weight),
+					Error::<T>::MaxWeightTooLow
+				);
+				let timepoint = Self::timepoint();
+				return Ok(Self::execute(who, id, timepoint, call_hash, call, s, call_len as u32))
+			}
+
 			// Just start the operation by recording it in storage.
-			let deposit = Self::deposit(threshold);
+			let deposit = quorum.deposit();
 
 			T::Currency::reserve(&who, deposit)?;
 
@@ -748,13 +1142,78 @@ impl<T: Config> Pallet<T> {
 			);
 			Self::deposit_event(Event::NewMultisig { approving: who, multisig: id, call_hash });
 
-			let final_weight =
-				T::WeightInfo::as_multi_create(other_signatories_len as u32, call_len as u32);
+			let final_weight = T::WeightInfo::as_multi_create(s, call_len as u32);
 			// Call is not made, so the actual weight does not include call
 			Ok(Some(final_weight).into())
 		}
 	}
 
+	/// Dispatch the approved `call` from the multisig account `id`.
+	fn execute(
+		who: T::AccountId,
+		id: T::AccountId,
+		timepoint: Timepoint<BlockNumberFor<T>>,
+		call_hash: CallHash,
+		call: <T as Config>::RuntimeCall,
+		s: u32,
+		call_len: u32,
+	) -> PostDispatchInfo {
+		let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
+		Self::deposit_event(Event::MultisigExecuted {
+			approving: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+			result: result.map(|_| ()).map_err(|e| e.error),
+		});
+		get_result_weight(result)
+			.map(|actual_weight| {
+				T::WeightInfo::as_multi_complete(s, call_len).saturating_add(actual_weight)
+			})
+			.into()
+	}
+
+	/// Cancel the operation of the multisig account `id` that was started by `who`.
+	fn cancel(
+		who: T::AccountId,
+		id: T::AccountId,
+		timepoint: Timepoint<BlockNumberFor<T>>,
+		call_hash: CallHash,
+	) -> DispatchResult {
+		let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+		ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+		let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+		debug_assert!(err_amount.is_zero());
+		<Multisigs<T>>::remove(&id, &call_hash);
+
+		Self::deposit_event(Event::MultisigCancelled {
+			cancelling: who,
+			timepoint,
+			multisig: id,
+			call_hash,
+		});
+		Ok(())
+	}
+
+	/// Check the members of a stored multisig account and their weights against `threshold`.
+	fn ensure_valid_members(
+		members: Vec<(T::AccountId, u16)>,
+		threshold: u16,
+	) -> Result<BoundedVec<(T::AccountId, u16), T::MaxSignatories>, DispatchError> {
+		ensure!(members.len() >= 2, Error::<T>::TooFewSignatories);
+		ensure!(
+			members.windows(2).all(|pair| pair[0].0 < pair[1].0),
+			Error::<T>::SignatoriesOutOfOrder
+		);
+		ensure!(members.iter().all(|(_, weight)| *weight > 0), Error::<T>::ZeroWeight);
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		let total = members.iter().map(|(_, weight)| u32::from(*weight)).sum::<u32>();
+		ensure!(total >= threshold.into(), Error::<T>::ThresholdUnreachable);
+		members.try_into().map_err(|_| Error::<T>::TooManySignatories.into())
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -54,6 +54,9 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 			RuntimeCall::Balances(_) => true,
 			// Needed for benchmarking
 			RuntimeCall::System(frame_system::Call::remark { .. }) => true,
+			RuntimeCall::Multisig(
+				Call::set_stored_multisig { .. } | Call::destroy_stored_multisig { .. },
+			) => true,
 			_ => false,
 		}
 	}
@@ -1015,3 +1018,309 @@ fn poke_deposit_handles_insufficient_balance() {
 		);
 	});
 }
+
+fn create_stored(members: Vec<(u64, u16)>, threshold: u16) -> u64 {
+	assert_ok!(Multisig::create_stored_multisig(RuntimeOrigin::signed(1), members, threshold, 0));
+	let multi = Multisig::stored_multisig_account_id(&1, 0, None);
+	assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+	assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+	multi
+}
+
+#[test]
+fn stored_multisig_create_and_destroy_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Multisig::create_stored_multisig(RuntimeOrigin::signed(1), vec![(2, 1)], 2, 0),
+			Error::<Test>::TooFewSignatories,
+		);
+		assert_noop!(
+			Multisig::create_stored_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 1), (2, 1), (3, 1), (4, 1)],
+				2,
+				0
+			),
+			Error::<Test>::TooManySignatories,
+		);
+		assert_noop!(
+			Multisig::create_stored_multisig(RuntimeOrigin::signed(1), vec![(2, 1), (1, 1)], 2, 0),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_stored_multisig(RuntimeOrigin::signed(1), vec![(1, 1), (2, 0)], 1, 0),
+			Error::<Test>::ZeroWeight,
+		);
+		assert_noop!(
+			Multisig::create_stored_multisig(RuntimeOrigin::signed(1), vec![(1, 1), (2, 1)], 1, 0),
+			Error::<Test>::MinimumThreshold,
+		);
+		assert_noop!(
+			Multisig::create_stored_multisig(RuntimeOrigin::signed(1), vec![(1, 1), (2, 1)], 3, 0),
+			Error::<Test>::ThresholdUnreachable,
+		);
+
+		assert_ok!(Multisig::create_stored_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(2, 1), (3, 1)],
+			2,
+			0
+		));
+		let multi = Multisig::stored_multisig_account_id(&1, 0, None);
+		System::assert_last_event(
+			Event::StoredMultisigCreated { multisig: multi, depositor: 1, threshold: 2 }.into(),
+		);
+		// `DepositBase + DepositFactor * members`.
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_noop!(
+			Multisig::create_stored_multisig(RuntimeOrigin::signed(1), vec![(2, 1), (3, 1)], 2, 0),
+			Error::<Test>::AlreadyStored,
+		);
+		// A different index results in a different account.
+		assert_ok!(Multisig::create_stored_multisig(
+			RuntimeOrigin::signed(1),
+			vec![(2, 1), (3, 1)],
+			2,
+			1
+		));
+		assert_ne!(Multisig::stored_multisig_account_id(&1, 1, None), multi);
+
+		assert_noop!(
+			Multisig::destroy_stored_multisig(RuntimeOrigin::signed(1)),
+			Error::<Test>::NotStoredMultisig,
+		);
+		// The account can only be destroyed once nothing is left on it.
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_noop!(
+			Multisig::destroy_stored_multisig(RuntimeOrigin::signed(multi)),
+			Error::<Test>::NotEmpty,
+		);
+		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(multi), 2, false));
+		assert_ok!(Multisig::destroy_stored_multisig(RuntimeOrigin::signed(multi)));
+		System::assert_last_event(Event::StoredMultisigDestroyed { multisig: multi }.into());
+		assert!(!StoredMultisigs::<Test>::contains_key(multi));
+		assert_eq!(Balances::reserved_balance(1), 3);
+	});
+}
+
+#[test]
+fn stored_multisig_weighted_threshold_works() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stored(vec![(1, 1), (2, 1), (3, 2)], 3);
+		let call = call_transfer(6, 10);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+
+		assert_noop!(
+			Multisig::approve_as_multi_stored(
+				RuntimeOrigin::signed(4),
+				multi,
+				None,
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::NotMember,
+		);
+		assert_noop!(
+			Multisig::approve_as_multi_stored(
+				RuntimeOrigin::signed(1),
+				2,
+				None,
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::NotStoredMultisig,
+		);
+
+		assert_ok!(Multisig::approve_as_multi_stored(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		// The deposit of the stored multisig and of the operation.
+		assert_eq!(Balances::reserved_balance(1), 4 + 4);
+		// A weight of two out of three is not enough.
+		assert_ok!(Multisig::as_multi_stored(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			call.clone(),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+
+		assert_ok!(Multisig::as_multi_stored(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 10);
+		assert_eq!(Balances::reserved_balance(1), 4);
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExecuted {
+				approving: 3,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn stored_multisig_member_reaching_threshold_dispatches_immediately() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stored(vec![(1, 1), (2, 1), (3, 2)], 2);
+
+		assert_ok!(Multisig::as_multi_stored(
+			RuntimeOrigin::signed(3),
+			multi,
+			None,
+			call_transfer(6, 10),
+			Weight::MAX
+		));
+		assert_eq!(Balances::free_balance(6), 10);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Multisigs::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn stored_multisig_members_can_be_changed() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stored(vec![(1, 1), (2, 1), (3, 1)], 2);
+		let transfer = call_transfer(6, 5);
+		let transfer_hash = blake2_256(&transfer.encode());
+		assert_ok!(Multisig::approve_as_multi_stored(
+			RuntimeOrigin::signed(1),
+			multi,
+			None,
+			transfer_hash,
+			Weight::zero()
+		));
+
+		// Replace member 1 with member 4.
+		let call = Box::new(RuntimeCall::Multisig(Call::set_stored_multisig {
+			members: vec![(2, 1), (3, 1), (4, 1)],
+			threshold: 2,
+		}));
+		assert_noop!(
+			Multisig::set_stored_multisig(RuntimeOrigin::signed(1), vec![(2, 1), (3, 1)], 2),
+			Error::<Test>::NotStoredMultisig,
+		);
+		assert_ok!(Multisig::as_multi_stored(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			call.clone(),
+			Weight::MAX
+		));
+		assert_ok!(Multisig::as_multi_stored(
+			RuntimeOrigin::signed(3),
+			multi,
+			Some(now()),
+			call,
+			Weight::MAX
+		));
+		System::assert_has_event(
+			Event::StoredMultisigUpdated { multisig: multi, threshold: 2 }.into(),
+		);
+		// The account and the depositor stay the same.
+		let stored = StoredMultisigs::<Test>::get(multi).unwrap();
+		assert_eq!(stored.members.into_inner(), vec![(2, 1), (3, 1), (4, 1)]);
+		assert_eq!(stored.depositor, 1);
+		assert_eq!(Balances::reserved_balance(multi), 0);
+		// The deposit of the stored multisig and of the open operation.
+		assert_eq!(Balances::reserved_balance(1), 8);
+
+		// The approval of the removed member no longer counts.
+		assert_noop!(
+			Multisig::approve_as_multi_stored(
+				RuntimeOrigin::signed(1),
+				multi,
+				Some(now()),
+				transfer_hash,
+				Weight::zero()
+			),
+			Error::<Test>::NotMember,
+		);
+		assert_ok!(Multisig::as_multi_stored(
+			RuntimeOrigin::signed(4),
+			multi,
+			Some(now()),
+			transfer.clone(),
+			Weight::MAX
+		));
+		assert_eq!(Balances::free_balance(6), 0);
+		assert_ok!(Multisig::as_multi_stored(
+			RuntimeOrigin::signed(2),
+			multi,
+			Some(now()),
+			transfer,
+			Weight::MAX
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::reserved_balance(1), 4);
+	});
+}
+
+#[test]
+fn stored_multisig_can_be_destroyed_after_changing_members() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stored(vec![(1, 1), (2, 1)], 2);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// A member more raises the deposit of the depositor.
+		assert_ok!(Multisig::set_stored_multisig(
+			RuntimeOrigin::signed(multi),
+			vec![(1, 1), (2, 1), (3, 1)],
+			2
+		));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		// A member less lowers it again.
+		assert_ok!(Multisig::set_stored_multisig(
+			RuntimeOrigin::signed(multi),
+			vec![(2, 1), (3, 1)],
+			2
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_eq!(Balances::reserved_balance(multi), 0);
+
+		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(multi), 2, false));
+		assert_ok!(Multisig::destroy_stored_multisig(RuntimeOrigin::signed(multi)));
+		assert!(!StoredMultisigs::<Test>::contains_key(multi));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn cancel_stored_multisig_operation_works() {
+	new_test_ext().execute_with(|| {
+		let multi = create_stored(vec![(1, 1), (2, 1), (3, 1)], 2);
+		let hash = blake2_256(&call_transfer(6, 10).encode());
+		assert_ok!(Multisig::approve_as_multi_stored(
+			RuntimeOrigin::signed(2),
+			multi,
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(2), 4);
+
+		assert_noop!(
+			Multisig::cancel_as_multi_stored(RuntimeOrigin::signed(1), multi, now(), hash),
+			Error::<Test>::NotOwner,
+		);
+		// Operations can still be cancelled after the multisig was destroyed.
+		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(multi), 6, false));
+		assert_ok!(Multisig::destroy_stored_multisig(RuntimeOrigin::signed(multi)));
+		assert_ok!(Multisig::cancel_as_multi_stored(RuntimeOrigin::signed(2), multi, now(), hash));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
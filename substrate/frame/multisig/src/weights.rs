@@ -35,21 +35,21 @@
 //! Autogenerated weights for `pallet_multisig`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-25, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `c8c7296f7413`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
-// frame-omni-bencher
+// ./target/release/frame-omni-bencher
 // v1
 // benchmark
 // pallet
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_multisig
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/multisig/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/multisig/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -59,7 +59,6 @@
 // --no-min-squares
 // --no-median-slopes
 // --genesis-builder-policy=none
-// --exclude-pallets=pallet_xcm,pallet_xcm_benchmarks::fungible,pallet_xcm_benchmarks::generic,pallet_nomination_pools,pallet_remark,pallet_transaction_storage,pallet_election_provider_multi_block,pallet_election_provider_multi_block::signed,pallet_election_provider_multi_block::unsigned,pallet_election_provider_multi_block::verifier
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -79,6 +78,9 @@ pub trait WeightInfo {
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
 	fn poke_deposit(s: u32, ) -> Weight;
+	fn create_stored_multisig(s: u32, ) -> Weight;
+	fn set_stored_multisig(s: u32, ) -> Weight;
+	fn destroy_stored_multisig(s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_multisig` using the Substrate node and recommended hardware.
@@ -93,10 +95,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `3997`
-		// Minimum execution time: 18_622_000 picoseconds.
-		Weight::from_parts(20_470_382, 3997)
-			// Standard Error: 21
-			.saturating_add(Weight::from_parts(397, 0).saturating_mul(z.into()))
+		// Minimum execution time: 23_758_000 picoseconds.
+		Weight::from_parts(34_769_436, 3997)
+			// Standard Error: 98
+			.saturating_add(Weight::from_parts(1_238, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -107,12 +109,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `229 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 41_568_000 picoseconds.
-		Weight::from_parts(29_976_762, 6811)
-			// Standard Error: 1_712
-			.saturating_add(Weight::from_parts(144_320, 0).saturating_mul(s.into()))
-			// Standard Error: 16
-			.saturating_add(Weight::from_parts(1_819, 0).saturating_mul(z.into()))
+		// Minimum execution time: 46_182_000 picoseconds.
+		Weight::from_parts(50_037_204, 6811)
+			// Standard Error: 10_059
+			.saturating_add(Weight::from_parts(66_286, 0).saturating_mul(s.into()))
+			// Standard Error: 98
+			.saturating_add(Weight::from_parts(1_120, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -124,12 +126,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `185`
 		//  Estimated: `6811`
-		// Minimum execution time: 26_237_000 picoseconds.
-		Weight::from_parts(14_821_348, 6811)
-			// Standard Error: 1_425
-			.saturating_add(Weight::from_parts(127_874, 0).saturating_mul(s.into()))
-			// Standard Error: 13
-			.saturating_add(Weight::from_parts(1_968, 0).saturating_mul(z.into()))
+		// Minimum execution time: 28_362_000 picoseconds.
+		Weight::from_parts(22_129_742, 6811)
+			// Standard Error: 2_756
+			.saturating_add(Weight::from_parts(95_943, 0).saturating_mul(s.into()))
+			// Standard Error: 26
+			.saturating_add(Weight::from_parts(1_628, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -147,12 +149,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `288 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 50_942_000 picoseconds.
-		Weight::from_parts(34_745_418, 6811)
-			// Standard Error: 2_993
-			.saturating_add(Weight::from_parts(193_313, 0).saturating_mul(s.into()))
-			// Standard Error: 29
-			.saturating_add(Weight::from_parts(2_057, 0).saturating_mul(z.into()))
+		// Minimum execution time: 56_854_000 picoseconds.
+		Weight::from_parts(44_062_823, 6811)
+			// Standard Error: 12_989
+			.saturating_add(Weight::from_parts(196_532, 0).saturating_mul(s.into()))
+			// Standard Error: 127
+			.saturating_add(Weight::from_parts(2_194, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -163,10 +165,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `233 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_583_000 picoseconds.
-		Weight::from_parts(27_463_275, 6811)
-			// Standard Error: 1_158
-			.saturating_add(Weight::from_parts(139_226, 0).saturating_mul(s.into()))
+		// Minimum execution time: 32_566_000 picoseconds.
+		Weight::from_parts(37_650_714, 6811)
+			// Standard Error: 4_700
+			.saturating_add(Weight::from_parts(135_374, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -177,10 +179,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `185`
 		//  Estimated: `6811`
-		// Minimum execution time: 13_086_000 picoseconds.
-		Weight::from_parts(14_074_258, 6811)
-			// Standard Error: 1_279
-			.saturating_add(Weight::from_parts(126_544, 0).saturating_mul(s.into()))
+		// Minimum execution time: 16_528_000 picoseconds.
+		Weight::from_parts(18_883_520, 6811)
+			// Standard Error: 2_365
+			.saturating_add(Weight::from_parts(97_617, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -191,10 +193,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `357 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 26_950_000 picoseconds.
-		Weight::from_parts(28_623_566, 6811)
-			// Standard Error: 1_795
-			.saturating_add(Weight::from_parts(135_351, 0).saturating_mul(s.into()))
+		// Minimum execution time: 32_884_000 picoseconds.
+		Weight::from_parts(36_325_993, 6811)
+			// Standard Error: 1_930
+			.saturating_add(Weight::from_parts(118_846, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -205,13 +207,59 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `357 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_804_000 picoseconds.
-		Weight::from_parts(27_349_525, 6811)
-			// Standard Error: 1_276
-			.saturating_add(Weight::from_parts(121_813, 0).saturating_mul(s.into()))
+		// Minimum execution time: 31_811_000 picoseconds.
+		Weight::from_parts(37_800_993, 6811)
+			// Standard Error: 11_450
+			.saturating_add(Weight::from_parts(126_072, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Multisig::StoredMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StoredMultisigs` (`max_values`: None, `max_size`: Some(3492), added: 5967, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stored_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94 + s * (3 ±0)`
+		//  Estimated: `6957`
+		// Minimum execution time: 31_240_000 picoseconds.
+		Weight::from_parts(37_972_541, 6957)
+			// Standard Error: 12_279
+			.saturating_add(Weight::from_parts(69_964, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::StoredMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StoredMultisigs` (`max_values`: None, `max_size`: Some(3492), added: 5967, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_stored_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539`
+		//  Estimated: `6957`
+		// Minimum execution time: 16_847_000 picoseconds.
+		Weight::from_parts(39_250_537, 6957)
+			// Standard Error: 7_274
+			.saturating_add(Weight::from_parts(26_904, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StoredMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StoredMultisigs` (`max_values`: None, `max_size`: Some(3492), added: 5967, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn destroy_stored_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150 + s * (37 ±0)`
+		//  Estimated: `6957`
+		// Minimum execution time: 33_207_000 picoseconds.
+		Weight::from_parts(31_754_242, 6957)
+			// Standard Error: 11_273
+			.saturating_add(Weight::from_parts(213_580, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -225,10 +273,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `3997`
-		// Minimum execution time: 18_622_000 picoseconds.
-		Weight::from_parts(20_470_382, 3997)
-			// Standard Error: 21
-			.saturating_add(Weight::from_parts(397, 0).saturating_mul(z.into()))
+		// Minimum execution time: 23_758_000 picoseconds.
+		Weight::from_parts(34_769_436, 3997)
+			// Standard Error: 98
+			.saturating_add(Weight::from_parts(1_238, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -239,12 +287,12 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `229 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 41_568_000 picoseconds.
-		Weight::from_parts(29_976_762, 6811)
-			// Standard Error: 1_712
-			.saturating_add(Weight::from_parts(144_320, 0).saturating_mul(s.into()))
-			// Standard Error: 16
-			.saturating_add(Weight::from_parts(1_819, 0).saturating_mul(z.into()))
+		// Minimum execution time: 46_182_000 picoseconds.
+		Weight::from_parts(50_037_204, 6811)
+			// Standard Error: 10_059
+			.saturating_add(Weight::from_parts(66_286, 0).saturating_mul(s.into()))
+			// Standard Error: 98
+			.saturating_add(Weight::from_parts(1_120, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -256,12 +304,12 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `185`
 		//  Estimated: `6811`
-		// Minimum execution time: 26_237_000 picoseconds.
-		Weight::from_parts(14_821_348, 6811)
-			// Standard Error: 1_425
-			.saturating_add(Weight::from_parts(127_874, 0).saturating_mul(s.into()))
-			// Standard Error: 13
-			.saturating_add(Weight::from_parts(1_968, 0).saturating_mul(z.into()))
+		// Minimum execution time: 28_362_000 picoseconds.
+		Weight::from_parts(22_129_742, 6811)
+			// Standard Error: 2_756
+			.saturating_add(Weight::from_parts(95_943, 0).saturating_mul(s.into()))
+			// Standard Error: 26
+			.saturating_add(Weight::from_parts(1_628, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -279,12 +327,12 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `288 + s * (33 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 50_942_000 picoseconds.
-		Weight::from_parts(34_745_418, 6811)
-			// Standard Error: 2_993
-			.saturating_add(Weight::from_parts(193_313, 0).saturating_mul(s.into()))
-			// Standard Error: 29
-			.saturating_add(Weight::from_parts(2_057, 0).saturating_mul(z.into()))
+		// Minimum execution time: 56_854_000 picoseconds.
+		Weight::from_parts(44_062_823, 6811)
+			// Standard Error: 12_989
+			.saturating_add(Weight::from_parts(196_532, 0).saturating_mul(s.into()))
+			// Standard Error: 127
+			.saturating_add(Weight::from_parts(2_194, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -295,10 +343,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `233 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_583_000 picoseconds.
-		Weight::from_parts(27_463_275, 6811)
-			// Standard Error: 1_158
-			.saturating_add(Weight::from_parts(139_226, 0).saturating_mul(s.into()))
+		// Minimum execution time: 32_566_000 picoseconds.
+		Weight::from_parts(37_650_714, 6811)
+			// Standard Error: 4_700
+			.saturating_add(Weight::from_parts(135_374, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -309,10 +357,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `185`
 		//  Estimated: `6811`
-		// Minimum execution time: 13_086_000 picoseconds.
-		Weight::from_parts(14_074_258, 6811)
-			// Standard Error: 1_279
-			.saturating_add(Weight::from_parts(126_544, 0).saturating_mul(s.into()))
+		// Minimum execution time: 16_528_000 picoseconds.
+		Weight::from_parts(18_883_520, 6811)
+			// Standard Error: 2_365
+			.saturating_add(Weight::from_parts(97_617, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -323,10 +371,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `357 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 26_950_000 picoseconds.
-		Weight::from_parts(28_623_566, 6811)
-			// Standard Error: 1_795
-			.saturating_add(Weight::from_parts(135_351, 0).saturating_mul(s.into()))
+		// Minimum execution time: 32_884_000 picoseconds.
+		Weight::from_parts(36_325_993, 6811)
+			// Standard Error: 1_930
+			.saturating_add(Weight::from_parts(118_846, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -337,11 +385,57 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `357 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_804_000 picoseconds.
-		Weight::from_parts(27_349_525, 6811)
-			// Standard Error: 1_276
-			.saturating_add(Weight::from_parts(121_813, 0).saturating_mul(s.into()))
+		// Minimum execution time: 31_811_000 picoseconds.
+		Weight::from_parts(37_800_993, 6811)
+			// Standard Error: 11_450
+			.saturating_add(Weight::from_parts(126_072, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Multisig::StoredMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StoredMultisigs` (`max_values`: None, `max_size`: Some(3492), added: 5967, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn create_stored_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94 + s * (3 ±0)`
+		//  Estimated: `6957`
+		// Minimum execution time: 31_240_000 picoseconds.
+		Weight::from_parts(37_972_541, 6957)
+			// Standard Error: 12_279
+			.saturating_add(Weight::from_parts(69_964, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::StoredMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StoredMultisigs` (`max_values`: None, `max_size`: Some(3492), added: 5967, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_stored_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `539`
+		//  Estimated: `6957`
+		// Minimum execution time: 16_847_000 picoseconds.
+		Weight::from_parts(39_250_537, 6957)
+			// Standard Error: 7_274
+			.saturating_add(Weight::from_parts(26_904, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::StoredMultisigs` (r:1 w:1)
+	/// Proof: `Multisig::StoredMultisigs` (`max_values`: None, `max_size`: Some(3492), added: 5967, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn destroy_stored_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150 + s * (37 ±0)`
+		//  Estimated: `6957`
+		// Minimum execution time: 33_207_000 picoseconds.
+		Weight::from_parts(31_754_242, 6957)
+			// Standard Error: 11_273
+			.saturating_add(Weight::from_parts(213_580, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -72,9 +72,11 @@ impl pallet_assets::Config for Test {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type PermitSignature = Signature;
 	type WeightInfo = ();
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
+		type PermitBenchmarkHelper = ();
 	}
 }
 
@@ -87,6 +89,8 @@ impl pallet_nfts::Config for Test {
 	type CollectionId = u32;
 	type ItemId = u32;
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type NativeBalance = Balances;
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<Self::AccountId>>;
 	type ForceOrigin = frame_system::EnsureRoot<Self::AccountId>;
 	type Locker = ();
@@ -103,6 +107,9 @@ impl pallet_nfts::Config for Test {
 	type MaxTips = ConstU32<10>;
 	type MaxDeadlineDuration = ConstU64<10000>;
 	type MaxAttributesPerCall = ConstU32<2>;
+	type MaxRoyaltyRecipients = ConstU32<5>;
+	type MaxRoyalty = ConstU16<5_000>;
+	type AuctionExtensionPeriod = ConstU64<5>;
 	type Features = Features;
 	type OffchainSignature = Signature;
 	type OffchainPublic = AccountPublic;
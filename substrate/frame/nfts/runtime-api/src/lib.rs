@@ -55,5 +55,10 @@ sp_api::decl_runtime_apis! {
 		) -> Option<Vec<u8>>;
 
 		fn collection_attribute(collection: CollectionId, key: Vec<u8>) -> Option<Vec<u8>>;
+
+		/// The royalty recipients of a collection and their shares of the sale price in basis
+		/// points.
+		#[api_version(2)]
+		fn collection_royalty(collection: CollectionId) -> Vec<(AccountId, u16)>;
 	}
 }
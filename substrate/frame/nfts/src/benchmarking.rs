@@ -215,6 +215,20 @@ fn default_item_config() -> ItemConfig {
 	ItemConfig { settings: ItemSettings::all_enabled() }
 }
 
+fn royalty_recipients<T: Config<I>, I: 'static>(n: u32) -> RoyaltyRecipientsOf<T, I> {
+	(0..n)
+		.map(|i| RoyaltyRecipient { account: account("royalty", i, SEED), basis_points: 1 })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+/// A price paying every royalty recipient's share of one basis point exactly the existential
+/// deposit.
+fn royalty_price<T: Config<I>, I: 'static>() -> ItemPrice<T, I> {
+	T::Currency::minimum_balance().saturating_mul(MAX_ROYALTY_BASIS_POINTS.into())
+}
+
 fn make_filled_vec(value: u16, length: usize) -> Vec<u8> {
 	let mut vec = vec![0u8; length];
 	let mut s = Vec::from(value.to_be_bytes());
@@ -350,7 +364,8 @@ benchmarks_instance_pallet! {
 			CollectionSetting::TransferableItems |
 				CollectionSetting::UnlockedMetadata |
 				CollectionSetting::UnlockedAttributes |
-				CollectionSetting::UnlockedMaxSupply,
+				CollectionSetting::UnlockedMaxSupply |
+				CollectionSetting::UnlockedRoyalties,
 		);
 	}: _(SystemOrigin::Signed(caller.clone()), collection, lock_settings)
 	verify {
@@ -662,14 +677,21 @@ benchmarks_instance_pallet! {
 	}
 
 	buy_item {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
 		let (collection, seller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
 		let buyer: T::AccountId = account("buyer", 0, SEED);
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
-		let price = ItemPrice::<T, I>::from(0u32);
-		let origin = SystemOrigin::Signed(seller.clone()).into();
-		Nfts::<T, I>::set_price(origin, collection, item, Some(price), Some(buyer_lookup))?;
-		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
+		let price = royalty_price::<T, I>();
+		let origin = SystemOrigin::Signed(seller.clone());
+		Nfts::<T, I>::set_collection_royalty(
+			origin.clone().into(),
+			collection,
+			royalty_recipients::<T, I>(r),
+		)?;
+		Nfts::<T, I>::set_price(origin.into(), collection, item, Some(price), Some(buyer_lookup))?;
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&buyer, price.saturating_mul(2u32.into()));
 	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price)
 	verify {
 		assert_last_event::<T, I>(Event::ItemBought {
@@ -749,18 +771,31 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	set_collection_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let recipients = royalty_recipients::<T, I>(T::MaxRoyaltyRecipients::get());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, recipients.clone())
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltySet { collection, recipients }.into());
+	}
+
 	claim_swap {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
 		let (item2, ..) = mint_item::<T, I>(1);
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = royalty_price::<T, I>();
 		let price_direction = PriceDirection::Receive;
 		let price_with_direction = PriceWithDirection { amount: price, direction: price_direction };
 		let duration = T::MaxDeadlineDuration::get();
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup = T::Lookup::unlookup(target.clone());
-		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
 		let origin = SystemOrigin::Signed(caller.clone());
+		Nfts::<T, I>::set_collection_royalty(
+			origin.clone().into(),
+			collection,
+			royalty_recipients::<T, I>(r),
+		)?;
 		T::BlockNumberProvider::set_block_number(One::one());
 		Nfts::<T, I>::transfer(origin.clone().into(), collection, item2, target_lookup)?;
 		Nfts::<T, I>::create_swap(
@@ -772,6 +807,8 @@ benchmarks_instance_pallet! {
 			Some(price_with_direction.clone()),
 			duration,
 		)?;
+		T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&target, price.saturating_mul(2u32.into()));
 	}: _(SystemOrigin::Signed(target.clone()), collection, item2, collection, item1, Some(price_with_direction.clone()))
 	verify {
 		let current_block = T::BlockNumberProvider::current_block_number();
@@ -878,5 +915,141 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	create_auction {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let kind = AuctionKind::English { reserve_price: royalty_price::<T, I>() };
+		let duration = T::MaxDeadlineDuration::get();
+		T::BlockNumberProvider::set_block_number(One::one());
+	}: _(SystemOrigin::Signed(caller.clone()), collection, item, kind.clone(), duration)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionCreated {
+			collection,
+			item,
+			seller: caller,
+			kind,
+			end: duration.saturating_add(One::one()),
+		}.into());
+	}
+
+	place_bid_english {
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let outbid: T::AccountId = account("outbid", 0, SEED);
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		let price = royalty_price::<T, I>();
+		let bid = price.saturating_add(One::one());
+		T::BlockNumberProvider::set_block_number(One::one());
+		Nfts::<T, I>::create_auction(
+			SystemOrigin::Signed(seller).into(),
+			collection,
+			item,
+			AuctionKind::English { reserve_price: price },
+			T::MaxDeadlineDuration::get(),
+		)?;
+		// Outbidding releases the previous bid.
+		T::Currency::make_free_balance_be(&outbid, price.saturating_mul(2u32.into()));
+		Nfts::<T, I>::place_bid(SystemOrigin::Signed(outbid).into(), collection, item, price)?;
+		T::Currency::make_free_balance_be(&bidder, bid.saturating_mul(2u32.into()));
+	}: place_bid(SystemOrigin::Signed(bidder.clone()), collection, item, bid)
+	verify {
+		let end = ItemAuctionOf::<T, I>::get(collection, item).unwrap().end;
+		assert_last_event::<T, I>(Event::AuctionBidPlaced {
+			collection,
+			item,
+			bidder,
+			amount: bid,
+			end,
+		}.into());
+	}
+
+	place_bid_dutch {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let price = royalty_price::<T, I>();
+		let origin = SystemOrigin::Signed(seller.clone());
+		Nfts::<T, I>::set_collection_royalty(
+			origin.clone().into(),
+			collection,
+			royalty_recipients::<T, I>(r),
+		)?;
+		T::BlockNumberProvider::set_block_number(One::one());
+		// A bid on a Dutch auction buys the item right away.
+		Nfts::<T, I>::create_auction(
+			origin.into(),
+			collection,
+			item,
+			AuctionKind::Dutch { start_price: price, end_price: price },
+			T::MaxDeadlineDuration::get(),
+		)?;
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&buyer, price.saturating_mul(2u32.into()));
+	}: place_bid(SystemOrigin::Signed(buyer.clone()), collection, item, price)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionSettled {
+			collection,
+			item,
+			seller,
+			buyer,
+			price,
+		}.into());
+	}
+
+	cancel_auction {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let origin = SystemOrigin::Signed(caller.clone());
+		T::BlockNumberProvider::set_block_number(One::one());
+		Nfts::<T, I>::create_auction(
+			origin.clone().into(),
+			collection,
+			item,
+			AuctionKind::English { reserve_price: royalty_price::<T, I>() },
+			T::MaxDeadlineDuration::get(),
+		)?;
+	}: _(origin, collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionClosed { collection, item }.into());
+	}
+
+	// Only English auctions have a bid left to settle, Dutch auctions are sold on their bid.
+	settle_auction {
+		let r in 0 .. T::MaxRoyaltyRecipients::get();
+		let (collection, seller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let price = royalty_price::<T, I>();
+		let origin = SystemOrigin::Signed(seller.clone());
+		Nfts::<T, I>::set_collection_royalty(
+			origin.clone().into(),
+			collection,
+			royalty_recipients::<T, I>(r),
+		)?;
+		T::BlockNumberProvider::set_block_number(One::one());
+		Nfts::<T, I>::create_auction(
+			origin.into(),
+			collection,
+			item,
+			AuctionKind::English { reserve_price: price },
+			One::one(),
+		)?;
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance());
+		T::Currency::make_free_balance_be(&buyer, price.saturating_mul(2u32.into()));
+		Nfts::<T, I>::place_bid(SystemOrigin::Signed(buyer.clone()).into(), collection, item, price)?;
+		let end = ItemAuctionOf::<T, I>::get(collection, item).unwrap().end;
+		T::BlockNumberProvider::set_block_number(end.saturating_add(One::one()));
+	}: _(SystemOrigin::Signed(seller.clone()), collection, item)
+	verify {
+		assert_last_event::<T, I>(Event::AuctionSettled {
+			collection,
+			item,
+			seller,
+			buyer,
+			price,
+		}.into());
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
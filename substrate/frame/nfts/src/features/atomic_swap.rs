@@ -21,10 +21,7 @@
 //! to have the functionality defined in this module.
 
 use crate::*;
-use frame_support::{
-	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::KeepAlive},
-};
+use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Creates a new swap offer for the specified item.
@@ -146,8 +143,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// `send_item_id`, `receive_collection_id`, and `receive_item_id`. The `caller` account must be
 	/// the owner of the item specified by `send_collection_id` and `send_item_id`. If the claimed
 	/// swap has an associated `price`, it will be transferred between the owners of the two items
-	/// based on the `price.direction`. After the swap is completed, the function emits the
-	/// `SwapClaimed` event.
+	/// based on the `price.direction`, paying the royalties of the collection of the item bought
+	/// with it. After the swap is completed, the function emits the `SwapClaimed` event.
 	///
 	/// - `caller`: The account claiming the swap offer, which must be the owner of the sent item.
 	/// - `send_collection_id`: The identifier of the collection containing the item being sent.
@@ -191,18 +188,21 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ensure!(now <= swap.deadline, Error::<T, I>::DeadlineExpired);
 
 		if let Some(ref price) = swap.price {
+			// The royalties are due on the item the price is paid for.
 			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
+				PriceDirection::Send => Self::do_pay_with_royalties(
+					send_collection_id,
+					send_item_id,
 					&receive_item.owner,
 					&send_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
-				PriceDirection::Receive => T::Currency::transfer(
+				PriceDirection::Receive => Self::do_pay_with_royalties(
+					receive_collection_id,
+					receive_item_id,
 					&send_item.owner,
 					&receive_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
 			};
 		}
@@ -0,0 +1,338 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to run English and Dutch auctions of items in the NFTs
+//! pallet.
+//! The bitflag [`PalletFeature::Trading`] needs to be set in the [`Config::Features`] for NFTs
+//! to have the functionality defined in this module.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{fungible::MutateHold, tokens::Precision::BestEffort},
+	weights::WeightMeter,
+};
+use sp_runtime::{traits::SaturatedConversion, Perbill};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Puts an item up for auction.
+	///
+	/// The `caller` must be the owner of the item, and the item must be transferable and not
+	/// locked. Any price or swap offer set for the item is removed, as the item can't be
+	/// transferred until the auction is over. The auction accepts bids for `duration` blocks.
+	///
+	/// - `caller`: The owner of the item, receiving the auction's proceeds.
+	/// - `collection`: The collection of the item.
+	/// - `item`: The item to be auctioned.
+	/// - `kind`: The kind of the auction and its prices.
+	/// - `duration`: The number of blocks the auction accepts bids for.
+	pub(crate) fn do_create_auction(
+		caller: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		kind: AuctionKind<ItemPrice<T, I>>,
+		duration: BlockNumberFor<T, I>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Trading),
+			Error::<T, I>::MethodDisabled
+		);
+		ensure!(
+			!duration.is_zero() && duration <= T::MaxDeadlineDuration::get(),
+			Error::<T, I>::WrongDuration
+		);
+		if let AuctionKind::Dutch { start_price, end_price } = kind {
+			ensure!(start_price >= end_price, Error::<T, I>::WrongAuctionPrices);
+		}
+
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == caller, Error::<T, I>::NoPermission);
+		ensure!(
+			!ItemAuctionOf::<T, I>::contains_key(&collection, &item),
+			Error::<T, I>::ItemInAuction
+		);
+
+		// The item has to be transferable to the auction's winner.
+		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
+		ensure!(
+			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
+			Error::<T, I>::ItemLocked
+		);
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::TransferableItems),
+			Error::<T, I>::ItemsNonTransferable
+		);
+		let item_config = Self::get_item_config(&collection, &item)?;
+		ensure!(
+			item_config.is_setting_enabled(ItemSetting::Transferable),
+			Error::<T, I>::ItemLocked
+		);
+
+		let start = T::BlockNumberProvider::current_block_number();
+		let end = start.saturating_add(duration);
+
+		ItemPriceOf::<T, I>::remove(&collection, &item);
+		PendingSwapOf::<T, I>::remove(&collection, &item);
+		ItemAuctionOf::<T, I>::insert(
+			&collection,
+			&item,
+			AuctionDetails {
+				seller: caller.clone(),
+				kind: kind.clone(),
+				start,
+				end,
+				highest_bid: None,
+			},
+		);
+
+		Self::deposit_event(Event::AuctionCreated { collection, item, seller: caller, kind, end });
+
+		Ok(())
+	}
+
+	/// Bids on the auction of an item.
+	///
+	/// On an English auction, the bid must meet the reserve price and exceed the highest bid. It
+	/// is held from the `bidder`, and the outbid bid is released. A bid placed within
+	/// [`Config::AuctionExtensionPeriod`] of the auction's end extends the auction to end that
+	/// period after the bid.
+	///
+	/// On a Dutch auction, a bid meeting the current price buys the item at that price right away.
+	///
+	/// - `bidder`: The account bidding, which must not be the seller.
+	/// - `collection`: The collection of the item.
+	/// - `item`: The auctioned item.
+	/// - `amount`: The bid, or the highest price the `bidder` is willing to pay on a Dutch auction.
+	pub(crate) fn do_place_bid(
+		bidder: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		amount: ItemPrice<T, I>,
+	) -> DispatchResult {
+		ensure!(
+			Self::is_pallet_feature_enabled(PalletFeature::Trading),
+			Error::<T, I>::MethodDisabled
+		);
+
+		let mut auction =
+			ItemAuctionOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		ensure!(auction.seller != bidder, Error::<T, I>::NoPermission);
+
+		let now = T::BlockNumberProvider::current_block_number();
+		ensure!(now <= auction.end, Error::<T, I>::AuctionEnded);
+
+		match auction.kind {
+			AuctionKind::English { reserve_price } => {
+				ensure!(amount >= reserve_price, Error::<T, I>::BidTooLow);
+				if let Some((_, highest_amount)) = auction.highest_bid {
+					ensure!(amount > highest_amount, Error::<T, I>::BidTooLow);
+				}
+
+				let reason = HoldReason::<I>::AuctionBid.into();
+				T::NativeBalance::hold(&reason, &bidder, amount)?;
+				if let Some((outbid, outbid_amount)) = auction.highest_bid.take() {
+					T::NativeBalance::release(&reason, &outbid, outbid_amount, BestEffort)?;
+				}
+
+				// Late bids extend the auction, so that others get the chance to outbid them.
+				auction.end = auction.end.max(now.saturating_add(T::AuctionExtensionPeriod::get()));
+				auction.highest_bid = Some((bidder.clone(), amount));
+				ItemAuctionOf::<T, I>::insert(&collection, &item, &auction);
+
+				Self::deposit_event(Event::AuctionBidPlaced {
+					collection,
+					item,
+					bidder,
+					amount,
+					end: auction.end,
+				});
+			},
+			AuctionKind::Dutch { start_price, end_price } => {
+				let price = Self::dutch_auction_price(
+					auction.start,
+					auction.end,
+					start_price,
+					end_price,
+					now,
+				);
+				ensure!(amount >= price, Error::<T, I>::BidTooLow);
+
+				ItemAuctionOf::<T, I>::remove(&collection, &item);
+				Self::do_sell_auctioned_item(collection, item, auction.seller, bidder, price)?;
+			},
+		}
+
+		Ok(())
+	}
+
+	/// Cancels the auction of an item that has no bids.
+	///
+	/// - `caller`: The account cancelling the auction, which must be the seller.
+	/// - `collection`: The collection of the item.
+	/// - `item`: The auctioned item.
+	pub(crate) fn do_cancel_auction(
+		caller: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let auction =
+			ItemAuctionOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		ensure!(auction.seller == caller, Error::<T, I>::NoPermission);
+		ensure!(auction.highest_bid.is_none(), Error::<T, I>::AuctionHasBids);
+
+		ItemAuctionOf::<T, I>::remove(&collection, &item);
+
+		Self::deposit_event(Event::AuctionClosed { collection, item });
+
+		Ok(())
+	}
+
+	/// Settles an ended auction.
+	///
+	/// The highest bid is released and pays for the item, the collection's royalties included.
+	/// If the item can no longer be sold, e.g. because its collection was locked in the meantime,
+	/// the auction closes with the bid released and the item left with the seller, as it does
+	/// when there were no bids.
+	///
+	/// - `collection`: The collection of the item.
+	/// - `item`: The auctioned item.
+	pub(crate) fn do_settle_auction(
+		collection: T::CollectionId,
+		item: T::ItemId,
+	) -> DispatchResult {
+		let auction =
+			ItemAuctionOf::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownAuction)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		ensure!(now > auction.end, Error::<T, I>::AuctionNotEnded);
+
+		ItemAuctionOf::<T, I>::remove(&collection, &item);
+
+		let sold = match auction.highest_bid {
+			Some((buyer, price)) => {
+				T::NativeBalance::release(
+					&HoldReason::<I>::AuctionBid.into(),
+					&buyer,
+					price,
+					BestEffort,
+				)?;
+				with_storage_layer(|| {
+					Self::do_sell_auctioned_item(collection, item, auction.seller, buyer, price)
+				})
+				.is_ok()
+			},
+			None => false,
+		};
+
+		if !sold {
+			Self::deposit_event(Event::AuctionClosed { collection, item });
+		}
+
+		Ok(())
+	}
+
+	/// Settles the ended auctions within the `limit` weight.
+	///
+	/// The auctions are checked in storage order, resuming after the last auction checked by the
+	/// previous call, and starting over once all of them were checked.
+	pub(crate) fn settle_ended_auctions(limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		let db_weight = T::DbWeight::get();
+		// Reading and updating the cursor.
+		if meter.try_consume(db_weight.reads_writes(1, 1)).is_err() {
+			return meter.consumed()
+		}
+
+		let check_weight = db_weight.reads(1);
+		let settle_weight = T::WeightInfo::settle_auction(T::MaxRoyaltyRecipients::get());
+		let now = T::BlockNumberProvider::current_block_number();
+
+		let mut auctions = match AuctionSettlementCursor::<T, I>::get() {
+			Some((collection, item)) => ItemAuctionOf::<T, I>::iter_from(
+				ItemAuctionOf::<T, I>::hashed_key_for(&collection, &item),
+			),
+			None => ItemAuctionOf::<T, I>::iter(),
+		};
+		// Only updated once an auction was checked, or all of them were.
+		let mut cursor = None;
+		let mut ended = Vec::new();
+		while meter.can_consume(check_weight.saturating_add(settle_weight)) {
+			let Some((collection, item, auction)) = auctions.next() else {
+				// All the auctions were checked, the next call starts over.
+				cursor = Some(None);
+				break
+			};
+			meter.consume(check_weight);
+			if now > auction.end {
+				meter.consume(settle_weight);
+				ended.push((collection, item));
+			}
+			cursor = Some(Some((collection, item)));
+		}
+		if let Some(cursor) = cursor {
+			AuctionSettlementCursor::<T, I>::set(cursor);
+		}
+
+		for (collection, item) in ended {
+			if let Err(error) = Self::do_settle_auction(collection, item) {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to settle the ended auction of item {:?} of collection {:?}: {:?}",
+					item,
+					collection,
+					error,
+				);
+			}
+		}
+
+		meter.consumed()
+	}
+
+	/// Returns the price of a Dutch auction at the block `now`, declining linearly from the
+	/// `start_price` at its `start` to the `end_price` at its `end`.
+	pub(crate) fn dutch_auction_price(
+		start: BlockNumberFor<T, I>,
+		end: BlockNumberFor<T, I>,
+		start_price: ItemPrice<T, I>,
+		end_price: ItemPrice<T, I>,
+		now: BlockNumberFor<T, I>,
+	) -> ItemPrice<T, I> {
+		let elapsed = now.saturating_sub(start).saturated_into::<u64>();
+		let duration = end.saturating_sub(start).saturated_into::<u64>();
+		let decline = Perbill::from_rational(elapsed, duration)
+			.mul_floor(start_price.saturating_sub(end_price));
+		start_price.saturating_sub(decline)
+	}
+
+	/// Sells an auctioned item to the `buyer` for the `price`, paying the collection's royalties
+	/// out of it.
+	fn do_sell_auctioned_item(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		seller: T::AccountId,
+		buyer: T::AccountId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		Self::do_pay_with_royalties(collection, item, &buyer, &seller, price)?;
+		Self::do_transfer(collection, item, buyer.clone(), |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::AuctionSettled { collection, item, seller, buyer, price });
+
+		Ok(())
+	}
+}
@@ -205,6 +205,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// This function returns a dispatch error in the following cases:
 	/// - If the collection ID is invalid ([`UnknownCollection`](crate::Error::UnknownCollection)).
 	/// - If the item is locked ([`ItemLocked`](crate::Error::ItemLocked)).
+	/// - If the item is being auctioned ([`ItemInAuction`](crate::Error::ItemInAuction)).
 	pub fn do_burn(
 		collection: T::CollectionId,
 		item: T::ItemId,
@@ -215,6 +216,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
 			Error::<T, I>::ItemLocked
 		);
+		ensure!(
+			!ItemAuctionOf::<T, I>::contains_key(&collection, &item),
+			Error::<T, I>::ItemInAuction
+		);
 		let item_config = Self::get_item_config(&collection, &item)?;
 		// NOTE: if item's settings are not empty (e.g. item's metadata is locked)
 		// then we keep the config record and don't remove it
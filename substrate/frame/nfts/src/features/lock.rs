@@ -75,6 +75,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Self::has_role(&collection, &origin, CollectionRole::Freezer),
 			Error::<T, I>::NoPermission
 		);
+		ensure!(
+			!ItemAuctionOf::<T, I>::contains_key(&collection, &item),
+			Error::<T, I>::ItemInAuction
+		);
 
 		let mut config = Self::get_item_config(&collection, &item)?;
 		if !config.has_disabled_setting(ItemSetting::Transferable) {
@@ -0,0 +1,125 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper methods to configure and pay the creator royalties of a collection
+//! in the NFTs pallet.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement::KeepAlive},
+};
+use sp_runtime::Permill;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Set the royalty recipients of a collection.
+	///
+	/// - `maybe_check_owner`: An optional account ID used to check permissions.
+	/// - `collection`: The ID of the collection for which to set the royalties.
+	/// - `recipients`: The accounts receiving royalties and their shares in basis points.
+	///
+	/// This function checks if the setting `UnlockedRoyalties` is enabled in the collection
+	/// configuration and, if `maybe_check_owner` is `Some(owner)`, that the caller is the owner
+	/// of the collection. Every share must be non-zero and the shares must not add up to more than
+	/// [`Config::MaxRoyalty`], otherwise it returns an `Error::WrongRoyalty`. An empty list of
+	/// `recipients` clears the royalties. Emits a `CollectionRoyaltySet` event.
+	pub(crate) fn do_set_collection_royalty(
+		maybe_check_owner: Option<T::AccountId>,
+		collection: T::CollectionId,
+		recipients: RoyaltyRecipientsOf<T, I>,
+	) -> DispatchResult {
+		let collection_config = Self::get_collection_config(&collection)?;
+		ensure!(
+			collection_config.is_setting_enabled(CollectionSetting::UnlockedRoyalties),
+			Error::<T, I>::LockedCollectionRoyalties
+		);
+
+		let details =
+			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+
+		let mut total: u32 = 0;
+		for recipient in recipients.iter() {
+			ensure!(!recipient.basis_points.is_zero(), Error::<T, I>::WrongRoyalty);
+			total = total.saturating_add(recipient.basis_points.into());
+		}
+		let max_royalty = T::MaxRoyalty::get().min(MAX_ROYALTY_BASIS_POINTS);
+		ensure!(total <= max_royalty.into(), Error::<T, I>::WrongRoyalty);
+
+		if recipients.is_empty() {
+			CollectionRoyaltyOf::<T, I>::remove(&collection);
+		} else {
+			CollectionRoyaltyOf::<T, I>::insert(&collection, &recipients);
+		}
+		Self::deposit_event(Event::CollectionRoyaltySet { collection, recipients });
+		Ok(())
+	}
+
+	/// Pay for an item, sending the royalties of its collection to their recipients and the rest
+	/// of the `price` to the `seller`.
+	///
+	/// - `collection`: The collection of the item being sold.
+	/// - `item`: The item being sold.
+	/// - `buyer`: The account paying the `price`.
+	/// - `seller`: The account receiving the `price` net of royalties.
+	/// - `price`: The full price of the item.
+	///
+	/// Every royalty is rounded down. A royalty below the existential deposit is left to the
+	/// `seller` if its recipient has no account, which the transfer could not create. Emits a
+	/// `RoyaltyPaid` event for every royalty paid.
+	pub(crate) fn do_pay_with_royalties(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		let mut remaining = price;
+		for RoyaltyRecipient { account, basis_points } in
+			CollectionRoyaltyOf::<T, I>::get(&collection).unwrap_or_default()
+		{
+			let share = Permill::from_parts(u32::from(basis_points).saturating_mul(100));
+			let amount = share.mul_floor(price);
+			if amount.is_zero() ||
+				(amount < T::Currency::minimum_balance() &&
+					T::Currency::total_balance(&account).is_zero())
+			{
+				continue
+			}
+			T::Currency::transfer(buyer, &account, amount, KeepAlive)?;
+			remaining = remaining.saturating_sub(amount);
+			Self::deposit_event(Event::RoyaltyPaid {
+				collection,
+				item,
+				recipient: account,
+				amount,
+			});
+		}
+		T::Currency::transfer(buyer, seller, remaining, KeepAlive)
+	}
+
+	/// Get the royalty recipients of a collection and their shares in basis points.
+	pub fn collection_royalty(
+		collection: &T::CollectionId,
+	) -> Vec<(T::AccountId, RoyaltyBasisPoints)> {
+		CollectionRoyaltyOf::<T, I>::get(collection)
+			.map(|recipients| recipients.into_iter().map(|r| (r.account, r.basis_points)).collect())
+			.unwrap_or_default()
+	}
+}
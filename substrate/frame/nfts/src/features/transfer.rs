@@ -41,6 +41,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// - If the item ID is invalid ([`UnknownItem`](crate::Error::UnknownItem)).
 	/// - If the item is locked or transferring it is disabled
 	///   ([`ItemLocked`](crate::Error::ItemLocked)).
+	/// - If the item is being auctioned ([`ItemInAuction`](crate::Error::ItemInAuction)).
 	/// - If the collection or item is non-transferable
 	///   ([`ItemsNonTransferable`](crate::Error::ItemsNonTransferable)).
 	pub fn do_transfer(
@@ -59,6 +60,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// Ensure the item is not locked.
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
 
+		// Ensure the item is not being auctioned.
+		ensure!(
+			!ItemAuctionOf::<T, I>::contains_key(&collection, &item),
+			Error::<T, I>::ItemInAuction
+		);
+
 		// Ensure the item is not transfer disabled on the system level attribute.
 		ensure!(
 			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
@@ -423,6 +423,10 @@ impl<T: Config<I>, I: 'static> Transfer<T::AccountId> for Pallet<T, I> {
 		if transfer_disabled {
 			return Err(Error::<T, I>::ItemLocked.into())
 		}
+		// Can't lock an item promised to an auction's winner
+		if ItemAuctionOf::<T, I>::contains_key(collection, item) {
+			return Err(Error::<T, I>::ItemInAuction.into())
+		}
 
 		<Self as Mutate<T::AccountId, ItemConfig>>::set_attribute(
 			collection,
@@ -53,8 +53,8 @@ extern crate alloc;
 use alloc::{boxed::Box, vec, vec::Vec};
 use codec::{Decode, Encode};
 use frame_support::traits::{
-	tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, Incrementable,
-	ReservableCurrency,
+	fungible, tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg,
+	Incrementable, ReservableCurrency,
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
@@ -76,7 +76,10 @@ type AccountIdLookupOf<T> = <<T as SystemConfig>::Lookup as StaticLookup>::Sourc
 pub mod pallet {
 	use super::*;
 	use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
-	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+	use frame_system::{
+		ensure_signed,
+		pallet_prelude::{BlockNumberFor as SystemBlockNumberFor, OriginFor},
+	};
 
 	/// The in-code storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -149,6 +152,13 @@ pub mod pallet {
 		/// The currency mechanism, used for paying for reserves.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason<I>>;
+
+		/// The fungible holding the bids on auctions. Must share the balance of `Currency`.
+		type NativeBalance: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ fungible::Inspect<Self::AccountId, Balance = BalanceOf<Self, I>>;
+
 		/// The origin which may forcibly create or destroy an item or otherwise alter privileged
 		/// attributes.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -217,6 +227,20 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
 
+		/// The max number of accounts receiving royalties on the sales of a collection's items.
+		#[pallet::constant]
+		type MaxRoyaltyRecipients: Get<u32>;
+
+		/// The max total share of a sale price, in basis points, that the royalties of a collection
+		/// can add up to. Values above [`MAX_ROYALTY_BASIS_POINTS`] have no effect.
+		#[pallet::constant]
+		type MaxRoyalty: Get<RoyaltyBasisPoints>;
+
+		/// A bid placed this close to the end of an English auction extends the auction to end
+		/// this long after the bid.
+		#[pallet::constant]
+		type AuctionExtensionPeriod: Get<BlockNumberFor<Self, I>>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
@@ -402,6 +426,11 @@ pub mod pallet {
 	pub type CollectionConfigOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, CollectionConfigFor<T, I>, OptionQuery>;
 
+	/// Royalty recipients of a collection, paid out of every sale of its items.
+	#[pallet::storage]
+	pub type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltyRecipientsOf<T, I>, OptionQuery>;
+
 	/// Config of an item.
 	#[pallet::storage]
 	pub type ItemConfigOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -414,6 +443,23 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Handles all the running auctions.
+	#[pallet::storage]
+	pub type ItemAuctionOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		AuctionDetailsFor<T, I>,
+		OptionQuery,
+	>;
+
+	/// The last auction checked for settlement in `on_idle`, the next check resumes after it.
+	#[pallet::storage]
+	pub type AuctionSettlementCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (T::CollectionId, T::ItemId), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -543,6 +589,42 @@ pub mod pallet {
 			seller: T::AccountId,
 			buyer: T::AccountId,
 		},
+		/// The royalty recipients of a `collection` were set. Empty `recipients` means the
+		/// royalties were cleared.
+		CollectionRoyaltySet { collection: T::CollectionId, recipients: RoyaltyRecipientsOf<T, I> },
+		/// A royalty was paid out of the sale of an `item`.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			recipient: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
+		/// An auction of an `item` was created.
+		AuctionCreated {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			kind: AuctionKind<ItemPrice<T, I>>,
+			end: BlockNumberFor<T, I>,
+		},
+		/// A bid was placed on the auction of an `item`, which now ends at `end`.
+		AuctionBidPlaced {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			bidder: T::AccountId,
+			amount: ItemPrice<T, I>,
+			end: BlockNumberFor<T, I>,
+		},
+		/// The auction of an `item` was won by the `buyer`.
+		AuctionSettled {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: ItemPrice<T, I>,
+		},
+		/// The auction of an `item` closed without a sale.
+		AuctionClosed { collection: T::CollectionId, item: T::ItemId },
 		/// A tip was sent.
 		TipSent {
 			collection: T::CollectionId,
@@ -688,6 +770,37 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// Collection's royalties are locked.
+		LockedCollectionRoyalties,
+		/// The royalty shares are zero or add up to more than the sale price.
+		WrongRoyalty,
+		/// The item is being auctioned.
+		ItemInAuction,
+		/// The item isn't being auctioned.
+		UnknownAuction,
+		/// The auction has ended.
+		AuctionEnded,
+		/// The auction hasn't ended yet.
+		AuctionNotEnded,
+		/// The auction can't be cancelled once it has bids.
+		AuctionHasBids,
+		/// The Dutch auction's end price is higher than its start price.
+		WrongAuctionPrices,
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason<I: 'static = ()> {
+		/// Funds are held for the highest bid on an item's auction.
+		#[codec(index = 0)]
+		AuctionBid,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<SystemBlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(_n: SystemBlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::settle_ended_auctions(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -1754,13 +1867,16 @@ pub mod pallet {
 		///
 		/// Origin must be Signed and must not be the owner of the `item`.
 		///
+		/// The collection's royalties are paid out of the price, the rest goes to the seller.
+		///
 		/// - `collection`: The collection of the item.
 		/// - `item`: The item the sender wants to buy.
 		/// - `bid_price`: The price the sender is willing to pay.
 		///
+		/// Emits `RoyaltyPaid` for every royalty paid.
 		/// Emits `ItemBought` on success.
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::buy_item())]
+		#[pallet::weight(T::WeightInfo::buy_item(T::MaxRoyaltyRecipients::get()))]
 		pub fn buy_item(
 			origin: OriginFor<T>,
 			collection: T::CollectionId,
@@ -1852,15 +1968,19 @@ pub mod pallet {
 		///
 		/// Origin must be Signed and must be an owner of the `item`.
 		///
+		/// If the swap has a price, the royalties of the collection of the item bought with it
+		/// are paid out of that price.
+		///
 		/// - `send_collection`: The collection of the item to be sent.
 		/// - `send_item`: The item to be sent.
 		/// - `receive_collection`: The collection of the item to be received.
 		/// - `receive_item`: The item to be received.
 		/// - `witness_price`: A price that was previously agreed on.
 		///
+		/// Emits `RoyaltyPaid` for every royalty paid.
 		/// Emits `SwapClaimed` on success.
 		#[pallet::call_index(36)]
-		#[pallet::weight(T::WeightInfo::claim_swap())]
+		#[pallet::weight(T::WeightInfo::claim_swap(T::MaxRoyaltyRecipients::get()))]
 		pub fn claim_swap(
 			origin: OriginFor<T>,
 			send_collection: T::CollectionId,
@@ -1931,6 +2051,138 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Set the royalties paid out of every sale of the items of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// The royalties are enforced on `buy_item` and on priced swaps. They could be locked with
+		/// the `UnlockedRoyalties` collection setting.
+		///
+		/// - `collection`: The identifier of the collection to change.
+		/// - `recipients`: The accounts receiving royalties and their shares of the sale price in
+		///   basis points. The shares must not add up to more than the whole price. An empty list
+		///   clears the royalties.
+		///
+		/// Emits `CollectionRoyaltySet` event when successful.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			recipients: RoyaltyRecipientsOf<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_collection_royalty(maybe_check_owner, collection, recipients)
+		}
+
+		/// Put an item up for auction.
+		///
+		/// Origin must be Signed and must be the owner of the `item`. The item must be
+		/// transferable and must not be locked.
+		///
+		/// The item can't be transferred, burned or locked while it's auctioned, and any price or
+		/// swap offer set for it is removed.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to be auctioned.
+		/// - `kind`: Whether bids ascend from a reserve price (English) or the price declines
+		///   linearly from a start to an end price (Dutch).
+		/// - `duration`: The number of blocks the auction accepts bids for.
+		///
+		/// Emits `AuctionCreated` on success.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			kind: AuctionKind<ItemPrice<T, I>>,
+			duration: BlockNumberFor<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_create_auction(origin, collection, item, kind, duration)
+		}
+
+		/// Bid on the auction of an item.
+		///
+		/// Origin must be Signed and must not be the seller.
+		///
+		/// On an English auction, the `amount` is held from the sender until the bid is outbid or
+		/// the auction is settled. It must meet the reserve price and exceed the highest bid. A
+		/// bid placed within `AuctionExtensionPeriod` of the end extends the auction.
+		///
+		/// On a Dutch auction, the first bid meeting the current price buys the item at that
+		/// price, paying the collection's royalties out of it.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		/// - `amount`: The bid, or the highest price the sender is willing to pay on a Dutch
+		///   auction.
+		///
+		/// Emits `AuctionBidPlaced` on a successful English auction bid.
+		/// Emits `RoyaltyPaid` for every royalty paid and `AuctionSettled` on a Dutch auction sale.
+		#[pallet::call_index(41)]
+		#[pallet::weight(
+			T::WeightInfo::place_bid_english()
+				.max(T::WeightInfo::place_bid_dutch(T::MaxRoyaltyRecipients::get()))
+		)]
+		pub fn place_bid(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			amount: ItemPrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_place_bid(origin, collection, item, amount)
+		}
+
+		/// Cancel the auction of an item.
+		///
+		/// Origin must be Signed and must be the seller. An auction can't be cancelled once it has
+		/// bids.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		///
+		/// Emits `AuctionClosed` on success.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_auction(origin, collection, item)
+		}
+
+		/// Settle an ended auction.
+		///
+		/// Origin must be Signed. Ended auctions are also settled in `on_idle`.
+		///
+		/// The item goes to the highest bidder, whose bid pays the collection's royalties and the
+		/// seller. An auction without bids, or whose item became locked, closes without a sale
+		/// and releases its bid.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The auctioned item.
+		///
+		/// Emits `RoyaltyPaid` for every royalty paid and `AuctionSettled` on a sale.
+		/// Emits `AuctionClosed` otherwise.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::settle_auction(T::MaxRoyaltyRecipients::get()))]
+		pub fn settle_auction(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_settle_auction(collection, item)
+		}
 	}
 }
 
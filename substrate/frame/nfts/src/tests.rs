@@ -22,9 +22,11 @@ use enumflags2::BitFlags;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{
-		tokens::nonfungibles_v2::{Create, Destroy, Inspect, Mutate},
-		Currency, Get,
+		fungible,
+		tokens::nonfungibles_v2::{self, Create, Destroy, Inspect, Mutate},
+		Currency, Get, Hooks,
 	},
+	weights::{RuntimeDbWeight, Weight},
 };
 use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
@@ -3039,6 +3041,818 @@ fn claim_swap_should_work() {
 	});
 }
 
+#[test]
+fn set_collection_royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+		let royalty = |recipients: Vec<(AccountIdOf<Test>, u16)>| -> RoyaltyRecipientsOf<Test> {
+			recipients
+				.into_iter()
+				.map(|(account, basis_points)| RoyaltyRecipient { account, basis_points })
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap()
+		};
+
+		Balances::make_free_balance_be(&user_1, 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				royalty(vec![(user_2.clone(), 500)]),
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				royalty(vec![(user_1.clone(), 500), (user_2.clone(), 0)]),
+			),
+			Error::<Test>::WrongRoyalty
+		);
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				royalty(vec![(user_1.clone(), 5_000), (user_2.clone(), 5_001)]),
+			),
+			Error::<Test>::WrongRoyalty
+		);
+		// the royalties can not add up to more than `MaxRoyalty`.
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				royalty(vec![(user_1.clone(), 4_000), (user_2.clone(), 1_001)]),
+			),
+			Error::<Test>::WrongRoyalty
+		);
+
+		let recipients = royalty(vec![(user_1.clone(), 500), (user_2.clone(), 250)]);
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			recipients.clone(),
+		));
+		assert!(events().contains(&Event::<Test>::CollectionRoyaltySet {
+			collection: collection_id,
+			recipients: recipients.clone(),
+		}));
+		assert_eq!(CollectionRoyaltyOf::<Test>::get(collection_id), Some(recipients));
+		assert_eq!(
+			Nfts::collection_royalty(&collection_id),
+			vec![(user_1.clone(), 500), (user_2.clone(), 250)]
+		);
+
+		// an empty list clears the royalties.
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			royalty(vec![]),
+		));
+		assert!(!CollectionRoyaltyOf::<Test>::contains_key(collection_id));
+
+		// the force origin could set the royalties too.
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::root(),
+			collection_id,
+			royalty(vec![(user_2.clone(), 5_000)]),
+		));
+		assert_eq!(Nfts::collection_royalty(&collection_id), vec![(user_2.clone(), 5_000)]);
+
+		// locked royalties can't be changed, not even by the force origin.
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::UnlockedRoyalties.into()),
+		));
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::root(),
+				collection_id,
+				royalty(vec![(user_2.clone(), 500)]),
+			),
+			Error::<Test>::LockedCollectionRoyalties
+		);
+
+		// destroying the collection removes its royalties.
+		let w = Nfts::get_destroy_witness(&collection_id).unwrap();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(user_1.clone()), collection_id, w));
+		assert!(!CollectionRoyaltyOf::<Test>::contains_key(collection_id));
+	});
+}
+
+#[test]
+fn royalties_should_be_paid_on_sales_and_swaps() {
+	new_test_ext().execute_with(|| {
+		let creator = account(1);
+		let seller = account(2);
+		let buyer = account(3);
+		let curator = account(4);
+		let collection_id = 0;
+		let other_collection_id = 1;
+		let item_1 = 1;
+		let item_2 = 2;
+		let price = 1_000;
+		let initial_balance = 10_000;
+
+		for who in [&creator, &seller, &buyer, &curator] {
+			Balances::make_free_balance_be(who, initial_balance);
+		}
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			creator.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			creator.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(creator.clone()),
+			collection_id,
+			item_1,
+			seller.clone(),
+			default_item_config(),
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(creator.clone()),
+			other_collection_id,
+			item_2,
+			seller.clone(),
+			default_item_config(),
+		));
+		// 5% to the creator and 2.5% to the curator of the first collection only.
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(creator.clone()),
+			collection_id,
+			vec![
+				RoyaltyRecipient { account: creator.clone(), basis_points: 500 },
+				RoyaltyRecipient { account: curator.clone(), basis_points: 250 },
+			]
+			.try_into()
+			.unwrap(),
+		));
+		let creator_balance = Balances::free_balance(&creator);
+
+		// buying an item pays the royalties out of its price.
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(seller.clone()),
+			collection_id,
+			item_1,
+			Some(price),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(buyer.clone()),
+			collection_id,
+			item_1,
+			price
+		));
+		assert_eq!(Balances::free_balance(&buyer), initial_balance - price);
+		assert_eq!(Balances::free_balance(&seller), initial_balance + price - 75);
+		assert_eq!(Balances::free_balance(&creator), creator_balance + 50);
+		assert_eq!(Balances::free_balance(&curator), initial_balance + 25);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_1,
+			recipient: curator.clone(),
+			amount: 25,
+		}));
+
+		// the swap price pays the royalties of the item bought with it, whichever side pays.
+		let buyer_balance = Balances::free_balance(&buyer);
+		let seller_balance = Balances::free_balance(&seller);
+		let creator_balance = Balances::free_balance(&creator);
+		let receive_price =
+			PriceWithDirection { amount: price, direction: PriceDirection::Receive };
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(buyer.clone()),
+			collection_id,
+			item_1,
+			other_collection_id,
+			Some(item_2),
+			Some(receive_price.clone()),
+			2,
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(seller.clone()),
+			other_collection_id,
+			item_2,
+			collection_id,
+			item_1,
+			Some(receive_price),
+		));
+		assert_eq!(Balances::free_balance(&seller), seller_balance - price);
+		assert_eq!(Balances::free_balance(&buyer), buyer_balance + price - 75);
+		assert_eq!(Balances::free_balance(&creator), creator_balance + 50);
+		assert_eq!(Balances::free_balance(&curator), initial_balance + 50);
+
+		let buyer_balance = Balances::free_balance(&buyer);
+		let seller_balance = Balances::free_balance(&seller);
+		let send_price = PriceWithDirection { amount: price, direction: PriceDirection::Send };
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(buyer.clone()),
+			other_collection_id,
+			item_2,
+			collection_id,
+			Some(item_1),
+			Some(send_price.clone()),
+			2,
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(seller.clone()),
+			collection_id,
+			item_1,
+			other_collection_id,
+			item_2,
+			Some(send_price),
+		));
+		assert_eq!(Balances::free_balance(&buyer), buyer_balance - price);
+		assert_eq!(Balances::free_balance(&seller), seller_balance + price - 75);
+		assert_eq!(Balances::free_balance(&curator), initial_balance + 75);
+
+		// royalties stop being due once the collection's royalties are cleared.
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(creator.clone()),
+			collection_id,
+			Default::default(),
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(buyer.clone()),
+			collection_id,
+			item_1,
+			Some(price),
+			None,
+		));
+		let buyer_balance = Balances::free_balance(&buyer);
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(seller.clone()),
+			collection_id,
+			item_1,
+			price
+		));
+		assert_eq!(Balances::free_balance(&buyer), buyer_balance + price);
+		assert_eq!(Balances::free_balance(&curator), initial_balance + 75);
+	});
+}
+
+#[test]
+fn royalties_below_existential_deposit_are_left_to_the_seller() {
+	new_test_ext().execute_with(|| {
+		let creator = account(1);
+		let seller = account(2);
+		let buyer = account(3);
+		let curator = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let price = 500;
+		let initial_balance = 10_000;
+
+		ExistentialDeposit::set(10);
+		for who in [&creator, &seller, &buyer] {
+			Balances::make_free_balance_be(who, initial_balance);
+		}
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			creator.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(creator.clone()),
+			collection_id,
+			item_id,
+			seller.clone(),
+			None,
+		));
+		// 1% to the creator and to the curator, who has no account yet.
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(creator.clone()),
+			collection_id,
+			vec![
+				RoyaltyRecipient { account: creator.clone(), basis_points: 100 },
+				RoyaltyRecipient { account: curator.clone(), basis_points: 100 },
+			]
+			.try_into()
+			.unwrap(),
+		));
+		let creator_balance = Balances::free_balance(&creator);
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(seller.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+
+		// the royalty of the curator is below the existential deposit and left to the seller.
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(buyer.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Balances::free_balance(&buyer), initial_balance - price);
+		assert_eq!(Balances::free_balance(&seller), initial_balance + price - 5);
+		assert_eq!(Balances::free_balance(&creator), creator_balance + 5);
+		assert_eq!(Balances::total_balance(&curator), 0);
+		assert!(!events().iter().any(|event| matches!(
+			event,
+			Event::<Test>::RoyaltyPaid { recipient, .. } if *recipient == curator
+		)));
+	});
+}
+
+#[test]
+fn english_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		let creator = account(1);
+		let seller = account(2);
+		let bidder_1 = account(3);
+		let bidder_2 = account(4);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 1_000;
+		let reason: RuntimeHoldReason = HoldReason::AuctionBid.into();
+		let on_hold = |who: &AccountIdOf<Test>| {
+			<Balances as fungible::InspectHold<_>>::balance_on_hold(&reason, who)
+		};
+
+		for who in [&creator, &seller, &bidder_1, &bidder_2] {
+			Balances::make_free_balance_be(who, initial_balance);
+		}
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			creator.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(creator.clone()),
+			collection_id,
+			item_id,
+			seller.clone(),
+			None,
+		));
+		// 10% royalties to the creator.
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(creator.clone()),
+			collection_id,
+			vec![RoyaltyRecipient { account: creator.clone(), basis_points: 1_000 }]
+				.try_into()
+				.unwrap(),
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(seller.clone()),
+			collection_id,
+			item_id,
+			Some(1),
+			None,
+		));
+
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(bidder_1.clone()),
+				collection_id,
+				item_id,
+				AuctionKind::English { reserve_price: 100 },
+				20,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(seller.clone()),
+				collection_id,
+				item_id,
+				AuctionKind::English { reserve_price: 100 },
+				0,
+			),
+			Error::<Test>::WrongDuration
+		);
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			collection_id,
+			item_id,
+			AuctionKind::English { reserve_price: 100 },
+			20,
+		));
+		assert!(events().contains(&Event::<Test>::AuctionCreated {
+			collection: collection_id,
+			item: item_id,
+			seller: seller.clone(),
+			kind: AuctionKind::English { reserve_price: 100 },
+			end: 21,
+		}));
+		// the item is no longer for sale at a fixed price.
+		assert!(!ItemPriceOf::<Test>::contains_key(collection_id, item_id));
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(seller.clone()),
+				collection_id,
+				item_id,
+				AuctionKind::English { reserve_price: 100 },
+				20,
+			),
+			Error::<Test>::ItemInAuction
+		);
+
+		// the item can't leave the seller while it's auctioned.
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed(seller.clone()),
+				collection_id,
+				item_id,
+				bidder_1.clone()
+			),
+			Error::<Test>::ItemInAuction
+		);
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(seller.clone()), collection_id, item_id),
+			Error::<Test>::ItemInAuction
+		);
+		assert_noop!(
+			Nfts::lock_item_transfer(
+				RuntimeOrigin::signed(creator.clone()),
+				collection_id,
+				item_id
+			),
+			Error::<Test>::ItemInAuction
+		);
+
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(seller.clone()), collection_id, item_id, 100),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(bidder_1.clone()), collection_id, item_id, 99),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Nfts::place_bid(
+			RuntimeOrigin::signed(bidder_1.clone()),
+			collection_id,
+			item_id,
+			100
+		));
+		assert_eq!(on_hold(&bidder_1), 100);
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(bidder_2.clone()), collection_id, item_id, 100),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			Nfts::cancel_auction(RuntimeOrigin::signed(seller.clone()), collection_id, item_id),
+			Error::<Test>::AuctionHasBids
+		);
+
+		// outbidding releases the previous bid.
+		assert_ok!(Nfts::place_bid(
+			RuntimeOrigin::signed(bidder_2.clone()),
+			collection_id,
+			item_id,
+			150
+		));
+		assert_eq!(on_hold(&bidder_1), 0);
+		assert_eq!(on_hold(&bidder_2), 150);
+		assert_eq!(Balances::free_balance(&bidder_1), initial_balance);
+
+		// a bid in the last blocks extends the auction.
+		System::set_block_number(18);
+		assert_ok!(Nfts::place_bid(
+			RuntimeOrigin::signed(bidder_1.clone()),
+			collection_id,
+			item_id,
+			200
+		));
+		assert!(events().contains(&Event::<Test>::AuctionBidPlaced {
+			collection: collection_id,
+			item: item_id,
+			bidder: bidder_1.clone(),
+			amount: 200,
+			end: 23,
+		}));
+		assert_eq!(on_hold(&bidder_2), 0);
+
+		System::set_block_number(23);
+		assert_noop!(
+			Nfts::settle_auction(RuntimeOrigin::signed(bidder_2.clone()), collection_id, item_id),
+			Error::<Test>::AuctionNotEnded
+		);
+
+		System::set_block_number(24);
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(bidder_2.clone()), collection_id, item_id, 300),
+			Error::<Test>::AuctionEnded
+		);
+		let creator_balance = Balances::free_balance(&creator);
+		assert_ok!(Nfts::settle_auction(
+			RuntimeOrigin::signed(bidder_2.clone()),
+			collection_id,
+			item_id
+		));
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: collection_id,
+			item: item_id,
+			seller: seller.clone(),
+			buyer: bidder_1.clone(),
+			price: 200,
+		}));
+		assert_eq!(Item::<Test>::get(collection_id, item_id).unwrap().owner, bidder_1);
+		assert!(!ItemAuctionOf::<Test>::contains_key(collection_id, item_id));
+		assert_eq!(on_hold(&bidder_1), 0);
+		assert_eq!(Balances::free_balance(&bidder_1), initial_balance - 200);
+		assert_eq!(Balances::free_balance(&seller), initial_balance + 180);
+		assert_eq!(Balances::free_balance(&creator), creator_balance + 20);
+		assert_noop!(
+			Nfts::settle_auction(RuntimeOrigin::signed(bidder_2.clone()), collection_id, item_id),
+			Error::<Test>::UnknownAuction
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_should_work() {
+	new_test_ext().execute_with(|| {
+		let creator = account(1);
+		let seller = account(2);
+		let buyer = account(3);
+		let collection_id = 0;
+		let item_id = 1;
+		let initial_balance = 1_000;
+
+		for who in [&creator, &seller, &buyer] {
+			Balances::make_free_balance_be(who, initial_balance);
+		}
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			creator.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(creator.clone()),
+			collection_id,
+			item_id,
+			seller.clone(),
+			None,
+		));
+
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(seller.clone()),
+				collection_id,
+				item_id,
+				AuctionKind::Dutch { start_price: 100, end_price: 200 },
+				10,
+			),
+			Error::<Test>::WrongAuctionPrices
+		);
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			collection_id,
+			item_id,
+			AuctionKind::Dutch { start_price: 500, end_price: 100 },
+			10,
+		));
+
+		// the price declines by 40 every block.
+		System::set_block_number(6);
+		assert_eq!(Nfts::dutch_auction_price(1, 11, 500, 100, 6), 300);
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(buyer.clone()), collection_id, item_id, 299),
+			Error::<Test>::BidTooLow
+		);
+		// the current price is paid, whatever the bid.
+		assert_ok!(Nfts::place_bid(
+			RuntimeOrigin::signed(buyer.clone()),
+			collection_id,
+			item_id,
+			400
+		));
+		assert!(events().contains(&Event::<Test>::AuctionSettled {
+			collection: collection_id,
+			item: item_id,
+			seller: seller.clone(),
+			buyer: buyer.clone(),
+			price: 300,
+		}));
+		assert_eq!(Item::<Test>::get(collection_id, item_id).unwrap().owner, buyer);
+		assert!(!ItemAuctionOf::<Test>::contains_key(collection_id, item_id));
+		assert_eq!(Balances::free_balance(&buyer), initial_balance - 300);
+		assert_eq!(Balances::free_balance(&seller), initial_balance + 300);
+
+		// an unsold auction expires.
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(buyer.clone()),
+			collection_id,
+			item_id,
+			AuctionKind::Dutch { start_price: 500, end_price: 100 },
+			10,
+		));
+		System::set_block_number(17);
+		assert_noop!(
+			Nfts::place_bid(RuntimeOrigin::signed(seller.clone()), collection_id, item_id, 500),
+			Error::<Test>::AuctionEnded
+		);
+		assert_ok!(Nfts::settle_auction(
+			RuntimeOrigin::signed(seller.clone()),
+			collection_id,
+			item_id
+		));
+		assert!(events()
+			.contains(&Event::<Test>::AuctionClosed { collection: collection_id, item: item_id }));
+		assert_eq!(Item::<Test>::get(collection_id, item_id).unwrap().owner, buyer);
+	});
+}
+
+#[test]
+fn auctions_respect_item_locks() {
+	new_test_ext().execute_with(|| {
+		let owner = account(1);
+		let bidder = account(2);
+		let collection_id = 0;
+		let reason: RuntimeHoldReason = HoldReason::AuctionBid.into();
+
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			owner.clone(),
+			default_collection_config()
+		));
+		for item_id in 1..=3 {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(owner.clone()),
+				collection_id,
+				item_id,
+				owner.clone(),
+				None,
+			));
+		}
+
+		// locked items can't be auctioned.
+		assert_ok!(Nfts::lock_item_transfer(
+			RuntimeOrigin::signed(owner.clone()),
+			collection_id,
+			1
+		));
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(owner.clone()),
+				collection_id,
+				1,
+				AuctionKind::English { reserve_price: 10 },
+				10,
+			),
+			Error::<Test>::ItemLocked
+		);
+		assert_ok!(<Nfts as nonfungibles_v2::Transfer<_>>::disable_transfer(&collection_id, &2));
+		assert_noop!(
+			Nfts::create_auction(
+				RuntimeOrigin::signed(owner.clone()),
+				collection_id,
+				2,
+				AuctionKind::English { reserve_price: 10 },
+				10,
+			),
+			Error::<Test>::ItemLocked
+		);
+
+		// nor can auctioned items be locked by other pallets.
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(owner.clone()),
+			collection_id,
+			3,
+			AuctionKind::English { reserve_price: 10 },
+			10,
+		));
+		assert_noop!(
+			<Nfts as nonfungibles_v2::Transfer<_>>::disable_transfer(&collection_id, &3),
+			Error::<Test>::ItemInAuction
+		);
+
+		// an auction without bids could be cancelled by its seller.
+		assert_noop!(
+			Nfts::cancel_auction(RuntimeOrigin::signed(bidder.clone()), collection_id, 3),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::cancel_auction(RuntimeOrigin::signed(owner.clone()), collection_id, 3));
+		assert!(
+			events().contains(&Event::<Test>::AuctionClosed { collection: collection_id, item: 3 })
+		);
+		assert!(!ItemAuctionOf::<Test>::contains_key(collection_id, 3));
+
+		// the item stays with the seller if the collection got locked meanwhile.
+		assert_ok!(Nfts::create_auction(
+			RuntimeOrigin::signed(owner.clone()),
+			collection_id,
+			3,
+			AuctionKind::English { reserve_price: 10 },
+			10,
+		));
+		assert_ok!(Nfts::place_bid(RuntimeOrigin::signed(bidder.clone()), collection_id, 3, 50));
+		assert_ok!(Nfts::lock_collection(
+			RuntimeOrigin::signed(owner.clone()),
+			collection_id,
+			CollectionSettings::from_disabled(CollectionSetting::TransferableItems.into()),
+		));
+		System::set_block_number(12);
+		assert_ok!(Nfts::settle_auction(RuntimeOrigin::signed(bidder.clone()), collection_id, 3));
+		assert!(
+			events().contains(&Event::<Test>::AuctionClosed { collection: collection_id, item: 3 })
+		);
+		assert_eq!(Item::<Test>::get(collection_id, 3).unwrap().owner, owner);
+		assert_eq!(<Balances as fungible::InspectHold<_>>::balance_on_hold(&reason, &bidder), 0);
+		assert_eq!(Balances::free_balance(&bidder), 100);
+	});
+}
+
+#[test]
+fn ended_auctions_are_settled_on_idle() {
+	new_test_ext().execute_with(|| {
+		let owner = account(1);
+		let bidder = account(2);
+		let collection_id = 0;
+
+		Balances::make_free_balance_be(&owner, 100);
+		Balances::make_free_balance_be(&bidder, 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			owner.clone(),
+			default_collection_config()
+		));
+		for item_id in 1..=3 {
+			assert_ok!(Nfts::mint(
+				RuntimeOrigin::signed(owner.clone()),
+				collection_id,
+				item_id,
+				owner.clone(),
+				None,
+			));
+			assert_ok!(Nfts::create_auction(
+				RuntimeOrigin::signed(owner.clone()),
+				collection_id,
+				item_id,
+				AuctionKind::English { reserve_price: 10 },
+				// the third auction runs longer.
+				if item_id == 3 { 20 } else { 10 },
+			));
+			assert_ok!(Nfts::place_bid(
+				RuntimeOrigin::signed(bidder.clone()),
+				collection_id,
+				item_id,
+				10
+			));
+		}
+
+		let owner_balance = Balances::free_balance(&owner);
+
+		// nothing ended yet.
+		System::set_block_number(11);
+		Nfts::on_idle(11, Weight::MAX);
+		assert_eq!(ItemAuctionOf::<Test>::iter().count(), 3);
+
+		// the weight for a single settlement settles one of the ended auctions at a time.
+		System::set_block_number(12);
+		let db_weight: RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+		let settle_one =
+			db_weight.reads_writes(1, 1).saturating_add(db_weight.reads(1)).saturating_add(
+				<() as WeightInfo>::settle_auction(<Test as Config>::MaxRoyaltyRecipients::get()),
+			);
+		Nfts::on_idle(12, settle_one);
+		let cursor = AuctionSettlementCursor::<Test>::get();
+		assert!(cursor.is_some());
+
+		// too little weight to check any auction keeps the cursor.
+		Nfts::on_idle(12, db_weight.reads_writes(1, 1));
+		assert_eq!(AuctionSettlementCursor::<Test>::get(), cursor);
+
+		let mut settled = 0;
+		for _ in 0..3 {
+			Nfts::on_idle(12, settle_one);
+			settled = 3 - ItemAuctionOf::<Test>::iter().count();
+			assert!(settled <= 2);
+		}
+		assert_eq!(settled, 2);
+		assert_eq!(Item::<Test>::get(collection_id, 1).unwrap().owner, bidder);
+		assert_eq!(Item::<Test>::get(collection_id, 2).unwrap().owner, bidder);
+		assert!(ItemAuctionOf::<Test>::contains_key(collection_id, 3));
+
+		System::set_block_number(22);
+		Nfts::on_idle(22, Weight::MAX);
+		assert_eq!(ItemAuctionOf::<Test>::iter().count(), 0);
+		assert_eq!(Item::<Test>::get(collection_id, 3).unwrap().owner, bidder);
+		assert_eq!(Balances::free_balance(&owner), owner_balance + 30);
+	});
+}
+
 #[test]
 fn various_collection_settings() {
 	new_test_ext().execute_with(|| {
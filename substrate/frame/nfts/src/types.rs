@@ -73,6 +73,14 @@ pub type ItemTipOf<T, I = ()> = ItemTip<
 /// A type alias for the settings configuration of a collection.
 pub type CollectionConfigFor<T, I = ()> =
 	CollectionConfig<BalanceOf<T, I>, BlockNumberFor<T, I>, <T as Config<I>>::CollectionId>;
+/// A type alias for the royalty recipients of a collection.
+pub type RoyaltyRecipientsOf<T, I = ()> = BoundedVec<
+	RoyaltyRecipient<<T as SystemConfig>::AccountId>,
+	<T as Config<I>>::MaxRoyaltyRecipients,
+>;
+/// A type alias for the details of an item's auction.
+pub type AuctionDetailsFor<T, I = ()> =
+	AuctionDetails<<T as SystemConfig>::AccountId, ItemPrice<T, I>, BlockNumberFor<T, I>>;
 /// A type alias for the pre-signed minting configuration for a specified collection.
 pub type PreSignedMintOf<T, I = ()> = PreSignedMint<
 	<T as Config<I>>::CollectionId,
@@ -295,6 +303,73 @@ pub struct PriceWithDirection<Amount> {
 	pub direction: PriceDirection,
 }
 
+/// The share of a sale price paid to a collection's royalty recipient, in basis points.
+pub type RoyaltyBasisPoints = u16;
+
+/// The number of basis points making up the whole sale price.
+pub const MAX_ROYALTY_BASIS_POINTS: RoyaltyBasisPoints = 10_000;
+
+/// An account receiving creator royalties on the sales of a collection's items.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct RoyaltyRecipient<AccountId> {
+	/// The account receiving the royalty.
+	pub account: AccountId,
+	/// The recipient's share of the sale price, in basis points.
+	pub basis_points: RoyaltyBasisPoints,
+}
+
+/// The kind of an item's auction and its pricing.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum AuctionKind<Amount> {
+	/// Ascending bids, the highest bid at the end of the auction wins.
+	English {
+		/// The lowest acceptable bid.
+		reserve_price: Amount,
+	},
+	/// A price declining linearly over the auction's duration, the first bid meeting it wins.
+	Dutch {
+		/// The price at the start of the auction.
+		start_price: Amount,
+		/// The price at the end of the auction.
+		end_price: Amount,
+	},
+}
+
+/// Information about an item's auction.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AuctionDetails<AccountId, Amount, BlockNumber> {
+	/// The owner of the item at the auction's creation, receiving its proceeds.
+	pub seller: AccountId,
+	/// The kind of the auction.
+	pub kind: AuctionKind<Amount>,
+	/// The block the auction started at.
+	pub start: BlockNumber,
+	/// The last block bids are accepted at. Late bids on an English auction extend it.
+	pub end: BlockNumber,
+	/// The highest bid and its bidder. Only English auctions hold bids.
+	pub highest_bid: Option<(AccountId, Amount)>,
+}
+
 /// Support for up to 64 user-enabled features on a collection.
 #[bitflags]
 #[repr(u64)]
@@ -310,6 +385,8 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// The royalties of this collection can be modified.
+	UnlockedRoyalties,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
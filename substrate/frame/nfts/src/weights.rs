@@ -35,21 +35,21 @@
 //! Autogenerated weights for `pallet_nfts`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `4563561839a5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
-// frame-omni-bencher
+// ./target/release/frame-omni-bencher
 // v1
 // benchmark
 // pallet
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_nfts
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/nfts/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/nfts/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -59,7 +59,6 @@
 // --no-min-squares
 // --no-median-slopes
 // --genesis-builder-policy=none
-// --exclude-pallets=pallet_xcm,pallet_xcm_benchmarks::fungible,pallet_xcm_benchmarks::generic,pallet_nomination_pools,pallet_remark,pallet_transaction_storage,pallet_election_provider_multi_block,pallet_election_provider_multi_block::signed,pallet_election_provider_multi_block::unsigned,pallet_election_provider_multi_block::verifier
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -104,13 +103,19 @@ pub trait WeightInfo {
 	fn set_collection_max_supply() -> Weight;
 	fn update_mint_settings() -> Weight;
 	fn set_price() -> Weight;
-	fn buy_item() -> Weight;
+	fn buy_item(r: u32, ) -> Weight;
 	fn pay_tips(n: u32, ) -> Weight;
 	fn create_swap() -> Weight;
 	fn cancel_swap() -> Weight;
-	fn claim_swap() -> Weight;
+	fn set_collection_royalty() -> Weight;
+	fn claim_swap(r: u32, ) -> Weight;
 	fn mint_pre_signed(n: u32, ) -> Weight;
 	fn set_attributes_pre_signed(n: u32, ) -> Weight;
+	fn create_auction() -> Weight;
+	fn place_bid_english() -> Weight;
+	fn place_bid_dutch(r: u32, ) -> Weight;
+	fn cancel_auction() -> Weight;
+	fn settle_auction(r: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_nfts` using the Substrate node and recommended hardware.
@@ -130,8 +135,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `52`
 		//  Estimated: `3549`
-		// Minimum execution time: 29_866_000 picoseconds.
-		Weight::from_parts(30_591_000, 3549)
+		// Minimum execution time: 38_582_000 picoseconds.
+		Weight::from_parts(39_571_000, 3549)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
@@ -149,8 +154,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `3549`
-		// Minimum execution time: 15_636_000 picoseconds.
-		Weight::from_parts(16_162_000, 3549)
+		// Minimum execution time: 20_428_000 picoseconds.
+		Weight::from_parts(21_178_000, 3549)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
@@ -164,6 +169,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1000 w:1000)
 	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:0 w:1)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionMetadataOf` (r:0 w:1)
 	/// Proof: `Nfts::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:0 w:1)
@@ -173,21 +180,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `m` is `[0, 1000]`.
 	/// The range of component `c` is `[0, 1000]`.
 	/// The range of component `a` is `[0, 1000]`.
-	fn destroy(m: u32, c: u32, a: u32, ) -> Weight {
+	fn destroy(_m: u32, _c: u32, a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `32112 + a * (366 ±0)`
 		//  Estimated: `2523990 + a * (2954 ±0)`
-		// Minimum execution time: 1_304_899_000 picoseconds.
-		Weight::from_parts(1_206_247_822, 2523990)
-			// Standard Error: 4_591
-			.saturating_add(Weight::from_parts(49_431, 0).saturating_mul(m.into()))
-			// Standard Error: 4_591
-			.saturating_add(Weight::from_parts(24_260, 0).saturating_mul(c.into()))
-			// Standard Error: 4_591
-			.saturating_add(Weight::from_parts(7_104_726, 0).saturating_mul(a.into()))
+		// Minimum execution time: 1_452_185_000 picoseconds.
+		Weight::from_parts(4_913_205_068, 2523990)
+			// Standard Error: 171_670
+			.saturating_add(Weight::from_parts(9_277_394, 0).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(1004_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
-			.saturating_add(T::DbWeight::get().writes(1005_u64))
+			.saturating_add(T::DbWeight::get().writes(1006_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_parts(0, 2954).saturating_mul(a.into()))
 	}
@@ -207,8 +210,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `362`
 		//  Estimated: `4326`
-		// Minimum execution time: 48_441_000 picoseconds.
-		Weight::from_parts(49_363_000, 4326)
+		// Minimum execution time: 99_487_000 picoseconds.
+		Weight::from_parts(107_384_000, 4326)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
@@ -228,13 +231,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `362`
 		//  Estimated: `4326`
-		// Minimum execution time: 46_608_000 picoseconds.
-		Weight::from_parts(47_860_000, 4326)
+		// Minimum execution time: 91_710_000 picoseconds.
+		Weight::from_parts(101_424_000, 4326)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
 	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:1)
@@ -255,13 +260,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `472`
 		//  Estimated: `4326`
-		// Minimum execution time: 51_492_000 picoseconds.
-		Weight::from_parts(52_170_000, 4326)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 108_340_000 picoseconds.
+		Weight::from_parts(118_642_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
@@ -280,9 +287,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `501`
 		//  Estimated: `4326`
-		// Minimum execution time: 40_207_000 picoseconds.
-		Weight::from_parts(41_360_000, 4326)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 89_173_000 picoseconds.
+		Weight::from_parts(92_923_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Nfts::Collection` (r:1 w:0)
@@ -296,10 +303,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `671 + i * (108 ±0)`
 		//  Estimated: `3549 + i * (3336 ±0)`
-		// Minimum execution time: 14_100_000 picoseconds.
-		Weight::from_parts(14_412_000, 3549)
-			// Standard Error: 20_746
-			.saturating_add(Weight::from_parts(17_706_123, 0).saturating_mul(i.into()))
+		// Minimum execution time: 26_483_000 picoseconds.
+		Weight::from_parts(27_655_000, 3549)
+			// Standard Error: 185_740
+			.saturating_add(Weight::from_parts(26_002_079, 0).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
@@ -307,15 +314,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
 	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
 	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn lock_item_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `343`
-		//  Estimated: `3534`
-		// Minimum execution time: 18_777_000 picoseconds.
-		Weight::from_parts(19_084_000, 3534)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		//  Estimated: `3627`
+		// Minimum execution time: 25_362_000 picoseconds.
+		Weight::from_parts(26_615_000, 3627)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
@@ -326,8 +335,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `343`
 		//  Estimated: `3534`
-		// Minimum execution time: 18_460_000 picoseconds.
-		Weight::from_parts(18_907_000, 3534)
+		// Minimum execution time: 22_408_000 picoseconds.
+		Weight::from_parts(23_296_000, 3534)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -339,8 +348,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `248`
 		//  Estimated: `3549`
-		// Minimum execution time: 15_010_000 picoseconds.
-		Weight::from_parts(15_523_000, 3549)
+		// Minimum execution time: 18_202_000 picoseconds.
+		Weight::from_parts(18_841_000, 3549)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -356,8 +365,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `399`
 		//  Estimated: `3593`
-		// Minimum execution time: 24_987_000 picoseconds.
-		Weight::from_parts(25_668_000, 3593)
+		// Minimum execution time: 31_214_000 picoseconds.
+		Weight::from_parts(33_207_000, 3593)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
@@ -369,8 +378,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `277`
 		//  Estimated: `6078`
-		// Minimum execution time: 36_287_000 picoseconds.
-		Weight::from_parts(36_653_000, 6078)
+		// Minimum execution time: 42_318_000 picoseconds.
+		Weight::from_parts(57_933_000, 6078)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
@@ -382,8 +391,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `219`
 		//  Estimated: `3549`
-		// Minimum execution time: 15_143_000 picoseconds.
-		Weight::from_parts(15_551_000, 3549)
+		// Minimum execution time: 19_303_000 picoseconds.
+		Weight::from_parts(32_443_000, 3549)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
@@ -395,8 +404,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `184`
 		//  Estimated: `3549`
-		// Minimum execution time: 11_145_000 picoseconds.
-		Weight::from_parts(11_395_000, 3549)
+		// Minimum execution time: 13_463_000 picoseconds.
+		Weight::from_parts(14_296_000, 3549)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -408,8 +417,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `343`
 		//  Estimated: `3534`
-		// Minimum execution time: 17_174_000 picoseconds.
-		Weight::from_parts(17_738_000, 3534)
+		// Minimum execution time: 20_738_000 picoseconds.
+		Weight::from_parts(21_745_000, 3534)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -427,8 +436,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `447`
 		//  Estimated: `3944`
-		// Minimum execution time: 49_013_000 picoseconds.
-		Weight::from_parts(50_124_000, 3944)
+		// Minimum execution time: 106_134_000 picoseconds.
+		Weight::from_parts(113_374_000, 3944)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -440,8 +449,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `252`
 		//  Estimated: `3944`
-		// Minimum execution time: 23_738_000 picoseconds.
-		Weight::from_parts(24_558_000, 3944)
+		// Minimum execution time: 46_337_000 picoseconds.
+		Weight::from_parts(53_213_000, 3944)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -457,8 +466,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `891`
 		//  Estimated: `3944`
-		// Minimum execution time: 45_483_000 picoseconds.
-		Weight::from_parts(46_308_000, 3944)
+		// Minimum execution time: 89_585_000 picoseconds.
+		Weight::from_parts(99_134_000, 3944)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -470,8 +479,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `289`
 		//  Estimated: `4326`
-		// Minimum execution time: 15_153_000 picoseconds.
-		Weight::from_parts(15_686_000, 4326)
+		// Minimum execution time: 30_960_000 picoseconds.
+		Weight::from_parts(34_015_000, 4326)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -488,10 +497,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `668 + n * (398 ±0)`
 		//  Estimated: `4326 + n * (2954 ±0)`
-		// Minimum execution time: 23_720_000 picoseconds.
-		Weight::from_parts(24_121_000, 4326)
-			// Standard Error: 4_581
-			.saturating_add(Weight::from_parts(7_030_724, 0).saturating_mul(n.into()))
+		// Minimum execution time: 46_484_000 picoseconds.
+		Weight::from_parts(48_546_000, 4326)
+			// Standard Error: 59_626
+			.saturating_add(Weight::from_parts(8_860_394, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
@@ -512,8 +521,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `447`
 		//  Estimated: `3812`
-		// Minimum execution time: 40_386_000 picoseconds.
-		Weight::from_parts(40_903_000, 3812)
+		// Minimum execution time: 49_913_000 picoseconds.
+		Weight::from_parts(51_528_000, 3812)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -529,8 +538,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `757`
 		//  Estimated: `3812`
-		// Minimum execution time: 38_438_000 picoseconds.
-		Weight::from_parts(39_323_000, 3812)
+		// Minimum execution time: 46_785_000 picoseconds.
+		Weight::from_parts(49_243_000, 3812)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -546,8 +555,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `306`
 		//  Estimated: `3759`
-		// Minimum execution time: 36_043_000 picoseconds.
-		Weight::from_parts(36_498_000, 3759)
+		// Minimum execution time: 43_940_000 picoseconds.
+		Weight::from_parts(45_408_000, 3759)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -563,8 +572,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `624`
 		//  Estimated: `3759`
-		// Minimum execution time: 35_275_000 picoseconds.
-		Weight::from_parts(35_879_000, 3759)
+		// Minimum execution time: 42_628_000 picoseconds.
+		Weight::from_parts(44_945_000, 3759)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -576,8 +585,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `318`
 		//  Estimated: `4326`
-		// Minimum execution time: 18_190_000 picoseconds.
-		Weight::from_parts(18_717_000, 4326)
+		// Minimum execution time: 22_032_000 picoseconds.
+		Weight::from_parts(22_889_000, 4326)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -587,8 +596,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `326`
 		//  Estimated: `4326`
-		// Minimum execution time: 15_088_000 picoseconds.
-		Weight::from_parts(15_564_000, 4326)
+		// Minimum execution time: 18_523_000 picoseconds.
+		Weight::from_parts(19_478_000, 4326)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -598,8 +607,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `326`
 		//  Estimated: `4326`
-		// Minimum execution time: 14_365_000 picoseconds.
-		Weight::from_parts(14_920_000, 4326)
+		// Minimum execution time: 17_661_000 picoseconds.
+		Weight::from_parts(18_345_000, 4326)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -609,8 +618,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `3517`
-		// Minimum execution time: 9_845_000 picoseconds.
-		Weight::from_parts(10_228_000, 3517)
+		// Minimum execution time: 13_076_000 picoseconds.
+		Weight::from_parts(13_450_000, 3517)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -622,8 +631,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `248`
 		//  Estimated: `3549`
-		// Minimum execution time: 16_666_000 picoseconds.
-		Weight::from_parts(17_328_000, 3549)
+		// Minimum execution time: 20_023_000 picoseconds.
+		Weight::from_parts(21_056_000, 3549)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -635,8 +644,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `231`
 		//  Estimated: `3538`
-		// Minimum execution time: 15_969_000 picoseconds.
-		Weight::from_parts(16_717_000, 3538)
+		// Minimum execution time: 19_394_000 picoseconds.
+		Weight::from_parts(20_192_000, 3538)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -652,8 +661,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `426`
 		//  Estimated: `4326`
-		// Minimum execution time: 21_833_000 picoseconds.
-		Weight::from_parts(22_580_000, 4326)
+		// Minimum execution time: 26_877_000 picoseconds.
+		Weight::from_parts(27_739_000, 4326)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -661,8 +670,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:1 w:1)
 	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
@@ -673,24 +688,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
 	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
-	fn buy_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `613`
-		//  Estimated: `4326`
-		// Minimum execution time: 50_016_000 picoseconds.
-		Weight::from_parts(51_155_000, 4326)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+	/// The range of component `r` is `[0, 5]`.
+	fn buy_item(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `762 + r * (34 ±0)`
+		//  Estimated: `4326 + r * (2603 ±0)`
+		// Minimum execution time: 112_572_000 picoseconds.
+		Weight::from_parts(115_750_633, 4326)
+			// Standard Error: 183_184
+			.saturating_add(Weight::from_parts(45_939_642, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
 	}
 	/// The range of component `n` is `[0, 10]`.
 	fn pay_tips(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 1_695_000 picoseconds.
-		Weight::from_parts(2_625_514, 0)
-			// Standard Error: 5_213
-			.saturating_add(Weight::from_parts(1_843_482, 0).saturating_mul(n.into()))
+		// Minimum execution time: 2_483_000 picoseconds.
+		Weight::from_parts(4_244_005, 0)
+			// Standard Error: 39_430
+			.saturating_add(Weight::from_parts(2_558_262, 0).saturating_mul(n.into()))
 	}
 	/// Storage: `Nfts::Item` (r:2 w:0)
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
@@ -700,8 +721,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `402`
 		//  Estimated: `7662`
-		// Minimum execution time: 18_600_000 picoseconds.
-		Weight::from_parts(19_453_000, 7662)
+		// Minimum execution time: 23_443_000 picoseconds.
+		Weight::from_parts(24_866_000, 7662)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -713,8 +734,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `421`
 		//  Estimated: `4326`
-		// Minimum execution time: 18_476_000 picoseconds.
-		Weight::from_parts(19_091_000, 4326)
+		// Minimum execution time: 23_710_000 picoseconds.
+		Weight::from_parts(25_468_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:0 w:1)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	fn set_collection_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `248`
+		//  Estimated: `3549`
+		// Minimum execution time: 19_626_000 picoseconds.
+		Weight::from_parts(23_039_000, 3549)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -722,8 +758,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:1 w:2)
 	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:2 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:2 w:0)
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
@@ -734,14 +776,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:0 w:2)
 	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
-	fn claim_swap() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `742`
-		//  Estimated: `7662`
-		// Minimum execution time: 81_691_000 picoseconds.
-		Weight::from_parts(84_109_000, 7662)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+	/// The range of component `r` is `[0, 5]`.
+	fn claim_swap(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `891 + r * (34 ±0)`
+		//  Estimated: `7662 + r * (2603 ±0)`
+		// Minimum execution time: 161_342_000 picoseconds.
+		Weight::from_parts(177_698_614, 7662)
+			// Standard Error: 1_221_715
+			.saturating_add(Weight::from_parts(50_412_648, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
 	}
 	/// Storage: `Nfts::CollectionRoleOf` (r:2 w:0)
 	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
@@ -766,10 +814,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `464`
 		//  Estimated: `6078 + n * (2954 ±0)`
-		// Minimum execution time: 125_533_000 picoseconds.
-		Weight::from_parts(130_187_155, 6078)
-			// Standard Error: 46_370
-			.saturating_add(Weight::from_parts(33_019_771, 0).saturating_mul(n.into()))
+		// Minimum execution time: 163_287_000 picoseconds.
+		Weight::from_parts(177_391_545, 6078)
+			// Standard Error: 1_328_112
+			.saturating_add(Weight::from_parts(65_078_351, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
@@ -793,16 +841,141 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `496`
 		//  Estimated: `4326 + n * (2954 ±0)`
-		// Minimum execution time: 65_098_000 picoseconds.
-		Weight::from_parts(74_929_657, 4326)
-			// Standard Error: 61_212
-			.saturating_add(Weight::from_parts(31_387_885, 0).saturating_mul(n.into()))
+		// Minimum execution time: 82_437_000 picoseconds.
+		Weight::from_parts(49_466_963, 4326)
+			// Standard Error: 994_950
+			.saturating_add(Weight::from_parts(73_668_088, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2954).saturating_mul(n.into()))
 	}
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `426`
+		//  Estimated: `4326`
+		// Minimum execution time: 74_051_000 picoseconds.
+		Weight::from_parts(77_549_000, 4326)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(463), added: 2938, mode: `MaxEncodedLen`)
+	fn place_bid_english() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `608`
+		//  Estimated: `6866`
+		// Minimum execution time: 108_483_000 picoseconds.
+		Weight::from_parts(175_389_000, 6866)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 5]`.
+	fn place_bid_dutch(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `788 + r * (34 ±0)`
+		//  Estimated: `4326 + r * (2603 ±0)`
+		// Minimum execution time: 113_171_000 picoseconds.
+		Weight::from_parts(142_794_332, 4326)
+			// Standard Error: 1_309_461
+			.saturating_add(Weight::from_parts(43_490_829, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298`
+		//  Estimated: `3627`
+		// Minimum execution time: 16_927_000 picoseconds.
+		Weight::from_parts(18_005_000, 3627)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(463), added: 2938, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 5]`.
+	fn settle_auction(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `876 + r * (34 ±0)`
+		//  Estimated: `4326 + r * (2603 ±0)`
+		// Minimum execution time: 147_772_000 picoseconds.
+		Weight::from_parts(312_223_932, 4326)
+			// Standard Error: 2_039_794
+			.saturating_add(Weight::from_parts(28_746_098, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -821,8 +994,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `52`
 		//  Estimated: `3549`
-		// Minimum execution time: 29_866_000 picoseconds.
-		Weight::from_parts(30_591_000, 3549)
+		// Minimum execution time: 38_582_000 picoseconds.
+		Weight::from_parts(39_571_000, 3549)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
@@ -840,8 +1013,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `3549`
-		// Minimum execution time: 15_636_000 picoseconds.
-		Weight::from_parts(16_162_000, 3549)
+		// Minimum execution time: 20_428_000 picoseconds.
+		Weight::from_parts(21_178_000, 3549)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
@@ -855,6 +1028,8 @@ impl WeightInfo for () {
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1000 w:1000)
 	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:0 w:1)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionMetadataOf` (r:0 w:1)
 	/// Proof: `Nfts::CollectionMetadataOf` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:0 w:1)
@@ -864,21 +1039,17 @@ impl WeightInfo for () {
 	/// The range of component `m` is `[0, 1000]`.
 	/// The range of component `c` is `[0, 1000]`.
 	/// The range of component `a` is `[0, 1000]`.
-	fn destroy(m: u32, c: u32, a: u32, ) -> Weight {
+	fn destroy(_m: u32, _c: u32, a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `32112 + a * (366 ±0)`
 		//  Estimated: `2523990 + a * (2954 ±0)`
-		// Minimum execution time: 1_304_899_000 picoseconds.
-		Weight::from_parts(1_206_247_822, 2523990)
-			// Standard Error: 4_591
-			.saturating_add(Weight::from_parts(49_431, 0).saturating_mul(m.into()))
-			// Standard Error: 4_591
-			.saturating_add(Weight::from_parts(24_260, 0).saturating_mul(c.into()))
-			// Standard Error: 4_591
-			.saturating_add(Weight::from_parts(7_104_726, 0).saturating_mul(a.into()))
+		// Minimum execution time: 1_452_185_000 picoseconds.
+		Weight::from_parts(4_913_205_068, 2523990)
+			// Standard Error: 171_670
+			.saturating_add(Weight::from_parts(9_277_394, 0).saturating_mul(a.into()))
 			.saturating_add(RocksDbWeight::get().reads(1004_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
-			.saturating_add(RocksDbWeight::get().writes(1005_u64))
+			.saturating_add(RocksDbWeight::get().writes(1006_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
 			.saturating_add(Weight::from_parts(0, 2954).saturating_mul(a.into()))
 	}
@@ -898,8 +1069,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `362`
 		//  Estimated: `4326`
-		// Minimum execution time: 48_441_000 picoseconds.
-		Weight::from_parts(49_363_000, 4326)
+		// Minimum execution time: 99_487_000 picoseconds.
+		Weight::from_parts(107_384_000, 4326)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
@@ -919,13 +1090,15 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `362`
 		//  Estimated: `4326`
-		// Minimum execution time: 46_608_000 picoseconds.
-		Weight::from_parts(47_860_000, 4326)
+		// Minimum execution time: 91_710_000 picoseconds.
+		Weight::from_parts(101_424_000, 4326)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
 	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:1)
@@ -946,13 +1119,15 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `472`
 		//  Estimated: `4326`
-		// Minimum execution time: 51_492_000 picoseconds.
-		Weight::from_parts(52_170_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 108_340_000 picoseconds.
+		Weight::from_parts(118_642_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
@@ -971,9 +1146,9 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `501`
 		//  Estimated: `4326`
-		// Minimum execution time: 40_207_000 picoseconds.
-		Weight::from_parts(41_360_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 89_173_000 picoseconds.
+		Weight::from_parts(92_923_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Nfts::Collection` (r:1 w:0)
@@ -987,10 +1162,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `671 + i * (108 ±0)`
 		//  Estimated: `3549 + i * (3336 ±0)`
-		// Minimum execution time: 14_100_000 picoseconds.
-		Weight::from_parts(14_412_000, 3549)
-			// Standard Error: 20_746
-			.saturating_add(Weight::from_parts(17_706_123, 0).saturating_mul(i.into()))
+		// Minimum execution time: 26_483_000 picoseconds.
+		Weight::from_parts(27_655_000, 3549)
+			// Standard Error: 185_740
+			.saturating_add(Weight::from_parts(26_002_079, 0).saturating_mul(i.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(i.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(i.into())))
@@ -998,15 +1173,17 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
 	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
 	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	fn lock_item_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `343`
-		//  Estimated: `3534`
-		// Minimum execution time: 18_777_000 picoseconds.
-		Weight::from_parts(19_084_000, 3534)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Estimated: `3627`
+		// Minimum execution time: 25_362_000 picoseconds.
+		Weight::from_parts(26_615_000, 3627)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
@@ -1017,8 +1194,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `343`
 		//  Estimated: `3534`
-		// Minimum execution time: 18_460_000 picoseconds.
-		Weight::from_parts(18_907_000, 3534)
+		// Minimum execution time: 22_408_000 picoseconds.
+		Weight::from_parts(23_296_000, 3534)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1030,8 +1207,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `248`
 		//  Estimated: `3549`
-		// Minimum execution time: 15_010_000 picoseconds.
-		Weight::from_parts(15_523_000, 3549)
+		// Minimum execution time: 18_202_000 picoseconds.
+		Weight::from_parts(18_841_000, 3549)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1047,8 +1224,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `399`
 		//  Estimated: `3593`
-		// Minimum execution time: 24_987_000 picoseconds.
-		Weight::from_parts(25_668_000, 3593)
+		// Minimum execution time: 31_214_000 picoseconds.
+		Weight::from_parts(33_207_000, 3593)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
@@ -1060,8 +1237,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `277`
 		//  Estimated: `6078`
-		// Minimum execution time: 36_287_000 picoseconds.
-		Weight::from_parts(36_653_000, 6078)
+		// Minimum execution time: 42_318_000 picoseconds.
+		Weight::from_parts(57_933_000, 6078)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
@@ -1073,8 +1250,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `219`
 		//  Estimated: `3549`
-		// Minimum execution time: 15_143_000 picoseconds.
-		Weight::from_parts(15_551_000, 3549)
+		// Minimum execution time: 19_303_000 picoseconds.
+		Weight::from_parts(32_443_000, 3549)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
@@ -1086,8 +1263,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `184`
 		//  Estimated: `3549`
-		// Minimum execution time: 11_145_000 picoseconds.
-		Weight::from_parts(11_395_000, 3549)
+		// Minimum execution time: 13_463_000 picoseconds.
+		Weight::from_parts(14_296_000, 3549)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1099,8 +1276,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `343`
 		//  Estimated: `3534`
-		// Minimum execution time: 17_174_000 picoseconds.
-		Weight::from_parts(17_738_000, 3534)
+		// Minimum execution time: 20_738_000 picoseconds.
+		Weight::from_parts(21_745_000, 3534)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1118,8 +1295,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `447`
 		//  Estimated: `3944`
-		// Minimum execution time: 49_013_000 picoseconds.
-		Weight::from_parts(50_124_000, 3944)
+		// Minimum execution time: 106_134_000 picoseconds.
+		Weight::from_parts(113_374_000, 3944)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1131,8 +1308,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `252`
 		//  Estimated: `3944`
-		// Minimum execution time: 23_738_000 picoseconds.
-		Weight::from_parts(24_558_000, 3944)
+		// Minimum execution time: 46_337_000 picoseconds.
+		Weight::from_parts(53_213_000, 3944)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1148,8 +1325,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `891`
 		//  Estimated: `3944`
-		// Minimum execution time: 45_483_000 picoseconds.
-		Weight::from_parts(46_308_000, 3944)
+		// Minimum execution time: 89_585_000 picoseconds.
+		Weight::from_parts(99_134_000, 3944)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1161,8 +1338,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `289`
 		//  Estimated: `4326`
-		// Minimum execution time: 15_153_000 picoseconds.
-		Weight::from_parts(15_686_000, 4326)
+		// Minimum execution time: 30_960_000 picoseconds.
+		Weight::from_parts(34_015_000, 4326)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1179,10 +1356,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `668 + n * (398 ±0)`
 		//  Estimated: `4326 + n * (2954 ±0)`
-		// Minimum execution time: 23_720_000 picoseconds.
-		Weight::from_parts(24_121_000, 4326)
-			// Standard Error: 4_581
-			.saturating_add(Weight::from_parts(7_030_724, 0).saturating_mul(n.into()))
+		// Minimum execution time: 46_484_000 picoseconds.
+		Weight::from_parts(48_546_000, 4326)
+			// Standard Error: 59_626
+			.saturating_add(Weight::from_parts(8_860_394, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
@@ -1203,8 +1380,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `447`
 		//  Estimated: `3812`
-		// Minimum execution time: 40_386_000 picoseconds.
-		Weight::from_parts(40_903_000, 3812)
+		// Minimum execution time: 49_913_000 picoseconds.
+		Weight::from_parts(51_528_000, 3812)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1220,8 +1397,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `757`
 		//  Estimated: `3812`
-		// Minimum execution time: 38_438_000 picoseconds.
-		Weight::from_parts(39_323_000, 3812)
+		// Minimum execution time: 46_785_000 picoseconds.
+		Weight::from_parts(49_243_000, 3812)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1237,8 +1414,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `306`
 		//  Estimated: `3759`
-		// Minimum execution time: 36_043_000 picoseconds.
-		Weight::from_parts(36_498_000, 3759)
+		// Minimum execution time: 43_940_000 picoseconds.
+		Weight::from_parts(45_408_000, 3759)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1254,8 +1431,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `624`
 		//  Estimated: `3759`
-		// Minimum execution time: 35_275_000 picoseconds.
-		Weight::from_parts(35_879_000, 3759)
+		// Minimum execution time: 42_628_000 picoseconds.
+		Weight::from_parts(44_945_000, 3759)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -1267,8 +1444,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `318`
 		//  Estimated: `4326`
-		// Minimum execution time: 18_190_000 picoseconds.
-		Weight::from_parts(18_717_000, 4326)
+		// Minimum execution time: 22_032_000 picoseconds.
+		Weight::from_parts(22_889_000, 4326)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1278,8 +1455,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `326`
 		//  Estimated: `4326`
-		// Minimum execution time: 15_088_000 picoseconds.
-		Weight::from_parts(15_564_000, 4326)
+		// Minimum execution time: 18_523_000 picoseconds.
+		Weight::from_parts(19_478_000, 4326)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1289,8 +1466,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `326`
 		//  Estimated: `4326`
-		// Minimum execution time: 14_365_000 picoseconds.
-		Weight::from_parts(14_920_000, 4326)
+		// Minimum execution time: 17_661_000 picoseconds.
+		Weight::from_parts(18_345_000, 4326)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1300,8 +1477,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `3517`
-		// Minimum execution time: 9_845_000 picoseconds.
-		Weight::from_parts(10_228_000, 3517)
+		// Minimum execution time: 13_076_000 picoseconds.
+		Weight::from_parts(13_450_000, 3517)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1313,8 +1490,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `248`
 		//  Estimated: `3549`
-		// Minimum execution time: 16_666_000 picoseconds.
-		Weight::from_parts(17_328_000, 3549)
+		// Minimum execution time: 20_023_000 picoseconds.
+		Weight::from_parts(21_056_000, 3549)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1326,8 +1503,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `231`
 		//  Estimated: `3538`
-		// Minimum execution time: 15_969_000 picoseconds.
-		Weight::from_parts(16_717_000, 3538)
+		// Minimum execution time: 19_394_000 picoseconds.
+		Weight::from_parts(20_192_000, 3538)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1343,8 +1520,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `426`
 		//  Estimated: `4326`
-		// Minimum execution time: 21_833_000 picoseconds.
-		Weight::from_parts(22_580_000, 4326)
+		// Minimum execution time: 26_877_000 picoseconds.
+		Weight::from_parts(27_739_000, 4326)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1352,8 +1529,14 @@ impl WeightInfo for () {
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:1 w:1)
 	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
@@ -1364,24 +1547,30 @@ impl WeightInfo for () {
 	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
 	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
-	fn buy_item() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `613`
-		//  Estimated: `4326`
-		// Minimum execution time: 50_016_000 picoseconds.
-		Weight::from_parts(51_155_000, 4326)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	/// The range of component `r` is `[0, 5]`.
+	fn buy_item(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `762 + r * (34 ±0)`
+		//  Estimated: `4326 + r * (2603 ±0)`
+		// Minimum execution time: 112_572_000 picoseconds.
+		Weight::from_parts(115_750_633, 4326)
+			// Standard Error: 183_184
+			.saturating_add(Weight::from_parts(45_939_642, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
 	}
 	/// The range of component `n` is `[0, 10]`.
 	fn pay_tips(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 1_695_000 picoseconds.
-		Weight::from_parts(2_625_514, 0)
-			// Standard Error: 5_213
-			.saturating_add(Weight::from_parts(1_843_482, 0).saturating_mul(n.into()))
+		// Minimum execution time: 2_483_000 picoseconds.
+		Weight::from_parts(4_244_005, 0)
+			// Standard Error: 39_430
+			.saturating_add(Weight::from_parts(2_558_262, 0).saturating_mul(n.into()))
 	}
 	/// Storage: `Nfts::Item` (r:2 w:0)
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
@@ -1391,8 +1580,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `402`
 		//  Estimated: `7662`
-		// Minimum execution time: 18_600_000 picoseconds.
-		Weight::from_parts(19_453_000, 7662)
+		// Minimum execution time: 23_443_000 picoseconds.
+		Weight::from_parts(24_866_000, 7662)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1404,8 +1593,23 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `421`
 		//  Estimated: `4326`
-		// Minimum execution time: 18_476_000 picoseconds.
-		Weight::from_parts(19_091_000, 4326)
+		// Minimum execution time: 23_710_000 picoseconds.
+		Weight::from_parts(25_468_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:0 w:1)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	fn set_collection_royalty() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `248`
+		//  Estimated: `3549`
+		// Minimum execution time: 19_626_000 picoseconds.
+		Weight::from_parts(23_039_000, 3549)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -1413,8 +1617,14 @@ impl WeightInfo for () {
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:1 w:2)
 	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:2 w:0)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:2 w:0)
 	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
@@ -1425,14 +1635,20 @@ impl WeightInfo for () {
 	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:0 w:2)
 	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
-	fn claim_swap() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `742`
-		//  Estimated: `7662`
-		// Minimum execution time: 81_691_000 picoseconds.
-		Weight::from_parts(84_109_000, 7662)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	/// The range of component `r` is `[0, 5]`.
+	fn claim_swap(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `891 + r * (34 ±0)`
+		//  Estimated: `7662 + r * (2603 ±0)`
+		// Minimum execution time: 161_342_000 picoseconds.
+		Weight::from_parts(177_698_614, 7662)
+			// Standard Error: 1_221_715
+			.saturating_add(Weight::from_parts(50_412_648, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
 	}
 	/// Storage: `Nfts::CollectionRoleOf` (r:2 w:0)
 	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
@@ -1457,10 +1673,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `464`
 		//  Estimated: `6078 + n * (2954 ±0)`
-		// Minimum execution time: 125_533_000 picoseconds.
-		Weight::from_parts(130_187_155, 6078)
-			// Standard Error: 46_370
-			.saturating_add(Weight::from_parts(33_019_771, 0).saturating_mul(n.into()))
+		// Minimum execution time: 163_287_000 picoseconds.
+		Weight::from_parts(177_391_545, 6078)
+			// Standard Error: 1_328_112
+			.saturating_add(Weight::from_parts(65_078_351, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
@@ -1484,14 +1700,139 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `496`
 		//  Estimated: `4326 + n * (2954 ±0)`
-		// Minimum execution time: 65_098_000 picoseconds.
-		Weight::from_parts(74_929_657, 4326)
-			// Standard Error: 61_212
-			.saturating_add(Weight::from_parts(31_387_885, 0).saturating_mul(n.into()))
+		// Minimum execution time: 82_437_000 picoseconds.
+		Weight::from_parts(49_466_963, 4326)
+			// Standard Error: 994_950
+			.saturating_add(Weight::from_parts(73_668_088, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2954).saturating_mul(n.into()))
 	}
+	/// Storage: `Nfts::Item` (r:1 w:0)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `426`
+		//  Estimated: `4326`
+		// Minimum execution time: 74_051_000 picoseconds.
+		Weight::from_parts(77_549_000, 4326)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:2 w:2)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(463), added: 2938, mode: `MaxEncodedLen`)
+	fn place_bid_english() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `608`
+		//  Estimated: `6866`
+		// Minimum execution time: 108_483_000 picoseconds.
+		Weight::from_parts(175_389_000, 6866)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 5]`.
+	fn place_bid_dutch(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `788 + r * (34 ±0)`
+		//  Estimated: `4326 + r * (2603 ±0)`
+		// Minimum execution time: 113_171_000 picoseconds.
+		Weight::from_parts(142_794_332, 4326)
+			// Standard Error: 1_309_461
+			.saturating_add(Weight::from_parts(43_490_829, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298`
+		//  Estimated: `3627`
+		// Minimum execution time: 16_927_000 picoseconds.
+		Weight::from_parts(18_005_000, 3627)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Nfts::ItemAuctionOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemAuctionOf` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:6 w:6)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(463), added: 2938, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoyaltyOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoyaltyOf` (`max_values`: None, `max_size`: Some(191), added: 2666, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(479), added: 2954, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 5]`.
+	fn settle_auction(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `876 + r * (34 ±0)`
+		//  Estimated: `4326 + r * (2603 ±0)`
+		// Minimum execution time: 147_772_000 picoseconds.
+		Weight::from_parts(312_223_932, 4326)
+			// Standard Error: 2_039_794
+			.saturating_add(Weight::from_parts(28_746_098, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(r.into()))
+	}
 }
@@ -84,6 +84,32 @@ fn add_announcements<T: Config>(
 	Ok(())
 }
 
+fn add_expiries<T: Config>(n: u32, maybe_who: Option<T::AccountId>) -> Result<(), &'static str> {
+	let caller = maybe_who.unwrap_or_else(whitelisted_caller);
+	for i in 0..n {
+		Proxy::<T>::set_proxy_expiry(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(account("target", i, SEED)),
+			Some(BlockNumberFor::<T>::max_value()),
+		)?;
+	}
+	Ok(())
+}
+
+fn add_allowances<T: Config>(n: u32, delegate: &T::AccountId) -> Result<(), &'static str> {
+	let caller: T::AccountId = whitelisted_caller();
+	for i in 0..n {
+		Proxy::<T>::set_allowance(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(delegate.clone()),
+			T::BenchmarkHelper::create_asset_kind(i),
+			BalanceOf::<T>::max_value(),
+			BlockNumberFor::<T>::one(),
+		)?;
+	}
+	Ok(())
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -254,6 +280,7 @@ mod benchmarks {
 	#[benchmark]
 	fn remove_proxy(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
+		add_expiries::<T>(1, None)?;
 		let caller: T::AccountId = whitelisted_caller();
 		let delegate = T::Lookup::unlookup(account("target", 0, SEED));
 
@@ -274,13 +301,15 @@ mod benchmarks {
 	#[benchmark]
 	fn remove_proxies(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
+		add_expiries::<T>(p, None)?;
 		let caller: T::AccountId = whitelisted_caller();
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
 
-		let (proxies, _) = Proxies::<T>::get(caller);
+		let (proxies, _) = Proxies::<T>::get(&caller);
 		assert_eq!(proxies.len() as u32, 0);
+		assert_eq!(Restrictions::<T>::iter_prefix(&caller).count(), 0);
 
 		Ok(())
 	}
@@ -328,6 +357,7 @@ mod benchmarks {
 		let pure_account = Pallet::<T>::pure_account(&caller, &T::ProxyType::default(), 0, None);
 
 		add_proxies::<T>(p, Some(pure_account.clone()))?;
+		add_expiries::<T>(p, Some(pure_account.clone()))?;
 		ensure!(Proxies::<T>::contains_key(&pure_account), "pure proxy not created");
 
 		#[extrinsic_call]
@@ -341,6 +371,7 @@ mod benchmarks {
 		);
 
 		assert!(!Proxies::<T>::contains_key(&pure_account));
+		assert_eq!(Restrictions::<T>::iter_prefix(&pure_account).count(), 0);
 
 		Ok(())
 	}
@@ -457,5 +488,113 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_proxy_expiry() -> Result<(), BenchmarkError> {
+		let p = T::MaxProxies::get() - 1;
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", p - 1, SEED);
+		let expiry = Some(BlockNumberFor::<T>::max_value());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(delegate.clone()), expiry);
+
+		assert_last_event::<T>(
+			Event::ProxyExpirySet { delegator: caller, delegatee: delegate, expiry }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_allowance() -> Result<(), BenchmarkError> {
+		// Allowances cannot be set if the runtime cannot enforce them.
+		if !T::SpendInspector::ENABLED {
+			return Err(BenchmarkError::Weightless)
+		}
+		let p = T::MaxProxies::get() - 1;
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", p - 1, SEED);
+		let a = T::MaxAllowances::get() - 1;
+		add_allowances::<T>(a, &delegate)?;
+		let asset = T::BenchmarkHelper::create_asset_kind(a);
+		let amount = BalanceOf::<T>::max_value();
+		let period = BlockNumberFor::<T>::one();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(delegate.clone()),
+			asset.clone(),
+			amount,
+			period,
+		);
+
+		assert_last_event::<T>(
+			Event::AllowanceSet { delegator: caller, delegatee: delegate, asset, amount, period }
+				.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn top_up_allowance() -> Result<(), BenchmarkError> {
+		if !T::SpendInspector::ENABLED {
+			return Err(BenchmarkError::Weightless)
+		}
+		let p = T::MaxProxies::get() - 1;
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", p - 1, SEED);
+		let a = T::MaxAllowances::get();
+		add_allowances::<T>(a, &delegate)?;
+		let asset = T::BenchmarkHelper::create_asset_kind(a - 1);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(delegate.clone()),
+			asset.clone(),
+			One::one(),
+		);
+
+		assert_last_event::<T>(
+			Event::AllowanceToppedUp {
+				delegator: caller,
+				delegatee: delegate,
+				asset,
+				amount: BalanceOf::<T>::max_value(),
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_allowance() -> Result<(), BenchmarkError> {
+		if !T::SpendInspector::ENABLED {
+			return Err(BenchmarkError::Weightless)
+		}
+		let p = T::MaxProxies::get() - 1;
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", p - 1, SEED);
+		let a = T::MaxAllowances::get();
+		add_allowances::<T>(a, &delegate)?;
+		let asset = T::BenchmarkHelper::create_asset_kind(a - 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(delegate.clone()), asset.clone());
+
+		assert_last_event::<T>(
+			Event::AllowanceRevoked { delegator: caller, delegatee: delegate, asset }.into(),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -23,6 +23,10 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! The proxied account may also restrict a proxy without removing it: an expiry block stops the
+//! proxy from being used from then on, and per-asset allowances cap the amount that the calls it
+//! dispatches may spend per period.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -34,7 +38,8 @@ mod tests;
 pub mod weights;
 
 extern crate alloc;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, rc::Rc, vec};
+use core::cell::{Cell, RefCell};
 use frame::{
 	prelude::*,
 	traits::{Currency, InstanceFilter, ReservableCurrency},
@@ -50,6 +55,13 @@ type BalanceOf<T> =
 pub type BlockNumberFor<T> =
 	<<T as Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
 
+type ProxyRestrictionsOf<T> = ProxyRestrictions<
+	<T as Config>::AssetKind,
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+	<T as Config>::MaxAllowances,
+>;
+
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 /// The parameters under which a particular account has a proxy relationship with some other
@@ -89,6 +101,120 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// An amount of an asset that a proxy may spend on behalf of the proxied account per period.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct SpendAllowance<AssetKind, Balance, BlockNumber> {
+	/// The asset the allowance is for.
+	pub asset: AssetKind,
+	/// The amount that may be spent in each period.
+	pub amount: Balance,
+	/// The length of a period in blocks. If zero, the allowance is never renewed.
+	pub period: BlockNumber,
+	/// The amount spent so far in the current period.
+	pub spent: Balance,
+	/// The block at which the current period started.
+	pub period_start: BlockNumber,
+}
+
+impl<AssetKind, Balance, BlockNumber> SpendAllowance<AssetKind, Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Record the spending of `amount` at block `now`, starting a new period first if the
+	/// current one is over.
+	///
+	/// Returns `false` without changing anything if `amount` exceeds what is left of the
+	/// allowance.
+	fn try_spend(&mut self, amount: Balance, now: BlockNumber) -> bool {
+		let renewed =
+			!self.period.is_zero() && now >= self.period_start.saturating_add(self.period);
+		let spent = if renewed { amount } else { self.spent.saturating_add(amount) };
+		if spent > self.amount {
+			return false
+		}
+		if renewed {
+			self.period_start = now;
+		}
+		self.spent = spent;
+		true
+	}
+}
+
+/// The restrictions placed on a proxy relationship by the proxied account.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(MaxAllowances))]
+pub struct ProxyRestrictions<AssetKind, Balance, BlockNumber, MaxAllowances: Get<u32>> {
+	/// The block from which the proxy may no longer be used, if any.
+	pub expiry: Option<BlockNumber>,
+	/// The spending allowances of the proxy. Assets without an allowance are not restricted.
+	pub allowances: BoundedVec<SpendAllowance<AssetKind, Balance, BlockNumber>, MaxAllowances>,
+	/// The amount held on deposit for storing these restrictions.
+	pub deposit: Balance,
+}
+
+/// The funds of the proxied account spent by a call dispatched through a proxy.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum CallSpend<AssetKind, Balance> {
+	/// The call does not spend any funds of the proxied account.
+	Nothing,
+	/// The call spends an amount of an asset from the proxied account.
+	Asset(AssetKind, Balance),
+	/// The call may spend funds of the proxied account in a way that cannot be determined.
+	///
+	/// Such calls are denied to proxies with any spending allowance.
+	Unknown,
+}
+
+/// Inspects calls dispatched through a proxy for the asset and amount they spend from the
+/// proxied account.
+///
+/// Calls which may move funds out of the proxied account but cannot be priced in one of the
+/// assets should be reported as [`CallSpend::Unknown`], so that they are denied to proxies with
+/// a spending allowance.
+pub trait SpendInspector<Call, AssetKind, Balance> {
+	/// Whether the inspector is able to enforce spending allowances at all.
+	///
+	/// If `false`, no spending allowance can be set.
+	const ENABLED: bool = true;
+
+	/// The funds that `call` spends from the proxied account.
+	fn spend(call: &Call) -> CallSpend<AssetKind, Balance>;
+}
+
+impl<Call, AssetKind, Balance> SpendInspector<Call, AssetKind, Balance> for () {
+	const ENABLED: bool = false;
+
+	fn spend(_: &Call) -> CallSpend<AssetKind, Balance> {
+		CallSpend::Nothing
+	}
+}
+
+/// Helper for creating asset kinds in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetKind> {
+	/// Returns an asset kind, distinct for every `seed` up to `MaxAllowances`.
+	fn create_asset_kind(seed: u32) -> AssetKind;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetKind: Decode> BenchmarkHelper<AssetKind> for () {
+	fn create_asset_kind(seed: u32) -> AssetKind {
+		Decode::decode(&mut TrailingZeroInput::new(seed.encode().as_ref()))
+			.expect("asset kind must be decodable from a trailing zero input")
+	}
+}
+
 /// The type of deposit
 #[derive(
 	Encode,
@@ -212,6 +338,25 @@ pub mod pallet {
 		///     providers can be used. Relay provider can be a bit better in cases where the
 		///     parachain is lagging its block production to avoid clock skew.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// Type identifying the assets that a proxy can be given a spending allowance for.
+		type AssetKind: Parameter + MaxEncodedLen;
+
+		/// Determines the asset and amount spent by a call dispatched through a proxy, so that
+		/// the allowances of the proxy can be enforced.
+		type SpendInspector: SpendInspector<
+			<Self as Config>::RuntimeCall,
+			Self::AssetKind,
+			BalanceOf<Self>,
+		>;
+
+		/// The maximum number of spending allowances a single proxy relationship can have.
+		#[pallet::constant]
+		type MaxAllowances: Get<u32>;
+
+		/// Helper type for benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetKind>;
 	}
 
 	#[pallet::call]
@@ -231,6 +376,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy(T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // Allowances spent by the inner calls, written once.
+				.saturating_add(T::DbWeight::get().writes(1))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -391,6 +538,7 @@ pub mod pallet {
 
 			let (_, deposit) = Proxies::<T>::take(&who);
 			T::Currency::unreserve(&spawner, deposit);
+			Self::clear_restrictions(&who);
 
 			Self::deposit_event(Event::PureKilled {
 				pure: who,
@@ -431,6 +579,7 @@ pub mod pallet {
 				.into_iter()
 				.find(|x| x.delegate == who)
 				.ok_or(Error::<T>::NotProxy)?;
+			Self::ensure_not_expired(&real, &who)?;
 
 			let announcement = Announcement {
 				real: real.clone(),
@@ -530,6 +679,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // Allowances spent by the inner calls, written once.
+				.saturating_add(T::DbWeight::get().writes(1))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -657,6 +808,179 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No.into() } else { Pays::Yes.into() })
 		}
+
+		/// Set the block from which a proxy of the sender may no longer be used.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// This will take a deposit of `ProxyDepositFactor` for storing the restrictions of the
+		/// proxy, unless it already has any.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that is a proxy of the sender.
+		/// - `expiry`: The block from which the proxy expires, or `None` if it should not expire.
+		///
+		/// Emits `ProxyExpirySet`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_proxy_expiry())]
+		pub fn set_proxy_expiry(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::mutate_restrictions(&who, &delegate, |restrictions| {
+				restrictions.expiry = expiry;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::ProxyExpirySet {
+				delegator: who,
+				delegatee: delegate,
+				expiry,
+			});
+
+			Ok(())
+		}
+
+		/// Set the amount of `asset` that a proxy of the sender may spend per period, replacing
+		/// any existing allowance for it.
+		///
+		/// Once set, calls dispatched by the proxy which spend more of `asset` than what is left
+		/// of the allowance in the current period are filtered, as are calls whose spending
+		/// cannot be determined by `SpendInspector`. Assets without an allowance are not
+		/// restricted.
+		///
+		/// Fails if the runtime's `SpendInspector` cannot enforce allowances.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// This will take a deposit of `ProxyDepositFactor` for every allowance of the proxy.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that is a proxy of the sender.
+		/// - `asset`: The asset to set the allowance for.
+		/// - `amount`: The amount of `asset` that may be spent per period.
+		/// - `period`: The length of a period in blocks. If zero, the allowance is never renewed.
+		///
+		/// Emits `AllowanceSet`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_allowance())]
+		pub fn set_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			asset: T::AssetKind,
+			amount: BalanceOf<T>,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::SpendInspector::ENABLED, Error::<T>::AllowancesUnsupported);
+			let delegate = T::Lookup::lookup(delegate)?;
+			let allowance = SpendAllowance {
+				asset: asset.clone(),
+				amount,
+				period,
+				spent: Zero::zero(),
+				period_start: T::BlockNumberProvider::current_block_number(),
+			};
+			Self::mutate_restrictions(&who, &delegate, |restrictions| {
+				match restrictions.allowances.iter_mut().find(|a| a.asset == asset) {
+					Some(existing) => *existing = allowance,
+					None => restrictions
+						.allowances
+						.try_push(allowance)
+						.map_err(|_| Error::<T>::TooMany)?,
+				}
+				Ok(())
+			})?;
+			Self::deposit_event(Event::AllowanceSet {
+				delegator: who,
+				delegatee: delegate,
+				asset,
+				amount,
+				period,
+			});
+
+			Ok(())
+		}
+
+		/// Increase the amount of `asset` that a proxy of the sender may spend per period.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that is a proxy of the sender.
+		/// - `asset`: The asset of the allowance to top up.
+		/// - `amount`: The amount to add to the allowance of each period.
+		///
+		/// Emits `AllowanceToppedUp`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::top_up_allowance())]
+		pub fn top_up_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			asset: T::AssetKind,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::SpendInspector::ENABLED, Error::<T>::AllowancesUnsupported);
+			let delegate = T::Lookup::lookup(delegate)?;
+			let new_amount = Self::mutate_restrictions(&who, &delegate, |restrictions| {
+				let allowance = restrictions
+					.allowances
+					.iter_mut()
+					.find(|a| a.asset == asset)
+					.ok_or(Error::<T>::NoAllowance)?;
+				allowance.amount = allowance.amount.saturating_add(amount);
+				Ok(allowance.amount)
+			})?;
+			Self::deposit_event(Event::AllowanceToppedUp {
+				delegator: who,
+				delegatee: delegate,
+				asset,
+				amount: new_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Revoke the allowance of a proxy of the sender for `asset`, so that it may no longer
+		/// spend any of it. The proxy relationship itself is kept.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that is a proxy of the sender.
+		/// - `asset`: The asset of the allowance to revoke.
+		///
+		/// Emits `AllowanceRevoked`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::revoke_allowance())]
+		pub fn revoke_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			asset: T::AssetKind,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::mutate_restrictions(&who, &delegate, |restrictions| {
+				let allowance = restrictions
+					.allowances
+					.iter_mut()
+					.find(|a| a.asset == asset)
+					.ok_or(Error::<T>::NoAllowance)?;
+				allowance.amount = Zero::zero();
+				allowance.spent = Zero::zero();
+				Ok(())
+			})?;
+			Self::deposit_event(Event::AllowanceRevoked {
+				delegator: who,
+				delegatee: delegate,
+				asset,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -706,6 +1030,29 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// The expiry of a proxy was set.
+		ProxyExpirySet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			expiry: Option<BlockNumberFor<T>>,
+		},
+		/// A spending allowance of a proxy was set.
+		AllowanceSet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			asset: T::AssetKind,
+			amount: BalanceOf<T>,
+			period: BlockNumberFor<T>,
+		},
+		/// A spending allowance of a proxy was increased to `amount` per period.
+		AllowanceToppedUp {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			asset: T::AssetKind,
+			amount: BalanceOf<T>,
+		},
+		/// A spending allowance of a proxy was revoked.
+		AllowanceRevoked { delegator: T::AccountId, delegatee: T::AccountId, asset: T::AssetKind },
 	}
 
 	#[pallet::error]
@@ -726,6 +1073,12 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The proxy relationship has expired.
+		Expired,
+		/// The proxy has no allowance for the asset.
+		NoAllowance,
+		/// Spending allowances cannot be enforced by the runtime.
+		AllowancesUnsupported,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -758,6 +1111,18 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The expiry and spending allowances of proxies, keyed by the account which has delegated
+	/// and the account which is being delegated to.
+	#[pallet::storage]
+	pub type Restrictions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		ProxyRestrictionsOf<T>,
+	>;
+
 	#[pallet::view_functions]
 	impl<T: Config> Pallet<T> {
 		/// Check if a `RuntimeCall` is allowed for a given `ProxyType`.
@@ -895,6 +1260,9 @@ impl<T: Config> Pallet<T> {
 			} else if new_deposit < old_deposit {
 				T::Currency::unreserve(delegator, old_deposit - new_deposit);
 			}
+			if !proxies.iter().any(|p| p.delegate == delegatee) {
+				Self::remove_restrictions(delegator, &delegatee);
+			}
 			if !proxies.is_empty() {
 				*x = Some((proxies, new_deposit))
 			}
@@ -972,7 +1340,123 @@ impl<T: Config> Pallet<T> {
 			&x.delegate == delegate &&
 				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y)
 		};
-		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
+		let def = Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?;
+		Self::ensure_not_expired(real, delegate)?;
+		Ok(def)
+	}
+
+	/// Ensure that the proxy relationship between `real` and `delegate` has not expired.
+	fn ensure_not_expired(real: &T::AccountId, delegate: &T::AccountId) -> DispatchResult {
+		if let Some(expiry) = Restrictions::<T>::get(real, delegate).and_then(|r| r.expiry) {
+			ensure!(T::BlockNumberProvider::current_block_number() < expiry, Error::<T>::Expired);
+		}
+		Ok(())
+	}
+
+	/// Mutate the restrictions of the proxy relationship between `real` and `delegate`,
+	/// adjusting the deposit held for them.
+	///
+	/// Restrictions without an expiry or any allowance are removed.
+	fn mutate_restrictions<R>(
+		real: &T::AccountId,
+		delegate: &T::AccountId,
+		f: impl FnOnce(&mut ProxyRestrictionsOf<T>) -> Result<R, DispatchError>,
+	) -> Result<R, DispatchError> {
+		ensure!(
+			Proxies::<T>::get(real).0.iter().any(|x| &x.delegate == delegate),
+			Error::<T>::NotProxy
+		);
+		Restrictions::<T>::try_mutate_exists(real, delegate, |maybe_restrictions| {
+			let mut restrictions = maybe_restrictions.take().unwrap_or_else(|| ProxyRestrictions {
+				expiry: None,
+				allowances: Default::default(),
+				deposit: Zero::zero(),
+			});
+			let result = f(&mut restrictions)?;
+			let is_empty = restrictions.expiry.is_none() && restrictions.allowances.is_empty();
+			let new_deposit = if is_empty {
+				Zero::zero()
+			} else {
+				T::ProxyDepositFactor::get()
+					.saturating_mul((restrictions.allowances.len() as u32 + 1).into())
+			};
+			if new_deposit > restrictions.deposit {
+				T::Currency::reserve(real, new_deposit - restrictions.deposit)?;
+			} else if new_deposit < restrictions.deposit {
+				T::Currency::unreserve(real, restrictions.deposit - new_deposit);
+			}
+			restrictions.deposit = new_deposit;
+			if !is_empty {
+				*maybe_restrictions = Some(restrictions);
+			}
+			Ok(result)
+		})
+	}
+
+	/// Remove the restrictions of the proxy relationship between `real` and `delegate`,
+	/// returning their deposit.
+	fn remove_restrictions(real: &T::AccountId, delegate: &T::AccountId) {
+		if let Some(restrictions) = Restrictions::<T>::take(real, delegate) {
+			T::Currency::unreserve(real, restrictions.deposit);
+		}
+	}
+
+	/// Remove the restrictions of all proxies of `real`, returning their deposits.
+	fn clear_restrictions(real: &T::AccountId) {
+		let deposit = Restrictions::<T>::drain_prefix(real)
+			.fold(BalanceOf::<T>::zero(), |total, (_, r)| total.saturating_add(r.deposit));
+		T::Currency::unreserve(real, deposit);
+	}
+
+	/// Spend the allowance in `restrictions` for the asset spent by `call`, if any.
+	///
+	/// Returns `false` if the allowance does not cover the amount spent by `call`, or if the
+	/// proxy has any allowance and the amount spent by `call` cannot be determined.
+	///
+	/// `spent` is set once any allowance is spent.
+	fn spend_allowance(
+		restrictions: &mut Option<ProxyRestrictionsOf<T>>,
+		spent: &Cell<bool>,
+		call: &<T as Config>::RuntimeCall,
+	) -> bool {
+		let Some(restrictions) = restrictions else { return true };
+		if restrictions.allowances.is_empty() {
+			return true
+		}
+		match T::SpendInspector::spend(call) {
+			CallSpend::Nothing => true,
+			CallSpend::Unknown => false,
+			CallSpend::Asset(asset, amount) =>
+				match restrictions.allowances.iter_mut().find(|a| a.asset == asset) {
+					Some(allowance) => {
+						let allowed = allowance
+							.try_spend(amount, T::BlockNumberProvider::current_block_number());
+						spent.set(spent.get() || allowed);
+						allowed
+					},
+					None => true,
+				},
+		}
+	}
+
+	/// Store the allowances spent by a proxied call.
+	///
+	/// Only the spent amounts are written back, since the call may have changed the restrictions
+	/// of the proxy itself.
+	fn store_spent_allowances(
+		real: &T::AccountId,
+		delegate: &T::AccountId,
+		spent: ProxyRestrictionsOf<T>,
+	) {
+		Restrictions::<T>::mutate(real, delegate, |maybe_restrictions| {
+			let Some(restrictions) = maybe_restrictions else { return };
+			for allowance in restrictions.allowances.iter_mut() {
+				if let Some(s) = spent.allowances.iter().find(|s| s.asset == allowance.asset) {
+					allowance.spent = s.spent;
+					allowance.period_start = s.period_start;
+				}
+			}
+		});
 	}
 
 	fn do_proxy(
@@ -981,12 +1465,17 @@ impl<T: Config> Pallet<T> {
 		call: <T as Config>::RuntimeCall,
 	) {
 		use frame::traits::{InstanceFilter as _, OriginTrait as _};
+		// The restrictions are read once and spent in memory by every filtered call, including
+		// nested ones, so that they are only written once.
+		let restrictions = Rc::new(RefCell::new(Restrictions::<T>::get(&real, &def.delegate)));
+		let spent = Rc::new(Cell::new(false));
+		let (filter_restrictions, filter_spent) = (restrictions.clone(), spent.clone());
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
-		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real).into();
+		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real.clone()).into();
 		origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
 			let c = <T as Config>::RuntimeCall::from_ref(c);
 			// We make sure the proxy call does access this pallet to change modify proxies.
-			match c.is_sub_type() {
+			let allowed = match c.is_sub_type() {
 				// Proxy call cannot add or remove a proxy with more permissions than it already
 				// has.
 				Some(Call::add_proxy { ref proxy_type, .. }) |
@@ -999,10 +1488,17 @@ impl<T: Config> Pallet<T> {
 					if def.proxy_type != T::ProxyType::default() =>
 					false,
 				_ => def.proxy_type.filter(c),
-			}
+			};
+			// Spending calls must be covered by the allowance of the proxy, if it has any.
+			allowed &&
+				Self::spend_allowance(&mut filter_restrictions.borrow_mut(), &filter_spent, c)
 		});
-		let e = call.dispatch(origin);
-		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
+		let result = call.dispatch(origin).map(|_| ()).map_err(|e| e.error);
+		// The allowance spent by a failing call is not stored.
+		if let (Ok(_), true, Some(restrictions)) = (&result, spent.get(), restrictions.take()) {
+			Self::store_spent_allowances(&real, &def.delegate, restrictions);
+		}
+		Self::deposit_event(Event::ProxyExecuted { result });
 	}
 
 	/// Removes all proxy delegates for a given delegator.
@@ -1012,5 +1508,6 @@ impl<T: Config> Pallet<T> {
 	pub fn remove_all_proxy_delegates(delegator: &T::AccountId) {
 		let (_, old_deposit) = Proxies::<T>::take(&delegator);
 		T::Currency::unreserve(&delegator, old_deposit);
+		Self::clear_restrictions(delegator);
 	}
 }
@@ -129,6 +129,30 @@ impl Config for Test {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type AssetKind = u32;
+	type SpendInspector = BalancesTransferSpend;
+	type MaxAllowances = ConstU32<2>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+/// Balance transfers spend the native asset, which is identified by `0`. Remarks and batches
+/// spend nothing themselves, while the spending of any other call is unknown.
+pub struct BalancesTransferSpend;
+impl SpendInspector<RuntimeCall, u32, u64> for BalancesTransferSpend {
+	fn spend(call: &RuntimeCall) -> CallSpend<u32, u64> {
+		match call {
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+				value, ..
+			}) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) =>
+				CallSpend::Asset(0, *value),
+			RuntimeCall::System(frame_system::Call::remark { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) => CallSpend::Nothing,
+			_ => CallSpend::Unknown,
+		}
+	}
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -836,3 +860,215 @@ fn poke_deposit_fails_for_unsigned_origin() {
 		assert_noop!(Proxy::poke_deposit(RuntimeOrigin::none()), DispatchError::BadOrigin,);
 	});
 }
+
+#[test]
+fn proxy_expiry_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_noop!(
+			Proxy::set_proxy_expiry(RuntimeOrigin::signed(1), 4, Some(5)),
+			Error::<Test>::NotProxy
+		);
+		assert_ok!(Proxy::set_proxy_expiry(RuntimeOrigin::signed(1), 3, Some(5)));
+		System::assert_last_event(
+			ProxyEvent::ProxyExpirySet { delegator: 1, delegatee: 3, expiry: Some(5) }.into(),
+		);
+		// One for the proxy definition, one for its restrictions.
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		let call = Box::new(call_transfer(6, 1));
+		System::set_block_number(4);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call.clone()));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+
+		System::set_block_number(5);
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call.clone()),
+			Error::<Test>::Expired
+		);
+		assert_noop!(
+			Proxy::announce(RuntimeOrigin::signed(3), 1, [1; 32].into()),
+			Error::<Test>::Expired
+		);
+
+		// Clearing the expiry returns the deposit for the restrictions.
+		assert_ok!(Proxy::set_proxy_expiry(RuntimeOrigin::signed(1), 3, None));
+		assert!(Restrictions::<Test>::get(1, 3).is_none());
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call.clone()));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+	});
+}
+
+#[test]
+fn spending_allowance_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 1000);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_allowance(RuntimeOrigin::signed(1), 3, 0, 5, 10));
+		System::assert_last_event(
+			ProxyEvent::AllowanceSet {
+				delegator: 1,
+				delegatee: 3,
+				asset: 0,
+				amount: 5,
+				period: 10,
+			}
+			.into(),
+		);
+		assert_eq!(Restrictions::<Test>::get(1, 3).unwrap().deposit, 2);
+
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 2))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 5);
+
+		// Nested calls are covered by the allowance as well.
+		let call = Box::new(RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![call_transfer(6, 1)],
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+
+		// The allowance is renewed once the period is over.
+		System::set_block_number(11);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 5))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 10);
+
+		// A failing call does not use up the allowance.
+		assert_ok!(Proxy::top_up_allowance(RuntimeOrigin::signed(1), 3, 0, 2000));
+		System::assert_last_event(
+			ProxyEvent::AllowanceToppedUp { delegator: 1, delegatee: 3, asset: 0, amount: 2005 }
+				.into(),
+		);
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(3),
+			1,
+			None,
+			Box::new(call_transfer(6, 2000))
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(ArithmeticError::Underflow.into()) }.into(),
+		);
+		assert_eq!(Restrictions::<Test>::get(1, 3).unwrap().allowances[0].spent, 5);
+
+		// Assets without an allowance are not restricted.
+		assert_noop!(
+			Proxy::top_up_allowance(RuntimeOrigin::signed(1), 3, 1, 1),
+			Error::<Test>::NoAllowance
+		);
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(3),
+			1,
+			None,
+			Box::new(RuntimeCall::System(SystemCall::remark { remark: vec![] }))
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+
+		assert_ok!(Proxy::revoke_allowance(RuntimeOrigin::signed(1), 3, 0));
+		System::assert_last_event(
+			ProxyEvent::AllowanceRevoked { delegator: 1, delegatee: 3, asset: 0 }.into(),
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, Box::new(call_transfer(6, 1))));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert_eq!(Balances::free_balance(6), 10);
+	});
+}
+
+#[test]
+fn unknown_spending_is_denied_with_allowance() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 1000);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		let call = || {
+			Box::new(RuntimeCall::Balances(BalancesCall::transfer_all {
+				dest: 6,
+				keep_alive: true,
+			}))
+		};
+
+		// An expiry alone does not restrict spending.
+		assert_ok!(Proxy::set_proxy_expiry(RuntimeOrigin::signed(1), 3, Some(10)));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call()));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+
+		Balances::make_free_balance_be(&1, 1000);
+		assert_ok!(Proxy::set_allowance(RuntimeOrigin::signed(1), 3, 0, 5, 0));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call()));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		// Only the additional deposit for the allowance was taken.
+		assert_eq!(Balances::free_balance(1), 999);
+	});
+}
+
+#[test]
+fn batched_spending_is_stored_once() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 1000);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_allowance(RuntimeOrigin::signed(1), 3, 0, 5, 0));
+
+		let call = Box::new(RuntimeCall::Utility(UtilityCall::batch {
+			calls: vec![call_transfer(6, 2), call_transfer(6, 2), call_transfer(6, 2)],
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call));
+		System::assert_has_event(
+			UtilityEvent::BatchInterrupted { index: 2, error: SystemError::CallFiltered.into() }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(6), 4);
+		assert_eq!(Restrictions::<Test>::get(1, 3).unwrap().allowances[0].spent, 4);
+
+		// Nothing is spent if the proxied call fails.
+		let call = Box::new(RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![call_transfer(6, 1), call_transfer(6, 1)],
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(3), 1, None, call));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert_eq!(Restrictions::<Test>::get(1, 3).unwrap().allowances[0].spent, 4);
+	});
+}
+
+#[test]
+fn allowances_are_bounded_and_removed_with_proxy() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustTransfer, 0));
+		assert_ok!(Proxy::set_allowance(RuntimeOrigin::signed(1), 3, 0, 5, 10));
+		assert_ok!(Proxy::set_allowance(RuntimeOrigin::signed(1), 3, 1, 5, 10));
+		assert_noop!(
+			Proxy::set_allowance(RuntimeOrigin::signed(1), 3, 2, 5, 10),
+			Error::<Test>::TooMany
+		);
+		// Replacing an existing allowance is still possible.
+		assert_ok!(Proxy::set_allowance(RuntimeOrigin::signed(1), 3, 1, 7, 0));
+		assert_eq!(Balances::reserved_balance(1), 6);
+
+		// Restrictions are kept as long as the delegate has any proxy definition left.
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert!(Restrictions::<Test>::get(1, 3).is_some());
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustTransfer, 0));
+		assert!(Restrictions::<Test>::get(1, 3).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_expiry(RuntimeOrigin::signed(1), 3, Some(10)));
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(1)));
+		assert!(Restrictions::<Test>::get(1, 3).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
@@ -35,21 +35,21 @@
 //! Autogenerated weights for `pallet_proxy`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-03-04, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `99fc4dfa9c86`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
-// frame-omni-bencher
+// ./target/release/frame-omni-bencher
 // v1
 // benchmark
 // pallet
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_proxy
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/proxy/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/proxy/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -58,7 +58,7 @@
 // --no-storage-info
 // --no-min-squares
 // --no-median-slopes
-// --exclude-pallets=pallet_xcm,pallet_xcm_benchmarks::fungible,pallet_xcm_benchmarks::generic,pallet_nomination_pools,pallet_remark,pallet_transaction_storage,pallet_election_provider_multi_block,pallet_election_provider_multi_block::signed,pallet_election_provider_multi_block::unsigned,pallet_election_provider_multi_block::verifier
+// --genesis-builder-policy=none
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -81,6 +81,10 @@ pub trait WeightInfo {
 	fn create_pure(p: u32, ) -> Weight;
 	fn kill_pure(p: u32, ) -> Weight;
 	fn poke_deposit() -> Weight;
+	fn set_proxy_expiry() -> Weight;
+	fn set_allowance() -> Weight;
+	fn top_up_allowance() -> Weight;
+	fn revoke_allowance() -> Weight;
 }
 
 /// Weights for `pallet_proxy` using the Substrate node and recommended hardware.
@@ -88,23 +92,25 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:0)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// Storage: `SafeMode::EnteredUntil` (r:1 w:0)
 	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
-	fn proxy(p: u32, ) -> Weight {
+	fn proxy(_p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `339 + p * (37 ±0)`
+		//  Measured:  `65 + p * (37 ±0)`
 		//  Estimated: `4706`
-		// Minimum execution time: 23_353_000 picoseconds.
-		Weight::from_parts(25_084_085, 4706)
-			// Standard Error: 2_569
-			.saturating_add(Weight::from_parts(33_574, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 23_649_000 picoseconds.
+		Weight::from_parts(46_422_083, 4706)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:0)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -115,17 +121,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
-	fn proxy_announced(a: u32, p: u32, ) -> Weight {
+	fn proxy_announced(a: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `666 + a * (68 ±0) + p * (37 ±0)`
+		//  Measured:  `358 + a * (68 ±0) + p * (37 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 47_196_000 picoseconds.
-		Weight::from_parts(48_686_812, 5698)
-			// Standard Error: 3_711
-			.saturating_add(Weight::from_parts(171_107, 0).saturating_mul(a.into()))
-			// Standard Error: 3_834
-			.saturating_add(Weight::from_parts(34_523, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		// Minimum execution time: 52_731_000 picoseconds.
+		Weight::from_parts(71_913_168, 5698)
+			// Standard Error: 49_737
+			.saturating_add(Weight::from_parts(273_414, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
@@ -136,14 +140,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_announcement(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `436 + a * (68 ±0)`
+		//  Measured:  `272 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 29_341_000 picoseconds.
-		Weight::from_parts(30_320_504, 5698)
-			// Standard Error: 1_821
-			.saturating_add(Weight::from_parts(158_572, 0).saturating_mul(a.into()))
-			// Standard Error: 1_881
-			.saturating_add(Weight::from_parts(8_433, 0).saturating_mul(p.into()))
+		// Minimum execution time: 29_475_000 picoseconds.
+		Weight::from_parts(33_548_093, 5698)
+			// Standard Error: 28_709
+			.saturating_add(Weight::from_parts(160_213, 0).saturating_mul(a.into()))
+			// Standard Error: 29_662
+			.saturating_add(Weight::from_parts(161_713, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -155,36 +159,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `p` is `[1, 31]`.
 	fn reject_announcement(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `436 + a * (68 ±0)`
+		//  Measured:  `272 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 28_422_000 picoseconds.
-		Weight::from_parts(29_754_384, 5698)
-			// Standard Error: 1_840
-			.saturating_add(Weight::from_parts(176_827, 0).saturating_mul(a.into()))
-			// Standard Error: 1_901
-			.saturating_add(Weight::from_parts(9_607, 0).saturating_mul(p.into()))
+		// Minimum execution time: 29_712_000 picoseconds.
+		Weight::from_parts(25_711_032, 5698)
+			// Standard Error: 28_653
+			.saturating_add(Weight::from_parts(466_906, 0).saturating_mul(a.into()))
+			// Standard Error: 29_604
+			.saturating_add(Weight::from_parts(168_708, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:0)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
-	fn announce(a: u32, p: u32, ) -> Weight {
+	fn announce(_a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `453 + a * (68 ±0) + p * (37 ±0)`
+		//  Measured:  `290 + a * (68 ±0) + p * (37 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 36_885_000 picoseconds.
-		Weight::from_parts(38_080_636, 5698)
-			// Standard Error: 2_642
-			.saturating_add(Weight::from_parts(157_335, 0).saturating_mul(a.into()))
-			// Standard Error: 2_730
-			.saturating_add(Weight::from_parts(28_872, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Minimum execution time: 41_618_000 picoseconds.
+		Weight::from_parts(60_049_282, 5698)
+			// Standard Error: 55_359
+			.saturating_add(Weight::from_parts(243_861, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
@@ -192,70 +196,82 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
+		//  Measured:  `65 + p * (37 ±0)`
 		//  Estimated: `4706`
-		// Minimum execution time: 27_016_000 picoseconds.
-		Weight::from_parts(28_296_216, 4706)
-			// Standard Error: 1_643
-			.saturating_add(Weight::from_parts(50_271, 0).saturating_mul(p.into()))
+		// Minimum execution time: 25_993_000 picoseconds.
+		Weight::from_parts(39_613_289, 4706)
+			// Standard Error: 26_906
+			.saturating_add(Weight::from_parts(324_474, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
+		//  Measured:  `193 + p * (37 ±0)`
 		//  Estimated: `4706`
-		// Minimum execution time: 26_955_000 picoseconds.
-		Weight::from_parts(28_379_566, 4706)
-			// Standard Error: 1_547
-			.saturating_add(Weight::from_parts(45_784, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		// Minimum execution time: 47_660_000 picoseconds.
+		Weight::from_parts(75_237_082, 4706)
+			// Standard Error: 16_553
+			.saturating_add(Weight::from_parts(11_787, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:32 w:31)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 24_656_000 picoseconds.
-		Weight::from_parts(25_821_878, 4706)
-			// Standard Error: 2_300
-			.saturating_add(Weight::from_parts(33_972, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Measured:  `128 + p * (105 ±0)`
+		//  Estimated: `4706 + p * (3297 ±0)`
+		// Minimum execution time: 71_100_000 picoseconds.
+		Weight::from_parts(79_538_541, 4706)
+			// Standard Error: 164_715
+			.saturating_add(Weight::from_parts(6_724_524, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3297).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
-	fn create_pure(p: u32, ) -> Weight {
+	fn create_pure(_p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `206`
+		//  Measured:  `76`
 		//  Estimated: `4706`
-		// Minimum execution time: 28_416_000 picoseconds.
-		Weight::from_parts(29_662_728, 4706)
-			// Standard Error: 1_851
-			.saturating_add(Weight::from_parts(29_928, 0).saturating_mul(p.into()))
+		// Minimum execution time: 27_853_000 picoseconds.
+		Weight::from_parts(38_796_202, 4706)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:31 w:30)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `231 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 25_505_000 picoseconds.
-		Weight::from_parts(26_780_627, 4706)
-			// Standard Error: 1_581
-			.saturating_add(Weight::from_parts(33_085, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `267 + p * (105 ±0)`
+		//  Estimated: `4706 + p * (3297 ±0)`
+		// Minimum execution time: 33_036_000 picoseconds.
+		Weight::from_parts(73_071_642, 4706)
+			// Standard Error: 108_000
+			.saturating_add(Weight::from_parts(5_543_653, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3297).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -265,36 +281,90 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `519`
+		//  Measured:  `343`
 		//  Estimated: `5698`
-		// Minimum execution time: 46_733_000 picoseconds.
-		Weight::from_parts(47_972_000, 5698)
+		// Minimum execution time: 52_195_000 picoseconds.
+		Weight::from_parts(54_394_000, 5698)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn set_proxy_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1212`
+		//  Estimated: `4706`
+		// Minimum execution time: 30_851_000 picoseconds.
+		Weight::from_parts(31_841_000, 4706)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn set_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2010`
+		//  Estimated: `4706`
+		// Minimum execution time: 37_996_000 picoseconds.
+		Weight::from_parts(40_462_000, 4706)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn top_up_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2055`
+		//  Estimated: `4706`
+		// Minimum execution time: 21_185_000 picoseconds.
+		Weight::from_parts(23_313_000, 4706)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn revoke_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2055`
+		//  Estimated: `4706`
+		// Minimum execution time: 21_675_000 picoseconds.
+		Weight::from_parts(23_415_000, 4706)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:0)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// Storage: `SafeMode::EnteredUntil` (r:1 w:0)
 	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
-	fn proxy(p: u32, ) -> Weight {
+	fn proxy(_p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `339 + p * (37 ±0)`
+		//  Measured:  `65 + p * (37 ±0)`
 		//  Estimated: `4706`
-		// Minimum execution time: 23_353_000 picoseconds.
-		Weight::from_parts(25_084_085, 4706)
-			// Standard Error: 2_569
-			.saturating_add(Weight::from_parts(33_574, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 23_649_000 picoseconds.
+		Weight::from_parts(46_422_083, 4706)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:0)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -305,17 +375,15 @@ impl WeightInfo for () {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
-	fn proxy_announced(a: u32, p: u32, ) -> Weight {
+	fn proxy_announced(a: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `666 + a * (68 ±0) + p * (37 ±0)`
+		//  Measured:  `358 + a * (68 ±0) + p * (37 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 47_196_000 picoseconds.
-		Weight::from_parts(48_686_812, 5698)
-			// Standard Error: 3_711
-			.saturating_add(Weight::from_parts(171_107, 0).saturating_mul(a.into()))
-			// Standard Error: 3_834
-			.saturating_add(Weight::from_parts(34_523, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		// Minimum execution time: 52_731_000 picoseconds.
+		Weight::from_parts(71_913_168, 5698)
+			// Standard Error: 49_737
+			.saturating_add(Weight::from_parts(273_414, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
@@ -326,14 +394,14 @@ impl WeightInfo for () {
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_announcement(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `436 + a * (68 ±0)`
+		//  Measured:  `272 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 29_341_000 picoseconds.
-		Weight::from_parts(30_320_504, 5698)
-			// Standard Error: 1_821
-			.saturating_add(Weight::from_parts(158_572, 0).saturating_mul(a.into()))
-			// Standard Error: 1_881
-			.saturating_add(Weight::from_parts(8_433, 0).saturating_mul(p.into()))
+		// Minimum execution time: 29_475_000 picoseconds.
+		Weight::from_parts(33_548_093, 5698)
+			// Standard Error: 28_709
+			.saturating_add(Weight::from_parts(160_213, 0).saturating_mul(a.into()))
+			// Standard Error: 29_662
+			.saturating_add(Weight::from_parts(161_713, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -345,36 +413,36 @@ impl WeightInfo for () {
 	/// The range of component `p` is `[1, 31]`.
 	fn reject_announcement(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `436 + a * (68 ±0)`
+		//  Measured:  `272 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 28_422_000 picoseconds.
-		Weight::from_parts(29_754_384, 5698)
-			// Standard Error: 1_840
-			.saturating_add(Weight::from_parts(176_827, 0).saturating_mul(a.into()))
-			// Standard Error: 1_901
-			.saturating_add(Weight::from_parts(9_607, 0).saturating_mul(p.into()))
+		// Minimum execution time: 29_712_000 picoseconds.
+		Weight::from_parts(25_711_032, 5698)
+			// Standard Error: 28_653
+			.saturating_add(Weight::from_parts(466_906, 0).saturating_mul(a.into()))
+			// Standard Error: 29_604
+			.saturating_add(Weight::from_parts(168_708, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:0)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
-	fn announce(a: u32, p: u32, ) -> Weight {
+	fn announce(_a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `453 + a * (68 ±0) + p * (37 ±0)`
+		//  Measured:  `290 + a * (68 ±0) + p * (37 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 36_885_000 picoseconds.
-		Weight::from_parts(38_080_636, 5698)
-			// Standard Error: 2_642
-			.saturating_add(Weight::from_parts(157_335, 0).saturating_mul(a.into()))
-			// Standard Error: 2_730
-			.saturating_add(Weight::from_parts(28_872, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Minimum execution time: 41_618_000 picoseconds.
+		Weight::from_parts(60_049_282, 5698)
+			// Standard Error: 55_359
+			.saturating_add(Weight::from_parts(243_861, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
@@ -382,70 +450,82 @@ impl WeightInfo for () {
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
+		//  Measured:  `65 + p * (37 ±0)`
 		//  Estimated: `4706`
-		// Minimum execution time: 27_016_000 picoseconds.
-		Weight::from_parts(28_296_216, 4706)
-			// Standard Error: 1_643
-			.saturating_add(Weight::from_parts(50_271, 0).saturating_mul(p.into()))
+		// Minimum execution time: 25_993_000 picoseconds.
+		Weight::from_parts(39_613_289, 4706)
+			// Standard Error: 26_906
+			.saturating_add(Weight::from_parts(324_474, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
+		//  Measured:  `193 + p * (37 ±0)`
 		//  Estimated: `4706`
-		// Minimum execution time: 26_955_000 picoseconds.
-		Weight::from_parts(28_379_566, 4706)
-			// Standard Error: 1_547
-			.saturating_add(Weight::from_parts(45_784, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		// Minimum execution time: 47_660_000 picoseconds.
+		Weight::from_parts(75_237_082, 4706)
+			// Standard Error: 16_553
+			.saturating_add(Weight::from_parts(11_787, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:32 w:31)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 24_656_000 picoseconds.
-		Weight::from_parts(25_821_878, 4706)
-			// Standard Error: 2_300
-			.saturating_add(Weight::from_parts(33_972, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Measured:  `128 + p * (105 ±0)`
+		//  Estimated: `4706 + p * (3297 ±0)`
+		// Minimum execution time: 71_100_000 picoseconds.
+		Weight::from_parts(79_538_541, 4706)
+			// Standard Error: 164_715
+			.saturating_add(Weight::from_parts(6_724_524, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3297).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
-	fn create_pure(p: u32, ) -> Weight {
+	fn create_pure(_p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `206`
+		//  Measured:  `76`
 		//  Estimated: `4706`
-		// Minimum execution time: 28_416_000 picoseconds.
-		Weight::from_parts(29_662_728, 4706)
-			// Standard Error: 1_851
-			.saturating_add(Weight::from_parts(29_928, 0).saturating_mul(p.into()))
+		// Minimum execution time: 27_853_000 picoseconds.
+		Weight::from_parts(38_796_202, 4706)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:31 w:30)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `231 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 25_505_000 picoseconds.
-		Weight::from_parts(26_780_627, 4706)
-			// Standard Error: 1_581
-			.saturating_add(Weight::from_parts(33_085, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `267 + p * (105 ±0)`
+		//  Estimated: `4706 + p * (3297 ±0)`
+		// Minimum execution time: 33_036_000 picoseconds.
+		Weight::from_parts(73_071_642, 4706)
+			// Standard Error: 108_000
+			.saturating_add(Weight::from_parts(5_543_653, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 3297).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -455,11 +535,63 @@ impl WeightInfo for () {
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `519`
+		//  Measured:  `343`
 		//  Estimated: `5698`
-		// Minimum execution time: 46_733_000 picoseconds.
-		Weight::from_parts(47_972_000, 5698)
+		// Minimum execution time: 52_195_000 picoseconds.
+		Weight::from_parts(54_394_000, 5698)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn set_proxy_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1212`
+		//  Estimated: `4706`
+		// Minimum execution time: 30_851_000 picoseconds.
+		Weight::from_parts(31_841_000, 4706)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn set_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2010`
+		//  Estimated: `4706`
+		// Minimum execution time: 37_996_000 picoseconds.
+		Weight::from_parts(40_462_000, 4706)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn top_up_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2055`
+		//  Estimated: `4706`
+		// Minimum execution time: 21_185_000 picoseconds.
+		Weight::from_parts(23_313_000, 4706)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Restrictions` (r:1 w:1)
+	/// Proof: `Proxy::Restrictions` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn revoke_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2055`
+		//  Estimated: `4706`
+		// Minimum execution time: 21_675_000 picoseconds.
+		Weight::from_parts(23_415_000, 4706)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
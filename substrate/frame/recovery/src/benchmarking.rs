@@ -124,7 +124,42 @@ fn setup_active_recovery_with_max_friends<T: Config>(
 	<ActiveRecoveries<T>>::insert(lost_account, caller, active_recovery);
 }
 
-#[benchmarks]
+fn insert_inheritance_config<T: Config>(account: &T::AccountId, n: u32) -> FriendsOf<T> {
+	T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value());
+
+	let bounded_heirs: FriendsOf<T> = generate_friends::<T>(n).try_into().unwrap();
+	let total_deposit = get_total_deposit::<T>(&bounded_heirs).unwrap();
+	T::Currency::reserve(&account, total_deposit).unwrap();
+
+	let config = InheritanceConfig {
+		inactivity_period: DEFAULT_DELAY.into(),
+		claim_delay: DEFAULT_DELAY.into(),
+		deposit: total_deposit,
+		heirs: bounded_heirs.clone(),
+	};
+	<Inheritable<T>>::insert(account, config);
+	<LastActive<T>>::insert(account, BlockNumberFromProviderOf::<T>::from(DEFAULT_DELAY));
+
+	bounded_heirs
+}
+
+fn insert_inheritance_claim<T: Config>(account: &T::AccountId, heir: &T::AccountId) {
+	T::Currency::make_free_balance_be(&heir, BalanceOf::<T>::max_value());
+	let deposit = T::RecoveryDeposit::get();
+	T::Currency::reserve(heir, deposit).unwrap();
+
+	let claim = InheritanceClaim { heir: heir.clone(), created: DEFAULT_DELAY.into(), deposit };
+	<InheritanceClaims<T>>::insert(account, claim);
+}
+
+#[benchmarks(where
+	T: Send + Sync,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = frame::deps::frame_support::dispatch::DispatchInfo> + GetDispatchInfo,
+	<<T as frame_system::Config>::RuntimeCall as Dispatchable>::PostInfo: Default,
+	<<T as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		frame::traits::AsSystemOriginSigner<T::AccountId> + AsTransactionAuthorizedOrigin + Clone,
+)]
 mod benchmarks {
 	use super::*;
 
@@ -465,5 +500,120 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let heirs = generate_friends::<T>(n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), heirs, DEFAULT_DELAY.into(), DEFAULT_DELAY.into());
+
+		assert_last_event::<T>(Event::InheritanceSet { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn remove_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		insert_inheritance_config::<T>(&caller, n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(Event::InheritanceRemoved { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn heartbeat() {
+		let caller: T::AccountId = whitelisted_caller();
+		insert_inheritance_config::<T>(&caller, T::MaxFriends::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert!(<LastActive<T>>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn initiate_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let stale_heir: T::AccountId = account("heir", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let account: T::AccountId = account("inherited_account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+
+		let mut heirs = generate_friends::<T>(n - 1);
+		heirs.push(caller.clone());
+		heirs.sort();
+		insert_inheritance_config::<T>(&account, 1);
+		<Inheritable<T>>::mutate(&account, |config| {
+			config.as_mut().unwrap().heirs = heirs.try_into().unwrap();
+		});
+		// Worst case: the stale claim of another heir is replaced.
+		insert_inheritance_claim::<T>(&account, &stale_heir);
+		let active = BlockNumberFromProviderOf::<T>::from(DEFAULT_DELAY + 1);
+		<LastActive<T>>::insert(&account, active);
+		T::BlockNumberProvider::set_block_number(active);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), account_lookup);
+
+		assert_last_event::<T>(Event::InheritanceInitiated { account, heir: caller }.into());
+	}
+
+	#[benchmark]
+	fn cancel_inheritance() {
+		let caller: T::AccountId = whitelisted_caller();
+		let heir: T::AccountId = account("heir", 0, SEED);
+		insert_inheritance_config::<T>(&caller, T::MaxFriends::get());
+		insert_inheritance_claim::<T>(&caller, &heir);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(Event::InheritanceCancelled { account: caller, heir }.into());
+	}
+
+	#[benchmark]
+	fn claim_inheritance(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("inherited_account", 0, SEED);
+		let account_lookup = T::Lookup::unlookup(account.clone());
+
+		insert_inheritance_config::<T>(&account, n);
+		insert_inheritance_claim::<T>(&account, &caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), account_lookup);
+
+		assert_last_event::<T>(
+			Event::AccountRecovered { lost_account: account, rescuer_account: caller }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn record_activity() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		insert_inheritance_config::<T>(&caller, 1);
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+
+		let call: <T as frame_system::Config>::RuntimeCall =
+			frame_system::Call::remark { remark: vec![] }.into();
+		let info = call.get_dispatch_info();
+		let ext = RecordActivity::<T>::new();
+
+		#[block]
+		{
+			ext.test_run(RawOrigin::Signed(caller.clone()).into(), &call, &info, 0, 0, |_| {
+				Ok(Default::default())
+			})
+			.map_err(|_| BenchmarkError::Stop("Transaction extension failed"))?
+			.map_err(|_| BenchmarkError::Stop("Dispatch failed"))?;
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
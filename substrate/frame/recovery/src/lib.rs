@@ -91,6 +91,32 @@
 //! this pallet makes it near-zero cost to re-configure the recovery settings and
 //! remove/replace friends who are acting inappropriately.
 //!
+//! ### Inheritance
+//!
+//! Besides social recovery, an account owner can designate heirs who may take over
+//! the account once it has become inactive, acting as a dead man's switch. The owner
+//! calls `set_inheritance` with:
+//! * `heirs` - The list of accounts which may inherit the account.
+//! * `inactivity_period` - The number of blocks the account must be inactive for before an heir can
+//!   start a claim.
+//! * `claim_delay` - The number of blocks after the start of a claim that need to pass before the
+//!   heir can take over the account.
+//!
+//! The account is considered active whenever its owner calls `heartbeat`. Runtimes can
+//! additionally include the [`RecordActivity`] transaction extension, which records every
+//! transaction signed by an inheritable account as activity.
+//!
+//! Once the account has been inactive for the inactivity period, any heir can call
+//! `initiate_inheritance`, placing the same deposit as for `initiate_recovery`. During
+//! the claim delay, the owner can call `cancel_inheritance` and claim the deposit of the
+//! heir. Any activity of the owner during the claim delay also prevents the claim: the heir
+//! gets their deposit back with `claim_inheritance`, and a new claim can replace it. After
+//! the claim delay, the heir calls `claim_inheritance`, which returns their deposit and
+//! allows them to call `as_recovered` on the account.
+//!
+//! The inheritance configuration is subject to the same deposit as a recovery
+//! configuration, returned in full when the owner calls `remove_inheritance`.
+//!
 //! ### Safety Considerations
 //!
 //! It is important to note that this is a powerful pallet that can compromise the
@@ -142,6 +168,20 @@
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
 //!
+//! #### For Heirs of an Inheritable Account
+//!
+//! * `initiate_inheritance` - Start a claim on an account which has been inactive for its
+//!   inactivity period.
+//! * `claim_inheritance` - Take over an account after the claim delay, allowing `as_recovered` for
+//!   it.
+//!
+//! #### For the Inheritable Account
+//!
+//! * `set_inheritance` - Set up or update the heirs of your account.
+//! * `heartbeat` - Signal that you still have access to your account.
+//! * `cancel_inheritance` - Cancel a pending claim on your account and reclaim the claim deposit.
+//! * `remove_inheritance` - Remove the inheritance configuration from the account.
+//!
 //! #### For Super Users
 //!
 //! * `set_recovered` - The ROOT origin is able to skip the recovery process and directly allow one
@@ -165,12 +205,15 @@ pub use weights::WeightInfo;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod extension;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 pub mod weights;
 
+pub use extension::RecordActivity;
+
 pub type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -225,6 +268,33 @@ pub enum DepositKind<T: Config> {
 	ActiveRecoveryFor(<T as frame_system::Config>::AccountId),
 }
 
+/// Configuration for inheriting an account after a period of inactivity.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InheritanceConfig<BlockNumber, Balance, Friends> {
+	/// The number of blocks the account must be inactive for before an heir can start a claim.
+	pub inactivity_period: BlockNumber,
+	/// The minimum number of blocks since the start of a claim before the heir can take over the
+	/// account. The owner can cancel the claim during this time.
+	pub claim_delay: BlockNumber,
+	/// The amount held in reserve of the `depositor`,
+	/// to be returned once this configuration is removed.
+	pub deposit: Balance,
+	/// The list of heirs which can claim the account. Always sorted.
+	pub heirs: Friends,
+}
+
+/// An active claim of an heir on an inactive account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InheritanceClaim<AccountId, BlockNumber, Balance> {
+	/// The heir making the claim.
+	pub heir: AccountId,
+	/// The block number when the claim was started.
+	pub created: BlockNumber,
+	/// The amount held in reserve of the `heir`,
+	/// to be returned once the claim succeeds.
+	pub deposit: Balance,
+}
+
 #[frame::pallet]
 pub mod pallet {
 	use super::*;
@@ -337,6 +407,17 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// Heirs have been set up to inherit an account.
+		InheritanceSet { account: T::AccountId },
+		/// The inheritance configuration has been removed for an account.
+		InheritanceRemoved { account: T::AccountId },
+		/// An heir has started to claim an inactive account.
+		InheritanceInitiated { account: T::AccountId, heir: T::AccountId },
+		/// The owner of an account has cancelled the claim of an heir.
+		InheritanceCancelled { account: T::AccountId, heir: T::AccountId },
+		/// The claim of an heir has been closed because the owner of the account has been active
+		/// since it was started.
+		InheritanceClaimClosed { account: T::AccountId, heir: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -373,6 +454,14 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// This account has no heirs set up
+		NotInheritable,
+		/// This account is not an heir of the account
+		NotHeir,
+		/// The account has been active within its inactivity period
+		NotInactive,
+		/// There is a pending inheritance claim on this account
+		ClaimPending,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -407,6 +496,31 @@ pub mod pallet {
 	#[pallet::getter(fn proxy)]
 	pub type Proxy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// The set of inheritable accounts and their inheritance configuration.
+	#[pallet::storage]
+	pub type Inheritable<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		InheritanceConfig<BlockNumberFromProviderOf<T>, BalanceOf<T>, FriendsOf<T>>,
+	>;
+
+	/// The block number of the last recorded activity of each inheritable account.
+	///
+	/// Updated by `heartbeat` and by the [`RecordActivity`] transaction extension.
+	#[pallet::storage]
+	pub type LastActive<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFromProviderOf<T>>;
+
+	/// Pending claims on inheritable accounts, keyed by the account being claimed.
+	#[pallet::storage]
+	pub type InheritanceClaims<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		InheritanceClaim<T::AccountId, BlockNumberFromProviderOf<T>, BalanceOf<T>>,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Send a call through a recovered account.
@@ -786,6 +900,223 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No } else { Pays::Yes }.into())
 		}
+
+		/// Set up heirs who can inherit your account once it becomes inactive, or update an
+		/// existing inheritance configuration.
+		///
+		/// Payment: `ConfigDepositBase` + `FriendDepositFactor` * #_of_heirs balance
+		/// will be reserved for storing the inheritance configuration. This deposit is returned
+		/// in full when the user calls `remove_inheritance`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the account must not have a
+		/// pending inheritance claim.
+		///
+		/// Parameters:
+		/// - `heirs`: A list of accounts which may claim your account. Should be ordered and
+		///   contain no duplicate values.
+		/// - `inactivity_period`: The number of blocks your account must be inactive for before an
+		///   heir can start a claim.
+		/// - `claim_delay`: The number of blocks after a claim is started that need to pass before
+		///   the heir can take over the account. You can cancel the claim during this time.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_inheritance(heirs.len() as u32))]
+		pub fn set_inheritance(
+			origin: OriginFor<T>,
+			heirs: Vec<T::AccountId>,
+			inactivity_period: BlockNumberFromProviderOf<T>,
+			claim_delay: BlockNumberFromProviderOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!<InheritanceClaims<T>>::contains_key(&who), Error::<T>::ClaimPending);
+			// Check user input is valid
+			ensure!(!heirs.is_empty(), Error::<T>::NotEnoughFriends);
+			let bounded_heirs: FriendsOf<T> =
+				heirs.try_into().map_err(|_| Error::<T>::MaxFriends)?;
+			ensure!(Self::is_sorted_and_unique(&bounded_heirs), Error::<T>::NotSorted);
+			// Adjust the deposit to the new number of heirs
+			let old_deposit =
+				<Inheritable<T>>::get(&who).map(|config| config.deposit).unwrap_or_default();
+			let new_deposit = Self::get_recovery_config_deposit(bounded_heirs.len())?;
+			if new_deposit > old_deposit {
+				T::Currency::reserve(&who, new_deposit.saturating_sub(old_deposit))?;
+			} else {
+				let excess = old_deposit.saturating_sub(new_deposit);
+				let remaining_unreserved = T::Currency::unreserve(&who, excess);
+				if !remaining_unreserved.is_zero() {
+					defensive!(
+						"Failed to unreserve full amount. (Requested, Actual)",
+						(excess, excess.saturating_sub(remaining_unreserved))
+					);
+				}
+			}
+			let config = InheritanceConfig {
+				inactivity_period,
+				claim_delay,
+				deposit: new_deposit,
+				heirs: bounded_heirs,
+			};
+			<Inheritable<T>>::insert(&who, config);
+			<LastActive<T>>::insert(&who, T::BlockNumberProvider::current_block_number());
+
+			Self::deposit_event(Event::<T>::InheritanceSet { account: who });
+			Ok(())
+		}
+
+		/// Remove the inheritance configuration for your account.
+		///
+		/// NOTE: The user must make sure to call `cancel_inheritance` on a pending claim
+		/// before calling this function else it will fail.
+		///
+		/// Payment: By calling this function the account will unreserve their inheritance
+		/// configuration deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an inheritable account.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::remove_inheritance(T::MaxFriends::get()))]
+		pub fn remove_inheritance(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!<InheritanceClaims<T>>::contains_key(&who), Error::<T>::ClaimPending);
+			let config = <Inheritable<T>>::take(&who).ok_or(Error::<T>::NotInheritable)?;
+			<LastActive<T>>::remove(&who);
+
+			T::Currency::unreserve(&who, config.deposit);
+			Self::deposit_event(Event::<T>::InheritanceRemoved { account: who });
+			Ok(())
+		}
+
+		/// Signal that you still have access to your inheritable account.
+		///
+		/// This resets the inactivity period of the account. Runtimes which include the
+		/// [`RecordActivity`] transaction extension record this for every signed transaction.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an inheritable account.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::heartbeat())]
+		pub fn heartbeat(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<Inheritable<T>>::contains_key(&who), Error::<T>::NotInheritable);
+			<LastActive<T>>::insert(&who, T::BlockNumberProvider::current_block_number());
+			Ok(())
+		}
+
+		/// Start to claim an account which has been inactive for its inactivity period.
+		///
+		/// Payment: `RecoveryDeposit` balance will be reserved for starting the claim. This
+		/// deposit is returned when the claim succeeds, and repatriated to the account if its
+		/// owner cancels the claim. See `cancel_inheritance`.
+		///
+		/// A pending claim is replaced if the owner has been active since it was started, in which
+		/// case the deposit of its heir is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an heir of `account`.
+		///
+		/// Parameters:
+		/// - `account`: The inactive account that you want to inherit.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::initiate_inheritance(T::MaxFriends::get()))]
+		pub fn initiate_inheritance(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let config = <Inheritable<T>>::get(&account).ok_or(Error::<T>::NotInheritable)?;
+			ensure!(Self::is_friend(&config.heirs, &who), Error::<T>::NotHeir);
+			if let Some(claim) = <InheritanceClaims<T>>::get(&account) {
+				ensure!(Self::is_stale_claim(&account, &claim), Error::<T>::AlreadyStarted);
+				Self::close_inheritance_claim(account.clone(), claim);
+			}
+			// Make sure the account has been inactive for long enough
+			let current_block_number = T::BlockNumberProvider::current_block_number();
+			let inactive_block_number = <LastActive<T>>::get(&account)
+				.unwrap_or_default()
+				.checked_add(&config.inactivity_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(inactive_block_number <= current_block_number, Error::<T>::NotInactive);
+			// Take claim deposit
+			let deposit = T::RecoveryDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			let claim =
+				InheritanceClaim { heir: who.clone(), created: current_block_number, deposit };
+			<InheritanceClaims<T>>::insert(&account, claim);
+			Self::deposit_event(Event::<T>::InheritanceInitiated { account, heir: who });
+			Ok(())
+		}
+
+		/// As the owner of an inheritable account, cancel the pending claim on your account.
+		///
+		/// Payment: By calling this function, the account will receive the deposit
+		/// `RecoveryDeposit` placed by the heir.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be an inheritable account
+		/// with a pending claim on it.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::cancel_inheritance())]
+		pub fn cancel_inheritance(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let claim = <InheritanceClaims<T>>::take(&who).ok_or(Error::<T>::NotStarted)?;
+			let res = T::Currency::repatriate_reserved(
+				&claim.heir,
+				&who,
+				claim.deposit,
+				BalanceStatus::Free,
+			);
+			debug_assert!(res.is_ok());
+			<LastActive<T>>::mutate_extant(&who, |last_active| {
+				*last_active = T::BlockNumberProvider::current_block_number()
+			});
+			Self::deposit_event(Event::<T>::InheritanceCancelled {
+				account: who,
+				heir: claim.heir,
+			});
+			Ok(())
+		}
+
+		/// Take over an inherited account, allowing you to call `as_recovered` for it.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the heir who started
+		/// the pending claim on `account`. The claim delay must have passed since the claim was
+		/// started.
+		///
+		/// If the owner has been active since the claim was started, the claim is closed instead
+		/// and the deposit of the heir is returned.
+		///
+		/// Parameters:
+		/// - `account`: The inactive account that you want to inherit.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::claim_inheritance(T::MaxFriends::get()))]
+		pub fn claim_inheritance(
+			origin: OriginFor<T>,
+			account: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let account = T::Lookup::lookup(account)?;
+			let config = <Inheritable<T>>::get(&account).ok_or(Error::<T>::NotInheritable)?;
+			let claim = <InheritanceClaims<T>>::get(&account).ok_or(Error::<T>::NotStarted)?;
+			ensure!(claim.heir == who, Error::<T>::NotStarted);
+			if Self::is_stale_claim(&account, &claim) {
+				Self::close_inheritance_claim(account, claim);
+				return Ok(())
+			}
+			ensure!(!Proxy::<T>::contains_key(&who), Error::<T>::AlreadyProxy);
+			// Make sure the claim delay has passed
+			let current_block_number = T::BlockNumberProvider::current_block_number();
+			let claimable_block_number = claim
+				.created
+				.checked_add(&config.claim_delay)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(claimable_block_number <= current_block_number, Error::<T>::DelayPeriod);
+			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
+			<InheritanceClaims<T>>::remove(&account);
+			T::Currency::unreserve(&who, claim.deposit);
+			// Create the recovery storage item
+			Proxy::<T>::insert(&who, &account);
+			Self::deposit_event(Event::<T>::AccountRecovered {
+				lost_account: account,
+				rescuer_account: who,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -800,6 +1131,30 @@ impl<T: Config> Pallet<T> {
 		friends.binary_search(&friend).is_ok()
 	}
 
+	/// Check whether the owner of `account` has been active since `claim` was started.
+	fn is_stale_claim(
+		account: &T::AccountId,
+		claim: &InheritanceClaim<T::AccountId, BlockNumberFromProviderOf<T>, BalanceOf<T>>,
+	) -> bool {
+		<LastActive<T>>::get(account).unwrap_or_default() > claim.created
+	}
+
+	/// Remove the inheritance claim on `account` and return the deposit of its heir.
+	fn close_inheritance_claim(
+		account: T::AccountId,
+		claim: InheritanceClaim<T::AccountId, BlockNumberFromProviderOf<T>, BalanceOf<T>>,
+	) {
+		<InheritanceClaims<T>>::remove(&account);
+		let remaining_unreserved = T::Currency::unreserve(&claim.heir, claim.deposit);
+		if !remaining_unreserved.is_zero() {
+			defensive!(
+				"Failed to unreserve the full claim deposit. (Requested, Actual)",
+				(claim.deposit, claim.deposit.saturating_sub(remaining_unreserved))
+			);
+		}
+		Self::deposit_event(Event::<T>::InheritanceClaimClosed { account, heir: claim.heir });
+	}
+
 	/// Helper function to calculate recovery config deposit
 	/// Total deposit is base fee + number of friends * factor fee
 	fn get_recovery_config_deposit(friends_count: usize) -> Result<BalanceOf<T>, DispatchError> {
@@ -786,3 +786,238 @@ fn poke_deposit_handles_insufficient_balance() {
 		assert_eq!(Balances::reserved_balance(5), 13);
 	});
 }
+
+#[test]
+fn inheritance_life_cycle_works() {
+	new_test_ext().execute_with(|| {
+		let heirs = vec![1, 2];
+		let inactivity_period = 10;
+		let claim_delay = 5;
+		// Account 5 sets up heirs for their account
+		assert_ok!(Recovery::set_inheritance(
+			RuntimeOrigin::signed(5),
+			heirs,
+			inactivity_period,
+			claim_delay
+		));
+		assert_eq!(Balances::reserved_balance(5), 12);
+		assert_eq!(<LastActive<Test>>::get(&5), Some(1));
+		// The account is still active
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NotInactive
+		);
+		// The account has been inactive for the inactivity period
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(3), 5),
+			Error::<Test>::NotHeir
+		);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(Event::InheritanceInitiated { account: 5, heir: 1 }.into());
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::AlreadyStarted
+		);
+		// Only the heir who started the claim can finish it, after the claim delay
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::NotStarted
+		);
+		assert_noop!(
+			Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::DelayPeriod
+		);
+		System::run_to_block::<AllPalletsWithSystem>(16);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(
+			Event::AccountRecovered { lost_account: 5, rescuer_account: 1 }.into(),
+		);
+		assert_eq!(<Proxy<Test>>::get(&1), Some(5));
+		assert!(!<InheritanceClaims<Test>>::contains_key(&5));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		// Account 1 can use account 5 to remove the inheritance configuration and take the funds
+		let call = Box::new(RuntimeCall::Recovery(RecoveryCall::remove_inheritance {}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert_eq!(Balances::free_balance(5), 100);
+		let call = Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+			dest: 1,
+			value: 100,
+		}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert_eq!(Balances::free_balance(1), 200);
+		assert!(!<Inheritable<Test>>::contains_key(&5));
+		assert!(!<LastActive<Test>>::contains_key(&5));
+	});
+}
+
+#[test]
+fn set_inheritance_handles_basic_errors() {
+	new_test_ext().execute_with(|| {
+		// No heirs
+		assert_noop!(
+			Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![], 10, 5),
+			Error::<Test>::NotEnoughFriends
+		);
+		// Too many heirs
+		let heirs = (1..=MaxFriends::get() as u64 + 1).collect::<Vec<_>>();
+		assert_noop!(
+			Recovery::set_inheritance(RuntimeOrigin::signed(5), heirs, 10, 5),
+			Error::<Test>::MaxFriends
+		);
+		// Unsorted or duplicate heirs
+		assert_noop!(
+			Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![2, 1], 10, 5),
+			Error::<Test>::NotSorted
+		);
+		assert_noop!(
+			Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 1], 10, 5),
+			Error::<Test>::NotSorted
+		);
+		// Nothing to remove or keep alive
+		assert_noop!(
+			Recovery::remove_inheritance(RuntimeOrigin::signed(5)),
+			Error::<Test>::NotInheritable
+		);
+		assert_noop!(Recovery::heartbeat(RuntimeOrigin::signed(5)), Error::<Test>::NotInheritable);
+		// The configuration cannot change while a claim is pending
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1], 0, 5));
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		assert_noop!(
+			Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5),
+			Error::<Test>::ClaimPending
+		);
+		assert_noop!(
+			Recovery::remove_inheritance(RuntimeOrigin::signed(5)),
+			Error::<Test>::ClaimPending
+		);
+	});
+}
+
+#[test]
+fn set_inheritance_updates_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2, 3], 10, 5));
+		System::assert_last_event(Event::InheritanceSet { account: 5 }.into());
+		assert_eq!(Balances::reserved_balance(5), 13);
+		// Updating the configuration adjusts the deposit to the number of heirs
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1], 20, 5));
+		assert_eq!(Balances::reserved_balance(5), 11);
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2, 3, 4], 20, 5));
+		assert_eq!(Balances::reserved_balance(5), 14);
+		assert_eq!(
+			<Inheritable<Test>>::get(&5),
+			Some(InheritanceConfig {
+				inactivity_period: 20,
+				claim_delay: 5,
+				deposit: 14,
+				heirs: bounded_vec![1, 2, 3, 4],
+			})
+		);
+		// Removing the configuration returns the deposit
+		assert_ok!(Recovery::remove_inheritance(RuntimeOrigin::signed(5)));
+		System::assert_last_event(Event::InheritanceRemoved { account: 5 }.into());
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert!(!<Inheritable<Test>>::contains_key(&5));
+		assert!(!<LastActive<Test>>::contains_key(&5));
+	});
+}
+
+#[test]
+fn owner_activity_prevents_inheritance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+		// A heartbeat resets the inactivity period
+		System::run_to_block::<AllPalletsWithSystem>(8);
+		assert_ok!(Recovery::heartbeat(RuntimeOrigin::signed(5)));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NotInactive
+		);
+		// Activity during the claim delay prevents the claim, which is closed instead
+		System::run_to_block::<AllPalletsWithSystem>(18);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		System::run_to_block::<AllPalletsWithSystem>(20);
+		assert_ok!(Recovery::heartbeat(RuntimeOrigin::signed(5)));
+		System::run_to_block::<AllPalletsWithSystem>(23);
+		assert_ok!(Recovery::claim_inheritance(RuntimeOrigin::signed(1), 5));
+		System::assert_last_event(Event::InheritanceClaimClosed { account: 5, heir: 1 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!<InheritanceClaims<Test>>::contains_key(&5));
+		assert!(!<Proxy<Test>>::contains_key(&1));
+		// The owner cancels a new claim and takes the deposit of the heir
+		System::run_to_block::<AllPalletsWithSystem>(30);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		assert_noop!(
+			Recovery::cancel_inheritance(RuntimeOrigin::signed(1)),
+			Error::<Test>::NotStarted
+		);
+		assert_ok!(Recovery::cancel_inheritance(RuntimeOrigin::signed(5)));
+		System::assert_last_event(Event::InheritanceCancelled { account: 5, heir: 1 }.into());
+		assert_eq!(Balances::free_balance(5), 98);
+		assert_eq!(Balances::total_balance(&1), 90);
+		assert_eq!(<LastActive<Test>>::get(&5), Some(30));
+		assert!(!<InheritanceClaims<Test>>::contains_key(&5));
+		assert!(!<Proxy<Test>>::contains_key(&1));
+	});
+}
+
+#[test]
+fn stale_inheritance_claim_is_replaced() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5));
+		// The owner is active during the claim delay, but never cancels the claim
+		System::run_to_block::<AllPalletsWithSystem>(12);
+		assert_ok!(Recovery::heartbeat(RuntimeOrigin::signed(5)));
+		System::run_to_block::<AllPalletsWithSystem>(17);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(2), 5),
+			Error::<Test>::NotInactive
+		);
+		// Once the account is inactive again, another heir replaces the stale claim
+		System::run_to_block::<AllPalletsWithSystem>(22);
+		assert_ok!(Recovery::initiate_inheritance(RuntimeOrigin::signed(2), 5));
+		System::assert_has_event(Event::InheritanceClaimClosed { account: 5, heir: 1 }.into());
+		System::assert_last_event(Event::InheritanceInitiated { account: 5, heir: 2 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert_eq!(<InheritanceClaims<Test>>::get(&5).map(|claim| claim.heir), Some(2));
+		// A claim which is not stale can not be replaced
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::AlreadyStarted
+		);
+	});
+}
+
+#[test]
+fn record_activity_extension_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Recovery::set_inheritance(RuntimeOrigin::signed(5), vec![1, 2], 10, 5));
+		System::run_to_block::<AllPalletsWithSystem>(9);
+
+		let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		let info = call.get_dispatch_info();
+		// Any transaction signed by the owner records activity
+		assert_ok!(RecordActivity::<Test>::new()
+			.test_run(RuntimeOrigin::signed(5), &call, &info, 0, 0, |_| Ok(Default::default()))
+			.unwrap());
+		assert_eq!(<LastActive<Test>>::get(&5), Some(9));
+		System::run_to_block::<AllPalletsWithSystem>(11);
+		assert_noop!(
+			Recovery::initiate_inheritance(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::NotInactive
+		);
+		// Accounts without an inheritance configuration are not tracked
+		assert_ok!(RecordActivity::<Test>::new()
+			.test_run(RuntimeOrigin::signed(4), &call, &info, 0, 0, |_| Ok(Default::default()))
+			.unwrap());
+		assert!(!<LastActive<Test>>::contains_key(&4));
+	});
+}
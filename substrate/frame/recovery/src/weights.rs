@@ -35,21 +35,21 @@
 //! Autogenerated weights for `pallet_recovery`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-04-16, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `25e4b24639fd`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
-// frame-omni-bencher
+// ./target/release/frame-omni-bencher
 // v1
 // benchmark
 // pallet
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_recovery
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/recovery/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/recovery/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -58,7 +58,7 @@
 // --no-storage-info
 // --no-min-squares
 // --no-median-slopes
-// --exclude-pallets=pallet_xcm,pallet_xcm_benchmarks::fungible,pallet_xcm_benchmarks::generic,pallet_nomination_pools,pallet_remark,pallet_transaction_storage
+// --genesis-builder-policy=none
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -80,6 +80,13 @@ pub trait WeightInfo {
 	fn remove_recovery(n: u32, ) -> Weight;
 	fn cancel_recovered() -> Weight;
 	fn poke_deposit(n: u32, ) -> Weight;
+	fn set_inheritance(n: u32, ) -> Weight;
+	fn remove_inheritance(n: u32, ) -> Weight;
+	fn heartbeat() -> Weight;
+	fn initiate_inheritance(n: u32, ) -> Weight;
+	fn cancel_inheritance() -> Weight;
+	fn claim_inheritance(n: u32, ) -> Weight;
+	fn record_activity() -> Weight;
 }
 
 /// Weights for `pallet_recovery` using the Substrate node and recommended hardware.
@@ -93,10 +100,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	fn as_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `530`
+		//  Measured:  `85`
 		//  Estimated: `3997`
-		// Minimum execution time: 18_724_000 picoseconds.
-		Weight::from_parts(19_503_000, 3997)
+		// Minimum execution time: 11_639_000 picoseconds.
+		Weight::from_parts(12_175_000, 3997)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
@@ -105,8 +112,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 6_933_000 picoseconds.
-		Weight::from_parts(7_249_000, 0)
+		// Minimum execution time: 7_324_000 picoseconds.
+		Weight::from_parts(7_813_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::Recoverable` (r:1 w:1)
@@ -114,12 +121,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[1, 9]`.
 	fn create_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `279`
+		//  Measured:  `0`
 		//  Estimated: `3816`
-		// Minimum execution time: 26_004_000 picoseconds.
-		Weight::from_parts(27_597_455, 3816)
-			// Standard Error: 7_992
-			.saturating_add(Weight::from_parts(165_970, 0).saturating_mul(n.into()))
+		// Minimum execution time: 26_799_000 picoseconds.
+		Weight::from_parts(28_337_201, 3816)
+			// Standard Error: 40_768
+			.saturating_add(Weight::from_parts(249_671, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -129,10 +136,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	fn initiate_recovery() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `376`
+		//  Measured:  `76`
 		//  Estimated: `3854`
-		// Minimum execution time: 33_370_000 picoseconds.
-		Weight::from_parts(34_078_000, 3854)
+		// Minimum execution time: 33_986_000 picoseconds.
+		Weight::from_parts(34_961_000, 3854)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -143,12 +150,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[1, 9]`.
 	fn vouch_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `464 + n * (64 ±0)`
+		//  Measured:  `169 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 21_199_000 picoseconds.
-		Weight::from_parts(22_295_139, 3854)
-			// Standard Error: 5_681
-			.saturating_add(Weight::from_parts(255_188, 0).saturating_mul(n.into()))
+		// Minimum execution time: 20_650_000 picoseconds.
+		Weight::from_parts(21_825_382, 3854)
+			// Standard Error: 8_415
+			.saturating_add(Weight::from_parts(167_182, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -159,14 +166,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
-	fn claim_recovery(n: u32, ) -> Weight {
+	fn claim_recovery(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `496 + n * (64 ±0)`
+		//  Measured:  `201 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 26_340_000 picoseconds.
-		Weight::from_parts(27_828_030, 3854)
-			// Standard Error: 7_254
-			.saturating_add(Weight::from_parts(113_751, 0).saturating_mul(n.into()))
+		// Minimum execution time: 25_827_000 picoseconds.
+		Weight::from_parts(35_538_662, 3854)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -175,14 +180,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
-	fn close_recovery(n: u32, ) -> Weight {
+	fn close_recovery(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `617 + n * (32 ±0)`
+		//  Measured:  `248 + n * (32 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 36_912_000 picoseconds.
-		Weight::from_parts(38_818_613, 3854)
-			// Standard Error: 7_806
-			.saturating_add(Weight::from_parts(149_710, 0).saturating_mul(n.into()))
+		// Minimum execution time: 39_862_000 picoseconds.
+		Weight::from_parts(42_591_422, 3854)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -193,12 +196,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `n` is `[1, 9]`.
 	fn remove_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `374 + n * (32 ±0)`
+		//  Measured:  `71 + n * (32 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 30_824_000 picoseconds.
-		Weight::from_parts(31_960_172, 3854)
-			// Standard Error: 10_777
-			.saturating_add(Weight::from_parts(237_226, 0).saturating_mul(n.into()))
+		// Minimum execution time: 31_416_000 picoseconds.
+		Weight::from_parts(33_554_023, 3854)
+			// Standard Error: 38_783
+			.saturating_add(Weight::from_parts(197_429, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -206,10 +209,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn cancel_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `385`
+		//  Measured:  `85`
 		//  Estimated: `3545`
-		// Minimum execution time: 15_369_000 picoseconds.
-		Weight::from_parts(15_730_000, 3545)
+		// Minimum execution time: 11_107_000 picoseconds.
+		Weight::from_parts(11_669_000, 3545)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -218,15 +221,128 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
-	fn poke_deposit(_n: u32, ) -> Weight {
+	fn poke_deposit(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1110`
+		//  Measured:  `815`
 		//  Estimated: `3854`
-		// Minimum execution time: 46_517_000 picoseconds.
-		Weight::from_parts(49_326_481, 3854)
+		// Minimum execution time: 51_918_000 picoseconds.
+		Weight::from_parts(53_950_366, 3854)
+			// Standard Error: 183_319
+			.saturating_add(Weight::from_parts(1_404_095, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:0)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritable` (r:1 w:1)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn set_inheritance(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3818`
+		// Minimum execution time: 31_967_000 picoseconds.
+		Weight::from_parts(35_522_283, 3818)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:0)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritable` (r:1 w:1)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn remove_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110 + n * (32 ±0)`
+		//  Estimated: `3818`
+		// Minimum execution time: 32_297_000 picoseconds.
+		Weight::from_parts(34_807_233, 3818)
+			// Standard Error: 51_207
+			.saturating_add(Weight::from_parts(192_978, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::Inheritable` (r:1 w:0)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn heartbeat() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `3818`
+		// Minimum execution time: 11_020_000 picoseconds.
+		Weight::from_parts(11_586_000, 3818)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritable` (r:1 w:0)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:1 w:0)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn initiate_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `360 + n * (32 ±0)`
+		//  Estimated: `3818`
+		// Minimum execution time: 64_960_000 picoseconds.
+		Weight::from_parts(69_338_019, 3818)
+			// Standard Error: 283_672
+			.saturating_add(Weight::from_parts(3_636_583, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:1 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn cancel_inheritance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `3593`
+		// Minimum execution time: 48_710_000 picoseconds.
+		Weight::from_parts(71_663_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::Inheritable` (r:1 w:0)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:1 w:0)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_inheritance(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `257 + n * (32 ±0)`
+		//  Estimated: `3818`
+		// Minimum execution time: 44_680_000 picoseconds.
+		Weight::from_parts(60_212_843, 3818)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::LastActive` (r:1 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn record_activity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `86`
+		//  Estimated: `3509`
+		// Minimum execution time: 7_998_000 picoseconds.
+		Weight::from_parts(9_839_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -239,10 +355,10 @@ impl WeightInfo for () {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	fn as_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `530`
+		//  Measured:  `85`
 		//  Estimated: `3997`
-		// Minimum execution time: 18_724_000 picoseconds.
-		Weight::from_parts(19_503_000, 3997)
+		// Minimum execution time: 11_639_000 picoseconds.
+		Weight::from_parts(12_175_000, 3997)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
@@ -251,8 +367,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 6_933_000 picoseconds.
-		Weight::from_parts(7_249_000, 0)
+		// Minimum execution time: 7_324_000 picoseconds.
+		Weight::from_parts(7_813_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::Recoverable` (r:1 w:1)
@@ -260,12 +376,12 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[1, 9]`.
 	fn create_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `279`
+		//  Measured:  `0`
 		//  Estimated: `3816`
-		// Minimum execution time: 26_004_000 picoseconds.
-		Weight::from_parts(27_597_455, 3816)
-			// Standard Error: 7_992
-			.saturating_add(Weight::from_parts(165_970, 0).saturating_mul(n.into()))
+		// Minimum execution time: 26_799_000 picoseconds.
+		Weight::from_parts(28_337_201, 3816)
+			// Standard Error: 40_768
+			.saturating_add(Weight::from_parts(249_671, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -275,10 +391,10 @@ impl WeightInfo for () {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	fn initiate_recovery() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `376`
+		//  Measured:  `76`
 		//  Estimated: `3854`
-		// Minimum execution time: 33_370_000 picoseconds.
-		Weight::from_parts(34_078_000, 3854)
+		// Minimum execution time: 33_986_000 picoseconds.
+		Weight::from_parts(34_961_000, 3854)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -289,12 +405,12 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[1, 9]`.
 	fn vouch_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `464 + n * (64 ±0)`
+		//  Measured:  `169 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 21_199_000 picoseconds.
-		Weight::from_parts(22_295_139, 3854)
-			// Standard Error: 5_681
-			.saturating_add(Weight::from_parts(255_188, 0).saturating_mul(n.into()))
+		// Minimum execution time: 20_650_000 picoseconds.
+		Weight::from_parts(21_825_382, 3854)
+			// Standard Error: 8_415
+			.saturating_add(Weight::from_parts(167_182, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -305,14 +421,12 @@ impl WeightInfo for () {
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
-	fn claim_recovery(n: u32, ) -> Weight {
+	fn claim_recovery(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `496 + n * (64 ±0)`
+		//  Measured:  `201 + n * (64 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 26_340_000 picoseconds.
-		Weight::from_parts(27_828_030, 3854)
-			// Standard Error: 7_254
-			.saturating_add(Weight::from_parts(113_751, 0).saturating_mul(n.into()))
+		// Minimum execution time: 25_827_000 picoseconds.
+		Weight::from_parts(35_538_662, 3854)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -321,14 +435,12 @@ impl WeightInfo for () {
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
-	fn close_recovery(n: u32, ) -> Weight {
+	fn close_recovery(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `617 + n * (32 ±0)`
+		//  Measured:  `248 + n * (32 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 36_912_000 picoseconds.
-		Weight::from_parts(38_818_613, 3854)
-			// Standard Error: 7_806
-			.saturating_add(Weight::from_parts(149_710, 0).saturating_mul(n.into()))
+		// Minimum execution time: 39_862_000 picoseconds.
+		Weight::from_parts(42_591_422, 3854)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -339,12 +451,12 @@ impl WeightInfo for () {
 	/// The range of component `n` is `[1, 9]`.
 	fn remove_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `374 + n * (32 ±0)`
+		//  Measured:  `71 + n * (32 ±0)`
 		//  Estimated: `3854`
-		// Minimum execution time: 30_824_000 picoseconds.
-		Weight::from_parts(31_960_172, 3854)
-			// Standard Error: 10_777
-			.saturating_add(Weight::from_parts(237_226, 0).saturating_mul(n.into()))
+		// Minimum execution time: 31_416_000 picoseconds.
+		Weight::from_parts(33_554_023, 3854)
+			// Standard Error: 38_783
+			.saturating_add(Weight::from_parts(197_429, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -352,10 +464,10 @@ impl WeightInfo for () {
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
 	fn cancel_recovered() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `385`
+		//  Measured:  `85`
 		//  Estimated: `3545`
-		// Minimum execution time: 15_369_000 picoseconds.
-		Weight::from_parts(15_730_000, 3545)
+		// Minimum execution time: 11_107_000 picoseconds.
+		Weight::from_parts(11_669_000, 3545)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -364,13 +476,126 @@ impl WeightInfo for () {
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
-	fn poke_deposit(_n: u32, ) -> Weight {
+	fn poke_deposit(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1110`
+		//  Measured:  `815`
 		//  Estimated: `3854`
-		// Minimum execution time: 46_517_000 picoseconds.
-		Weight::from_parts(49_326_481, 3854)
+		// Minimum execution time: 51_918_000 picoseconds.
+		Weight::from_parts(53_950_366, 3854)
+			// Standard Error: 183_319
+			.saturating_add(Weight::from_parts(1_404_095, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:0)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritable` (r:1 w:1)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn set_inheritance(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3818`
+		// Minimum execution time: 31_967_000 picoseconds.
+		Weight::from_parts(35_522_283, 3818)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:0)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Inheritable` (r:1 w:1)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn remove_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110 + n * (32 ±0)`
+		//  Estimated: `3818`
+		// Minimum execution time: 32_297_000 picoseconds.
+		Weight::from_parts(34_807_233, 3818)
+			// Standard Error: 51_207
+			.saturating_add(Weight::from_parts(192_978, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::Inheritable` (r:1 w:0)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:0 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn heartbeat() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `3818`
+		// Minimum execution time: 11_020_000 picoseconds.
+		Weight::from_parts(11_586_000, 3818)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Recovery::Inheritable` (r:1 w:0)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:1 w:0)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn initiate_inheritance(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `360 + n * (32 ±0)`
+		//  Estimated: `3818`
+		// Minimum execution time: 64_960_000 picoseconds.
+		Weight::from_parts(69_338_019, 3818)
+			// Standard Error: 283_672
+			.saturating_add(Weight::from_parts(3_636_583, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:1 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn cancel_inheritance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `3593`
+		// Minimum execution time: 48_710_000 picoseconds.
+		Weight::from_parts(71_663_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Recovery::Inheritable` (r:1 w:0)
+	/// Proof: `Recovery::Inheritable` (`max_values`: None, `max_size`: Some(353), added: 2828, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::InheritanceClaims` (r:1 w:1)
+	/// Proof: `Recovery::InheritanceClaims` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::LastActive` (r:1 w:0)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn claim_inheritance(_n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `257 + n * (32 ±0)`
+		//  Estimated: `3818`
+		// Minimum execution time: 44_680_000 picoseconds.
+		Weight::from_parts(60_212_843, 3818)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::LastActive` (r:1 w:1)
+	/// Proof: `Recovery::LastActive` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn record_activity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `86`
+		//  Estimated: `3509`
+		// Minimum execution time: 7_998_000 picoseconds.
+		Weight::from_parts(9_839_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -124,7 +124,7 @@ mod remote_tests {
 		let mut ext = Builder::<Block>::default()
 			.mode(if let Some(state_snapshot) = maybe_state_snapshot {
 				Mode::OfflineOrElseOnline(
-					OfflineConfig { state_snapshot: state_snapshot.clone() },
+					OfflineConfig { state_snapshot: state_snapshot.clone(), ..Default::default() },
 					OnlineConfig {
 						transport,
 						state_snapshot: Some(state_snapshot),
@@ -1876,7 +1876,7 @@ mod remote_tests_local {
 
 		sp_tracing::try_init_simple();
 		let mode = Mode::OfflineOrElseOnline(
-			OfflineConfig { state_snapshot: snap.clone() },
+			OfflineConfig { state_snapshot: snap.clone(), ..Default::default() },
 			OnlineConfig { transport: ws_api, state_snapshot: Some(snap), ..Default::default() },
 		);
 
@@ -44,6 +44,7 @@ pub fn expand_runtime_metadata(
 			let name = &decl.name;
 			let index = &decl.index;
 			let storage = expand_pallet_metadata_storage(&filtered_names, runtime, decl);
+			let storage_version = expand_pallet_metadata_storage_version(runtime, decl);
 			let calls = expand_pallet_metadata_calls(&filtered_names, runtime, decl);
 			let view_functions = expand_pallet_metadata_view_functions(runtime, decl);
 			let event = expand_pallet_metadata_events(&filtered_names, runtime, decl);
@@ -59,6 +60,7 @@ pub fn expand_runtime_metadata(
 					name: stringify!(#name),
 					index: #index,
 					storage: #storage,
+					storage_version: #storage_version,
 					calls: #calls,
 					view_functions: #view_functions,
 					event: #event,
@@ -240,6 +242,13 @@ fn expand_pallet_metadata_events(
 	}
 }
 
+fn expand_pallet_metadata_storage_version(runtime: &Ident, decl: &Pallet) -> TokenStream {
+	let path = &decl.path;
+	let instance = decl.instance.as_ref().into_iter();
+
+	quote! { #path::Pallet::<#runtime #(, #path::#instance)*>::storage_version_metadata() }
+}
+
 fn expand_pallet_metadata_deprecation(runtime: &Ident, decl: &Pallet) -> TokenStream {
 	let path = &decl.path;
 	let instance = decl.instance.as_ref().into_iter();
@@ -1047,6 +1047,16 @@ pub fn disable_try_decode_storage(_: TokenStream, _: TokenStream) -> TokenStream
 	pallet_macro_stub()
 }
 
+///
+/// ---
+///
+/// Documentation for this macro can be found at
+/// `frame_support::pallet_macros::secondary_index`.
+#[proc_macro_attribute]
+pub fn secondary_index(_: TokenStream, _: TokenStream) -> TokenStream {
+	pallet_macro_stub()
+}
+
 ///
 /// ---
 ///
@@ -67,6 +67,30 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	};
 
+	let secondary_index_checks = def
+		.storages
+		.iter()
+		.filter(|storage| storage.secondary_index.is_some())
+		.map(|storage| {
+			let storage_ident = &storage.ident;
+			let storage_name = storage_ident.to_string();
+			let cfg_attrs = &storage.cfg_attrs;
+			quote::quote_spanned!(storage.attr_span =>
+				#(#cfg_attrs)*
+				if let Err(err) = <#storage_ident<#type_use_gen>>::try_state() {
+					#frame_support::__private::log::error!(
+						target: #frame_support::LOG_TARGET,
+						"❌ {:?} secondary index of {} is inconsistent: {:?}",
+						#pallet_name,
+						#storage_name,
+						err
+					);
+					return Err(err);
+				}
+			)
+		})
+		.collect::<Vec<_>>();
+
 	let log_runtime_upgrade = if has_runtime_upgrade {
 		// a migration is defined here.
 		quote::quote! {
@@ -320,6 +344,7 @@ pub fn expand_hooks(def: &mut Def) -> proc_macro2::TokenStream {
 						"🩺 Running {:?} try-state checks",
 						#pallet_name,
 					);
+					#( #secondary_index_checks )*
 					<
 						Self as #frame_support::traits::Hooks<
 							#frame_system::pallet_prelude::BlockNumberFor::<T>
@@ -170,13 +170,21 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	);
 
-	let (storage_version, in_code_storage_version_ty) =
+	let (storage_version, in_code_storage_version_ty, storage_version_metadata) =
 		if let Some(v) = def.pallet_struct.storage_version.as_ref() {
-			(quote::quote! { #v }, quote::quote! { #frame_support::traits::StorageVersion })
+			(
+				quote::quote! { #v },
+				quote::quote! { #frame_support::traits::StorageVersion },
+				quote::quote! {
+					let storage_version: #frame_support::traits::StorageVersion = #v;
+					core::option::Option::Some(storage_version.into())
+				},
+			)
 		} else {
 			(
 				quote::quote! { core::default::Default::default() },
 				quote::quote! { #frame_support::traits::NoStorageVersionSet },
+				quote::quote! { core::option::Option::None },
 			)
 		};
 
@@ -303,6 +311,12 @@ pub fn expand_pallet_struct(def: &mut Def) -> proc_macro2::TokenStream {
 			pub fn deprecation_info() -> #frame_support::__private::metadata_ir::ItemDeprecationInfoIR {
 				#deprecation_status
 			}
+
+			#[allow(dead_code)]
+			#[doc(hidden)]
+			pub fn storage_version_metadata() -> core::option::Option<u16> {
+				#storage_version_metadata
+			}
 		}
 	)
 }
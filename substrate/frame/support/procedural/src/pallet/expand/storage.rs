@@ -46,6 +46,13 @@ fn counter_prefix_ident(storage_ident: &syn::Ident) -> syn::Ident {
 	)
 }
 
+/// Generate the index_key_of_ident related to the storage.
+/// index_key_of_ident is used for the struct extracting the index key of the values of a storage
+/// with a secondary index.
+fn index_key_of_ident(storage_ident: &syn::Ident) -> syn::Ident {
+	syn::Ident::new(&format!("_GeneratedIndexKeyOfStorage{}", storage_ident), storage_ident.span())
+}
+
 /// Check for duplicated storage prefixes. This step is necessary since users can specify an
 /// alternative storage prefix using the #[pallet::storage_prefix] syntax, and we need to ensure
 /// that the prefix specified by the user is not a duplicate of an existing one.
@@ -106,6 +113,7 @@ pub struct ResultOnEmptyStructMetadata {
 /// * if generics are unnamed: replace the first generic `_` by the generated prefix structure
 /// * if generics are named: reorder the generic, remove their name, and add the missing ones.
 /// * Add `#[allow(type_alias_bounds)]`
+/// * if the storage has a secondary index: wrap the storage type in `IndexedStorage`
 pub fn process_generics(def: &mut Def) -> syn::Result<Vec<ResultOnEmptyStructMetadata>> {
 	let frame_support = &def.frame_support;
 	let mut on_empty_struct_metadata = Vec::new();
@@ -258,6 +266,29 @@ pub fn process_generics(def: &mut Def) -> syn::Result<Vec<ResultOnEmptyStructMet
 					let max_values = max_values.unwrap_or_else(|| default_max_values.clone());
 					args.args.push(syn::GenericArgument::Type(max_values));
 				},
+				StorageGenerics::ChildMap {
+					child_hasher,
+					child_id,
+					hasher,
+					key,
+					value,
+					query_kind,
+					on_empty,
+					max_values,
+				} => {
+					args.args.push(syn::GenericArgument::Type(child_hasher));
+					args.args.push(syn::GenericArgument::Type(child_id));
+					args.args.push(syn::GenericArgument::Type(hasher));
+					args.args.push(syn::GenericArgument::Type(key));
+					args.args.push(syn::GenericArgument::Type(value.clone()));
+					let mut query_kind = query_kind.unwrap_or_else(|| default_query_kind.clone());
+					set_result_query_type_parameter(&mut query_kind)?;
+					args.args.push(syn::GenericArgument::Type(query_kind));
+					let on_empty = on_empty.unwrap_or_else(|| default_on_empty(value));
+					args.args.push(syn::GenericArgument::Type(on_empty));
+					let max_values = max_values.unwrap_or_else(|| default_max_values.clone());
+					args.args.push(syn::GenericArgument::Type(max_values));
+				},
 			}
 		} else {
 			args.args[0] = syn::parse_quote!( #prefix_ident<#type_use_gen> );
@@ -266,13 +297,13 @@ pub fn process_generics(def: &mut Def) -> syn::Result<Vec<ResultOnEmptyStructMet
 				Metadata::Value { .. } => (1, 2, 3),
 				Metadata::NMap { .. } | Metadata::CountedNMap { .. } => (2, 3, 4),
 				Metadata::Map { .. } | Metadata::CountedMap { .. } => (3, 4, 5),
-				Metadata::DoubleMap { .. } => (5, 6, 7),
+				Metadata::DoubleMap { .. } | Metadata::ChildMap { .. } => (5, 6, 7),
 			};
 
 			if storage_def.use_default_hasher {
 				let hasher_indices: Vec<usize> = match storage_def.metadata {
 					Metadata::Map { .. } | Metadata::CountedMap { .. } => vec![1],
-					Metadata::DoubleMap { .. } => vec![1, 3],
+					Metadata::DoubleMap { .. } | Metadata::ChildMap { .. } => vec![1, 3],
 					_ => vec![],
 				};
 				for hasher_idx in hasher_indices {
@@ -305,6 +336,20 @@ pub fn process_generics(def: &mut Def) -> syn::Result<Vec<ResultOnEmptyStructMet
 				args.args.push(syn::GenericArgument::Type(on_empty));
 			}
 		}
+
+		if let Some(secondary_index) = storage_def.secondary_index.as_ref() {
+			let map = &typ_item.ty;
+			let index = &secondary_index.index;
+			let index_key_of = index_key_of_ident(&storage_def.ident);
+			let indexed_map = syn::parse_quote!(
+				#frame_support::storage::types::IndexedStorage<
+					#map,
+					#index,
+					#index_key_of<#type_use_gen>,
+				>
+			);
+			*typ_item.ty = indexed_map;
+		}
 	}
 
 	Ok(on_empty_struct_metadata)
@@ -377,6 +422,15 @@ fn augment_final_docs(def: &mut Def) {
 			);
 			push_string_literal(&doc_line, storage);
 		},
+		Metadata::ChildMap { child_id, key, value } => {
+			let doc_line = format!(
+				"Storage type is [`StorageChildMap`] with child id type {}, key type {} and value type {}.",
+				child_id.to_token_stream(),
+				key.to_token_stream(),
+				value.to_token_stream()
+			);
+			push_string_literal(&doc_line, storage);
+		},
 	});
 }
 
@@ -645,6 +699,34 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 						}
 					)
 				},
+				Metadata::ChildMap { child_id, key, value } => {
+					let query = match storage.query_kind.as_ref().expect("Checked by def") {
+						QueryKind::OptionQuery => quote::quote_spanned!(storage.attr_span =>
+							Option<#value>
+						),
+						QueryKind::ResultQuery(error_path, _) => {
+							quote::quote_spanned!(storage.attr_span =>
+								Result<#value, #error_path>
+							)
+						},
+						QueryKind::ValueQuery => quote::quote!(#value),
+					};
+					quote::quote_spanned!(storage.attr_span =>
+						#(#cfg_attrs)*
+						impl<#type_impl_gen> #pallet_ident<#type_use_gen> #completed_where_clause {
+							#[doc = #getter_doc_line]
+							#(#maybe_allow_attrs)*
+							pub fn #getter<KArg1, KArg2>(child_id: KArg1, key: KArg2) -> #query where
+								KArg1: #frame_support::__private::codec::EncodeLike<#child_id>,
+								KArg2: #frame_support::__private::codec::EncodeLike<#key>,
+							{
+								// NOTE: we can't use any trait here because StorageChildMap
+								// doesn't implement any.
+								<#full_ident>::get(child_id, key)
+							}
+						}
+					)
+				},
 			}
 		} else {
 			Default::default()
@@ -753,9 +835,43 @@ pub fn expand_storages(def: &mut Def) -> proc_macro2::TokenStream {
 			_ => proc_macro2::TokenStream::default(),
 		};
 
+		let maybe_index_key_of = match (&storage_def.secondary_index, &storage_def.metadata) {
+			(Some(secondary_index), Metadata::Map { value, .. } | Metadata::NMap { value, .. }) => {
+				let index_key_of_ident = index_key_of_ident(&storage_def.ident);
+				let index = &secondary_index.index;
+				let field = &secondary_index.field;
+				let where_clause = super::merge_where_clauses(&[
+					&def.config.where_clause,
+					&storage_def.where_clause,
+				]);
+				let index_key = quote::quote_spanned!(storage_def.attr_span =>
+					<#index as #frame_support::storage::types::SecondaryIndex>::IndexKey
+				);
+				quote::quote_spanned!(storage_def.attr_span =>
+					#(#cfg_attrs)*
+					#[doc(hidden)]
+					#prefix_struct_vis struct #index_key_of_ident<#type_use_gen>(
+						core::marker::PhantomData<(#type_use_gen,)>
+					);
+					#(#cfg_attrs)*
+					impl<#type_impl_gen> #frame_support::storage::types::IndexKeyOf<#value, #index_key>
+						for #index_key_of_ident<#type_use_gen>
+						#where_clause
+					{
+						#[allow(clippy::clone_on_copy)]
+						fn index_key(value: &#value) -> Option<#index_key> {
+							Some(value.#field.clone())
+						}
+					}
+				)
+			},
+			_ => proc_macro2::TokenStream::default(),
+		};
+
 		let storage_prefix_hash = two128_str(&prefix_struct_const);
 		quote::quote_spanned!(storage_def.attr_span =>
 			#maybe_counter
+			#maybe_index_key_of
 
 			#(#cfg_attrs)*
 			#[doc(hidden)]
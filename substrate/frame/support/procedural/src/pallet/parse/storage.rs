@@ -30,6 +30,7 @@ mod keyword {
 	syn::custom_keyword!(unbounded);
 	syn::custom_keyword!(whitelist_storage);
 	syn::custom_keyword!(disable_try_decode_storage);
+	syn::custom_keyword!(secondary_index);
 	syn::custom_keyword!(OptionQuery);
 	syn::custom_keyword!(ResultQuery);
 	syn::custom_keyword!(ValueQuery);
@@ -41,12 +42,14 @@ mod keyword {
 /// * `#[pallet::unbounded]`
 /// * `#[pallet::whitelist_storage]
 /// * `#[pallet::disable_try_decode_storage]`
+/// * `#[pallet::secondary_index(IndexStorage, field)]`
 pub enum PalletStorageAttr {
 	Getter(syn::Ident, proc_macro2::Span),
 	StorageName(syn::LitStr, proc_macro2::Span),
 	Unbounded(proc_macro2::Span),
 	WhitelistStorage(proc_macro2::Span),
 	DisableTryDecodeStorage(proc_macro2::Span),
+	SecondaryIndex(SecondaryIndexDef, proc_macro2::Span),
 }
 
 impl PalletStorageAttr {
@@ -57,6 +60,7 @@ impl PalletStorageAttr {
 			Self::Unbounded(span) |
 			Self::WhitelistStorage(span) => *span,
 			Self::DisableTryDecodeStorage(span) => *span,
+			Self::SecondaryIndex(_, span) => *span,
 		}
 	}
 }
@@ -100,6 +104,15 @@ impl syn::parse::Parse for PalletStorageAttr {
 		} else if lookahead.peek(keyword::disable_try_decode_storage) {
 			content.parse::<keyword::disable_try_decode_storage>()?;
 			Ok(Self::DisableTryDecodeStorage(attr_span))
+		} else if lookahead.peek(keyword::secondary_index) {
+			content.parse::<keyword::secondary_index>()?;
+
+			let index_content;
+			syn::parenthesized!(index_content in content);
+			let index = index_content.parse::<syn::Type>()?;
+			index_content.parse::<syn::Token![,]>()?;
+			let field = index_content.parse::<syn::Member>()?;
+			Ok(Self::SecondaryIndex(SecondaryIndexDef { index, field }, attr_span))
 		} else {
 			Err(lookahead.error())
 		}
@@ -112,6 +125,7 @@ struct PalletStorageAttrInfo {
 	unbounded: bool,
 	whitelisted: bool,
 	try_decode: bool,
+	secondary_index: Option<SecondaryIndexDef>,
 }
 
 impl PalletStorageAttrInfo {
@@ -121,6 +135,7 @@ impl PalletStorageAttrInfo {
 		let mut unbounded = false;
 		let mut whitelisted = false;
 		let mut disable_try_decode_storage = false;
+		let mut secondary_index = None;
 		for attr in attrs {
 			match attr {
 				PalletStorageAttr::Getter(ident, ..) if getter.is_none() => getter = Some(ident),
@@ -130,6 +145,8 @@ impl PalletStorageAttrInfo {
 				PalletStorageAttr::WhitelistStorage(..) if !whitelisted => whitelisted = true,
 				PalletStorageAttr::DisableTryDecodeStorage(..) if !disable_try_decode_storage =>
 					disable_try_decode_storage = true,
+				PalletStorageAttr::SecondaryIndex(index, ..) if secondary_index.is_none() =>
+					secondary_index = Some(index),
 				attr =>
 					return Err(syn::Error::new(
 						attr.attr_span(),
@@ -144,6 +161,7 @@ impl PalletStorageAttrInfo {
 			unbounded,
 			whitelisted,
 			try_decode: !disable_try_decode_storage,
+			secondary_index,
 		})
 	}
 }
@@ -156,6 +174,16 @@ pub enum Metadata {
 	DoubleMap { value: syn::Type, key1: syn::Type, key2: syn::Type },
 	NMap { keys: Vec<syn::Type>, keygen: syn::Type, value: syn::Type },
 	CountedNMap { keys: Vec<syn::Type>, keygen: syn::Type, value: syn::Type },
+	ChildMap { value: syn::Type, child_id: syn::Type, key: syn::Type },
+}
+
+/// The definition of a `#[pallet::secondary_index(IndexStorage, field)]` attribute.
+#[derive(Clone)]
+pub struct SecondaryIndexDef {
+	/// The storage holding the index.
+	pub index: syn::Type,
+	/// The field of the values the map is indexed by.
+	pub field: syn::Member,
 }
 
 pub enum QueryKind {
@@ -207,6 +235,8 @@ pub struct StorageDef {
 	pub try_decode: bool,
 	/// Whether or not a default hasher is allowed to replace `_`
 	pub use_default_hasher: bool,
+	/// The secondary index kept in sync with the values of the map, if any.
+	pub secondary_index: Option<SecondaryIndexDef>,
 	/// Attributes
 	pub attrs: Vec<syn::Attribute>,
 }
@@ -259,6 +289,16 @@ pub enum StorageGenerics {
 		on_empty: Option<syn::Type>,
 		max_values: Option<syn::Type>,
 	},
+	ChildMap {
+		child_hasher: syn::Type,
+		child_id: syn::Type,
+		hasher: syn::Type,
+		key: syn::Type,
+		value: syn::Type,
+		query_kind: Option<syn::Type>,
+		on_empty: Option<syn::Type>,
+		max_values: Option<syn::Type>,
+	},
 }
 
 impl StorageGenerics {
@@ -273,6 +313,8 @@ impl StorageGenerics {
 				Metadata::NMap { keys: collect_keys(&keygen)?, keygen, value },
 			Self::CountedNMap { keygen, value, .. } =>
 				Metadata::CountedNMap { keys: collect_keys(&keygen)?, keygen, value },
+			Self::ChildMap { value, child_id, key, .. } =>
+				Metadata::ChildMap { value, child_id, key },
 		};
 
 		Ok(res)
@@ -286,7 +328,8 @@ impl StorageGenerics {
 			Self::CountedMap { query_kind, .. } |
 			Self::Value { query_kind, .. } |
 			Self::NMap { query_kind, .. } |
-			Self::CountedNMap { query_kind, .. } => query_kind.clone(),
+			Self::CountedNMap { query_kind, .. } |
+			Self::ChildMap { query_kind, .. } => query_kind.clone(),
 		}
 	}
 }
@@ -298,6 +341,7 @@ enum StorageKind {
 	DoubleMap,
 	NMap,
 	CountedNMap,
+	ChildMap,
 }
 
 /// Check the generics in the `map` contains the generics in `gen` may contains generics in
@@ -549,6 +593,48 @@ fn process_named_generics(
 				max_values: parsed.remove("MaxValues").map(|binding| binding.ty),
 			}
 		},
+		StorageKind::ChildMap => {
+			let mut child_map_mandatory_generics = vec!["ChildId", "Key", "Value"];
+			if dev_mode {
+				map_optional_generics.extend(["ChildHasher", "Hasher"]);
+			} else {
+				child_map_mandatory_generics.extend(["ChildHasher", "Hasher"]);
+			}
+
+			check_generics(
+				&parsed,
+				&child_map_mandatory_generics,
+				&map_optional_generics,
+				"StorageChildMap",
+				args_span,
+			)?;
+
+			StorageGenerics::ChildMap {
+				child_hasher: parsed
+					.remove("ChildHasher")
+					.map(|binding| binding.ty)
+					.unwrap_or(syn::parse_quote!(Blake2_128Concat)),
+				child_id: parsed
+					.remove("ChildId")
+					.map(|binding| binding.ty)
+					.expect("checked above as mandatory generic"),
+				hasher: parsed
+					.remove("Hasher")
+					.map(|binding| binding.ty)
+					.unwrap_or(syn::parse_quote!(Blake2_128Concat)),
+				key: parsed
+					.remove("Key")
+					.map(|binding| binding.ty)
+					.expect("checked above as mandatory generic"),
+				value: parsed
+					.remove("Value")
+					.map(|binding| binding.ty)
+					.expect("checked above as mandatory generic"),
+				query_kind: parsed.remove("QueryKind").map(|binding| binding.ty),
+				on_empty: parsed.remove("OnEmpty").map(|binding| binding.ty),
+				max_values: parsed.remove("MaxValues").map(|binding| binding.ty),
+			}
+		},
 	};
 
 	let metadata = generics.metadata()?;
@@ -644,6 +730,16 @@ fn process_unnamed_generics(
 				false,
 			)
 		},
+		StorageKind::ChildMap => (
+			None,
+			Metadata::ChildMap {
+				child_id: retrieve_arg(2)?,
+				key: retrieve_arg(4)?,
+				value: retrieve_arg(5)?,
+			},
+			retrieve_arg(6).ok(),
+			use_default_hasher(1)? && use_default_hasher(3)?,
+		),
 	};
 
 	Ok(res)
@@ -661,11 +757,12 @@ fn process_generics(
 		"StorageDoubleMap" => StorageKind::DoubleMap,
 		"StorageNMap" => StorageKind::NMap,
 		"CountedStorageNMap" => StorageKind::CountedNMap,
+		"StorageChildMap" => StorageKind::ChildMap,
 		found => {
 			let msg = format!(
 				"Invalid pallet::storage, expected ident: `StorageValue` or \
 				`StorageMap` or `CountedStorageMap` or `StorageDoubleMap` or `StorageNMap` or `CountedStorageNMap` \
-				in order to expand metadata, found `{}`.",
+				or `StorageChildMap` in order to expand metadata, found `{}`.",
 				found,
 			);
 			return Err(syn::Error::new(segment.ident.span(), msg));
@@ -796,8 +893,14 @@ impl StorageDef {
 		};
 
 		let attrs: Vec<PalletStorageAttr> = helper::take_item_pallet_attrs(&mut item.attrs)?;
-		let PalletStorageAttrInfo { getter, rename_as, mut unbounded, whitelisted, try_decode } =
-			PalletStorageAttrInfo::from_attrs(attrs)?;
+		let PalletStorageAttrInfo {
+			getter,
+			rename_as,
+			mut unbounded,
+			whitelisted,
+			try_decode,
+			secondary_index,
+		} = PalletStorageAttrInfo::from_attrs(attrs)?;
 
 		// set all storages to be unbounded if dev_mode is enabled
 		unbounded |= dev_mode;
@@ -823,6 +926,20 @@ impl StorageDef {
 		let (named_generics, metadata, query_kind, use_default_hasher) =
 			process_generics(&typ.path.segments[0], dev_mode)?;
 
+		if let Some(secondary_index) = secondary_index.as_ref() {
+			if !matches!(metadata, Metadata::Map { .. } | Metadata::NMap { .. }) {
+				let msg =
+					"Invalid pallet::secondary_index, only `StorageMap` and `StorageNMap` can \
+					have a secondary index";
+				return Err(syn::Error::new(secondary_index.index.span(), msg));
+			}
+			if let Some(getter) = getter.as_ref() {
+				let msg = "Invalid pallet::storage, cannot generate getter for a storage with a \
+					secondary index";
+				return Err(syn::Error::new(getter.span(), msg));
+			}
+		}
+
 		let query_kind = query_kind
 			.map(|query_kind| {
 				use syn::{
@@ -944,6 +1061,7 @@ impl StorageDef {
 			whitelisted,
 			try_decode,
 			use_default_hasher,
+			secondary_index,
 			attrs: item.attrs.clone(),
 		})
 	}
@@ -909,7 +909,8 @@ pub mod pallet_prelude {
 			bounded_vec::BoundedVec,
 			types::{
 				CountedStorageMap, CountedStorageNMap, Key as NMapKey, OptionQuery, ResultQuery,
-				StorageDoubleMap, StorageMap, StorageNMap, StorageValue, ValueQuery,
+				StorageChildMap, StorageDoubleMap, StorageMap, StorageNMap, StorageValue,
+				ValueQuery,
 			},
 			weak_bounded_vec::WeakBoundedVec,
 			StorageList,
@@ -1196,6 +1197,54 @@ pub mod pallet_macros {
 	/// ```
 	pub use frame_support_procedural::disable_try_decode_storage;
 
+	/// Keeps a secondary index of a storage map in sync with the values of the map.
+	///
+	/// The attribute `#[pallet::secondary_index(IndexStorage, field)]` can be attached to a
+	/// [`StorageMap`](frame_support::storage::types::StorageMap) or
+	/// [`StorageNMap`](frame_support::storage::types::StorageNMap). Every value of the map is
+	/// indexed under its `field` in `IndexStorage`, a
+	/// [`StorageDoubleMap`](frame_support::storage::types::StorageDoubleMap) of the pallet with
+	/// the index key as first key, the key of the map as second key and `()` as value.
+	///
+	/// The storage type is wrapped in
+	/// [`IndexedStorage`](frame_support::storage::types::IndexedStorage), whose `insert`,
+	/// `mutate` and `remove` functions update the index whenever the `field` of a value changes.
+	/// The keys of all values with a given `field` can be enumerated with `iter_keys_by_index`.
+	/// With the `try-runtime` feature, the `try_state` hook of the pallet checks that the index is
+	/// consistent with the map before running the checks of the pallet.
+	///
+	/// The map must only be written to through the wrapper. A storage with a secondary index can
+	/// not have a [`macro@getter`].
+	///
+	/// ### Example
+	/// ```
+	/// #[frame_support::pallet]
+	/// mod pallet {
+	/// # 	use frame_support::pallet_prelude::*;
+	/// #
+	/// 	#[pallet::pallet]
+	/// 	pub struct Pallet<T>(_);
+	///
+	/// 	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Debug)]
+	/// 	pub struct Item<AccountId> {
+	/// 		pub owner: AccountId,
+	/// 		pub value: u32,
+	/// 	}
+	///
+	/// 	#[pallet::storage]
+	/// 	#[pallet::secondary_index(ItemsByOwner<T>, owner)]
+	/// 	pub type Items<T: Config> = StorageMap<_, Blake2_128Concat, u32, Item<T::AccountId>>;
+	///
+	/// 	#[pallet::storage]
+	/// 	pub type ItemsByOwner<T: Config> =
+	/// 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, u32, ()>;
+	/// #
+	/// # 	#[pallet::config]
+	/// # 	pub trait Config: frame_system::Config {}
+	/// }
+	/// ```
+	pub use frame_support_procedural::secondary_index;
+
 	/// Declares a storage as unbounded in potential size.
 	///
 	/// When implementing the storage info (when `#[pallet::generate_storage_info]` is
@@ -2479,6 +2528,7 @@ pub mod pallet_macros {
 	/// * [`StorageDoubleMap`](crate::storage::types::StorageDoubleMap)
 	/// * [`StorageNMap`](crate::storage::types::StorageNMap)
 	/// * [`CountedStorageNMap`](crate::storage::types::CountedStorageNMap)
+	/// * [`StorageChildMap`](crate::storage::types::StorageChildMap)
 	///
 	/// ## Storage Type Usage
 	///
@@ -2657,6 +2707,7 @@ pub mod pallet_macros {
 	/// * [`macro@unbounded`]: Declares the storage item as unbounded.
 	/// * [`macro@disable_try_decode_storage`]: Declares that try-runtime checks should not
 	///   attempt to decode the storage item.
+	/// * [`macro@secondary_index`]: Keeps a secondary index in sync with the values of a map.
 	///
 	/// #### Example
 	/// ```
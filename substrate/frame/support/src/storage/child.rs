@@ -191,11 +191,54 @@ pub fn clear_storage(
 	use sp_io::KillStorageResult::*;
 	let (maybe_cursor, backend) = match r {
 		AllRemoved(db) => (None, db),
-		SomeRemaining(db) => (Some(child_info.storage_key().to_vec()), db),
+		SomeRemaining(db) => (first_key_with_prefix(child_info, &[]), db),
 	};
 	MultiRemovalResults { maybe_cursor, backend, unique: backend, loops: backend }
 }
 
+/// Partially clear the child storage of each key-value pair where the key starts with `prefix`.
+///
+/// See [`clear_storage`] for the semantics of `maybe_limit` and `maybe_cursor`, which apply to the
+/// keys starting with `prefix` here.
+pub fn clear_prefix(
+	child_info: &ChildInfo,
+	prefix: &[u8],
+	maybe_limit: Option<u32>,
+	_maybe_cursor: Option<&[u8]>,
+) -> MultiRemovalResults {
+	// TODO: Once the network has upgraded to include the new host functions, this code can be
+	// enabled.
+	// sp_io::default_child_storage::clear_prefix(storage_key, prefix, maybe_limit, maybe_cursor)
+	let r = match child_info.child_type() {
+		ChildType::ParentKeyId => sp_io::default_child_storage::clear_prefix(
+			child_info.storage_key(),
+			prefix,
+			maybe_limit,
+		),
+	};
+	use sp_io::KillStorageResult::*;
+	let (maybe_cursor, backend) = match r {
+		AllRemoved(db) => (None, db),
+		SomeRemaining(db) => (first_key_with_prefix(child_info, prefix), db),
+	};
+	MultiRemovalResults { maybe_cursor, backend, unique: backend, loops: backend }
+}
+
+/// The first key starting with `prefix` which is left in the child storage.
+///
+/// The host functions do not hand out a cursor yet, so this is the cursor returned when a removal
+/// is not complete.
+fn first_key_with_prefix(child_info: &ChildInfo, prefix: &[u8]) -> Option<Vec<u8>> {
+	if exists(child_info, prefix) {
+		return Some(prefix.to_vec())
+	}
+	match child_info.child_type() {
+		ChildType::ParentKeyId =>
+			sp_io::default_child_storage::next_key(child_info.storage_key(), prefix)
+				.filter(|key| key.starts_with(prefix)),
+	}
+}
+
 /// Ensure `key` has no explicit entry in storage.
 pub fn kill(child_info: &ChildInfo, key: &[u8]) {
 	match child_info.child_type() {
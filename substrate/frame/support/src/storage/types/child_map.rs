@@ -0,0 +1,658 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage child map type. Stores the entries of a map in child tries, one child trie per child
+//! trie identifier.
+
+use crate::{
+	storage::{
+		child::{self, ChildInfo},
+		types::{OptionQuery, QueryKindTrait, StorageEntryMetadataBuilder},
+		ChildTriePrefixIterator,
+	},
+	traits::{Get, GetDefault, StorageInfo, StorageInstance},
+	ReversibleStorageHasher, StorageHasher,
+};
+use alloc::{vec, vec::Vec};
+use codec::{Decode, Encode, EncodeLike, FullCodec, MaxEncodedLen};
+use sp_arithmetic::traits::SaturatedConversion;
+use sp_metadata_ir::{StorageEntryMetadataIR, StorageEntryTypeIR};
+
+/// A type representing a *child map* in storage. A *storage child map* is a mapping of keys to
+/// values, where the entries are stored in a child trie selected by a child trie identifier,
+/// instead of in the main trie.
+///
+/// This gives every child trie identifier (e.g. an account or a fund index) its own isolated
+/// storage, whose root alone is stored in the main trie and which can be removed at once with
+/// [`Self::kill`].
+///
+/// Each child map has its own child trie per child trie identifier, under the storage key
+/// `Twox128(pallet_prefix) ++ Twox128(storage_prefix) ++ ChildHasher(child_id)`. Within the child
+/// trie, the entries are stored under `Hasher(key)`.
+///
+/// In the runtime metadata, a child map is described as a map keyed by `(child_id, key)` with the
+/// hashers `[ChildHasher, Hasher]`.
+///
+/// For general information regarding the `#[pallet::storage]` attribute, refer to
+/// [`crate::pallet_macros::storage`].
+///
+/// # Example
+///
+/// ```
+/// #[frame_support::pallet]
+/// mod pallet {
+///     # use frame_support::pallet_prelude::*;
+///     # #[pallet::config]
+///     # pub trait Config: frame_system::Config {}
+///     # #[pallet::pallet]
+///     # pub struct Pallet<T>(_);
+/// 	/// Contributions to a fund, stored in a child trie per fund index.
+///     #[pallet::storage]
+///     pub type Contributions<T: Config> = StorageChildMap<
+/// 		_,
+/// 		Twox64Concat,
+/// 		u32,
+/// 		Blake2_128Concat,
+/// 		T::AccountId,
+/// 		u64,
+/// 		ValueQuery,
+/// 	>;
+///
+/// 	/// Alternative named syntax.
+///     #[pallet::storage]
+///     pub type Memos<T: Config> = StorageChildMap<
+/// 		ChildHasher = Twox64Concat,
+/// 		ChildId = u32,
+/// 		Hasher = Blake2_128Concat,
+/// 		Key = T::AccountId,
+/// 		Value = BoundedVec<u8, ConstU32<32>>,
+/// 	>;
+/// }
+/// ```
+pub struct StorageChildMap<
+	Prefix,
+	ChildHasher,
+	ChildId,
+	Hasher,
+	Key,
+	Value,
+	QueryKind = OptionQuery,
+	OnEmpty = GetDefault,
+	MaxValues = GetDefault,
+>(
+	core::marker::PhantomData<(
+		Prefix,
+		ChildHasher,
+		ChildId,
+		Hasher,
+		Key,
+		Value,
+		QueryKind,
+		OnEmpty,
+		MaxValues,
+	)>,
+);
+
+impl<Prefix, ChildHasher, ChildId, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+	StorageChildMap<Prefix, ChildHasher, ChildId, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+where
+	Prefix: StorageInstance,
+	ChildHasher: StorageHasher,
+	ChildId: FullCodec,
+	Hasher: StorageHasher,
+	Key: FullCodec,
+	Value: FullCodec,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	/// Get the child trie holding the entries of the given child trie identifier.
+	pub fn child_info<ChildIdArg: EncodeLike<ChildId>>(child_id: ChildIdArg) -> ChildInfo {
+		let mut storage_key = Prefix::prefix_hash().to_vec();
+		storage_key.extend_from_slice(child_id.using_encoded(ChildHasher::hash).as_ref());
+		ChildInfo::new_default(&storage_key)
+	}
+
+	/// Get the key within the child trie used to fetch a value corresponding to a specific key.
+	pub fn hashed_key_for<KeyArg: EncodeLike<Key>>(key: KeyArg) -> Vec<u8> {
+		key.using_encoded(Hasher::hash).as_ref().to_vec()
+	}
+
+	/// Does the value (explicitly) exist in storage?
+	pub fn contains_key<ChildIdArg, KeyArg>(child_id: ChildIdArg, key: KeyArg) -> bool
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+	{
+		child::exists(&Self::child_info(child_id), &Self::hashed_key_for(key))
+	}
+
+	/// Load the value associated with the given key from the map.
+	pub fn get<ChildIdArg, KeyArg>(child_id: ChildIdArg, key: KeyArg) -> QueryKind::Query
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+	{
+		QueryKind::from_optional_value_to_query(child::get(
+			&Self::child_info(child_id),
+			&Self::hashed_key_for(key),
+		))
+	}
+
+	/// Try to get the value for the given key from the map.
+	///
+	/// Returns `Ok` if it exists, `Err` if not.
+	pub fn try_get<ChildIdArg, KeyArg>(child_id: ChildIdArg, key: KeyArg) -> Result<Value, ()>
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+	{
+		child::get(&Self::child_info(child_id), &Self::hashed_key_for(key)).ok_or(())
+	}
+
+	/// Store or remove the value to be associated with `key` so that `get` returns the `query`.
+	pub fn set<ChildIdArg, KeyArg>(child_id: ChildIdArg, key: KeyArg, query: QueryKind::Query)
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+	{
+		let child_info = Self::child_info(child_id);
+		let key = Self::hashed_key_for(key);
+		match QueryKind::from_query_to_optional_value(query) {
+			Some(value) => child::put(&child_info, &key, &value),
+			None => child::kill(&child_info, &key),
+		}
+	}
+
+	/// Store a value to be associated with the given key from the map.
+	pub fn insert<ChildIdArg, KeyArg, ValArg>(child_id: ChildIdArg, key: KeyArg, val: ValArg)
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+		ValArg: EncodeLike<Value>,
+	{
+		let child_info = Self::child_info(child_id);
+		val.using_encoded(|val| child::put_raw(&child_info, &Self::hashed_key_for(key), val))
+	}
+
+	/// Remove the value under a key.
+	pub fn remove<ChildIdArg, KeyArg>(child_id: ChildIdArg, key: KeyArg)
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+	{
+		child::kill(&Self::child_info(child_id), &Self::hashed_key_for(key))
+	}
+
+	/// Mutate the value under a key.
+	pub fn mutate<ChildIdArg, KeyArg, R, F>(child_id: ChildIdArg, key: KeyArg, f: F) -> R
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+		F: FnOnce(&mut QueryKind::Query) -> R,
+	{
+		Self::try_mutate(child_id, key, |v| Ok::<R, core::convert::Infallible>(f(v)))
+			.expect("`Never` can not be constructed; qed")
+	}
+
+	/// Mutate the item, only if an `Ok` value is returned.
+	pub fn try_mutate<ChildIdArg, KeyArg, R, E, F>(
+		child_id: ChildIdArg,
+		key: KeyArg,
+		f: F,
+	) -> Result<R, E>
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+		F: FnOnce(&mut QueryKind::Query) -> Result<R, E>,
+	{
+		let child_info = Self::child_info(child_id);
+		let key = Self::hashed_key_for(key);
+		let mut val = QueryKind::from_optional_value_to_query(child::get(&child_info, &key));
+
+		let ret = f(&mut val);
+		if ret.is_ok() {
+			match QueryKind::from_query_to_optional_value(val) {
+				Some(ref val) => child::put(&child_info, &key, val),
+				None => child::kill(&child_info, &key),
+			}
+		}
+		ret
+	}
+
+	/// Mutate the value under a key. Deletes the item if mutated to a `None`.
+	pub fn mutate_exists<ChildIdArg, KeyArg, R, F>(child_id: ChildIdArg, key: KeyArg, f: F) -> R
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+		F: FnOnce(&mut Option<Value>) -> R,
+	{
+		Self::try_mutate_exists(child_id, key, |v| Ok::<R, core::convert::Infallible>(f(v)))
+			.expect("`Never` can not be constructed; qed")
+	}
+
+	/// Mutate the item, only if an `Ok` value is returned. Deletes the item if mutated to a `None`.
+	/// `f` will always be called with an option representing if the storage item exists (`Some<V>`)
+	/// or if the storage item does not exist (`None`), independent of the `QueryType`.
+	pub fn try_mutate_exists<ChildIdArg, KeyArg, R, E, F>(
+		child_id: ChildIdArg,
+		key: KeyArg,
+		f: F,
+	) -> Result<R, E>
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+		F: FnOnce(&mut Option<Value>) -> Result<R, E>,
+	{
+		let child_info = Self::child_info(child_id);
+		let key = Self::hashed_key_for(key);
+		let mut val = child::get(&child_info, &key);
+
+		let ret = f(&mut val);
+		if ret.is_ok() {
+			match val {
+				Some(ref val) => child::put(&child_info, &key, val),
+				None => child::kill(&child_info, &key),
+			}
+		}
+		ret
+	}
+
+	/// Take the value under a key.
+	pub fn take<ChildIdArg, KeyArg>(child_id: ChildIdArg, key: KeyArg) -> QueryKind::Query
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		KeyArg: EncodeLike<Key>,
+	{
+		QueryKind::from_optional_value_to_query(child::take(
+			&Self::child_info(child_id),
+			&Self::hashed_key_for(key),
+		))
+	}
+
+	/// Attempt to remove all the entries of the map for the given child trie identifier, iterating
+	/// over the keys of its child trie.
+	///
+	/// See [`Self::kill`] for the meaning of `limit` and `maybe_cursor`, and of the returned
+	/// [`MultiRemovalResults`](sp_io::MultiRemovalResults).
+	pub fn clear_prefix<ChildIdArg: EncodeLike<ChildId>>(
+		child_id: ChildIdArg,
+		limit: u32,
+		maybe_cursor: Option<&[u8]>,
+	) -> sp_io::MultiRemovalResults {
+		child::clear_prefix(&Self::child_info(child_id), &[], Some(limit), maybe_cursor)
+	}
+
+	/// Attempt to remove the child trie of the given child trie identifier, and with it all the
+	/// entries of the map for this child trie identifier.
+	///
+	/// Returns [`MultiRemovalResults`](sp_io::MultiRemovalResults) to inform about the result. Once
+	/// the resultant `maybe_cursor` field is `None`, then no further items remain to be deleted.
+	///
+	/// NOTE: After the initial call for any given child trie, it is important that no further items
+	/// are inserted into it. If so, then the map may not be empty when the resultant
+	/// `maybe_cursor` is `None`.
+	///
+	/// # Limit
+	///
+	/// A `limit` must always be provided through in order to cap the maximum
+	/// amount of deletions done in a single call. This is one fewer than the
+	/// maximum number of backend iterations which may be done by this operation and as such
+	/// represents the maximum number of backend deletions which may happen. A `limit` of zero
+	/// implies that no keys will be deleted, though there may be a single iteration done.
+	///
+	/// # Cursor
+	///
+	/// A *cursor* may be passed in to this operation with `maybe_cursor`. `None` should only be
+	/// passed once (in the initial call) for any given child trie. Subsequent calls
+	/// operating on the same child trie should always pass `Some`, and this should be equal to the
+	/// previous call result's `maybe_cursor` field. The host functions do not resume from the
+	/// cursor of a child trie yet, so a subsequent call only makes progress in a new block.
+	pub fn kill<ChildIdArg: EncodeLike<ChildId>>(
+		child_id: ChildIdArg,
+		limit: u32,
+		maybe_cursor: Option<&[u8]>,
+	) -> sp_io::MultiRemovalResults {
+		child::clear_storage(&Self::child_info(child_id), Some(limit), maybe_cursor)
+	}
+}
+
+impl<Prefix, ChildHasher, ChildId, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+	StorageChildMap<Prefix, ChildHasher, ChildId, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+where
+	Prefix: StorageInstance,
+	ChildHasher: StorageHasher,
+	ChildId: FullCodec,
+	Hasher: StorageHasher + ReversibleStorageHasher,
+	Key: FullCodec,
+	Value: FullCodec,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	/// Enumerate all elements of the map in the child trie of the given child trie identifier in
+	/// no particular order.
+	///
+	/// If you alter the map while doing this, you'll get undefined results.
+	pub fn iter<ChildIdArg: EncodeLike<ChildId>>(
+		child_id: ChildIdArg,
+	) -> ChildTriePrefixIterator<(Key, Value)> {
+		Self::iter_child_trie(&Self::child_info(child_id))
+	}
+
+	/// Remove all elements of the map from the child trie of the given child trie identifier and
+	/// iterate through them in no particular order.
+	///
+	/// If you add elements to the map while doing this, you'll get undefined results.
+	pub fn drain<ChildIdArg: EncodeLike<ChildId>>(
+		child_id: ChildIdArg,
+	) -> ChildTriePrefixIterator<(Key, Value)> {
+		Self::iter(child_id).drain()
+	}
+
+	/// Translate the values of all elements of the map in the child trie of the given child trie
+	/// identifier by a function `f`, in no particular order.
+	///
+	/// By returning `None` from `f` for an element, you'll remove it from the map.
+	///
+	/// NOTE: If a value fails to decode because storage is corrupted, then it is skipped.
+	pub fn translate<ChildIdArg, O, F>(child_id: ChildIdArg, mut f: F)
+	where
+		ChildIdArg: EncodeLike<ChildId>,
+		O: Decode,
+		F: FnMut(Key, O) -> Option<Value>,
+	{
+		let child_info = Self::child_info(child_id);
+		for (key, old) in Self::iter_child_trie::<O>(&child_info) {
+			let hashed_key = Self::hashed_key_for(&key);
+			match f(key, old) {
+				Some(new) => child::put(&child_info, &hashed_key, &new),
+				None => child::kill(&child_info, &hashed_key),
+			}
+		}
+	}
+
+	/// Iterate over all the entries of `child_info`, which only holds entries of this map.
+	fn iter_child_trie<O: Decode>(child_info: &ChildInfo) -> ChildTriePrefixIterator<(Key, O)> {
+		let mut iter = ChildTriePrefixIterator::with_prefix_over_key::<Hasher>(child_info, &[]);
+		// The empty prefix is never the hashed key of an entry, so start iterating after it.
+		iter.fetch_previous_key = false;
+		iter
+	}
+}
+
+impl<Prefix, ChildHasher, ChildId, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+	StorageEntryMetadataBuilder
+	for StorageChildMap<
+		Prefix,
+		ChildHasher,
+		ChildId,
+		Hasher,
+		Key,
+		Value,
+		QueryKind,
+		OnEmpty,
+		MaxValues,
+	>
+where
+	Prefix: StorageInstance,
+	ChildHasher: StorageHasher,
+	ChildId: FullCodec + scale_info::StaticTypeInfo,
+	Hasher: StorageHasher,
+	Key: FullCodec + scale_info::StaticTypeInfo,
+	Value: FullCodec + scale_info::StaticTypeInfo,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	fn build_metadata(
+		deprecation_status: sp_metadata_ir::ItemDeprecationInfoIR,
+		docs: Vec<&'static str>,
+		entries: &mut Vec<StorageEntryMetadataIR>,
+	) {
+		let docs = if cfg!(feature = "no-metadata-docs") { vec![] } else { docs };
+
+		let entry = StorageEntryMetadataIR {
+			name: Prefix::STORAGE_PREFIX,
+			modifier: QueryKind::METADATA,
+			ty: StorageEntryTypeIR::ChildMap {
+				hashers: vec![ChildHasher::METADATA, Hasher::METADATA],
+				key: scale_info::meta_type::<(ChildId, Key)>(),
+				value: scale_info::meta_type::<Value>(),
+			},
+			default: OnEmpty::get().encode(),
+			docs,
+			deprecation_info: deprecation_status,
+		};
+
+		entries.push(entry);
+	}
+}
+
+impl<Prefix, ChildHasher, ChildId, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+	crate::traits::StorageInfoTrait
+	for StorageChildMap<
+		Prefix,
+		ChildHasher,
+		ChildId,
+		Hasher,
+		Key,
+		Value,
+		QueryKind,
+		OnEmpty,
+		MaxValues,
+	>
+where
+	Prefix: StorageInstance,
+	ChildHasher: StorageHasher,
+	ChildId: FullCodec,
+	Hasher: StorageHasher,
+	Key: FullCodec + MaxEncodedLen,
+	Value: FullCodec + MaxEncodedLen,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	fn storage_info() -> Vec<StorageInfo> {
+		vec![StorageInfo {
+			pallet_name: Prefix::pallet_prefix().as_bytes().to_vec(),
+			storage_name: Prefix::STORAGE_PREFIX.as_bytes().to_vec(),
+			prefix: Prefix::prefix_hash().to_vec(),
+			max_values: MaxValues::get(),
+			max_size: Some(
+				Hasher::max_len::<Key>()
+					.saturating_add(Value::max_encoded_len())
+					.saturated_into(),
+			),
+		}]
+	}
+}
+
+/// It doesn't require to implement `MaxEncodedLen` and give no information for `max_size`.
+impl<Prefix, ChildHasher, ChildId, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+	crate::traits::PartialStorageInfoTrait
+	for StorageChildMap<
+		Prefix,
+		ChildHasher,
+		ChildId,
+		Hasher,
+		Key,
+		Value,
+		QueryKind,
+		OnEmpty,
+		MaxValues,
+	>
+where
+	Prefix: StorageInstance,
+	ChildHasher: StorageHasher,
+	ChildId: FullCodec,
+	Hasher: StorageHasher,
+	Key: FullCodec,
+	Value: FullCodec,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	fn partial_storage_info() -> Vec<StorageInfo> {
+		vec![StorageInfo {
+			pallet_name: Prefix::pallet_prefix().as_bytes().to_vec(),
+			storage_name: Prefix::STORAGE_PREFIX.as_bytes().to_vec(),
+			prefix: Prefix::prefix_hash().to_vec(),
+			max_values: MaxValues::get(),
+			max_size: None,
+		}]
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{hash::*, storage::types::ValueQuery};
+	use sp_io::{hashing::twox_128, TestExternalities};
+	use sp_metadata_ir::{StorageEntryModifierIR, StorageHasherIR};
+
+	struct Prefix;
+	impl StorageInstance for Prefix {
+		fn pallet_prefix() -> &'static str {
+			"test"
+		}
+		const STORAGE_PREFIX: &'static str = "foo";
+	}
+
+	struct OtherPrefix;
+	impl StorageInstance for OtherPrefix {
+		fn pallet_prefix() -> &'static str {
+			"test"
+		}
+		const STORAGE_PREFIX: &'static str = "bar";
+	}
+
+	type A = StorageChildMap<Prefix, Twox64Concat, u16, Blake2_128Concat, u32, u64>;
+	type B = StorageChildMap<OtherPrefix, Twox64Concat, u16, Twox64Concat, u32, u8, ValueQuery>;
+
+	#[test]
+	fn entries_are_stored_in_child_trie() {
+		TestExternalities::default().execute_with(|| {
+			A::insert(1, 10, 100);
+			assert_eq!(A::get(1, 10), Some(100));
+			assert_eq!(A::get(2, 10), None);
+			assert!(A::contains_key(1, 10));
+			assert!(!A::contains_key(1, 11));
+
+			let mut expected_storage_key = twox_128(b"test").to_vec();
+			expected_storage_key.extend(twox_128(b"foo"));
+			expected_storage_key.extend(Twox64Concat::hash(&1u16.encode()));
+			assert_eq!(A::child_info(1).storage_key(), &expected_storage_key[..]);
+			assert_eq!(child::get::<u64>(&A::child_info(1), &A::hashed_key_for(10)), Some(100));
+			assert!(sp_io::storage::next_key(&[])
+				.map_or(true, |key| !key.starts_with(&twox_128(b"test"))));
+
+			assert_eq!(A::take(1, 10), Some(100));
+			assert_eq!(A::try_get(1, 10), Err(()));
+
+			B::mutate(1, 10, |v| *v += 3);
+			assert_eq!(B::get(1, 10), 3);
+			B::remove(1, 10);
+			assert!(!B::contains_key(1, 10));
+			assert_eq!(B::get(1, 10), 0);
+
+			assert_eq!(
+				A::try_mutate(1, 10, |v| {
+					*v = Some(5);
+					Err::<(), _>(())
+				}),
+				Err(())
+			);
+			assert_eq!(A::get(1, 10), None);
+			A::mutate_exists(1, 10, |v| *v = Some(5));
+			assert_eq!(A::get(1, 10), Some(5));
+			A::mutate_exists(1, 10, |v| *v = None);
+			assert!(!A::contains_key(1, 10));
+		});
+	}
+
+	#[test]
+	fn iter_clear_prefix_and_kill_work() {
+		let mut ext = TestExternalities::default();
+		ext.execute_with(|| {
+			for key in 0..3 {
+				A::insert(1, key, key as u64);
+				A::insert(2, key, key as u64);
+				B::insert(1, key, key as u8);
+			}
+
+			let mut entries = A::iter(1).collect::<Vec<_>>();
+			entries.sort();
+			assert_eq!(entries, vec![(0, 0), (1, 1), (2, 2)]);
+
+			A::translate(1, |key, old: u64| (key != 0).then_some(old * 10));
+			let mut entries = A::iter(1).collect::<Vec<_>>();
+			entries.sort();
+			assert_eq!(entries, vec![(1, 10), (2, 20)]);
+		});
+		ext.commit_all().unwrap();
+
+		// Only the entries of `A` for `1` are removed, in several blocks.
+		let cursor = ext.execute_with(|| {
+			let result = A::kill(1, 1, None);
+			assert!(result.maybe_cursor.is_some());
+			assert_eq!(A::iter(1).count(), 1);
+			result.maybe_cursor
+		});
+		ext.commit_all().unwrap();
+
+		ext.execute_with(|| {
+			assert_eq!(A::kill(1, 1, cursor.as_deref()).maybe_cursor, None);
+			assert_eq!(A::iter(1).count(), 0);
+			assert_eq!(A::iter(2).count(), 3);
+			assert_eq!(B::iter(1).count(), 3);
+
+			// Only the entries of `B` for `1` are removed.
+			assert_eq!(B::clear_prefix(1, u32::MAX, None).maybe_cursor, None);
+			assert_eq!(B::iter(1).count(), 0);
+			assert_eq!(A::iter(2).count(), 3);
+
+			assert_eq!(A::drain(2).count(), 3);
+			assert_eq!(A::iter(2).count(), 0);
+		});
+	}
+
+	#[test]
+	fn metadata_works() {
+		let mut entries = vec![];
+		A::build_metadata(
+			sp_metadata_ir::ItemDeprecationInfoIR::NotDeprecated,
+			vec![],
+			&mut entries,
+		);
+		assert_eq!(
+			entries,
+			vec![StorageEntryMetadataIR {
+				name: "foo",
+				modifier: StorageEntryModifierIR::Optional,
+				ty: StorageEntryTypeIR::ChildMap {
+					hashers: vec![StorageHasherIR::Twox64Concat, StorageHasherIR::Blake2_128Concat],
+					key: scale_info::meta_type::<(u16, u32)>(),
+					value: scale_info::meta_type::<u64>(),
+				},
+				default: Option::<u64>::None.encode(),
+				docs: vec![],
+				deprecation_info: sp_metadata_ir::ItemDeprecationInfoIR::NotDeprecated,
+			}]
+		);
+	}
+}
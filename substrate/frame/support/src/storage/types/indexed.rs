@@ -0,0 +1,655 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage map types with an automatically maintained secondary index.
+
+use crate::{
+	storage::{
+		types::{
+			EncodeLikeTuple, KeyGenerator, QueryKindTrait, StorageDoubleMap,
+			StorageEntryMetadataBuilder, StorageMap, StorageNMap, TupleToEncodedIter,
+		},
+		KeyPrefixIterator, PrefixIterator,
+	},
+	traits::{Get, StorageInfo, StorageInstance},
+	Never, ReversibleStorageHasher, StorageHasher,
+};
+use alloc::vec::Vec;
+use codec::{Decode, Encode, EncodeLike, FullCodec, Ref};
+use sp_metadata_ir::StorageEntryMetadataIR;
+
+/// A wrapper around a [`StorageMap`] or [`StorageNMap`] which keeps a [`SecondaryIndex`] in sync
+/// with the values of the map.
+///
+/// Every value of the map is indexed under the key returned by `KeyOf`. Whenever a value is
+/// inserted, mutated or removed through this type, its entry in the index is updated accordingly,
+/// so the primary keys of all values with a given index key can be enumerated with
+/// [`Self::iter_keys_by_index`] without keeping a reverse map in sync by hand.
+///
+/// This storage item has additional storage read and write overhead when manipulating values
+/// compared to a regular storage map: the index key of the old value is computed before every
+/// mutation and the index is written to whenever the index key of the value changes.
+///
+/// The map must only be written to through this type. In particular, draining or removing values
+/// through the returned iterators, or writing to the map with [`crate::migration`] helpers, leaves
+/// the index out of sync.
+///
+/// In a pallet this type is declared with the `#[pallet::secondary_index]` attribute on a storage
+/// item, see [`crate::pallet_macros::secondary_index`]. [`Self::try_state`] checks that the index
+/// is consistent with the map and is called from the `try_state` hook of the pallet.
+pub struct IndexedStorage<Map, Index, KeyOf>(core::marker::PhantomData<(Map, Index, KeyOf)>);
+
+/// A storage holding a secondary index of a map, as a set of `(index key, primary key)` pairs.
+///
+/// Implemented by [`StorageDoubleMap`] with the index key as first key, the primary key as second
+/// key and `()` as value. Both hashers must be reversible so the index can be enumerated.
+pub trait SecondaryIndex {
+	/// The key values are indexed under.
+	type IndexKey: FullCodec + PartialEq;
+	/// The key of the indexed map.
+	type PrimaryKey: FullCodec;
+
+	/// Add `key` to the entries indexed under `index_key`.
+	fn insert_entry<KArg: EncodeLike<Self::PrimaryKey>>(index_key: &Self::IndexKey, key: KArg);
+
+	/// Remove `key` from the entries indexed under `index_key`.
+	fn remove_entry<KArg: EncodeLike<Self::PrimaryKey>>(index_key: &Self::IndexKey, key: KArg);
+
+	/// Whether `key` is indexed under `index_key`.
+	fn contains_entry<KArg: EncodeLike<Self::PrimaryKey>>(
+		index_key: &Self::IndexKey,
+		key: KArg,
+	) -> bool;
+
+	/// Enumerate the primary keys indexed under `index_key` in no particular order.
+	fn iter_primary_keys(index_key: &Self::IndexKey) -> KeyPrefixIterator<Self::PrimaryKey>;
+
+	/// Enumerate all `(index key, primary key)` pairs of the index in no particular order.
+	fn iter_entries() -> KeyPrefixIterator<(Self::IndexKey, Self::PrimaryKey)>;
+}
+
+/// Extracts the key a value is indexed under.
+pub trait IndexKeyOf<Value, IndexKey> {
+	/// The index key of `value`, or `None` if `value` is not indexed.
+	fn index_key(value: &Value) -> Option<IndexKey>;
+}
+
+impl<Prefix, Hasher1, IndexKey, Hasher2, PrimaryKey, QueryKind, OnEmpty, MaxValues> SecondaryIndex
+	for StorageDoubleMap<
+		Prefix,
+		Hasher1,
+		IndexKey,
+		Hasher2,
+		PrimaryKey,
+		(),
+		QueryKind,
+		OnEmpty,
+		MaxValues,
+	>
+where
+	Prefix: StorageInstance,
+	Hasher1: StorageHasher + ReversibleStorageHasher,
+	Hasher2: StorageHasher + ReversibleStorageHasher,
+	IndexKey: FullCodec + PartialEq,
+	PrimaryKey: FullCodec,
+	QueryKind: QueryKindTrait<(), OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	type IndexKey = IndexKey;
+	type PrimaryKey = PrimaryKey;
+
+	fn insert_entry<KArg: EncodeLike<PrimaryKey>>(index_key: &IndexKey, key: KArg) {
+		Self::insert(index_key, key, ())
+	}
+
+	fn remove_entry<KArg: EncodeLike<PrimaryKey>>(index_key: &IndexKey, key: KArg) {
+		Self::remove(index_key, key)
+	}
+
+	fn contains_entry<KArg: EncodeLike<PrimaryKey>>(index_key: &IndexKey, key: KArg) -> bool {
+		Self::contains_key(index_key, key)
+	}
+
+	fn iter_primary_keys(index_key: &IndexKey) -> KeyPrefixIterator<PrimaryKey> {
+		Self::iter_key_prefix(index_key)
+	}
+
+	fn iter_entries() -> KeyPrefixIterator<(IndexKey, PrimaryKey)> {
+		Self::iter_keys()
+	}
+}
+
+/// Move `key` in the index from `old` to `new`.
+fn update_index<Index: SecondaryIndex, KArg: EncodeLike<Index::PrimaryKey>>(
+	key: &KArg,
+	old: Option<Index::IndexKey>,
+	new: Option<Index::IndexKey>,
+) {
+	if old == new {
+		return
+	}
+	if let Some(old) = old {
+		Index::remove_entry(&old, Ref::from(key));
+	}
+	if let Some(new) = new {
+		Index::insert_entry(&new, Ref::from(key));
+	}
+}
+
+/// Check that `Index` holds exactly the entries derived from the values of the map.
+///
+/// `get` returns the value of the map for a primary key and `values` are all values of the map.
+#[cfg(feature = "try-runtime")]
+fn check_index<Index, KeyOf, Value>(
+	get: impl Fn(&Index::PrimaryKey) -> Option<Value>,
+	values: impl Iterator<Item = Value>,
+) -> Result<(), sp_runtime::TryRuntimeError>
+where
+	Index: SecondaryIndex,
+	KeyOf: IndexKeyOf<Value, Index::IndexKey>,
+{
+	let mut entries = 0usize;
+	for (index_key, key) in Index::iter_entries() {
+		let indexed = get(&key).and_then(|value| KeyOf::index_key(&value));
+		crate::ensure!(
+			indexed.as_ref() == Some(&index_key),
+			"Secondary index contains an entry which does not match the indexed value"
+		);
+		entries += 1;
+	}
+
+	let indexed_values = values.filter(|value| KeyOf::index_key(value).is_some()).count();
+	crate::ensure!(entries == indexed_values, "Secondary index is missing entries");
+
+	Ok(())
+}
+
+impl<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues, Index, KeyOf>
+	IndexedStorage<StorageMap<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>, Index, KeyOf>
+where
+	Prefix: StorageInstance,
+	Hasher: StorageHasher,
+	Key: FullCodec,
+	Value: FullCodec,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+	Index: SecondaryIndex<PrimaryKey = Key>,
+	KeyOf: IndexKeyOf<Value, Index::IndexKey>,
+{
+	/// Get the storage key used to fetch a value corresponding to a specific key.
+	pub fn hashed_key_for<KeyArg: EncodeLike<Key>>(key: KeyArg) -> Vec<u8> {
+		StorageMap::<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>::hashed_key_for(key)
+	}
+
+	/// Does the value (explicitly) exist in storage?
+	pub fn contains_key<KeyArg: EncodeLike<Key>>(key: KeyArg) -> bool {
+		StorageMap::<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>::contains_key(key)
+	}
+
+	/// Load the value associated with the given key from the map.
+	pub fn get<KeyArg: EncodeLike<Key>>(key: KeyArg) -> QueryKind::Query {
+		StorageMap::<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>::get(key)
+	}
+
+	/// Try to get the value for the given key from the map.
+	///
+	/// Returns `Ok` if it exists, `Err` if not.
+	pub fn try_get<KeyArg: EncodeLike<Key>>(key: KeyArg) -> Result<Value, ()> {
+		StorageMap::<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>::try_get(key)
+	}
+
+	/// Store a value to be associated with the given key from the map.
+	pub fn insert<KeyArg: EncodeLike<Key>>(key: KeyArg, val: Value) {
+		Self::mutate_exists(key, |value| *value = Some(val))
+	}
+
+	/// Remove the value under a key.
+	pub fn remove<KeyArg: EncodeLike<Key>>(key: KeyArg) {
+		Self::mutate_exists(key, |value| *value = None)
+	}
+
+	/// Take the value under a key.
+	pub fn take<KeyArg: EncodeLike<Key>>(key: KeyArg) -> QueryKind::Query {
+		QueryKind::from_optional_value_to_query(Self::mutate_exists(key, Option::take))
+	}
+
+	/// Mutate the value under a key.
+	pub fn mutate<KeyArg: EncodeLike<Key>, R, F: FnOnce(&mut QueryKind::Query) -> R>(
+		key: KeyArg,
+		f: F,
+	) -> R {
+		Self::try_mutate(key, |v| Ok::<R, Never>(f(v)))
+			.expect("`Never` can not be constructed; qed")
+	}
+
+	/// Mutate the item, only if an `Ok` value is returned.
+	pub fn try_mutate<KeyArg, R, E, F>(key: KeyArg, f: F) -> Result<R, E>
+	where
+		KeyArg: EncodeLike<Key>,
+		F: FnOnce(&mut QueryKind::Query) -> Result<R, E>,
+	{
+		Self::try_mutate_exists(key, |value| {
+			let mut query = QueryKind::from_optional_value_to_query(value.take());
+			let result = f(&mut query)?;
+			*value = QueryKind::from_query_to_optional_value(query);
+			Ok(result)
+		})
+	}
+
+	/// Mutate the value under a key. Deletes the item if mutated to a `None`.
+	pub fn mutate_exists<KeyArg: EncodeLike<Key>, R, F: FnOnce(&mut Option<Value>) -> R>(
+		key: KeyArg,
+		f: F,
+	) -> R {
+		Self::try_mutate_exists(key, |v| Ok::<R, Never>(f(v)))
+			.expect("`Never` can not be constructed; qed")
+	}
+
+	/// Mutate the item, only if an `Ok` value is returned. Deletes the item if mutated to a `None`.
+	/// `f` will always be called with an option representing if the storage item exists (`Some<V>`)
+	/// or if the storage item does not exist (`None`), independent of the `QueryType`.
+	pub fn try_mutate_exists<KeyArg, R, E, F>(key: KeyArg, f: F) -> Result<R, E>
+	where
+		KeyArg: EncodeLike<Key>,
+		F: FnOnce(&mut Option<Value>) -> Result<R, E>,
+	{
+		StorageMap::<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>::try_mutate_exists(
+			Ref::from(&key),
+			|value| {
+				let old = value.as_ref().and_then(KeyOf::index_key);
+				let result = f(value)?;
+				update_index::<Index, _>(&key, old, value.as_ref().and_then(KeyOf::index_key));
+				Ok(result)
+			},
+		)
+	}
+
+	/// Iter over all value of the storage.
+	///
+	/// NOTE: If a value failed to decode because storage is corrupted then it is skipped.
+	pub fn iter_values() -> PrefixIterator<Value> {
+		StorageMap::<Prefix, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>::iter_values()
+	}
+
+	/// Enumerate the keys of all values indexed under `index_key` in no particular order.
+	pub fn iter_keys_by_index(index_key: &Index::IndexKey) -> KeyPrefixIterator<Key> {
+		Index::iter_primary_keys(index_key)
+	}
+
+	/// Enumerate the keys and values of all values indexed under `index_key` in no particular
+	/// order.
+	pub fn iter_by_index(index_key: &Index::IndexKey) -> impl Iterator<Item = (Key, Value)> {
+		Self::iter_keys_by_index(index_key)
+			.filter_map(|key| Self::try_get(&key).ok().map(|value| (key, value)))
+	}
+
+	/// Check that the secondary index is consistent with the values of the map.
+	#[cfg(feature = "try-runtime")]
+	pub fn try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		check_index::<Index, KeyOf, Value>(|key| Self::try_get(key).ok(), Self::iter_values())
+	}
+}
+
+impl<Prefix, Key, Value, QueryKind, OnEmpty, MaxValues, Index, KeyOf>
+	IndexedStorage<StorageNMap<Prefix, Key, Value, QueryKind, OnEmpty, MaxValues>, Index, KeyOf>
+where
+	Prefix: StorageInstance,
+	Key: KeyGenerator,
+	Key::Key: FullCodec,
+	Key::KArg: Decode + EncodeLikeTuple<Key::KArg> + TupleToEncodedIter,
+	Value: FullCodec,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+	Index: SecondaryIndex<PrimaryKey = Key::Key>,
+	KeyOf: IndexKeyOf<Value, Index::IndexKey>,
+{
+	/// Decode the key of the map from the encoded elements of the key tuple `key`.
+	///
+	/// The elements of a key tuple are encoded one after the other, like the key itself.
+	fn decode_key(key: &impl TupleToEncodedIter) -> Key::Key {
+		let encoded = key.to_encoded_iter().flatten().collect::<Vec<u8>>();
+		Decode::decode(&mut &encoded[..]).expect("The key tuple encodes like the key; qed")
+	}
+
+	/// Convert a key of the map to a key tuple which can be used to access the map.
+	fn key_arg(key: &Key::Key) -> Key::KArg {
+		Decode::decode(&mut &key.encode()[..]).expect("The key encodes like the key tuple; qed")
+	}
+
+	/// Get the storage key used to fetch a value corresponding to a specific key.
+	pub fn hashed_key_for<KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter>(
+		key: KArg,
+	) -> Vec<u8> {
+		StorageNMap::<Prefix, Key, Value, QueryKind, OnEmpty, MaxValues>::hashed_key_for(key)
+	}
+
+	/// Does the value (explicitly) exist in storage?
+	pub fn contains_key<KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter>(key: KArg) -> bool {
+		StorageNMap::<Prefix, Key, Value, QueryKind, OnEmpty, MaxValues>::contains_key(key)
+	}
+
+	/// Load the value associated with the given key from the map.
+	pub fn get<KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter>(
+		key: KArg,
+	) -> QueryKind::Query {
+		StorageNMap::<Prefix, Key, Value, QueryKind, OnEmpty, MaxValues>::get(key)
+	}
+
+	/// Try to get the value for the given key from the map.
+	///
+	/// Returns `Ok` if it exists, `Err` if not.
+	pub fn try_get<KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter>(
+		key: KArg,
+	) -> Result<Value, ()> {
+		StorageNMap::<Prefix, Key, Value, QueryKind, OnEmpty, MaxValues>::try_get(key)
+	}
+
+	/// Store a value to be associated with the given key from the map.
+	pub fn insert<KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter>(key: KArg, val: Value) {
+		Self::mutate_exists(key, |value| *value = Some(val))
+	}
+
+	/// Remove the value under a key.
+	pub fn remove<KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter>(key: KArg) {
+		Self::mutate_exists(key, |value| *value = None)
+	}
+
+	/// Take the value under a key.
+	pub fn take<KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter>(
+		key: KArg,
+	) -> QueryKind::Query {
+		QueryKind::from_optional_value_to_query(Self::mutate_exists(key, Option::take))
+	}
+
+	/// Mutate the value under a key.
+	pub fn mutate<KArg, R, F>(key: KArg, f: F) -> R
+	where
+		KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter,
+		F: FnOnce(&mut QueryKind::Query) -> R,
+	{
+		Self::try_mutate(key, |v| Ok::<R, Never>(f(v)))
+			.expect("`Never` can not be constructed; qed")
+	}
+
+	/// Mutate the item, only if an `Ok` value is returned.
+	pub fn try_mutate<KArg, R, E, F>(key: KArg, f: F) -> Result<R, E>
+	where
+		KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter,
+		F: FnOnce(&mut QueryKind::Query) -> Result<R, E>,
+	{
+		Self::try_mutate_exists(key, |value| {
+			let mut query = QueryKind::from_optional_value_to_query(value.take());
+			let result = f(&mut query)?;
+			*value = QueryKind::from_query_to_optional_value(query);
+			Ok(result)
+		})
+	}
+
+	/// Mutate the value under a key. Deletes the item if mutated to a `None`.
+	pub fn mutate_exists<KArg, R, F>(key: KArg, f: F) -> R
+	where
+		KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter,
+		F: FnOnce(&mut Option<Value>) -> R,
+	{
+		Self::try_mutate_exists(key, |v| Ok::<R, Never>(f(v)))
+			.expect("`Never` can not be constructed; qed")
+	}
+
+	/// Mutate the item, only if an `Ok` value is returned. Deletes the item if mutated to a `None`.
+	/// `f` will always be called with an option representing if the storage item exists (`Some<V>`)
+	/// or if the storage item does not exist (`None`), independent of the `QueryType`.
+	pub fn try_mutate_exists<KArg, R, E, F>(key: KArg, f: F) -> Result<R, E>
+	where
+		KArg: EncodeLikeTuple<Key::KArg> + TupleToEncodedIter,
+		F: FnOnce(&mut Option<Value>) -> Result<R, E>,
+	{
+		let primary_key = Self::decode_key(&key);
+		StorageNMap::<Prefix, Key, Value, QueryKind, OnEmpty, MaxValues>::try_mutate_exists(
+			key,
+			|value| {
+				let old = value.as_ref().and_then(KeyOf::index_key);
+				let result = f(value)?;
+				update_index::<Index, _>(
+					&primary_key,
+					old,
+					value.as_ref().and_then(KeyOf::index_key),
+				);
+				Ok(result)
+			},
+		)
+	}
+
+	/// Iter over all value of the storage.
+	///
+	/// NOTE: If a value failed to decode because storage is corrupted then it is skipped.
+	pub fn iter_values() -> PrefixIterator<Value> {
+		StorageNMap::<Prefix, Key, Value, QueryKind, OnEmpty, MaxValues>::iter_values()
+	}
+
+	/// Enumerate the keys of all values indexed under `index_key` in no particular order.
+	pub fn iter_keys_by_index(index_key: &Index::IndexKey) -> KeyPrefixIterator<Key::Key> {
+		Index::iter_primary_keys(index_key)
+	}
+
+	/// Enumerate the keys and values of all values indexed under `index_key` in no particular
+	/// order.
+	pub fn iter_by_index(index_key: &Index::IndexKey) -> impl Iterator<Item = (Key::Key, Value)> {
+		Self::iter_keys_by_index(index_key)
+			.filter_map(|key| Self::try_get(Self::key_arg(&key)).ok().map(|value| (key, value)))
+	}
+
+	/// Check that the secondary index is consistent with the values of the map.
+	#[cfg(feature = "try-runtime")]
+	pub fn try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		check_index::<Index, KeyOf, Value>(
+			|key| Self::try_get(Self::key_arg(key)).ok(),
+			Self::iter_values(),
+		)
+	}
+}
+
+impl<Map, Index, KeyOf> StorageEntryMetadataBuilder for IndexedStorage<Map, Index, KeyOf>
+where
+	Map: StorageEntryMetadataBuilder,
+{
+	fn build_metadata(
+		deprecation_status: sp_metadata_ir::ItemDeprecationInfoIR,
+		docs: Vec<&'static str>,
+		entries: &mut Vec<StorageEntryMetadataIR>,
+	) {
+		Map::build_metadata(deprecation_status, docs, entries)
+	}
+}
+
+impl<Map, Index, KeyOf> crate::traits::StorageInfoTrait for IndexedStorage<Map, Index, KeyOf>
+where
+	Map: crate::traits::StorageInfoTrait,
+{
+	fn storage_info() -> Vec<StorageInfo> {
+		Map::storage_info()
+	}
+}
+
+impl<Map, Index, KeyOf> crate::traits::PartialStorageInfoTrait for IndexedStorage<Map, Index, KeyOf>
+where
+	Map: crate::traits::PartialStorageInfoTrait,
+{
+	fn partial_storage_info() -> Vec<StorageInfo> {
+		Map::partial_storage_info()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{hash::*, storage::types::ValueQuery};
+	use sp_io::TestExternalities;
+
+	struct Prefix;
+	impl StorageInstance for Prefix {
+		fn pallet_prefix() -> &'static str {
+			"test"
+		}
+		const STORAGE_PREFIX: &'static str = "foo";
+	}
+
+	struct IndexPrefix;
+	impl StorageInstance for IndexPrefix {
+		fn pallet_prefix() -> &'static str {
+			"test"
+		}
+		const STORAGE_PREFIX: &'static str = "foo_index";
+	}
+
+	/// Values are `(owner, amount)` pairs indexed by owner, owner `0` is not indexed.
+	struct OwnerOf;
+	impl IndexKeyOf<(u16, u64), u16> for OwnerOf {
+		fn index_key(value: &(u16, u64)) -> Option<u16> {
+			(value.0 != 0).then_some(value.0)
+		}
+	}
+
+	type Index = StorageDoubleMap<IndexPrefix, Twox64Concat, u16, Twox64Concat, u32, ()>;
+	type A = IndexedStorage<StorageMap<Prefix, Twox64Concat, u32, (u16, u64)>, Index, OwnerOf>;
+	type B = IndexedStorage<
+		StorageMap<Prefix, Twox64Concat, u32, (u16, u64), ValueQuery>,
+		Index,
+		OwnerOf,
+	>;
+
+	type NIndex = StorageDoubleMap<IndexPrefix, Twox64Concat, u16, Twox64Concat, (u32, u8), ()>;
+	type C = IndexedStorage<
+		StorageNMap<
+			Prefix,
+			(
+				crate::storage::types::Key<Twox64Concat, u32>,
+				crate::storage::types::Key<Twox64Concat, u8>,
+			),
+			(u16, u64),
+		>,
+		NIndex,
+		OwnerOf,
+	>;
+
+	fn keys_of(owner: u16) -> Vec<u32> {
+		let mut keys = A::iter_keys_by_index(&owner).collect::<Vec<_>>();
+		keys.sort();
+		keys
+	}
+
+	#[test]
+	fn index_follows_insert_and_remove() {
+		TestExternalities::default().execute_with(|| {
+			A::insert(1, (7, 10));
+			A::insert(2, (7, 20));
+			A::insert(3, (8, 30));
+			assert_eq!(keys_of(7), vec![1, 2]);
+			assert_eq!(keys_of(8), vec![3]);
+
+			// Re-inserting moves the entry to the new owner.
+			A::insert(2, (8, 21));
+			assert_eq!(keys_of(7), vec![1]);
+			assert_eq!(keys_of(8), vec![2, 3]);
+
+			A::remove(1);
+			assert_eq!(keys_of(7), Vec::<u32>::new());
+			assert_eq!(A::take(3), Some((8, 30)));
+			assert_eq!(keys_of(8), vec![2]);
+			assert_eq!(A::iter_by_index(&8).collect::<Vec<_>>(), vec![(2, (8, 21))]);
+		})
+	}
+
+	#[test]
+	fn index_follows_mutations() {
+		TestExternalities::default().execute_with(|| {
+			A::insert(1, (7, 10));
+
+			A::mutate(1, |value| value.as_mut().unwrap().0 = 8);
+			assert_eq!(keys_of(7), Vec::<u32>::new());
+			assert_eq!(keys_of(8), vec![1]);
+
+			// Unindexed values have no entry.
+			A::mutate_exists(1, |value| value.as_mut().unwrap().0 = 0);
+			assert_eq!(Index::iter_entries().count(), 0);
+			assert_eq!(A::get(1), Some((0, 10)));
+
+			// Failed mutations leave the index untouched.
+			assert_eq!(
+				A::try_mutate(1, |value| {
+					*value = Some((9, 10));
+					Err::<(), _>(())
+				}),
+				Err(())
+			);
+			assert_eq!(keys_of(9), Vec::<u32>::new());
+
+			// Mutating a `ValueQuery` map to the default value keeps the value.
+			B::mutate(2, |value| value.0 = 9);
+			assert_eq!(keys_of(9), vec![2]);
+			B::mutate(2, |value| *value = Default::default());
+			assert_eq!(keys_of(9), Vec::<u32>::new());
+			assert!(B::contains_key(2));
+		})
+	}
+
+	#[test]
+	fn nmap_index_follows_mutations() {
+		TestExternalities::default().execute_with(|| {
+			C::insert((1, 2), (7, 10));
+			C::insert((1, 3), (7, 20));
+			let mut keys = C::iter_keys_by_index(&7).collect::<Vec<_>>();
+			keys.sort();
+			assert_eq!(keys, vec![(1, 2), (1, 3)]);
+
+			C::mutate((1, 2), |value| value.as_mut().unwrap().0 = 8);
+			assert_eq!(C::iter_by_index(&8).collect::<Vec<_>>(), vec![((1, 2), (8, 10))]);
+
+			C::remove((1, 3));
+			assert_eq!(C::iter_keys_by_index(&7).count(), 0);
+		})
+	}
+
+	#[cfg(feature = "try-runtime")]
+	#[test]
+	fn try_state_detects_inconsistent_index() {
+		TestExternalities::default().execute_with(|| {
+			A::insert(1, (7, 10));
+			A::insert(2, (0, 20));
+			assert_eq!(A::try_state(), Ok(()));
+
+			// An entry for a value with another index key.
+			Index::insert(8, 1, ());
+			assert!(A::try_state().is_err());
+			Index::remove(8, 1);
+
+			// A missing entry.
+			Index::remove(7, 1);
+			assert!(A::try_state().is_err());
+			Index::insert(7, 1, ());
+
+			// An entry for a missing value.
+			Index::insert(7, 3, ());
+			assert!(A::try_state().is_err());
+			Index::remove(7, 3);
+
+			assert_eq!(A::try_state(), Ok(()));
+		})
+	}
+}
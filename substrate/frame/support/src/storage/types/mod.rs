@@ -22,17 +22,21 @@ use alloc::vec::Vec;
 use codec::FullCodec;
 use sp_metadata_ir::{StorageEntryMetadataIR, StorageEntryModifierIR};
 
+mod child_map;
 mod counted_map;
 mod counted_nmap;
 mod double_map;
+mod indexed;
 mod key;
 mod map;
 mod nmap;
 mod value;
 
+pub use child_map::StorageChildMap;
 pub use counted_map::{CountedStorageMap, CountedStorageMapInstance, Counter};
 pub use counted_nmap::{CountedStorageNMap, CountedStorageNMapInstance};
 pub use double_map::StorageDoubleMap;
+pub use indexed::{IndexKeyOf, IndexedStorage, SecondaryIndex};
 pub use key::{
 	EncodeLikeTuple, HasKeyPrefix, HasReversibleKeyPrefix, Key, KeyGenerator,
 	KeyGeneratorMaxEncodedLen, ReversibleKeyGenerator, TupleToEncodedIter,
@@ -138,7 +142,7 @@ where
 
 /// Build the metadata of a storage.
 ///
-/// Implemented by each of the storage types: value, map, countedmap, doublemap and nmap.
+/// Implemented by each of the storage types: value, map, countedmap, doublemap, nmap and childmap.
 pub trait StorageEntryMetadataBuilder {
 	/// Build into `entries` the storage metadata entries of a storage given some `docs`.
 	fn build_metadata(
@@ -242,6 +242,12 @@ impl StorageVersion {
 	}
 }
 
+impl From<StorageVersion> for u16 {
+	fn from(version: StorageVersion) -> Self {
+		version.0
+	}
+}
+
 impl PartialEq<u16> for StorageVersion {
 	fn eq(&self, other: &u16) -> bool {
 		self.0 == *other
@@ -292,6 +292,94 @@ where
 	}
 }
 
+impl<Prefix, ChildHasher, ChildId, Hasher, Key, Value, QueryKind, OnEmpty, MaxValues>
+	TryDecodeEntireStorage
+	for crate::storage::types::StorageChildMap<
+		Prefix,
+		ChildHasher,
+		ChildId,
+		Hasher,
+		Key,
+		Value,
+		QueryKind,
+		OnEmpty,
+		MaxValues,
+	>
+where
+	Prefix: StorageInstance,
+	ChildHasher: StorageHasher,
+	ChildId: FullCodec,
+	Hasher: StorageHasher,
+	Key: FullCodec,
+	Value: FullCodec,
+	QueryKind: QueryKindTrait<Value, OnEmpty>,
+	OnEmpty: Get<QueryKind::Query> + 'static,
+	MaxValues: Get<Option<u32>>,
+{
+	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		let info = Self::partial_storage_info()
+			.first()
+			.cloned()
+			.expect("Child map has only one storage info; qed");
+		decode_child_storage_info::<Value>(info)
+	}
+}
+
+impl<Map, Index, KeyOf> TryDecodeEntireStorage
+	for crate::storage::types::IndexedStorage<Map, Index, KeyOf>
+where
+	Map: TryDecodeEntireStorage,
+{
+	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		// The index is a storage item of its own and decoded separately.
+		Map::try_decode_entire_state()
+	}
+}
+
+/// Decode all the values to `V`, in all the child tries whose storage key starts with the prefix
+/// of `info`.
+///
+/// The child tries are found through their roots in the main trie, thus child tries only created
+/// in the current block are not decoded.
+fn decode_child_storage_info<V: Decode>(
+	info: StorageInfo,
+) -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+	use sp_core::storage::{well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX, ChildInfo};
+
+	let mut decoded = 0;
+	let mut errors = vec![];
+
+	let root_prefix = [DEFAULT_CHILD_STORAGE_KEY_PREFIX, &info.prefix].concat();
+	let mut next_root_key = sp_io::storage::next_key(&root_prefix);
+	while let Some(root_key) = next_root_key.filter(|key| key.starts_with(&root_prefix)) {
+		let child_info =
+			ChildInfo::new_default(&root_key[DEFAULT_CHILD_STORAGE_KEY_PREFIX.len()..]);
+
+		let mut next_key = sp_io::default_child_storage::next_key(child_info.storage_key(), &[]);
+		while let Some(key) = next_key {
+			if let Some(bytes) = crate::storage::child::get_raw(&child_info, &key) {
+				match <V as DecodeAll>::decode_all(&mut bytes.as_ref()) {
+					Ok(_) => decoded += bytes.len(),
+					Err(_) => errors.push(TryDecodeEntireStorageError {
+						key: key.clone(),
+						raw: Some(bytes),
+						info: info.clone(),
+					}),
+				}
+			}
+			next_key = sp_io::default_child_storage::next_key(child_info.storage_key(), &key);
+		}
+
+		next_root_key = sp_io::storage::next_key(&root_key);
+	}
+
+	if errors.is_empty() {
+		Ok(decoded)
+	} else {
+		Err(errors)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -333,6 +421,9 @@ mod tests {
 	build_prefix!(NMapPrefix);
 	type NMap = types::StorageNMap<NMapPrefix, (Key<H, u8>, Key<H, u8>), u128>;
 
+	build_prefix!(ChildMapPrefix);
+	type ChildMap = types::StorageChildMap<ChildMapPrefix, H, u32, H, u32, u32>;
+
 	build_prefix!(CountedNMapCounterPrefix);
 	build_prefix!(CountedNMapPrefix);
 	impl CountedStorageNMapInstance for CountedNMapPrefix {
@@ -502,6 +593,35 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn try_decode_entire_state_child_map_works() {
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			ChildMap::insert(0, 0, 42);
+			ChildMap::insert(0, 1, 42);
+			ChildMap::insert(1, 0, 42);
+			// Child tries created in the current block are not visible yet.
+			assert_eq!(ChildMap::try_decode_entire_state(), Ok(0));
+		});
+		ext.commit_all().unwrap();
+
+		ext.execute_with(|| {
+			assert_eq!(ChildMap::try_decode_entire_state(), Ok(12));
+
+			// two bytes, cannot be decoded into u32.
+			crate::storage::child::put_raw(
+				&ChildMap::child_info(1),
+				&ChildMap::hashed_key_for(1),
+				&[0u8, 1],
+			);
+		});
+		ext.commit_all().unwrap();
+
+		ext.execute_with(|| {
+			assert_eq!(ChildMap::try_decode_entire_state().unwrap_err().len(), 1);
+		});
+	}
+
 	#[test]
 	fn extra_bytes_are_rejected() {
 		sp_io::TestExternalities::new_empty().execute_with(|| {
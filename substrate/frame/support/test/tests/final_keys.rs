@@ -16,7 +16,11 @@
 // limitations under the License.
 
 use codec::Encode;
-use frame_support::{derive_impl, storage::unhashed, StoragePrefixedMap};
+use frame_support::{
+	derive_impl,
+	storage::{child, unhashed},
+	StoragePrefixedMap,
+};
 use frame_system::pallet_prelude::BlockNumberFor;
 
 use sp_core::sr25519;
@@ -58,6 +62,11 @@ mod no_instance {
 	pub type DoubleMap2<T> =
 		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, u32, u32, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn child_map)]
+	pub type ChildMap<T> =
+		StorageChildMap<_, Twox64Concat, u32, Blake2_128Concat, u32, u32, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn test_generic_value)]
 	pub type TestGenericValue<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
@@ -257,6 +266,14 @@ fn final_keys_no_instance() {
 		k.extend(2u32.using_encoded(twox_64_concat));
 		assert_eq!(unhashed::get::<u32>(&k), Some(3u32));
 		assert_eq!(&k[..32], &<no_instance::DoubleMap2<Runtime>>::final_prefix());
+
+		<no_instance::ChildMap<Runtime>>::insert(1, 2, 3);
+		let mut storage_key = [twox_128(b"FinalKeysNone"), twox_128(b"ChildMap")].concat();
+		storage_key.extend(1u32.using_encoded(twox_64_concat));
+		let child_info = child::ChildInfo::new_default(&storage_key);
+		let k = 2u32.using_encoded(blake2_128_concat);
+		assert_eq!(child::get::<u32>(&child_info, &k), Some(3u32));
+		assert_eq!(<no_instance::Pallet<Runtime>>::child_map(1, 2), 3u32);
 	});
 }
 
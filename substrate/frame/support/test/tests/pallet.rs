@@ -1953,6 +1953,17 @@ fn metadata_ir_pallet_runtime_docs() {
 	assert_eq!(pallet.docs, expected);
 }
 
+#[test]
+fn metadata_ir_pallet_storage_version() {
+	let ir = Runtime::metadata_ir();
+	let storage_version =
+		|name| ir.pallets.iter().find(|pallet| pallet.name == name).unwrap().storage_version;
+
+	assert_eq!(storage_version("Example"), Some(10));
+	assert_eq!(storage_version("Example2"), Some(2));
+	assert_eq!(storage_version("Example4"), None);
+}
+
 #[test]
 fn extrinsic_metadata_ir_types() {
 	let ir = Runtime::metadata_ir().extrinsic;
@@ -0,0 +1,172 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for storage maps declared with `#[pallet::secondary_index]`.
+
+use frame_support::{assert_ok, derive_impl};
+use sp_io::TestExternalities;
+use sp_runtime::{generic, traits::BlakeTwo256};
+
+pub use self::pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Debug)]
+	pub struct Item<AccountId> {
+		pub owner: AccountId,
+		pub value: u32,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::zero())]
+		pub fn transfer(origin: OriginFor<T>, id: u32, dest: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Items::<T>::try_mutate(id, |item| {
+				let item = item.as_mut().ok_or(DispatchError::Other("unknown item"))?;
+				ensure!(item.owner == who, DispatchError::BadOrigin);
+				item.owner = dest;
+				Ok(())
+			})
+		}
+	}
+
+	#[pallet::storage]
+	#[pallet::secondary_index(ItemsByOwner<T>, owner)]
+	pub type Items<T: Config> = StorageMap<_, Blake2_128Concat, u32, Item<T::AccountId>>;
+
+	#[pallet::storage]
+	pub type ItemsByOwner<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, u32, ()>;
+
+	#[pallet::storage]
+	#[pallet::secondary_index(ItemsByValue<T>, 1)]
+	pub type CollectionItems<T: Config> = StorageNMap<
+		Key = (NMapKey<Blake2_128Concat, u32>, NMapKey<Blake2_128Concat, u32>),
+		Value = (u32, u32),
+		QueryKind = ValueQuery,
+	>;
+
+	#[pallet::storage]
+	pub type ItemsByValue<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, (u32, u32), ()>;
+}
+
+pub type BlockNumber = u32;
+pub type AccountId = u64;
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<u32, RuntimeCall, (), ()>;
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime
+	{
+		System: frame_system,
+		Example: pallet,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = Block;
+	type AccountId = AccountId;
+	type Lookup = sp_runtime::traits::IdentityLookup<AccountId>;
+}
+
+impl Config for Runtime {}
+
+fn items_of(owner: AccountId) -> Vec<u32> {
+	let mut items = Items::<Runtime>::iter_keys_by_index(&owner).collect::<Vec<_>>();
+	items.sort();
+	items
+}
+
+#[test]
+fn index_is_updated_by_writes_to_the_map() {
+	TestExternalities::default().execute_with(|| {
+		Items::<Runtime>::insert(1, Item { owner: 10, value: 1 });
+		Items::<Runtime>::insert(2, Item { owner: 10, value: 2 });
+		Items::<Runtime>::insert(3, Item { owner: 11, value: 3 });
+		assert_eq!(items_of(10), vec![1, 2]);
+		assert_eq!(items_of(11), vec![3]);
+
+		assert_ok!(Example::transfer(RuntimeOrigin::signed(10), 2, 11));
+		assert_eq!(items_of(10), vec![1]);
+		assert_eq!(items_of(11), vec![2, 3]);
+		assert!(ItemsByOwner::<Runtime>::contains_key(11, 2));
+
+		// A failed call leaves the map and the index untouched.
+		assert!(Example::transfer(RuntimeOrigin::signed(10), 3, 10).is_err());
+		assert_eq!(items_of(11), vec![2, 3]);
+
+		Items::<Runtime>::remove(1);
+		assert_eq!(items_of(10), Vec::<u32>::new());
+		assert_eq!(ItemsByOwner::<Runtime>::iter_keys().count(), 2);
+	});
+}
+
+#[test]
+fn nmap_index_is_updated_by_writes_to_the_map() {
+	TestExternalities::default().execute_with(|| {
+		CollectionItems::<Runtime>::insert((1, 1), (10, 5));
+		CollectionItems::<Runtime>::insert((1, 2), (10, 5));
+		CollectionItems::<Runtime>::mutate((1, 1), |item| item.1 = 6);
+
+		assert_eq!(
+			CollectionItems::<Runtime>::iter_by_index(&5).collect::<Vec<_>>(),
+			vec![((1, 2), (10, 5))],
+		);
+		assert_eq!(CollectionItems::<Runtime>::get((1, 1)), (10, 6));
+		assert!(ItemsByValue::<Runtime>::contains_key(6, (1, 1)));
+	});
+}
+
+#[test]
+fn metadata_of_indexed_map_is_the_metadata_of_the_map() {
+	let entries = Example::storage_metadata().entries;
+	let names = entries.iter().map(|entry| entry.name).collect::<Vec<_>>();
+	assert_eq!(names, vec!["Items", "ItemsByOwner", "CollectionItems", "ItemsByValue"]);
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn try_state_checks_the_index() {
+	use frame_support::traits::{TryState, TryStateSelect};
+
+	TestExternalities::default().execute_with(|| {
+		Items::<Runtime>::insert(1, Item { owner: 10, value: 1 });
+		CollectionItems::<Runtime>::insert((1, 1), (10, 5));
+		assert_ok!(Example::try_state(0, TryStateSelect::All));
+
+		ItemsByOwner::<Runtime>::remove(10, 1);
+		assert!(Example::try_state(0, TryStateSelect::All).is_err());
+		ItemsByOwner::<Runtime>::insert(10, 1, ());
+
+		ItemsByValue::<Runtime>::insert(6, (1, 1), ());
+		assert!(Example::try_state(0, TryStateSelect::All).is_err());
+	});
+}
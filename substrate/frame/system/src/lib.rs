@@ -2398,6 +2398,25 @@ pub fn unique(entropy: impl Encode) -> [u8; 32] {
 	next
 }
 
+#[cfg(feature = "experimental")]
+impl<T: Config> Pallet<T> {
+	/// Returns up to `max` tasks of the runtime which are currently valid.
+	///
+	/// Meant to back the `TasksApi` runtime api, which lets the node submit the tasks as unsigned
+	/// [`Call::do_task`] transactions.
+	pub fn valid_tasks(max: u32) -> Vec<T::RuntimeTask>
+	where
+		<T::RuntimeTask as frame_support::traits::Task>::Enumeration:
+			Iterator<Item = T::RuntimeTask>,
+	{
+		use frame_support::traits::Task;
+		T::RuntimeTask::iter()
+			.filter(|task| task.is_valid())
+			.take(max as usize)
+			.collect()
+	}
+}
+
 /// Event handler which registers a provider when created.
 pub struct Provider<T>(PhantomData<T>);
 impl<T: Config> HandleLifetime<T::AccountId> for Provider<T> {
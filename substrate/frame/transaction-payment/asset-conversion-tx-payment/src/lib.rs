@@ -25,7 +25,8 @@
 //! [`OnChargeAssetTransaction`] implementation analogous to [`pallet-transaction-payment`]. The
 //! included [`SwapAssetAdapter`] (implementing [`OnChargeAssetTransaction`]) determines the
 //! fee amount by converting the fee calculated by [`pallet-transaction-payment`] in the native
-//! asset into the amount required of the specified asset.
+//! asset into the amount required of the specified asset. The [`TwapSwapAssetAdapter`] does the
+//! same with the time-weighted average price of the pool instead of its spot price.
 //!
 //! ## Pallet API
 //!
@@ -199,10 +199,12 @@ impl pallet_assets::Config for Runtime {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type PermitSignature = sp_runtime::testing::TestSignature;
 	type WeightInfo = ();
 	type RemoveItemsLimit = ConstU32<1000>;
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
+		type PermitBenchmarkHelper = ();
 	}
 }
 
@@ -226,8 +228,10 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type PermitSignature = sp_runtime::testing::TestSignature;
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
+		type PermitBenchmarkHelper = ();
 	}
 }
 
@@ -247,7 +251,8 @@ pub type PoolIdToAccountId = pallet_asset_conversion::AccountIdConverter<
 	(NativeOrWithId<u32>, NativeOrWithId<u32>),
 >;
 
-type NativeAndAssets = UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, AccountId>;
+pub type NativeAndAssets =
+	UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, AccountId>;
 
 impl pallet_asset_conversion::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -269,6 +274,8 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LPFee = ConstU32<3>; // means 0.3%
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = MaxSwapPathLength;
+	type MaxPriceObservations = ConstU32<10>;
+	type MaxRoutePools = ConstU32<0>;
 	type MintMinLiquidity = ConstU64<100>; // 100 is good enough when the main currency has 12 decimals.
 	type WeightInfo = ();
 	pallet_asset_conversion::runtime_benchmarks_enabled! {
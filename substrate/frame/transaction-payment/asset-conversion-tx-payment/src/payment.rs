@@ -28,7 +28,7 @@ use frame_support::{
 	},
 	unsigned::TransactionValidityError,
 };
-use pallet_asset_conversion::{QuotePrice, SwapCredit};
+use pallet_asset_conversion::{PriceOracle, QuotePrice, SwapCredit};
 use sp_runtime::{
 	traits::{DispatchInfoOf, Get, PostDispatchInfoOf, Zero},
 	transaction_validity::InvalidTransaction,
@@ -321,3 +321,156 @@ where
 		Ok(fee_in_asset)
 	}
 }
+
+/// Means to withdraw, correct and deposit fees in the asset accepted by the system, pricing the
+/// fees from the time-weighted average price of the pool instead of its spot price.
+///
+/// Works like [`SwapAssetAdapter`], except that the amount of the user's asset withdrawn for the
+/// fee is quoted with the [`PriceOracle`] over the window `W`. This keeps a price manipulation
+/// within a block from affecting the fees charged. The quoted amount is swapped at the spot
+/// price, and the payment is rejected if the swap yields less than the fee. A surplus goes to the
+/// recipient of the fees. Refunds of overestimated fees are priced like in [`SwapAssetAdapter`].
+///
+/// Fees cannot be paid in an asset whose pool does not have an average price over the window.
+///
+/// Parameters:
+/// - `A`: The asset identifier that system accepts as a fee payment (eg. native asset).
+/// - `F`: The fungibles registry that can handle assets provided by user and the `A` asset.
+/// - `S`: The swap implementation that can swap assets provided by user for the `A` asset and
+///   provides their average prices.
+/// - `W`: The window, in blocks, of the average price.
+/// - OU: The handler for withdrawn `fee` and `tip`, passed in the respective order to
+///   [OnUnbalanced::on_unbalanceds].
+/// - `T`: The pallet's configuration.
+pub struct TwapSwapAssetAdapter<A, F, S, W, OU>(PhantomData<(A, F, S, W, OU)>);
+
+impl<A, F, S, W, OU, T> OnChargeAssetTransaction<T> for TwapSwapAssetAdapter<A, F, S, W, OU>
+where
+	A: Get<T::AssetId>,
+	F: fungibles::Balanced<T::AccountId, Balance = BalanceOf<T>, AssetId = T::AssetId>,
+	S: SwapCredit<
+			T::AccountId,
+			Balance = BalanceOf<T>,
+			AssetKind = T::AssetId,
+			Credit = fungibles::Credit<T::AccountId, F>,
+		> + QuotePrice<Balance = BalanceOf<T>, AssetKind = T::AssetId>
+		+ PriceOracle<Balance = BalanceOf<T>, AssetKind = T::AssetId>,
+	W: Get<<S as PriceOracle>::BlockNumber>,
+	OU: OnUnbalanced<fungibles::Credit<T::AccountId, F>>,
+	T: Config,
+{
+	type AssetId = T::AssetId;
+	type Balance = BalanceOf<T>;
+	type LiquidityInfo = (fungibles::Credit<T::AccountId, F>, BalanceOf<T>);
+
+	fn withdraw_fee(
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		dispatch_info: &DispatchInfoOf<<T>::RuntimeCall>,
+		asset_id: Self::AssetId,
+		fee: Self::Balance,
+		tip: Self::Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		if asset_id == A::get() {
+			// The `asset_id` is the target asset, we do not need to swap.
+			return <SwapAssetAdapter<A, F, S, OU> as OnChargeAssetTransaction<T>>::withdraw_fee(
+				who,
+				call,
+				dispatch_info,
+				asset_id,
+				fee,
+				tip,
+			)
+		}
+
+		// Quote the amount of the `asset_id` needed to pay the fee in the asset `A`.
+		let asset_fee =
+			S::quote_twap_tokens_for_exact_tokens(asset_id.clone(), A::get(), fee, W::get(), true)
+				.ok_or(InvalidTransaction::Payment)?;
+
+		// Withdraw the `asset_id` credit for the swap.
+		let asset_fee_credit = F::withdraw(
+			asset_id.clone(),
+			who,
+			asset_fee,
+			Precision::Exact,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		)
+		.map_err(|_| InvalidTransaction::Payment)?;
+
+		// The credit is swapped at the spot price, which must yield at least `fee`.
+		let fee_credit = match S::swap_exact_tokens_for_tokens(
+			vec![asset_id, A::get()],
+			asset_fee_credit,
+			Some(fee),
+		) {
+			Ok(fee_credit) => fee_credit,
+			Err((credit_in, _)) => {
+				let _ = F::resolve(who, credit_in).defensive_proof("Should resolve the credit");
+				return Err(InvalidTransaction::Payment.into())
+			},
+		};
+
+		// Any surplus over `fee` is not refundable, since `who` paid for `fee` at the average
+		// price.
+		let (fee_credit, surplus) = fee_credit.split(fee);
+		if !surplus.peek().is_zero() {
+			OU::on_unbalanceds(Some(surplus).into_iter());
+		}
+
+		Ok((fee_credit, asset_fee))
+	}
+
+	fn can_withdraw_fee(
+		who: &T::AccountId,
+		asset_id: Self::AssetId,
+		fee: BalanceOf<T>,
+	) -> Result<(), TransactionValidityError> {
+		if asset_id == A::get() {
+			// The `asset_id` is the target asset, we do not need to swap.
+			return <SwapAssetAdapter<A, F, S, OU> as OnChargeAssetTransaction<T>>::can_withdraw_fee(
+				who, asset_id, fee,
+			)
+		}
+
+		let asset_fee =
+			S::quote_twap_tokens_for_exact_tokens(asset_id.clone(), A::get(), fee, W::get(), true)
+				.ok_or(InvalidTransaction::Payment)?;
+
+		// Ensure we can withdraw enough `asset_id` for the swap.
+		match F::can_withdraw(asset_id.clone(), who, asset_fee) {
+			WithdrawConsequence::BalanceLow |
+			WithdrawConsequence::UnknownAsset |
+			WithdrawConsequence::Underflow |
+			WithdrawConsequence::Overflow |
+			WithdrawConsequence::Frozen =>
+				return Err(TransactionValidityError::from(InvalidTransaction::Payment)),
+			WithdrawConsequence::Success |
+			WithdrawConsequence::ReducedToZero(_) |
+			WithdrawConsequence::WouldDie => {},
+		};
+
+		Ok(())
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::AccountId,
+		dispatch_info: &DispatchInfoOf<<T>::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<<T>::RuntimeCall>,
+		corrected_fee: Self::Balance,
+		tip: Self::Balance,
+		asset_id: Self::AssetId,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<BalanceOf<T>, TransactionValidityError> {
+		<SwapAssetAdapter<A, F, S, OU> as OnChargeAssetTransaction<T>>::correct_and_deposit_fee(
+			who,
+			dispatch_info,
+			post_info,
+			corrected_fee,
+			tip,
+			asset_id,
+			already_withdrawn,
+		)
+	}
+}
@@ -23,7 +23,7 @@ use frame_support::{
 		fungible::{Inspect, NativeOrWithId},
 		fungibles::{Inspect as FungiblesInspect, Mutate},
 		tokens::{Fortitude, Precision, Preservation},
-		OriginTrait,
+		ConstU64, OriginTrait,
 	},
 	weights::Weight,
 };
@@ -925,3 +925,138 @@ fn no_fee_and_no_weight_for_other_origins() {
 		assert_eq!(post_info.actual_weight, Some(info.call_weight));
 	})
 }
+
+#[test]
+fn twap_adapter_prices_fees_from_average_price() {
+	type Adapter = TwapSwapAssetAdapter<
+		Native,
+		NativeAndAssets,
+		AssetConversion,
+		ConstU64<10>,
+		DealWithFungiblesFees,
+	>;
+
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance
+			));
+			let caller = 1;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 1000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			setup_lp(asset_id, balance_factor);
+
+			let asset = NativeOrWithId::WithId(asset_id);
+			let fee = 20;
+			let info = info_from_weight(WEIGHT_5);
+
+			// the average price does not cover the window yet.
+			assert!(<Adapter as OnChargeAssetTransaction<Runtime>>::can_withdraw_fee(
+				&caller,
+				asset.clone(),
+				fee
+			)
+			.is_err());
+
+			System::set_block_number(11);
+			let fee_in_asset = AssetConversion::quote_price_tokens_for_exact_tokens(
+				asset.clone(),
+				NativeOrWithId::Native,
+				fee,
+				true,
+			)
+			.unwrap();
+			assert_eq!(fee_in_asset, 201);
+
+			// the spot price is manipulated within the block.
+			assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(5),
+				vec![Box::new(NativeOrWithId::Native), Box::new(asset.clone())],
+				1_000 * balance_factor,
+				1,
+				5,
+				false,
+			));
+			assert!(
+				AssetConversion::quote_price_tokens_for_exact_tokens(
+					asset.clone(),
+					NativeOrWithId::Native,
+					fee,
+					true,
+				)
+				.unwrap() < fee_in_asset / 2
+			);
+
+			// the fee is still charged at the average price.
+			assert_ok!(<Adapter as OnChargeAssetTransaction<Runtime>>::can_withdraw_fee(
+				&caller,
+				asset.clone(),
+				fee
+			));
+			let liquidity_info = <Adapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+				&caller,
+				CALL,
+				&info,
+				asset.clone(),
+				fee,
+				0,
+			)
+			.unwrap();
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+			assert_eq!(liquidity_info.0.peek(), fee);
+			// the surplus of the swap at the spot price goes to the fee recipient.
+			let surplus = FeeUnbalancedAmount::get();
+			assert!(surplus > 0);
+
+			assert_eq!(
+				<Adapter as OnChargeAssetTransaction<Runtime>>::correct_and_deposit_fee(
+					&caller,
+					&info,
+					&default_post_info(),
+					fee,
+					0,
+					asset.clone(),
+					liquidity_info,
+				),
+				Ok(fee_in_asset)
+			);
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+			assert_eq!(FeeUnbalancedAmount::get(), surplus + fee);
+
+			// the payment is rejected if the swap at the spot price yields less than the fee.
+			assert_ok!(Assets::mint_into(asset_id.into(), &5, 4_000_000));
+			assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(5),
+				vec![Box::new(asset.clone()), Box::new(NativeOrWithId::Native)],
+				4_000_000,
+				1,
+				5,
+				false,
+			));
+			assert!(<Adapter as OnChargeAssetTransaction<Runtime>>::withdraw_fee(
+				&caller,
+				CALL,
+				&info,
+				asset.clone(),
+				fee,
+				0,
+			)
+			.is_err());
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+		});
+}
@@ -143,10 +143,12 @@ impl pallet_assets::Config for Runtime {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type PermitSignature = sp_runtime::testing::TestSignature;
 	type WeightInfo = ();
 	type RemoveItemsLimit = ConstU32<1000>;
 	pallet_assets::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
+		type PermitBenchmarkHelper = ();
 	}
 }
 
@@ -110,6 +110,29 @@ fn create_spend_arguments<T: Config<I>, I: 'static>(
 	(asset_kind, 100u32.into(), beneficiary, beneficiary_lookup)
 }
 
+// Create a scheduled spend that streams its amount over 10 blocks. Returns whether the spend was
+// created.
+fn create_scheduled_spend<T: Config<I>, I: 'static>() -> Result<bool, BenchmarkError> {
+	let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+		create_spend_arguments::<T, I>(SEED);
+	T::BalanceConverter::ensure_successful(asset_kind.clone());
+	T::Paymaster::ensure_successful(&beneficiary, asset_kind.clone(), amount);
+
+	let Ok(origin) = T::SpendOrigin::try_successful_origin() else { return Ok(false) };
+	Treasury::<T, I>::spend_scheduled(
+		origin,
+		Box::new(asset_kind),
+		amount,
+		Box::new(beneficiary_lookup),
+		None,
+		PayoutSchedule::Stream { duration: 10u32.into() },
+	)?;
+	// The whole stream has accrued, so the payout is not below the minimum balance of the asset.
+	let now = T::BlockNumberProvider::current_block_number();
+	T::BlockNumberProvider::set_block_number(now.saturating_add(10u32.into()));
+	Ok(true)
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -350,6 +373,130 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	/// a successful origin, in which case `spend_scheduled` is un-callable and can use weight=0.
+	#[benchmark]
+	fn spend_scheduled() -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let schedule = PayoutSchedule::Recurring { period: 10u32.into(), count: 2 };
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind.clone()),
+			amount,
+			Box::new(beneficiary_lookup),
+			None,
+			schedule.clone(),
+		);
+
+		let valid_from = T::BlockNumberProvider::current_block_number();
+		let expire_at = schedule.end(valid_from).saturating_add(T::PayoutPeriod::get());
+		assert_last_event::<T, I>(
+			Event::ScheduledSpendApproved {
+				index: 0,
+				asset_kind,
+				amount,
+				beneficiary,
+				valid_from,
+				schedule,
+				expire_at,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn payout_scheduled() -> Result<(), BenchmarkError> {
+		let spend_exists = create_scheduled_spend::<T, _>()?;
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::payout(RawOrigin::Signed(caller.clone()).into(), 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if spend_exists {
+			let id = match ScheduledSpends::<T, I>::get(0).unwrap().status {
+				PaymentState::Attempted { id, .. } => {
+					assert_ne!(T::Paymaster::check_payment(id), PaymentStatus::Failure);
+					id
+				},
+				_ => panic!("No payout attempt made"),
+			};
+			assert_last_event::<T, I>(Event::Paid { index: 0, payment_id: id }.into());
+			assert!(Treasury::<T, _>::payout(RawOrigin::Signed(caller).into(), 0u32).is_err());
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn check_status_scheduled() -> Result<(), BenchmarkError> {
+		let spend_exists = create_scheduled_spend::<T, _>()?;
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		if spend_exists {
+			Treasury::<T, _>::payout(RawOrigin::Signed(caller.clone()).into(), 0u32)?;
+			match ScheduledSpends::<T, I>::get(0).unwrap().status {
+				PaymentState::Attempted { id, .. } => {
+					T::Paymaster::ensure_concluded(id);
+				},
+				_ => panic!("No payout attempt made"),
+			};
+		}
+
+		#[block]
+		{
+			let res =
+				Treasury::<T, _>::check_status(RawOrigin::Signed(caller.clone()).into(), 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if let Some(s) = ScheduledSpends::<T, I>::get(0) {
+			assert!(!matches!(s.status, PaymentState::Attempted { .. }));
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn void_spend_scheduled() -> Result<(), BenchmarkError> {
+		let spend_exists = create_scheduled_spend::<T, _>()?;
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::void_spend(origin as T::RuntimeOrigin, 0u32);
+
+			if spend_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		assert!(ScheduledSpends::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
@@ -69,6 +69,12 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! The `spend_scheduled` dispatchable approves a spend that is paid out over time according to a
+//! [`PayoutSchedule`]: either a number of recurring payments or a stream that accrues linearly
+//! per block. Whatever has accrued is claimed with `payout`, the payment is tracked with
+//! `check_status` and the remainder can be cancelled with `void_spend`, just like for one-off
+//! spends.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -84,16 +90,16 @@ pub use benchmarking::ArgumentsFactory;
 
 extern crate alloc;
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
 use alloc::{boxed::Box, collections::btree_map::BTreeMap};
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
-		UniqueSaturatedInto, Zero,
+		AccountIdConversion, AtLeast32BitUnsigned, BlockNumberProvider, CheckedAdd, One,
+		Saturating, StaticLookup, UniqueSaturatedInto, Zero,
 	},
-	PerThing, Permill, RuntimeDebug,
+	PerThing, Perbill, Permill, RuntimeDebug,
 };
 
 use frame_support::{
@@ -193,6 +199,102 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 	status: PaymentState<PaymentId>,
 }
 
+/// How the amount of a scheduled treasury spend is paid out over time.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub enum PayoutSchedule<BlockNumber> {
+	/// `count` payments of the spend amount, the first one at `valid_from` and the following ones
+	/// every `period` blocks.
+	Recurring { period: BlockNumber, count: u32 },
+	/// The spend amount accrues linearly over `duration` blocks, starting at `valid_from`.
+	Stream { duration: BlockNumber },
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> PayoutSchedule<BlockNumber> {
+	/// Whether the schedule pays out anything at all.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Self::Recurring { period, count } => !period.is_zero() && *count > 0,
+			Self::Stream { duration } => !duration.is_zero(),
+		}
+	}
+
+	/// The block from which on the whole spend can be claimed.
+	pub fn end(&self, valid_from: BlockNumber) -> BlockNumber {
+		match self {
+			Self::Recurring { period, count } =>
+				valid_from.saturating_add(period.saturating_mul(count.saturating_sub(1).into())),
+			Self::Stream { duration } => valid_from.saturating_add(*duration),
+		}
+	}
+
+	/// The total amount paid out for a spend of `amount`, or `None` if it overflows.
+	pub fn total<Balance: AtLeast32BitUnsigned>(&self, amount: Balance) -> Option<Balance> {
+		match self {
+			Self::Recurring { count, .. } => amount.checked_mul(&(*count).into()),
+			Self::Stream { .. } => Some(amount),
+		}
+	}
+
+	/// The amount of a spend of `amount` that has accrued by block `now`.
+	pub fn accrued<Balance: AtLeast32BitUnsigned + Copy>(
+		&self,
+		amount: Balance,
+		valid_from: BlockNumber,
+		now: BlockNumber,
+	) -> Balance {
+		if now < valid_from {
+			return Zero::zero()
+		}
+		let elapsed = now.saturating_sub(valid_from);
+		match self {
+			Self::Recurring { period, count } => {
+				let payments = elapsed / (*period).max(One::one());
+				let payments: u32 = payments.saturating_add(One::one()).unique_saturated_into();
+				amount.saturating_mul(payments.min(*count).into())
+			},
+			Self::Stream { duration } if elapsed >= *duration => amount,
+			Self::Stream { duration } =>
+				Perbill::from_rational(elapsed, *duration).mul_floor(amount),
+		}
+	}
+}
+
+/// Info regarding an approved treasury spend that is paid out according to a
+/// [`PayoutSchedule`].
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct ScheduledSpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, PaymentId> {
+	// The kind of asset to be spent.
+	asset_kind: AssetKind,
+	/// The amount of a single payment of a recurring spend, or the total amount of a stream.
+	amount: AssetBalance,
+	/// The beneficiary of the spend.
+	beneficiary: Beneficiary,
+	/// The block number from which the spend starts to accrue.
+	valid_from: BlockNumber,
+	/// How the amount is paid out over time.
+	schedule: PayoutSchedule<BlockNumber>,
+	/// The block number by which the remainder of the spend has to be claimed.
+	expire_at: BlockNumber,
+	/// The amount paid out so far, including the last payment attempt.
+	paid: AssetBalance,
+	/// The amount of the last payment attempt.
+	attempted: AssetBalance,
+	/// The status of the last payout/claim.
+	status: PaymentState<PaymentId>,
+}
+
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
@@ -352,6 +454,25 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Scheduled spends that have been approved and being processed.
+	///
+	/// They share the index space of [`Spends`].
+	// Hasher: Twox safe since `SpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type ScheduledSpends<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		SpendIndex,
+		ScheduledSpendStatus<
+			T::AssetKind,
+			AssetBalanceOf<T, I>,
+			T::Beneficiary,
+			BlockNumberFor<T, I>,
+			<T::Paymaster as Pay>::Id,
+		>,
+		OptionQuery,
+	>;
+
 	/// The blocknumber for the last triggered spend period.
 	#[pallet::storage]
 	pub type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
@@ -414,6 +535,16 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new scheduled spend has been approved.
+		ScheduledSpendApproved {
+			index: SpendIndex,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::Beneficiary,
+			valid_from: BlockNumberFor<T, I>,
+			schedule: PayoutSchedule<BlockNumberFor<T, I>>,
+			expire_at: BlockNumberFor<T, I>,
+		},
 	}
 
 	/// Error for the treasury pallet.
@@ -442,6 +573,8 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// The payout schedule pays out nothing or its total amount overflows.
+		InvalidSchedule,
 	}
 
 	#[pallet::hooks]
@@ -496,6 +629,34 @@ pub mod pallet {
 		spend_in_context: BTreeMap<Balance, Balance>,
 	}
 
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Ensure that the spends of an origin allowed to spend `max_amount` within the current
+		/// dispatch context, including `native_amount`, don't exceed `max_amount`.
+		fn note_spend_in_context(
+			max_amount: BalanceOf<T, I>,
+			native_amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+				let context = v.or_default();
+				// We group based on `max_amount`, to distinguish between different kind of
+				// origins. (assumes that all origins have different `max_amount`)
+				//
+				// Worst case is that we reject some "valid" request.
+				let spend = context.spend_in_context.entry(max_amount).or_default();
+
+				// Ensure that we don't overflow nor use more than `max_amount`
+				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
+					Err(Error::<T, I>::InsufficientPermission)
+				} else {
+					*spend = spend.saturating_add(native_amount);
+					Ok(())
+				}
+			})
+			.unwrap_or(Ok(()))?;
+			Ok(())
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Propose and approve a spend of treasury funds.
@@ -664,24 +825,7 @@ pub mod pallet {
 					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
 
 			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
-
-			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
-				let context = v.or_default();
-				// We group based on `max_amount`, to distinguish between different kind of
-				// origins. (assumes that all origins have different `max_amount`)
-				//
-				// Worst case is that we reject some "valid" request.
-				let spend = context.spend_in_context.entry(max_amount).or_default();
-
-				// Ensure that we don't overflow nor use more than `max_amount`
-				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
-					Err(Error::<T, I>::InsufficientPermission)
-				} else {
-					*spend = spend.saturating_add(native_amount);
-					Ok(())
-				}
-			})
-			.unwrap_or(Ok(()))?;
+			Self::note_spend_in_context(max_amount, native_amount)?;
 
 			let index = SpendCount::<T, I>::get();
 			Spends::<T, I>::insert(
@@ -721,6 +865,9 @@ pub mod pallet {
 		/// In case of a payout failure, the spend status must be updated with the `check_status`
 		/// dispatchable before retrying with the current function.
 		///
+		/// For a scheduled spend, the amount accrued since the last payout is paid. It may be
+		/// claimed until one [`Config::PayoutPeriod`] after the schedule ended.
+		///
 		/// ### Parameters
 		/// - `index`: The spend index.
 		///
@@ -728,10 +875,12 @@ pub mod pallet {
 		///
 		/// Emits [`Event::Paid`] if successful.
 		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::payout())]
+		#[pallet::weight(T::WeightInfo::payout().max(T::WeightInfo::payout_scheduled()))]
 		pub fn payout(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
 			ensure_signed(origin)?;
-			let mut spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let Some(mut spend) = Spends::<T, I>::get(index) else {
+				return Self::do_payout_scheduled(index)
+			};
 			let now = T::BlockNumberProvider::current_block_number();
 			ensure!(now >= spend.valid_from, Error::<T, I>::EarlyPayout);
 			ensure!(spend.expire_at > now, Error::<T, I>::SpendExpired);
@@ -770,15 +919,18 @@ pub mod pallet {
 		/// ## Events
 		///
 		/// Emits [`Event::PaymentFailed`] if the spend payout has failed.
-		/// Emits [`Event::SpendProcessed`] if the spend payout has succeed.
+		/// Emits [`Event::SpendProcessed`] if the spend payout has succeed. A scheduled spend is
+		/// only processed once its whole amount has been paid.
 		#[pallet::call_index(7)]
-		#[pallet::weight(T::WeightInfo::check_status())]
+		#[pallet::weight(T::WeightInfo::check_status().max(T::WeightInfo::check_status_scheduled()))]
 		pub fn check_status(origin: OriginFor<T>, index: SpendIndex) -> DispatchResultWithPostInfo {
 			use PaymentState as State;
 			use PaymentStatus as Status;
 
 			ensure_signed(origin)?;
-			let mut spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let Some(mut spend) = Spends::<T, I>::get(index) else {
+				return Self::do_check_status_scheduled(index)
+			};
 			let now = T::BlockNumberProvider::current_block_number();
 
 			if now > spend.expire_at && !matches!(spend.status, State::Attempted { .. }) {
@@ -817,7 +969,8 @@ pub mod pallet {
 		///
 		/// ## Details
 		///
-		/// A spend void is only possible if the payout has not been attempted yet.
+		/// A spend void is only possible if the payout has not been attempted yet. Voiding a
+		/// scheduled spend cancels the part of it that has not been paid yet.
 		///
 		/// ### Parameters
 		/// - `index`: The spend index.
@@ -826,19 +979,106 @@ pub mod pallet {
 		///
 		/// Emits [`Event::AssetSpendVoided`] if successful.
 		#[pallet::call_index(8)]
-		#[pallet::weight(T::WeightInfo::void_spend())]
+		#[pallet::weight(T::WeightInfo::void_spend().max(T::WeightInfo::void_spend_scheduled()))]
 		pub fn void_spend(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
 			T::RejectOrigin::ensure_origin(origin)?;
-			let spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let status = match Spends::<T, I>::get(index) {
+				Some(spend) => spend.status,
+				None =>
+					ScheduledSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?.status,
+			};
 			ensure!(
-				matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
+				matches!(status, PaymentState::Pending | PaymentState::Failed),
 				Error::<T, I>::AlreadyAttempted
 			);
 
 			Spends::<T, I>::remove(index);
+			ScheduledSpends::<T, I>::remove(index);
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a spend of treasury funds that is paid out over time.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the total
+		/// amount of the spend in the native asset. The amount of `asset_kind` is converted for
+		/// assertion using the [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Create an approved spend for transferring `asset_kind` to a designated beneficiary
+		/// according to `schedule`. The accrued amount is claimed with the `payout` dispatchable,
+		/// at the latest one [`Config::PayoutPeriod`] after the schedule ended. The remainder can
+		/// be cancelled with the `void_spend` dispatchable.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `amount`: The amount of each payment of a [`PayoutSchedule::Recurring`] spend, or the
+		///   total amount of a [`PayoutSchedule::Stream`].
+		/// - `beneficiary`: The beneficiary of the spend.
+		/// - `valid_from`: The block number from which the spend starts to accrue. If `None`, it
+		///   starts with the approval.
+		/// - `schedule`: How the spend is paid out over time.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::ScheduledSpendApproved`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend_scheduled())]
+		pub fn spend_scheduled(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] amount: AssetBalanceOf<T, I>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			valid_from: Option<BlockNumberFor<T, I>>,
+			schedule: PayoutSchedule<BlockNumberFor<T, I>>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			ensure!(!amount.is_zero() && schedule.is_valid(), Error::<T, I>::InvalidSchedule);
+			let total = schedule.total(amount).ok_or(Error::<T, I>::InvalidSchedule)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let valid_from = valid_from.unwrap_or(now);
+			let expire_at = schedule.end(valid_from).saturating_add(T::PayoutPeriod::get());
+			ensure!(expire_at > now, Error::<T, I>::SpendExpired);
+
+			let native_amount = T::BalanceConverter::from_asset_balance(total, *asset_kind.clone())
+				.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+			Self::note_spend_in_context(max_amount, native_amount)?;
+
+			let index = SpendCount::<T, I>::get();
+			ScheduledSpends::<T, I>::insert(
+				index,
+				ScheduledSpendStatus {
+					asset_kind: *asset_kind.clone(),
+					amount,
+					beneficiary: beneficiary.clone(),
+					valid_from,
+					schedule: schedule.clone(),
+					expire_at,
+					paid: Zero::zero(),
+					attempted: Zero::zero(),
+					status: PaymentState::Pending,
+				},
+			);
+			SpendCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::ScheduledSpendApproved {
+				index,
+				asset_kind: *asset_kind,
+				amount,
+				beneficiary,
+				valid_from,
+				schedule,
+				expire_at,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -998,6 +1238,83 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			.saturating_sub(T::Currency::minimum_balance())
 	}
 
+	/// Pay out the amount of the scheduled spend `index` that accrued since the last payout.
+	fn do_payout_scheduled(index: SpendIndex) -> DispatchResult {
+		let mut spend = ScheduledSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		ensure!(now >= spend.valid_from, Error::<T, I>::EarlyPayout);
+		ensure!(spend.expire_at > now, Error::<T, I>::SpendExpired);
+		ensure!(
+			matches!(spend.status, PaymentState::Pending | PaymentState::Failed),
+			Error::<T, I>::AlreadyAttempted
+		);
+
+		let amount = spend
+			.schedule
+			.accrued(spend.amount, spend.valid_from, now)
+			.saturating_sub(spend.paid);
+		ensure!(!amount.is_zero(), Error::<T, I>::EarlyPayout);
+
+		let id = T::Paymaster::pay(&spend.beneficiary, spend.asset_kind.clone(), amount)
+			.map_err(|_| Error::<T, I>::PayoutError)?;
+
+		spend.status = PaymentState::Attempted { id };
+		spend.paid = spend.paid.saturating_add(amount);
+		spend.attempted = amount;
+		spend.expire_at = spend.expire_at.max(now.saturating_add(T::PayoutPeriod::get()));
+		ScheduledSpends::<T, I>::insert(index, spend);
+
+		Self::deposit_event(Event::<T, I>::Paid { index, payment_id: id });
+
+		Ok(())
+	}
+
+	/// Check the status of the last payment of the scheduled spend `index` and remove the spend
+	/// from the storage if it has been paid in full or expired.
+	fn do_check_status_scheduled(index: SpendIndex) -> DispatchResultWithPostInfo {
+		use frame_support::{dispatch::Pays, traits::tokens::PaymentStatus as Status};
+		use PaymentState as State;
+
+		let mut spend = ScheduledSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+		let now = T::BlockNumberProvider::current_block_number();
+
+		if now > spend.expire_at && !matches!(spend.status, State::Attempted { .. }) {
+			// spend has expired and no further status update is expected.
+			ScheduledSpends::<T, I>::remove(index);
+			Self::deposit_event(Event::<T, I>::SpendProcessed { index });
+			return Ok(Pays::No.into())
+		}
+
+		let payment_id = match spend.status {
+			State::Attempted { id } => id,
+			_ => return Err(Error::<T, I>::NotAttempted.into()),
+		};
+
+		match T::Paymaster::check_payment(payment_id) {
+			Status::Failure => {
+				spend.status = State::Failed;
+				spend.paid = spend.paid.saturating_sub(spend.attempted);
+				spend.attempted = Zero::zero();
+				ScheduledSpends::<T, I>::insert(index, spend);
+				Self::deposit_event(Event::<T, I>::PaymentFailed { index, payment_id });
+				Ok(Pays::Yes.into())
+			},
+			Status::Success | Status::Unknown => {
+				let total = spend.schedule.total(spend.amount).unwrap_or(spend.amount);
+				if spend.paid >= total {
+					ScheduledSpends::<T, I>::remove(index);
+					Self::deposit_event(Event::<T, I>::SpendProcessed { index });
+				} else {
+					spend.status = State::Pending;
+					spend.attempted = Zero::zero();
+					ScheduledSpends::<T, I>::insert(index, spend);
+				}
+				Ok(Pays::No.into())
+			},
+			Status::InProgress => Err(Error::<T, I>::Inconclusive.into()),
+		}
+	}
+
 	/// Ensure the correctness of the state of this pallet.
 	#[cfg(any(feature = "try-runtime", test))]
 	fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
@@ -1050,11 +1367,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// [`SpendCount`].
 	/// 3. For each spend entry contained in [`Spends`] we should have spend.expire_at
 	/// > spend.valid_from.
+	/// 4. Each entry in [`ScheduledSpends`] should be saved under a key strictly less than current
+	/// [`SpendCount`] that is not used by [`Spends`].
+	/// 5. For each spend entry contained in [`ScheduledSpends`] the paid amount should not exceed
+	/// the total amount.
 	#[cfg(any(feature = "try-runtime", test))]
 	fn try_state_spends() -> Result<(), sp_runtime::TryRuntimeError> {
 		let current_spend_count = SpendCount::<T, I>::get();
 		ensure!(
-			current_spend_count as usize >= Spends::<T, I>::iter().count(),
+			current_spend_count as usize >=
+				Spends::<T, I>::iter().count() + ScheduledSpends::<T, I>::iter().count(),
 			"Actual number of spends exceeds `SpendCount`."
 		);
 
@@ -1066,6 +1388,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		ScheduledSpends::<T, I>::iter().try_for_each(|(spend_index, spend)| -> DispatchResult {
+			ensure!(
+				current_spend_count > spend_index && !Spends::<T, I>::contains_key(spend_index),
+				"Scheduled spends must use a SpendIndex below `SpendCount` that is not used by `Spends`."
+			);
+			ensure!(
+				spend.schedule.total(spend.amount).map_or(false, |total| spend.paid <= total),
+				"Scheduled spend paid more than its total amount."
+			);
+			Ok(())
+		})?;
+
 		Spends::<T, I>::iter().try_for_each(|(_index, spend)| -> DispatchResult {
 			ensure!(
 				spend.valid_from < spend.expire_at,
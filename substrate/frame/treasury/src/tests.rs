@@ -242,6 +242,14 @@ fn get_payment_id(i: SpendIndex) -> Option<u64> {
 	}
 }
 
+fn get_scheduled_payment_id(i: SpendIndex) -> Option<u64> {
+	let spend = ScheduledSpends::<Test, _>::get(i).expect("no spend");
+	match spend.status {
+		PaymentState::Attempted { id } => Some(id),
+		_ => None,
+	}
+}
+
 #[test]
 fn genesis_config_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -849,6 +857,218 @@ fn check_status_works() {
 	});
 }
 
+#[test]
+fn spend_scheduled_recurring_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(RuntimeOrigin::signed(10), Box::new(1), 2, Box::new(6), None));
+		// three payments of `10` coins of asset `1`, one every `10` blocks.
+		let schedule = PayoutSchedule::Recurring { period: 10, count: 3 };
+		assert_ok!(Treasury::spend_scheduled(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			10,
+			Box::new(6),
+			None,
+			schedule.clone(),
+		));
+		// the scheduled spend shares the index space of one-off spends.
+		assert_eq!(SpendCount::<Test, _>::get(), 2);
+		System::assert_last_event(
+			Event::<Test, _>::ScheduledSpendApproved {
+				index: 1,
+				asset_kind: 1,
+				amount: 10,
+				beneficiary: 6,
+				valid_from: 1,
+				schedule,
+				expire_at: 26,
+			}
+			.into(),
+		);
+
+		// the first payment is due right away.
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+		assert_eq!(paid(6, 1), 10);
+		assert_noop!(
+			Treasury::payout(RuntimeOrigin::signed(1), 1),
+			Error::<Test, _>::AlreadyAttempted
+		);
+		let payment_id = get_scheduled_payment_id(1).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		// the spend is kept until all payments were made.
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 1));
+		assert_eq!(ScheduledSpends::<Test, _>::get(1).unwrap().status, PaymentState::Pending);
+
+		// the next payment is not due yet.
+		System::set_block_number(10);
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 1), Error::<Test, _>::EarlyPayout);
+
+		// missed payments are paid at once.
+		System::set_block_number(21);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+		assert_eq!(paid(6, 1), 30);
+		let payment_id = get_scheduled_payment_id(1).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 1));
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 1 }.into());
+		assert_eq!(ScheduledSpends::<Test, _>::get(1), None);
+		assert_ok!(Treasury::do_try_state());
+	});
+}
+
+#[test]
+fn spend_scheduled_stream_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// `100` coins of asset `1` stream over `10` blocks from block `2`.
+		assert_ok!(Treasury::spend_scheduled(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			100,
+			Box::new(6),
+			Some(2),
+			PayoutSchedule::Stream { duration: 10 },
+		));
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test, _>::EarlyPayout);
+
+		// half of the stream has accrued.
+		System::set_block_number(7);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 50);
+
+		// the payment fails and is retried with what accrued in the meantime.
+		let payment_id = get_scheduled_payment_id(0).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Failure);
+		unpay(6, 1, 50);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::<Test, _>::PaymentFailed { index: 0, payment_id }.into());
+		System::set_block_number(9);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 70);
+		let payment_id = get_scheduled_payment_id(0).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 0));
+
+		// the stream ended.
+		System::set_block_number(15);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 100);
+		let payment_id = get_scheduled_payment_id(0).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 0 }.into());
+	});
+}
+
+#[test]
+fn spend_scheduled_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(20);
+		let spend = |origin, amount, valid_from, schedule| {
+			Treasury::spend_scheduled(
+				origin,
+				Box::new(1),
+				amount,
+				Box::new(6),
+				valid_from,
+				schedule,
+			)
+		};
+		let recurring = |period, count| PayoutSchedule::Recurring { period, count };
+
+		// schedules that pay out nothing.
+		for (amount, schedule) in [
+			(0, recurring(10, 3)),
+			(1, recurring(0, 3)),
+			(1, recurring(10, 0)),
+			(1, PayoutSchedule::Stream { duration: 0 }),
+		] {
+			assert_noop!(
+				spend(RuntimeOrigin::root(), amount, None, schedule),
+				Error::<Test, _>::InvalidSchedule
+			);
+		}
+		assert_noop!(
+			spend(RuntimeOrigin::root(), u64::MAX, None, recurring(10, 2)),
+			Error::<Test, _>::InvalidSchedule
+		);
+
+		// the total amount is checked against the spend origin: 3 * 1 * 2 > 5.
+		assert_noop!(
+			spend(RuntimeOrigin::signed(10), 1, None, recurring(10, 3)),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_ok!(spend(RuntimeOrigin::signed(10), 1, None, recurring(10, 2)));
+
+		// the schedule ended more than a payout period ago.
+		assert_noop!(
+			spend(RuntimeOrigin::root(), 1, Some(5), recurring(5, 2)),
+			Error::<Test, _>::SpendExpired
+		);
+	});
+}
+
+#[test]
+fn void_spend_scheduled_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_scheduled(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			100,
+			Box::new(6),
+			None,
+			PayoutSchedule::Stream { duration: 10 },
+		));
+		System::set_block_number(6);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		// cannot be voided while a payment is attempted.
+		assert_noop!(
+			Treasury::void_spend(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::AlreadyAttempted
+		);
+		let payment_id = get_scheduled_payment_id(0).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 0));
+
+		// the remainder is cancelled.
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::<Test, _>::AssetSpendVoided { index: 0 }.into());
+		assert_eq!(ScheduledSpends::<Test, _>::get(0), None);
+		assert_eq!(paid(6, 1), 50);
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test, _>::InvalidIndex);
+	});
+}
+
+#[test]
+fn check_status_scheduled_expiry_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_scheduled(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			10,
+			Box::new(6),
+			None,
+			PayoutSchedule::Recurring { period: 2, count: 2 },
+		));
+		assert_noop!(
+			Treasury::check_status(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::NotAttempted
+		);
+
+		// the spend expires one payout period after the last payment was due.
+		System::set_block_number(8);
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test, _>::SpendExpired);
+		System::set_block_number(9);
+		let info = Treasury::check_status(RuntimeOrigin::signed(1), 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 0 }.into());
+		assert_eq!(ScheduledSpends::<Test, _>::get(0), None);
+	});
+}
+
 #[test]
 fn try_state_proposals_invariant_1_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -35,21 +35,21 @@
 //! Autogenerated weights for `pallet_treasury`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `4563561839a5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
-// frame-omni-bencher
+// ./target/release/frame-omni-bencher
 // v1
 // benchmark
 // pallet
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_treasury
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/treasury/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/treasury/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -59,7 +59,6 @@
 // --no-min-squares
 // --no-median-slopes
 // --genesis-builder-policy=none
-// --exclude-pallets=pallet_xcm,pallet_xcm_benchmarks::fungible,pallet_xcm_benchmarks::generic,pallet_nomination_pools,pallet_remark,pallet_transaction_storage,pallet_election_provider_multi_block,pallet_election_provider_multi_block::signed,pallet_election_provider_multi_block::unsigned,pallet_election_provider_multi_block::verifier
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -79,6 +78,10 @@ pub trait WeightInfo {
 	fn payout() -> Weight;
 	fn check_status() -> Weight;
 	fn void_spend() -> Weight;
+	fn spend_scheduled() -> Weight;
+	fn payout_scheduled() -> Weight;
+	fn check_status_scheduled() -> Weight;
+	fn void_spend_scheduled() -> Weight;
 }
 
 /// Weights for `pallet_treasury` using the Substrate node and recommended hardware.
@@ -94,8 +97,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `1887`
-		// Minimum execution time: 9_084_000 picoseconds.
-		Weight::from_parts(9_260_000, 1887)
+		// Minimum execution time: 12_010_000 picoseconds.
+		Weight::from_parts(12_537_000, 1887)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
@@ -105,8 +108,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `69`
 		//  Estimated: `1887`
-		// Minimum execution time: 5_149_000 picoseconds.
-		Weight::from_parts(5_358_000, 1887)
+		// Minimum execution time: 6_606_000 picoseconds.
+		Weight::from_parts(6_962_000, 1887)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -119,10 +122,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `78`
 		//  Estimated: `1501`
-		// Minimum execution time: 8_211_000 picoseconds.
-		Weight::from_parts(11_324_784, 1501)
-			// Standard Error: 806
-			.saturating_add(Weight::from_parts(45_246, 0).saturating_mul(p.into()))
+		// Minimum execution time: 11_130_000 picoseconds.
+		Weight::from_parts(17_682_006, 1501)
+			// Standard Error: 4_596
+			.saturating_add(Weight::from_parts(27_729, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -136,8 +139,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `3502`
-		// Minimum execution time: 11_348_000 picoseconds.
-		Weight::from_parts(11_874_000, 3502)
+		// Minimum execution time: 14_469_000 picoseconds.
+		Weight::from_parts(15_112_000, 3502)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -153,8 +156,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `473`
 		//  Estimated: `6208`
-		// Minimum execution time: 55_665_000 picoseconds.
-		Weight::from_parts(57_099_000, 6208)
+		// Minimum execution time: 68_790_000 picoseconds.
+		Weight::from_parts(71_754_000, 6208)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
@@ -164,22 +167,84 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `143`
 		//  Estimated: `3539`
-		// Minimum execution time: 12_058_000 picoseconds.
-		Weight::from_parts(12_297_000, 3539)
+		// Minimum execution time: 14_944_000 picoseconds.
+		Weight::from_parts(15_465_000, 3539)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:0 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
 	fn void_spend() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `144`
 		//  Estimated: `3539`
-		// Minimum execution time: 10_730_000 picoseconds.
-		Weight::from_parts(10_908_000, 3539)
+		// Minimum execution time: 14_226_000 picoseconds.
+		Weight::from_parts(14_963_000, 3539)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:0 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	fn spend_scheduled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3502`
+		// Minimum execution time: 14_953_000 picoseconds.
+		Weight::from_parts(15_353_000, 3502)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Treasury::Spends` (r:1 w:0)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:1 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout_scheduled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `511`
+		//  Estimated: `6208`
+		// Minimum execution time: 71_319_000 picoseconds.
+		Weight::from_parts(74_175_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Treasury::Spends` (r:1 w:0)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:1 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	fn check_status_scheduled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `181`
+		//  Estimated: `3580`
+		// Minimum execution time: 18_406_000 picoseconds.
+		Weight::from_parts(19_009_000, 3580)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Treasury::Spends` (r:1 w:1)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:1 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	fn void_spend_scheduled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `181`
+		//  Estimated: `3580`
+		// Minimum execution time: 18_214_000 picoseconds.
+		Weight::from_parts(23_902_000, 3580)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -194,8 +259,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `1887`
-		// Minimum execution time: 9_084_000 picoseconds.
-		Weight::from_parts(9_260_000, 1887)
+		// Minimum execution time: 12_010_000 picoseconds.
+		Weight::from_parts(12_537_000, 1887)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
@@ -205,8 +270,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `69`
 		//  Estimated: `1887`
-		// Minimum execution time: 5_149_000 picoseconds.
-		Weight::from_parts(5_358_000, 1887)
+		// Minimum execution time: 6_606_000 picoseconds.
+		Weight::from_parts(6_962_000, 1887)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -219,10 +284,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `78`
 		//  Estimated: `1501`
-		// Minimum execution time: 8_211_000 picoseconds.
-		Weight::from_parts(11_324_784, 1501)
-			// Standard Error: 806
-			.saturating_add(Weight::from_parts(45_246, 0).saturating_mul(p.into()))
+		// Minimum execution time: 11_130_000 picoseconds.
+		Weight::from_parts(17_682_006, 1501)
+			// Standard Error: 4_596
+			.saturating_add(Weight::from_parts(27_729, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -236,8 +301,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `3502`
-		// Minimum execution time: 11_348_000 picoseconds.
-		Weight::from_parts(11_874_000, 3502)
+		// Minimum execution time: 14_469_000 picoseconds.
+		Weight::from_parts(15_112_000, 3502)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -253,8 +318,8 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `473`
 		//  Estimated: `6208`
-		// Minimum execution time: 55_665_000 picoseconds.
-		Weight::from_parts(57_099_000, 6208)
+		// Minimum execution time: 68_790_000 picoseconds.
+		Weight::from_parts(71_754_000, 6208)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
@@ -264,20 +329,82 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `143`
 		//  Estimated: `3539`
-		// Minimum execution time: 12_058_000 picoseconds.
-		Weight::from_parts(12_297_000, 3539)
+		// Minimum execution time: 14_944_000 picoseconds.
+		Weight::from_parts(15_465_000, 3539)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Treasury::Spends` (r:1 w:1)
 	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:0 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
 	fn void_spend() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `144`
 		//  Estimated: `3539`
-		// Minimum execution time: 10_730_000 picoseconds.
-		Weight::from_parts(10_908_000, 3539)
+		// Minimum execution time: 14_226_000 picoseconds.
+		Weight::from_parts(14_963_000, 3539)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:0 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	fn spend_scheduled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3502`
+		// Minimum execution time: 14_953_000 picoseconds.
+		Weight::from_parts(15_353_000, 3502)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Treasury::Spends` (r:1 w:0)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:1 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn payout_scheduled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `511`
+		//  Estimated: `6208`
+		// Minimum execution time: 71_319_000 picoseconds.
+		Weight::from_parts(74_175_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Treasury::Spends` (r:1 w:0)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:1 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	fn check_status_scheduled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `181`
+		//  Estimated: `3580`
+		// Minimum execution time: 18_406_000 picoseconds.
+		Weight::from_parts(19_009_000, 3580)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Treasury::Spends` (r:1 w:1)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::ScheduledSpends` (r:1 w:1)
+	/// Proof: `Treasury::ScheduledSpends` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	fn void_spend_scheduled() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `181`
+		//  Estimated: `3580`
+		// Minimum execution time: 18_214_000 picoseconds.
+		Weight::from_parts(23_902_000, 3580)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -55,6 +55,25 @@ impl BenchmarkHelper<MultiSignature, AccountId32> for () {
 	}
 }
 
+#[cfg(feature = "std")]
+impl BenchmarkHelper<sp_runtime::testing::TestSignature, u64> for () {
+	fn create_signature(_entropy: &[u8], msg: &[u8]) -> (sp_runtime::testing::TestSignature, u64) {
+		(sp_runtime::testing::TestSignature(0, msg.to_vec()), 0)
+	}
+}
+
+impl<AccountId: Default + Clone>
+	BenchmarkHelper<crate::testing::TestSignature<AccountId>, AccountId> for ()
+{
+	fn create_signature(
+		_entropy: &[u8],
+		msg: &[u8],
+	) -> (crate::testing::TestSignature<AccountId>, AccountId) {
+		let who = AccountId::default();
+		(crate::testing::TestSignature(who.clone(), msg.to_vec()), who)
+	}
+}
+
 #[benchmarks(where
 	T: Config + Send + Sync,
 	T::RuntimeCall: Dispatchable<Info = DispatchInfo> + GetDispatchInfo,
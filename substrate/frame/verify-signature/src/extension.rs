@@ -18,11 +18,10 @@
 //! Transaction extension which validates a signature against a payload constructed from a call and
 //! the rest of the transaction extension pipeline.
 
-use crate::{Config, WeightInfo};
+use crate::{signing_message, Config, WeightInfo};
 use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::{pallet_prelude::TransactionSource, traits::OriginTrait};
 use scale_info::TypeInfo;
-use sp_io::hashing::blake2_256;
 use sp_runtime::{
 	impl_tx_ext_default,
 	traits::{
@@ -142,7 +141,7 @@ where
 		//   model).
 		//
 		// The encoded bytes of the payload are then hashed using `blake2_256`.
-		let msg = inherited_implication.using_encoded(blake2_256);
+		let msg = signing_message(inherited_implication);
 
 		// The extension was enabled, so the signature must match.
 		if !signature.verify(&msg[..], account) {
@@ -24,6 +24,7 @@
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod extension;
+pub mod testing;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -39,6 +40,14 @@ pub use weights::WeightInfo;
 
 pub use pallet::*;
 
+/// The message signed for `payload`: the `blake2_256` hash of its encoding.
+///
+/// This is how the [`VerifySignature`] extension builds the message it checks the signature
+/// against, and it can be used by pallets verifying signatures of their own payloads.
+pub fn signing_message(payload: &impl Encode) -> [u8; 32] {
+	payload.using_encoded(sp_io::hashing::blake2_256)
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
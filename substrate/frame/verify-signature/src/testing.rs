@@ -0,0 +1,49 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Signature types for the tests of pallets that verify signatures.
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{IdentifyAccount, Lazy, Verify};
+
+/// A dummy signature of a message by an account of any type, like
+/// [`sp_runtime::testing::TestSignature`] for `u64` accounts.
+///
+/// It is valid for exactly the account and the message it holds.
+#[derive(Eq, PartialEq, Clone, Debug, Encode, Decode, DecodeWithMemTracking, TypeInfo)]
+pub struct TestSignature<AccountId>(pub AccountId, pub Vec<u8>);
+
+/// The signer of a [`TestSignature`].
+pub struct TestSigner<AccountId>(pub AccountId);
+
+impl<AccountId> IdentifyAccount for TestSigner<AccountId> {
+	type AccountId = AccountId;
+
+	fn into_account(self) -> AccountId {
+		self.0
+	}
+}
+
+impl<AccountId: PartialEq> Verify for TestSignature<AccountId> {
+	type Signer = TestSigner<AccountId>;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+		signer == &self.0 && msg.get() == &self.1[..]
+	}
+}
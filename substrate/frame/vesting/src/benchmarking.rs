@@ -22,7 +22,10 @@
 use frame_benchmarking::{v2::*, BenchmarkError};
 use frame_support::assert_ok;
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
-use sp_runtime::traits::{Bounded, CheckedDiv, CheckedMul};
+use sp_runtime::{
+	traits::{Bounded, CheckedDiv, CheckedMul},
+	Perbill,
+};
 
 use crate::*;
 
@@ -69,6 +72,35 @@ fn add_vesting_schedules<T: Config>(
 	Ok(total_locked)
 }
 
+fn add_vesting_grants<T: Config>(
+	target: &T::AccountId,
+	revoker: &T::AccountId,
+	n: u32,
+) -> Result<BalanceOf<T>, &'static str> {
+	let amount = T::MinVestedTransfer::get().checked_mul(&20_u32.into()).unwrap();
+	// Grant vests linearly between blocks 1 and 21.
+	let curve =
+		VestingCurve::Linear { start: 1_u32.into(), cliff: 1_u32.into(), end: 21_u32.into() };
+
+	let source = account("source", 0, SEED);
+	T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
+
+	T::BlockNumberProvider::set_block_number(BlockNumberFor::<T>::zero());
+
+	let mut total_locked: BalanceOf<T> = Zero::zero();
+	for _ in 0..n {
+		total_locked += amount;
+
+		let grant = VestingGrant { amount, curve: curve.clone(), revoker: Some(revoker.clone()) };
+		assert_ok!(Pallet::<T>::do_vested_grant_transfer(&source, target, grant));
+
+		// Top up to guarantee we can always transfer another grant.
+		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
+	}
+
+	Ok(total_locked)
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -435,6 +467,74 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn vested_grant_transfer(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<0, { T::MAX_VESTING_SCHEDULES - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		// Give target existing locks and grants.
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		let orig_balance = T::Currency::free_balance(&target);
+		let revoker = account::<T::AccountId>("revoker", 0, SEED);
+		let mut expected_balance = add_vesting_grants::<T>(&target, &revoker, s)?;
+
+		let amount = T::MinVestedTransfer::get();
+		expected_balance += amount;
+		// The curve with the most milestones is the most expensive to evaluate.
+		let milestones = (1..=MAX_MILESTONES)
+			.map(|i| ((i + 1).into(), Perbill::from_rational(i, MAX_MILESTONES)))
+			.collect::<Vec<_>>();
+		let curve = VestingCurve::Milestones(milestones.try_into().unwrap());
+		let grant = VestingGrant { amount, curve, revoker: Some(caller.clone()) };
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), target_lookup, grant);
+
+		assert_eq!(
+			orig_balance + expected_balance,
+			T::Currency::free_balance(&target),
+			"Transfer didn't happen",
+		);
+		assert_eq!(
+			Pallet::<T>::vesting_balance(&target),
+			Some(expected_balance),
+			"Lock not correctly updated",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_grant(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<1, { T::MAX_VESTING_SCHEDULES }>,
+	) -> Result<(), BenchmarkError> {
+		let revoker = whitelisted_caller();
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		add_vesting_grants::<T>(&target, &revoker, s)?;
+
+		// Half of each grant has vested.
+		T::BlockNumberProvider::set_block_number(11_u32.into());
+		let grant_index = s - 1;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(revoker), target_lookup, grant_index);
+
+		assert_eq!(Pallet::<T>::grants(target).len(), grant_index as usize, "Grant not revoked");
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::ExtBuilder::default().existential_deposit(256).build(),
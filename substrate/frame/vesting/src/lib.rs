@@ -42,6 +42,16 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `vested_grant_transfer` - Transfer funds that vest along a [`VestingCurve`], which supports
+//!   cliffs, steps and milestones.
+//! - `revoke_grant` - Revoke the unvested part of a grant.
+//!
+//! ### Grants
+//!
+//! Besides the linear schedules of [`VestingInfo`], an account may have grants. The amount of a
+//! [`VestingGrant`] vests along a [`VestingCurve`] and the grant may name a revoker. The revoker
+//! can take back the part of the grant that has not vested yet, while the vested part stays with
+//! the account. Grants are locked together with the schedules and unlocked by `vest`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -51,6 +61,7 @@ mod benchmarking;
 mod mock;
 #[cfg(test)]
 mod tests;
+mod vesting_grant;
 mod vesting_info;
 
 pub mod migrations;
@@ -82,6 +93,7 @@ use sp_runtime::{
 };
 
 pub use pallet::*;
+pub use vesting_grant::*;
 pub use vesting_info::*;
 pub use weights::WeightInfo;
 
@@ -90,6 +102,8 @@ type BalanceOf<T> =
 type MaxLocksOf<T> =
 	<<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type VestingGrantOf<T> =
+	VestingGrant<BalanceOf<T>, BlockNumberFor<T>, <T as frame_system::Config>::AccountId>;
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
@@ -232,6 +246,15 @@ pub mod pallet {
 		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
 	>;
 
+	/// The vesting grants of a given account.
+	#[pallet::storage]
+	pub type Grants<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingGrantOf<T>, MaxVestingSchedulesGet<T>>,
+	>;
+
 	/// Storage version of the pallet.
 	///
 	/// New networks start with latest version, as determined by the genesis build.
@@ -293,6 +316,10 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// A vesting grant has been created.
+		GrantCreated { account: T::AccountId, grant_index: u32 },
+		/// The unvested part of a vesting grant has been returned to its revoker.
+		GrantRevoked { account: T::AccountId, revoker: T::AccountId, unvested: BalanceOf<T> },
 	}
 
 	/// Error for the vesting pallet.
@@ -309,6 +336,8 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// The sender is not the revoker of the grant.
+		NotRevoker,
 	}
 
 	#[pallet::call]
@@ -488,6 +517,81 @@ pub mod pallet {
 			))
 			.into())
 		}
+
+		/// Create a vested transfer whose funds vest along a custom curve.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account receiving the vested funds.
+		/// - `grant`: The grant attached to the transfer. If it names a revoker, that account can
+		///   take back the part that has not vested yet with `revoke_grant`.
+		///
+		/// Emits `GrantCreated`.
+		///
+		/// NOTE: This will unlock all schedules and grants through the current block.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::vested_grant_transfer(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn vested_grant_transfer(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			grant: VestingGrantOf<T>,
+		) -> DispatchResult {
+			let transactor = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_vested_grant_transfer(&transactor, &target, grant)
+		}
+
+		/// Revoke a vesting grant and transfer its unvested funds back to the revoker.
+		///
+		/// The funds that have vested by the current block stay with the `target` and are
+		/// unlocked.
+		///
+		/// Fails if the unvested funds cannot be transferred because another lock of the
+		/// `target`, like a staking lock, also covers them. The grant can be revoked once that
+		/// lock is lifted.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the revoker of the grant.
+		///
+		/// - `target`: The account that received the grant.
+		/// - `grant_index`: The index of the grant to revoke.
+		///
+		/// Emits `GrantRevoked`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_grant(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn revoke_grant(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			grant_index: u32,
+		) -> DispatchResult {
+			let revoker = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+
+			let mut grants = Grants::<T>::get(&target).ok_or(Error::<T>::NotVesting)?;
+			let grant_index = grant_index as usize;
+			let grant = grants.get(grant_index).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+			ensure!(grant.revoker.as_ref() == Some(&revoker), Error::<T>::NotRevoker);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let unvested = grant.locked_at(now);
+			grants.remove(grant_index);
+			Self::write_grants(&target, grants);
+
+			// Unlock the vested funds before moving the unvested ones.
+			let schedules = Vesting::<T>::get(&target).unwrap_or_default();
+			let (schedules, locked_now) =
+				Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+			Self::write_vesting(&target, schedules)?;
+			Self::write_lock(&target, locked_now);
+
+			T::Currency::transfer(&target, &revoker, unvested, ExistenceRequirement::AllowDeath)?;
+
+			Self::deposit_event(Event::<T>::GrantRevoked { account: target, revoker, unvested });
+			Ok(())
+		}
 	}
 }
 
@@ -549,6 +653,35 @@ impl<T: Config> Pallet<T> {
 		Some(schedule)
 	}
 
+	// Public function for accessing the vesting grants.
+	pub fn grants(account: T::AccountId) -> Vec<VestingGrantOf<T>> {
+		Grants::<T>::get(account).map(|grants| grants.into_inner()).unwrap_or_default()
+	}
+
+	// Execute a vested transfer from `source` to `target` with the given `grant`.
+	fn do_vested_grant_transfer(
+		source: &T::AccountId,
+		target: &T::AccountId,
+		grant: VestingGrantOf<T>,
+	) -> DispatchResult {
+		// Validate user inputs.
+		ensure!(grant.amount >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+		ensure!(grant.is_valid(), Error::<T>::InvalidScheduleParams);
+
+		let mut grants = Grants::<T>::get(target).unwrap_or_default();
+		let amount = grant.amount;
+		ensure!(grants.try_push(grant).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		T::Currency::transfer(source, target, amount, ExistenceRequirement::AllowDeath)?;
+
+		Self::deposit_event(Event::<T>::GrantCreated {
+			account: target.clone(),
+			grant_index: grants.len() as u32 - 1,
+		});
+		Grants::<T>::insert(target, grants);
+		Self::do_vest(target.clone())
+	}
+
 	// Execute a vested transfer from `source` to `target` with the given `schedule`.
 	fn do_vested_transfer(
 		source: &T::AccountId,
@@ -617,8 +750,43 @@ impl<T: Config> Pallet<T> {
 		(filtered_schedules, total_locked_now)
 	}
 
+	/// Remove the fully vested grants of `who` and return the amount still locked by the others.
+	fn update_grants(who: &T::AccountId) -> BalanceOf<T> {
+		let Some(mut grants) = Grants::<T>::get(who) else { return Zero::zero() };
+		let now = T::BlockNumberProvider::current_block_number();
+
+		let count = grants.len();
+		let mut total_locked_now: BalanceOf<T> = Zero::zero();
+		grants.retain(|grant| {
+			let locked_now = grant.locked_at(now);
+			total_locked_now = total_locked_now.saturating_add(locked_now);
+			!locked_now.is_zero()
+		});
+		if grants.len() != count {
+			Self::write_grants(who, grants);
+		}
+
+		total_locked_now
+	}
+
+	/// Write the updated vesting grants of an account to storage.
+	fn write_grants(
+		who: &T::AccountId,
+		grants: BoundedVec<VestingGrantOf<T>, MaxVestingSchedulesGet<T>>,
+	) {
+		if grants.is_empty() {
+			Grants::<T>::remove(who);
+		} else {
+			Grants::<T>::insert(who, grants);
+		}
+	}
+
 	/// Write an accounts updated vesting lock to storage.
+	///
+	/// `total_locked_now` is the amount locked by the schedules; the amount locked by the grants
+	/// is added to it.
 	fn write_lock(who: &T::AccountId, total_locked_now: BalanceOf<T>) {
+		let total_locked_now = total_locked_now.saturating_add(Self::update_grants(who));
 		if total_locked_now.is_zero() {
 			T::Currency::remove_lock(VESTING_ID, who);
 			Self::deposit_event(Event::<T>::VestingCompleted { account: who.clone() });
@@ -653,7 +821,11 @@ impl<T: Config> Pallet<T> {
 
 	/// Unlock any vested funds of `who`.
 	fn do_vest(who: T::AccountId) -> DispatchResult {
-		let schedules = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
+		let schedules = match Vesting::<T>::get(&who) {
+			Some(schedules) => schedules,
+			None if Grants::<T>::contains_key(&who) => Default::default(),
+			None => return Err(Error::<T>::NotVesting.into()),
+		};
 
 		let (schedules, locked_now) =
 			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
@@ -718,15 +890,22 @@ where
 
 	/// Get the amount that is currently being vested and cannot be transferred out of this account.
 	fn vesting_balance(who: &T::AccountId) -> Option<BalanceOf<T>> {
-		if let Some(v) = Vesting::<T>::get(who) {
-			let now = T::BlockNumberProvider::current_block_number();
-			let total_locked_now = v.iter().fold(Zero::zero(), |total, schedule| {
+		let schedules = Vesting::<T>::get(who);
+		let grants = Grants::<T>::get(who);
+		if schedules.is_none() && grants.is_none() {
+			return None
+		}
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let schedules_locked =
+			schedules.unwrap_or_default().iter().fold(Zero::zero(), |total, schedule| {
 				schedule.locked_at::<T::BlockNumberToBalance>(now).saturating_add(total)
 			});
-			Some(T::Currency::free_balance(who).min(total_locked_now))
-		} else {
-			None
-		}
+		let total_locked_now = grants
+			.unwrap_or_default()
+			.iter()
+			.fold(schedules_locked, |total, grant| grant.locked_at(now).saturating_add(total));
+		Some(T::Currency::free_balance(who).min(total_locked_now))
 	}
 
 	/// Adds a vesting schedule to a given account.
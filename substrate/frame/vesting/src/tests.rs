@@ -25,6 +25,7 @@ use sp_runtime::{
 
 use super::{Vesting as VestingStorage, *};
 use crate::mock::{vesting_events_since_last_call, Balances, ExtBuilder, System, Test, Vesting};
+use sp_runtime::Perbill;
 
 /// A default existential deposit.
 const ED: u64 = 256;
@@ -1262,3 +1263,209 @@ fn vested_transfer_impl_works() {
 		);
 	});
 }
+
+/// A linear grant from 0 to 20 with a cliff at block 10 and no revoker.
+fn linear_grant(amount: u64) -> VestingGrantOf<Test> {
+	VestingGrant {
+		amount,
+		curve: VestingCurve::Linear { start: 0, cliff: 10, end: 20 },
+		revoker: None,
+	}
+}
+
+#[test]
+fn vested_grant_transfer_with_cliff_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_eq!(Balances::free_balance(&4), ED * 40);
+		let grant = linear_grant(ED * 10);
+		assert_ok!(Vesting::vested_grant_transfer(Some(3).into(), 4, grant.clone()));
+		assert!(vesting_events_since_last_call()
+			.contains(&Event::GrantCreated { account: 4, grant_index: 0 }));
+		assert_eq!(Vesting::grants(4), vec![grant]);
+		assert_eq!(Balances::free_balance(&3), ED * 20);
+		assert_eq!(Balances::free_balance(&4), ED * 50);
+
+		// Nothing vests before the cliff.
+		System::set_block_number(9);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+		assert_ok!(Vesting::vest(Some(4).into()));
+		assert_noop!(
+			Balances::transfer_allow_death(Some(4).into(), 3, ED * 40 + 1),
+			TokenError::Frozen,
+		);
+
+		// At the cliff, everything vested since the start is released.
+		System::set_block_number(10);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 5));
+		assert_ok!(Vesting::vest(Some(4).into()));
+		assert_ok!(Balances::transfer_allow_death(Some(4).into(), 3, ED * 45));
+
+		// Completed grants are removed by `vest`.
+		System::set_block_number(20);
+		assert_eq!(Vesting::vesting_balance(&4), Some(0));
+		assert_ok!(Vesting::vest(Some(4).into()));
+		assert!(!Grants::<Test>::contains_key(4));
+		assert_eq!(Vesting::vesting_balance(&4), None);
+	});
+}
+
+#[test]
+fn grants_are_locked_together_with_schedules() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Account 12 has 5 * ED locked by its schedule, which ends at block 30.
+		assert_eq!(Vesting::vesting_balance(&12), Some(ED * 5));
+		let grant = VestingGrant {
+			amount: ED * 10,
+			curve: VestingCurve::Stepped { start: 0, period: 40, steps: 1 },
+			revoker: None,
+		};
+		assert_ok!(Vesting::vested_grant_transfer(Some(3).into(), 12, grant));
+		assert_eq!(Vesting::vesting_balance(&12), Some(ED * 15));
+		assert_noop!(
+			Balances::transfer_allow_death(Some(12).into(), 3, ED * 5 + 1),
+			TokenError::Frozen,
+		);
+
+		// The schedule completes, the grant is still locked.
+		System::set_block_number(30);
+		assert_ok!(Vesting::vest(Some(12).into()));
+		assert!(!VestingStorage::<Test>::contains_key(12));
+		assert_eq!(Vesting::vesting_balance(&12), Some(ED * 10));
+		assert_ok!(Balances::transfer_allow_death(Some(12).into(), 3, ED * 10));
+		assert_noop!(Balances::transfer_allow_death(Some(12).into(), 3, 1), TokenError::Frozen);
+	});
+}
+
+#[test]
+fn vesting_curves_work() {
+	let stepped = VestingCurve::Stepped { start: 10u64, period: 5, steps: 4 };
+	assert!(stepped.is_valid());
+	assert_eq!(stepped.vested_at(14), Perbill::zero());
+	assert_eq!(stepped.vested_at(15), Perbill::from_percent(25));
+	assert_eq!(stepped.vested_at(29), Perbill::from_percent(75));
+	assert_eq!(stepped.vested_at(30), Perbill::one());
+	assert_eq!(stepped.vested_at(100), Perbill::one());
+	assert_eq!(stepped.ending_block(), 30);
+
+	let milestones = VestingCurve::Milestones(
+		vec![
+			(5u64, Perbill::from_percent(10)),
+			(15, Perbill::from_percent(60)),
+			(25, Perbill::one()),
+		]
+		.try_into()
+		.unwrap(),
+	);
+	assert!(milestones.is_valid());
+	assert_eq!(milestones.vested_at(4), Perbill::zero());
+	assert_eq!(milestones.vested_at(5), Perbill::from_percent(10));
+	assert_eq!(milestones.vested_at(24), Perbill::from_percent(60));
+	assert_eq!(milestones.vested_at(25), Perbill::one());
+	assert_eq!(milestones.ending_block(), 25);
+
+	let grant = VestingGrant { amount: 1000u64, curve: milestones, revoker: None::<u64> };
+	assert_eq!(grant.locked_at(15), 400);
+
+	// Invalid curves.
+	assert!(!VestingCurve::Linear { start: 10u64, cliff: 5, end: 20 }.is_valid());
+	assert!(!VestingCurve::Linear { start: 10u64, cliff: 25, end: 20 }.is_valid());
+	assert!(!VestingCurve::Linear { start: 10u64, cliff: 10, end: 10 }.is_valid());
+	assert!(!VestingCurve::Stepped { start: 10u64, period: 0, steps: 4 }.is_valid());
+	assert!(!VestingCurve::Stepped { start: 10u64, period: 5, steps: 0 }.is_valid());
+	assert!(!VestingCurve::<u64>::Milestones(Default::default()).is_valid());
+	let not_complete = vec![(5u64, Perbill::from_percent(10)), (15, Perbill::from_percent(60))];
+	assert!(!VestingCurve::Milestones(not_complete.try_into().unwrap()).is_valid());
+	let decreasing = vec![(5u64, Perbill::from_percent(60)), (15, Perbill::from_percent(10))];
+	assert!(!VestingCurve::Milestones(decreasing.try_into().unwrap()).is_valid());
+	let unordered = vec![(15u64, Perbill::from_percent(10)), (5, Perbill::one())];
+	assert!(!VestingCurve::Milestones(unordered.try_into().unwrap()).is_valid());
+}
+
+#[test]
+fn vested_grant_transfer_correctly_fails() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Fails due to too low transfer amount.
+		let min_transfer = <Test as Config>::MinVestedTransfer::get();
+		assert_noop!(
+			Vesting::vested_grant_transfer(Some(3).into(), 4, linear_grant(min_transfer - 1)),
+			Error::<Test>::AmountLow,
+		);
+
+		// Fails due to an invalid curve.
+		let mut grant = linear_grant(ED * 2);
+		grant.curve = VestingCurve::Stepped { start: 0, period: 0, steps: 4 };
+		assert_noop!(
+			Vesting::vested_grant_transfer(Some(3).into(), 4, grant),
+			Error::<Test>::InvalidScheduleParams,
+		);
+
+		// Fails due to too many grants.
+		for _ in 0..<Test as Config>::MAX_VESTING_SCHEDULES {
+			assert_ok!(Vesting::vested_grant_transfer(Some(3).into(), 4, linear_grant(ED * 2)));
+		}
+		assert_noop!(
+			Vesting::vested_grant_transfer(Some(3).into(), 4, linear_grant(ED * 2)),
+			Error::<Test>::AtMaxVestingSchedules,
+		);
+	});
+}
+
+#[test]
+fn revoke_grant_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let grant = VestingGrant {
+			amount: ED * 20,
+			curve: VestingCurve::Linear { start: 0, cliff: 0, end: 20 },
+			revoker: Some(3),
+		};
+		assert_ok!(Vesting::vested_grant_transfer(Some(13).into(), 4, grant));
+		assert_ok!(Vesting::vested_grant_transfer(Some(13).into(), 4, linear_grant(ED * 2)));
+		assert_eq!(Balances::free_balance(&4), ED * 62);
+
+		System::set_block_number(5);
+		// Only the revoker may revoke, and only grants that name one.
+		assert_noop!(Vesting::revoke_grant(Some(13).into(), 4, 0), Error::<Test>::NotRevoker);
+		assert_noop!(Vesting::revoke_grant(Some(3).into(), 4, 1), Error::<Test>::NotRevoker);
+		assert_noop!(
+			Vesting::revoke_grant(Some(3).into(), 4, 2),
+			Error::<Test>::ScheduleIndexOutOfBounds,
+		);
+		assert_noop!(Vesting::revoke_grant(Some(3).into(), 1, 0), Error::<Test>::NotVesting);
+
+		// A quarter has vested, the rest goes back to the revoker.
+		assert_ok!(Vesting::revoke_grant(Some(3).into(), 4, 0));
+		System::assert_last_event(
+			Event::<Test>::GrantRevoked { account: 4, revoker: 3, unvested: ED * 15 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&3), ED * 45);
+		assert_eq!(Balances::free_balance(&4), ED * 47);
+		assert_eq!(Vesting::grants(4), vec![linear_grant(ED * 2)]);
+
+		// The vested part stays with the account and is unlocked.
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 2));
+		assert_ok!(Balances::transfer_allow_death(Some(4).into(), 3, ED * 45));
+		assert_noop!(Balances::transfer_allow_death(Some(4).into(), 3, 1), TokenError::Frozen);
+	});
+}
+
+#[test]
+fn revoke_grant_fails_while_other_locks_cover_unvested_funds() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let grant = VestingGrant {
+			amount: ED * 20,
+			curve: VestingCurve::Linear { start: 0, cliff: 0, end: 20 },
+			revoker: Some(3),
+		};
+		assert_ok!(Vesting::vested_grant_transfer(Some(13).into(), 4, grant));
+		// The unvested funds are staked, for example.
+		Balances::set_lock(*b"otherlck", &4, ED * 60, WithdrawReasons::all());
+
+		System::set_block_number(5);
+		assert_noop!(Vesting::revoke_grant(Some(3).into(), 4, 0), TokenError::Frozen);
+
+		// Once the other lock is lifted, the grant can be revoked.
+		Balances::remove_lock(*b"otherlck", &4);
+		assert_ok!(Vesting::revoke_grant(Some(3).into(), 4, 0));
+		assert_eq!(Balances::free_balance(&3), ED * 45);
+	});
+}
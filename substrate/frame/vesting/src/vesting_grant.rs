@@ -0,0 +1,143 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Vesting grants with custom unlock curves.
+
+use super::*;
+use frame_support::traits::ConstU32;
+use sp_runtime::{traits::SaturatedConversion, Perbill};
+
+/// The maximum number of milestones of a [`VestingCurve::Milestones`] curve.
+pub const MAX_MILESTONES: u32 = 16;
+
+/// How the amount of a [`VestingGrant`] vests over time.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum VestingCurve<BlockNumber> {
+	/// Vests linearly from `start` until `end`.
+	///
+	/// Nothing vests before `cliff`; at the cliff, everything that vested since `start` is
+	/// released at once.
+	Linear { start: BlockNumber, cliff: BlockNumber, end: BlockNumber },
+	/// Vests in `steps` equal parts, one every `period` blocks after `start`.
+	Stepped { start: BlockNumber, period: BlockNumber, steps: u32 },
+	/// Vests the given share of the amount at each block.
+	///
+	/// The shares are cumulative: the blocks must be increasing, the shares must not decrease
+	/// and the last share must be 100%.
+	Milestones(BoundedVec<(BlockNumber, Perbill), ConstU32<MAX_MILESTONES>>),
+}
+
+impl<BlockNumber> VestingCurve<BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Validate the parameters of the curve.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			Self::Linear { start, cliff, end } => start < end && start <= cliff && cliff <= end,
+			Self::Stepped { period, steps, .. } => !period.is_zero() && *steps > 0,
+			Self::Milestones(milestones) =>
+				milestones
+					.windows(2)
+					.all(|pair| pair[0].0 < pair[1].0 && pair[0].1 <= pair[1].1) &&
+					milestones.last().map_or(false, |(_, share)| share.is_one()),
+		}
+	}
+
+	/// The share of the amount that has vested at block `n`.
+	pub fn vested_at(&self, n: BlockNumber) -> Perbill {
+		match self {
+			Self::Linear { start, cliff, end } =>
+				if n < *cliff {
+					Perbill::zero()
+				} else if n >= *end {
+					Perbill::one()
+				} else {
+					Perbill::from_rational(n.saturating_sub(*start), end.saturating_sub(*start))
+				},
+			Self::Stepped { start, period, steps } => {
+				let passed = n.saturating_sub(*start) / (*period).max(One::one());
+				let passed: u32 = passed.min((*steps).into()).saturated_into();
+				Perbill::from_rational(passed, (*steps).max(1))
+			},
+			Self::Milestones(milestones) => milestones
+				.iter()
+				.take_while(|(block, _)| *block <= n)
+				.last()
+				.map_or(Perbill::zero(), |(_, share)| *share),
+		}
+	}
+
+	/// The block from which on everything has vested.
+	pub fn ending_block(&self) -> BlockNumber {
+		match self {
+			Self::Linear { end, .. } => *end,
+			Self::Stepped { start, period, steps } =>
+				start.saturating_add(period.saturating_mul((*steps).into())),
+			Self::Milestones(milestones) =>
+				milestones.last().map_or(Zero::zero(), |(block, _)| *block),
+		}
+	}
+}
+
+/// A vesting grant: an amount that vests along a [`VestingCurve`] and that may be revoked.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct VestingGrant<Balance, BlockNumber, AccountId> {
+	/// The amount granted.
+	pub amount: Balance,
+	/// How the amount vests over time.
+	pub curve: VestingCurve<BlockNumber>,
+	/// The account that may revoke the unvested part of the grant, if any.
+	pub revoker: Option<AccountId>,
+}
+
+impl<Balance, BlockNumber, AccountId> VestingGrant<Balance, BlockNumber, AccountId>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Validate the parameters of the grant. Note that this does not check against
+	/// `MinVestedTransfer`.
+	pub fn is_valid(&self) -> bool {
+		!self.amount.is_zero() && self.curve.is_valid()
+	}
+
+	/// Amount locked at block `n`.
+	pub fn locked_at(&self, n: BlockNumber) -> Balance {
+		self.amount.saturating_sub(self.curve.vested_at(n).mul_floor(self.amount))
+	}
+}
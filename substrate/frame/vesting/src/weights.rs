@@ -35,21 +35,21 @@
 //! Autogenerated weights for `pallet_vesting`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-19, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `4563561839a5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
-// frame-omni-bencher
+// ./target/release/frame-omni-bencher
 // v1
 // benchmark
 // pallet
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_vesting
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/vesting/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/vesting/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -59,7 +59,6 @@
 // --no-min-squares
 // --no-median-slopes
 // --genesis-builder-policy=none
-// --exclude-pallets=pallet_xcm,pallet_xcm_benchmarks::fungible,pallet_xcm_benchmarks::generic,pallet_nomination_pools,pallet_remark,pallet_transaction_storage,pallet_election_provider_multi_block,pallet_election_provider_multi_block::signed,pallet_election_provider_multi_block::unsigned,pallet_election_provider_multi_block::verifier
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -81,6 +80,8 @@ pub trait WeightInfo {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight;
+	fn vested_grant_transfer(l: u32, s: u32, ) -> Weight;
+	fn revoke_grant(l: u32, s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_vesting` using the Substrate node and recommended hardware.
@@ -88,27 +89,29 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[1, 28]`.
-	fn vest_locked(l: u32, s: u32, ) -> Weight {
+	fn vest_locked(_l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 29_108_000 picoseconds.
-		Weight::from_parts(28_662_441, 4764)
-			// Standard Error: 954
-			.saturating_add(Weight::from_parts(30_514, 0).saturating_mul(l.into()))
-			// Standard Error: 1_698
-			.saturating_add(Weight::from_parts(62_299, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 39_260_000 picoseconds.
+		Weight::from_parts(63_358_572, 8526)
+			// Standard Error: 53_312
+			.saturating_add(Weight::from_parts(48_242, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -118,18 +121,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 30_994_000 picoseconds.
-		Weight::from_parts(30_464_494, 4764)
-			// Standard Error: 1_481
-			.saturating_add(Weight::from_parts(28_285, 0).saturating_mul(l.into()))
-			// Standard Error: 2_636
-			.saturating_add(Weight::from_parts(66_703, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 41_703_000 picoseconds.
+		Weight::from_parts(40_069_122, 8526)
+			// Standard Error: 12_593
+			.saturating_add(Weight::from_parts(163_597, 0).saturating_mul(l.into()))
+			// Standard Error: 22_406
+			.saturating_add(Weight::from_parts(118_188, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -138,21 +143,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[1, 28]`.
-	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
+	fn vest_other_locked(l: u32, _s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 31_696_000 picoseconds.
-		Weight::from_parts(31_191_832, 4764)
-			// Standard Error: 1_199
-			.saturating_add(Weight::from_parts(29_734, 0).saturating_mul(l.into()))
-			// Standard Error: 2_133
-			.saturating_add(Weight::from_parts(65_658, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 41_333_000 picoseconds.
+		Weight::from_parts(52_160_192, 8526)
+			// Standard Error: 19_974
+			.saturating_add(Weight::from_parts(36_966, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -164,20 +169,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 33_388_000 picoseconds.
-		Weight::from_parts(32_877_344, 4764)
-			// Standard Error: 1_245
-			.saturating_add(Weight::from_parts(34_308, 0).saturating_mul(l.into()))
-			// Standard Error: 2_216
-			.saturating_add(Weight::from_parts(58_989, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 45_315_000 picoseconds.
+		Weight::from_parts(53_081_042, 8526)
+			// Standard Error: 34_363
+			.saturating_add(Weight::from_parts(161_391, 0).saturating_mul(l.into()))
+			// Standard Error: 61_138
+			.saturating_add(Weight::from_parts(13_649, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -187,20 +194,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 67_470_000 picoseconds.
-		Weight::from_parts(68_202_160, 4764)
-			// Standard Error: 2_799
-			.saturating_add(Weight::from_parts(48_357, 0).saturating_mul(l.into()))
-			// Standard Error: 4_979
-			.saturating_add(Weight::from_parts(102_560, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 88_865_000 picoseconds.
+		Weight::from_parts(90_360_323, 8526)
+			// Standard Error: 56_879
+			.saturating_add(Weight::from_parts(305_853, 0).saturating_mul(l.into()))
+			// Standard Error: 101_197
+			.saturating_add(Weight::from_parts(384_848, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -210,60 +219,62 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `328 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `6196`
-		// Minimum execution time: 69_510_000 picoseconds.
-		Weight::from_parts(69_862_164, 6196)
-			// Standard Error: 2_523
-			.saturating_add(Weight::from_parts(49_783, 0).saturating_mul(l.into()))
-			// Standard Error: 4_489
-			.saturating_add(Weight::from_parts(114_991, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 90_343_000 picoseconds.
+		Weight::from_parts(93_015_066, 8526)
+			// Standard Error: 16_261
+			.saturating_add(Weight::from_parts(122_440, 0).saturating_mul(l.into()))
+			// Standard Error: 28_931
+			.saturating_add(Weight::from_parts(231_340, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
-	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
+	fn not_unlocking_merge_schedules(l: u32, _s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 29_865_000 picoseconds.
-		Weight::from_parts(29_471_812, 4764)
-			// Standard Error: 1_177
-			.saturating_add(Weight::from_parts(30_158, 0).saturating_mul(l.into()))
-			// Standard Error: 2_174
-			.saturating_add(Weight::from_parts(65_109, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 40_354_000 picoseconds.
+		Weight::from_parts(54_341_045, 8526)
+			// Standard Error: 37_601
+			.saturating_add(Weight::from_parts(259_218, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
-	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
+	fn unlocking_merge_schedules(_l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 32_137_000 picoseconds.
-		Weight::from_parts(31_237_719, 4764)
-			// Standard Error: 1_135
-			.saturating_add(Weight::from_parts(35_827, 0).saturating_mul(l.into()))
-			// Standard Error: 2_097
-			.saturating_add(Weight::from_parts(79_394, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 44_484_000 picoseconds.
+		Weight::from_parts(58_047_342, 8526)
+			// Standard Error: 59_046
+			.saturating_add(Weight::from_parts(223_241, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -272,46 +283,94 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
-	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
+	fn force_remove_vesting_schedule(_l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 35_493_000 picoseconds.
-		Weight::from_parts(34_502_101, 4764)
-			// Standard Error: 1_833
-			.saturating_add(Weight::from_parts(40_786, 0).saturating_mul(l.into()))
-			// Standard Error: 3_385
-			.saturating_add(Weight::from_parts(81_931, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 47_472_000 picoseconds.
+		Weight::from_parts(50_867_383, 8526)
+			// Standard Error: 60_313
+			.saturating_add(Weight::from_parts(554_419, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Grants` (r:1 w:1)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[0, 27]`.
+	fn vested_grant_transfer(_l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `225 + l * (25 ±0) + s * (62 ±0)`
+		//  Estimated: `8526`
+		// Minimum execution time: 94_257_000 picoseconds.
+		Weight::from_parts(100_153_685, 8526)
+			// Standard Error: 87_454
+			.saturating_add(Weight::from_parts(1_015_511, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::Grants` (r:1 w:1)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_grant(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `225 + l * (25 ±0) + s * (62 ±0)`
+		//  Estimated: `8526`
+		// Minimum execution time: 94_503_000 picoseconds.
+		Weight::from_parts(73_190_261, 8526)
+			// Standard Error: 29_120
+			.saturating_add(Weight::from_parts(470_192, 0).saturating_mul(l.into()))
+			// Standard Error: 51_810
+			.saturating_add(Weight::from_parts(1_007_457, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[1, 28]`.
-	fn vest_locked(l: u32, s: u32, ) -> Weight {
+	fn vest_locked(_l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 29_108_000 picoseconds.
-		Weight::from_parts(28_662_441, 4764)
-			// Standard Error: 954
-			.saturating_add(Weight::from_parts(30_514, 0).saturating_mul(l.into()))
-			// Standard Error: 1_698
-			.saturating_add(Weight::from_parts(62_299, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 39_260_000 picoseconds.
+		Weight::from_parts(63_358_572, 8526)
+			// Standard Error: 53_312
+			.saturating_add(Weight::from_parts(48_242, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -321,18 +380,20 @@ impl WeightInfo for () {
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 30_994_000 picoseconds.
-		Weight::from_parts(30_464_494, 4764)
-			// Standard Error: 1_481
-			.saturating_add(Weight::from_parts(28_285, 0).saturating_mul(l.into()))
-			// Standard Error: 2_636
-			.saturating_add(Weight::from_parts(66_703, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 41_703_000 picoseconds.
+		Weight::from_parts(40_069_122, 8526)
+			// Standard Error: 12_593
+			.saturating_add(Weight::from_parts(163_597, 0).saturating_mul(l.into()))
+			// Standard Error: 22_406
+			.saturating_add(Weight::from_parts(118_188, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -341,21 +402,21 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[1, 28]`.
-	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
+	fn vest_other_locked(l: u32, _s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 31_696_000 picoseconds.
-		Weight::from_parts(31_191_832, 4764)
-			// Standard Error: 1_199
-			.saturating_add(Weight::from_parts(29_734, 0).saturating_mul(l.into()))
-			// Standard Error: 2_133
-			.saturating_add(Weight::from_parts(65_658, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 41_333_000 picoseconds.
+		Weight::from_parts(52_160_192, 8526)
+			// Standard Error: 19_974
+			.saturating_add(Weight::from_parts(36_966, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -367,20 +428,22 @@ impl WeightInfo for () {
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 33_388_000 picoseconds.
-		Weight::from_parts(32_877_344, 4764)
-			// Standard Error: 1_245
-			.saturating_add(Weight::from_parts(34_308, 0).saturating_mul(l.into()))
-			// Standard Error: 2_216
-			.saturating_add(Weight::from_parts(58_989, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 45_315_000 picoseconds.
+		Weight::from_parts(53_081_042, 8526)
+			// Standard Error: 34_363
+			.saturating_add(Weight::from_parts(161_391, 0).saturating_mul(l.into()))
+			// Standard Error: 61_138
+			.saturating_add(Weight::from_parts(13_649, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -390,20 +453,22 @@ impl WeightInfo for () {
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 67_470_000 picoseconds.
-		Weight::from_parts(68_202_160, 4764)
-			// Standard Error: 2_799
-			.saturating_add(Weight::from_parts(48_357, 0).saturating_mul(l.into()))
-			// Standard Error: 4_979
-			.saturating_add(Weight::from_parts(102_560, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 88_865_000 picoseconds.
+		Weight::from_parts(90_360_323, 8526)
+			// Standard Error: 56_879
+			.saturating_add(Weight::from_parts(305_853, 0).saturating_mul(l.into()))
+			// Standard Error: 101_197
+			.saturating_add(Weight::from_parts(384_848, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -413,60 +478,62 @@ impl WeightInfo for () {
 	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `328 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `6196`
-		// Minimum execution time: 69_510_000 picoseconds.
-		Weight::from_parts(69_862_164, 6196)
-			// Standard Error: 2_523
-			.saturating_add(Weight::from_parts(49_783, 0).saturating_mul(l.into()))
-			// Standard Error: 4_489
-			.saturating_add(Weight::from_parts(114_991, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 90_343_000 picoseconds.
+		Weight::from_parts(93_015_066, 8526)
+			// Standard Error: 16_261
+			.saturating_add(Weight::from_parts(122_440, 0).saturating_mul(l.into()))
+			// Standard Error: 28_931
+			.saturating_add(Weight::from_parts(231_340, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
-	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
+	fn not_unlocking_merge_schedules(l: u32, _s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 29_865_000 picoseconds.
-		Weight::from_parts(29_471_812, 4764)
-			// Standard Error: 1_177
-			.saturating_add(Weight::from_parts(30_158, 0).saturating_mul(l.into()))
-			// Standard Error: 2_174
-			.saturating_add(Weight::from_parts(65_109, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 40_354_000 picoseconds.
+		Weight::from_parts(54_341_045, 8526)
+			// Standard Error: 37_601
+			.saturating_add(Weight::from_parts(259_218, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
-	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
+	fn unlocking_merge_schedules(_l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 32_137_000 picoseconds.
-		Weight::from_parts(31_237_719, 4764)
-			// Standard Error: 1_135
-			.saturating_add(Weight::from_parts(35_827, 0).saturating_mul(l.into()))
-			// Standard Error: 2_097
-			.saturating_add(Weight::from_parts(79_394, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 44_484_000 picoseconds.
+		Weight::from_parts(58_047_342, 8526)
+			// Standard Error: 59_046
+			.saturating_add(Weight::from_parts(223_241, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
 	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Grants` (r:1 w:0)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -475,17 +542,63 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[0, 49]`.
 	/// The range of component `s` is `[2, 28]`.
-	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
+	fn force_remove_vesting_schedule(_l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `225 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
-		// Minimum execution time: 35_493_000 picoseconds.
-		Weight::from_parts(34_502_101, 4764)
-			// Standard Error: 1_833
-			.saturating_add(Weight::from_parts(40_786, 0).saturating_mul(l.into()))
-			// Standard Error: 3_385
-			.saturating_add(Weight::from_parts(81_931, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		//  Estimated: `8526`
+		// Minimum execution time: 47_472_000 picoseconds.
+		Weight::from_parts(50_867_383, 8526)
+			// Standard Error: 60_313
+			.saturating_add(Weight::from_parts(554_419, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Grants` (r:1 w:1)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[0, 27]`.
+	fn vested_grant_transfer(_l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `225 + l * (25 ±0) + s * (62 ±0)`
+		//  Estimated: `8526`
+		// Minimum execution time: 94_257_000 picoseconds.
+		Weight::from_parts(100_153_685, 8526)
+			// Standard Error: 87_454
+			.saturating_add(Weight::from_parts(1_015_511, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::Grants` (r:1 w:1)
+	/// Proof: `Vesting::Grants` (`max_values`: None, `max_size`: Some(5061), added: 7536, mode: `MaxEncodedLen`)
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_grant(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `225 + l * (25 ±0) + s * (62 ±0)`
+		//  Estimated: `8526`
+		// Minimum execution time: 94_503_000 picoseconds.
+		Weight::from_parts(73_190_261, 8526)
+			// Standard Error: 29_120
+			.saturating_add(Weight::from_parts(470_192, 0).saturating_mul(l.into()))
+			// Standard Error: 51_810
+			.saturating_add(Weight::from_parts(1_007_457, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
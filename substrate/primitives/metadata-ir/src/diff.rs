@@ -0,0 +1,600 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compare the storage layout of two runtimes.
+//!
+//! [`storage_diff`] reports every change to the storage entries of each pallet between two V16
+//! metadata. Changes that make the existing storage undecodable or unreachable by the new runtime
+//! are incompatible and need a migration, which is expected to bump the in-code storage version
+//! of the pallet. The storage versions are read from the [`STORAGE_VERSIONS_KEY`] custom
+//! metadata.
+//!
+//! Types are compared by their encoding only, so renaming a type or a field is compatible, and so
+//! is adding a variant to an enum. Wrapping a type into a single field struct is compatible as
+//! well.
+
+use crate::STORAGE_VERSIONS_KEY;
+use alloc::{
+	collections::{BTreeMap, BTreeSet},
+	string::{String, ToString},
+	vec::Vec,
+};
+use codec::Decode;
+use core::fmt;
+use frame_metadata::v16::{
+	RuntimeMetadataV16, StorageEntryMetadata, StorageEntryModifier, StorageEntryType, StorageHasher,
+};
+use scale_info::{form::PortableForm, PortableRegistry, TypeDef};
+
+/// The storage layout changes between two runtimes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StorageDiff {
+	/// The pallets with changes to their storage, ordered by name.
+	pub pallets: Vec<PalletStorageDiff>,
+}
+
+impl StorageDiff {
+	/// The pallets with incompatible changes but without a storage version bump.
+	pub fn missing_migrations(&self) -> impl Iterator<Item = &PalletStorageDiff> {
+		self.pallets.iter().filter(|pallet| pallet.needs_migration())
+	}
+
+	/// The pallets with incompatible changes whose storage version in the old runtime is unknown,
+	/// so that it can not be told whether they were bumped.
+	pub fn unknown_storage_versions(&self) -> impl Iterator<Item = &PalletStorageDiff> {
+		self.pallets
+			.iter()
+			.filter(|pallet| pallet.is_incompatible() && pallet.storage_version_unknown())
+	}
+}
+
+/// The storage layout changes of one pallet.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PalletStorageDiff {
+	/// The name of the pallet.
+	pub pallet: String,
+	/// The in-code storage version in the old runtime.
+	pub old_storage_version: Option<u16>,
+	/// The in-code storage version in the new runtime.
+	pub new_storage_version: Option<u16>,
+	/// The changes to the storage of the pallet.
+	pub changes: Vec<StorageChange>,
+}
+
+impl PalletStorageDiff {
+	/// Whether any of the changes is incompatible.
+	pub fn is_incompatible(&self) -> bool {
+		self.changes.iter().any(|change| change.kind.is_incompatible())
+	}
+
+	/// Whether the new runtime declares a higher storage version than the old one.
+	///
+	/// This is `false` if either of the storage versions is unknown.
+	pub fn storage_version_bumped(&self) -> bool {
+		matches!(
+			(self.old_storage_version, self.new_storage_version),
+			(Some(old), Some(new)) if new > old
+		)
+	}
+
+	/// Whether only the new runtime declares a storage version for the pallet, e.g. because the
+	/// old runtime predates the storage versions in the metadata.
+	pub fn storage_version_unknown(&self) -> bool {
+		self.old_storage_version.is_none() && self.new_storage_version.is_some()
+	}
+
+	/// Whether the pallet has incompatible changes but no storage version bump.
+	///
+	/// Pallets whose old storage version is unknown are reported by
+	/// [`StorageDiff::unknown_storage_versions`] instead.
+	pub fn needs_migration(&self) -> bool {
+		self.is_incompatible() && !self.storage_version_bumped() && !self.storage_version_unknown()
+	}
+}
+
+/// A change to the storage of a pallet.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StorageChange {
+	/// The storage entry that changed, `None` for changes to the whole pallet.
+	pub entry: Option<String>,
+	/// What changed.
+	pub kind: StorageChangeKind,
+}
+
+/// The kind of a [`StorageChange`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum StorageChangeKind {
+	/// The pallet was removed together with its storage.
+	PalletRemoved,
+	/// The pallet was added.
+	PalletAdded,
+	/// The storage prefix of the pallet changed.
+	PrefixChanged {
+		/// The old prefix.
+		old: String,
+		/// The new prefix.
+		new: String,
+	},
+	/// The storage entry was removed.
+	EntryRemoved,
+	/// The storage entry was added.
+	EntryAdded,
+	/// The entry changed between a plain value and a map.
+	KindChanged,
+	/// The hashers of the map keys changed.
+	HashersChanged,
+	/// The type of the map key changed in an incompatible way.
+	KeyTypeChanged,
+	/// The type of the value changed in an incompatible way.
+	ValueTypeChanged,
+	/// The entry changed between `OptionQuery` and `ValueQuery`.
+	ModifierChanged,
+	/// The default value of the entry changed.
+	DefaultChanged,
+}
+
+impl StorageChangeKind {
+	/// Whether the change makes existing storage undecodable or unreachable.
+	pub fn is_incompatible(&self) -> bool {
+		!matches!(self, Self::PalletAdded | Self::EntryAdded | Self::ModifierChanged)
+	}
+}
+
+impl fmt::Display for StorageChangeKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::PalletRemoved => write!(f, "pallet removed"),
+			Self::PalletAdded => write!(f, "pallet added"),
+			Self::PrefixChanged { old, new } => write!(f, "prefix changed from {old} to {new}"),
+			Self::EntryRemoved => write!(f, "entry removed"),
+			Self::EntryAdded => write!(f, "entry added"),
+			Self::KindChanged => write!(f, "kind changed"),
+			Self::HashersChanged => write!(f, "hashers changed"),
+			Self::KeyTypeChanged => write!(f, "key type changed"),
+			Self::ValueTypeChanged => write!(f, "value type changed"),
+			Self::ModifierChanged => write!(f, "modifier changed"),
+			Self::DefaultChanged => write!(f, "default value changed"),
+		}
+	}
+}
+
+/// The in-code storage versions of the pallets, by pallet name.
+pub fn storage_versions(metadata: &RuntimeMetadataV16) -> BTreeMap<String, u16> {
+	metadata
+		.custom
+		.map
+		.get(STORAGE_VERSIONS_KEY)
+		.and_then(|versions| Vec::<(String, u16)>::decode(&mut &versions.value[..]).ok())
+		.unwrap_or_default()
+		.into_iter()
+		.collect()
+}
+
+/// Compare the storage layout of the `old` and `new` runtime.
+pub fn storage_diff(old: &RuntimeMetadataV16, new: &RuntimeMetadataV16) -> StorageDiff {
+	let old_versions = storage_versions(old);
+	let new_versions = storage_versions(new);
+	let types = Types { old: &old.types, new: &new.types };
+
+	let old_pallets = old
+		.pallets
+		.iter()
+		.filter_map(|pallet| Some((pallet.name.to_string(), pallet.storage.as_ref()?)))
+		.collect::<BTreeMap<_, _>>();
+	let new_pallets = new
+		.pallets
+		.iter()
+		.filter_map(|pallet| Some((pallet.name.to_string(), pallet.storage.as_ref()?)))
+		.collect::<BTreeMap<_, _>>();
+	let names = old_pallets.keys().chain(new_pallets.keys()).collect::<BTreeSet<_>>();
+
+	let mut pallets = Vec::new();
+	for name in names {
+		let changes = match (old_pallets.get(name), new_pallets.get(name)) {
+			(Some(_), None) =>
+				alloc::vec![StorageChange { entry: None, kind: StorageChangeKind::PalletRemoved }],
+			(None, Some(_)) =>
+				alloc::vec![StorageChange { entry: None, kind: StorageChangeKind::PalletAdded }],
+			(Some(old), Some(new)) => {
+				let mut changes = Vec::new();
+				if old.prefix != new.prefix {
+					changes.push(StorageChange {
+						entry: None,
+						kind: StorageChangeKind::PrefixChanged {
+							old: old.prefix.to_string(),
+							new: new.prefix.to_string(),
+						},
+					});
+				}
+				changes.extend(entries_diff(&types, &old.entries, &new.entries));
+				changes
+			},
+			(None, None) => unreachable!("name is taken from one of the maps; qed"),
+		};
+
+		if !changes.is_empty() {
+			pallets.push(PalletStorageDiff {
+				pallet: name.clone(),
+				old_storage_version: old_versions.get(name).copied(),
+				new_storage_version: new_versions.get(name).copied(),
+				changes,
+			});
+		}
+	}
+
+	StorageDiff { pallets }
+}
+
+fn entries_diff(
+	types: &Types,
+	old: &[StorageEntryMetadata<PortableForm>],
+	new: &[StorageEntryMetadata<PortableForm>],
+) -> Vec<StorageChange> {
+	let old = old
+		.iter()
+		.map(|entry| (entry.name.to_string(), entry))
+		.collect::<BTreeMap<_, _>>();
+	let new = new
+		.iter()
+		.map(|entry| (entry.name.to_string(), entry))
+		.collect::<BTreeMap<_, _>>();
+	let names = old.keys().chain(new.keys()).collect::<BTreeSet<_>>();
+
+	let mut changes = Vec::new();
+	for name in names {
+		let mut push = |kind| changes.push(StorageChange { entry: Some(name.clone()), kind });
+		let (old, new) = match (old.get(name), new.get(name)) {
+			(Some(old), Some(new)) => (old, new),
+			(Some(_), None) => {
+				push(StorageChangeKind::EntryRemoved);
+				continue
+			},
+			_ => {
+				push(StorageChangeKind::EntryAdded);
+				continue
+			},
+		};
+
+		match (&old.ty, &new.ty) {
+			(StorageEntryType::Plain(old_value), StorageEntryType::Plain(new_value)) =>
+				if !types.compatible(old_value.id, new_value.id) {
+					push(StorageChangeKind::ValueTypeChanged);
+				},
+			(
+				StorageEntryType::Map { hashers: old_hashers, key: old_key, value: old_value },
+				StorageEntryType::Map { hashers: new_hashers, key: new_key, value: new_value },
+			) => {
+				if !hashers_eq(old_hashers, new_hashers) {
+					push(StorageChangeKind::HashersChanged);
+				}
+				if !types.compatible(old_key.id, new_key.id) {
+					push(StorageChangeKind::KeyTypeChanged);
+				}
+				if !types.compatible(old_value.id, new_value.id) {
+					push(StorageChangeKind::ValueTypeChanged);
+				}
+			},
+			_ => push(StorageChangeKind::KindChanged),
+		}
+		if !modifiers_eq(&old.modifier, &new.modifier) {
+			push(StorageChangeKind::ModifierChanged);
+		} else if old.default != new.default &&
+			matches!(old.modifier, StorageEntryModifier::Default)
+		{
+			push(StorageChangeKind::DefaultChanged);
+		}
+	}
+	changes
+}
+
+fn hashers_eq(old: &[StorageHasher], new: &[StorageHasher]) -> bool {
+	old.len() == new.len() &&
+		old.iter()
+			.zip(new)
+			.all(|(old, new)| core::mem::discriminant(old) == core::mem::discriminant(new))
+}
+
+fn modifiers_eq(old: &StorageEntryModifier, new: &StorageEntryModifier) -> bool {
+	core::mem::discriminant(old) == core::mem::discriminant(new)
+}
+
+/// The type registries of the old and new runtime.
+struct Types<'a> {
+	old: &'a PortableRegistry,
+	new: &'a PortableRegistry,
+}
+
+impl Types<'_> {
+	/// Whether values encoded with the old type `old` decode as the new type `new`.
+	fn compatible(&self, old: u32, new: u32) -> bool {
+		self.compatible_inner(old, new, &mut BTreeSet::new())
+	}
+
+	fn compatible_inner(&self, old: u32, new: u32, visited: &mut BTreeSet<(u32, u32)>) -> bool {
+		// Recursive types are assumed to be compatible while they are being compared.
+		if !visited.insert((old, new)) {
+			return true
+		}
+		let (Some(old), Some(new)) = (self.old.resolve(old), self.new.resolve(new)) else {
+			return false
+		};
+		let old_def = unwrap_newtype(self.old, &old.type_def);
+		let new_def = unwrap_newtype(self.new, &new.type_def);
+
+		let mut all_compatible = |old: &mut dyn Iterator<Item = u32>,
+		                          new: &mut dyn Iterator<Item = u32>| {
+			let (old, new) = (old.collect::<Vec<_>>(), new.collect::<Vec<_>>());
+			old.len() == new.len() &&
+				old.into_iter()
+					.zip(new)
+					.all(|(old, new)| self.compatible_inner(old, new, visited))
+		};
+
+		match (old_def, new_def) {
+			(TypeDef::Composite(old), TypeDef::Composite(new)) => all_compatible(
+				&mut old.fields.iter().map(|field| field.ty.id),
+				&mut new.fields.iter().map(|field| field.ty.id),
+			),
+			(TypeDef::Variant(old), TypeDef::Variant(new)) => old.variants.iter().all(|old| {
+				new.variants.iter().find(|new| new.index == old.index).map_or(false, |new| {
+					all_compatible(
+						&mut old.fields.iter().map(|field| field.ty.id),
+						&mut new.fields.iter().map(|field| field.ty.id),
+					)
+				})
+			}),
+			(TypeDef::Sequence(old), TypeDef::Sequence(new)) => all_compatible(
+				&mut [old.type_param.id].into_iter(),
+				&mut [new.type_param.id].into_iter(),
+			),
+			(TypeDef::Array(old), TypeDef::Array(new)) =>
+				old.len == new.len &&
+					all_compatible(
+						&mut [old.type_param.id].into_iter(),
+						&mut [new.type_param.id].into_iter(),
+					),
+			(TypeDef::Tuple(old), TypeDef::Tuple(new)) => all_compatible(
+				&mut old.fields.iter().map(|ty| ty.id),
+				&mut new.fields.iter().map(|ty| ty.id),
+			),
+			(TypeDef::Primitive(old), TypeDef::Primitive(new)) => old == new,
+			(TypeDef::Compact(old), TypeDef::Compact(new)) => all_compatible(
+				&mut [old.type_param.id].into_iter(),
+				&mut [new.type_param.id].into_iter(),
+			),
+			(TypeDef::BitSequence(old), TypeDef::BitSequence(new)) => all_compatible(
+				&mut [old.bit_store_type.id, old.bit_order_type.id].into_iter(),
+				&mut [new.bit_store_type.id, new.bit_order_type.id].into_iter(),
+			),
+			_ => false,
+		}
+	}
+}
+
+/// Strip single field structs and tuples, which have the same encoding as their field.
+fn unwrap_newtype<'a>(
+	registry: &'a PortableRegistry,
+	mut def: &'a TypeDef<PortableForm>,
+) -> &'a TypeDef<PortableForm> {
+	// Bounded to not loop forever on malformed registries.
+	for _ in 0..registry.types.len() {
+		let inner = match def {
+			TypeDef::Composite(composite) if composite.fields.len() == 1 =>
+				composite.fields[0].ty.id,
+			TypeDef::Tuple(tuple) if tuple.fields.len() == 1 => tuple.fields[0].id,
+			_ => break,
+		};
+		match registry.resolve(inner) {
+			Some(ty) => def = &ty.type_def,
+			None => break,
+		}
+	}
+	def
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::*;
+	use alloc::vec;
+	use scale_info::{meta_type, TypeInfo};
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct Balance(u128);
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum OldStatus {
+		Active,
+		Inactive(u32),
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	enum NewStatus {
+		Active,
+		Inactive(u32),
+		Frozen,
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct OldInfo {
+		owner: u64,
+		status: OldStatus,
+	}
+
+	#[derive(TypeInfo)]
+	#[allow(dead_code)]
+	struct NewInfo {
+		who: u64,
+		status: NewStatus,
+	}
+
+	fn entry(name: &'static str, ty: StorageEntryTypeIR) -> StorageEntryMetadataIR {
+		StorageEntryMetadataIR {
+			name,
+			modifier: StorageEntryModifierIR::Optional,
+			ty,
+			default: vec![],
+			docs: vec![],
+			deprecation_info: ItemDeprecationInfoIR::NotDeprecated,
+		}
+	}
+
+	fn map(key: scale_info::MetaType, value: scale_info::MetaType) -> StorageEntryTypeIR {
+		StorageEntryTypeIR::Map { hashers: vec![StorageHasherIR::Blake2_128Concat], key, value }
+	}
+
+	fn metadata(
+		entries: Vec<StorageEntryMetadataIR>,
+		storage_version: Option<u16>,
+	) -> RuntimeMetadataV16 {
+		MetadataIR {
+			pallets: vec![PalletMetadataIR {
+				name: "Example",
+				storage: Some(PalletStorageMetadataIR { prefix: "Example", entries }),
+				storage_version,
+				calls: None,
+				view_functions: vec![],
+				event: None,
+				constants: vec![],
+				error: None,
+				associated_types: vec![],
+				index: 0,
+				docs: vec![],
+				deprecation_info: ItemDeprecationInfoIR::NotDeprecated,
+			}],
+			extrinsic: ExtrinsicMetadataIR {
+				ty: meta_type::<()>(),
+				versions: vec![0],
+				address_ty: meta_type::<()>(),
+				call_ty: meta_type::<()>(),
+				signature_ty: meta_type::<()>(),
+				extra_ty: meta_type::<()>(),
+				extensions: vec![],
+			},
+			ty: meta_type::<()>(),
+			apis: vec![],
+			outer_enums: OuterEnumsIR {
+				call_enum_ty: meta_type::<()>(),
+				event_enum_ty: meta_type::<()>(),
+				error_enum_ty: meta_type::<()>(),
+			},
+		}
+		.into()
+	}
+
+	fn old_entries() -> Vec<StorageEntryMetadataIR> {
+		vec![
+			entry("Total", StorageEntryTypeIR::Plain(meta_type::<u128>())),
+			entry("Infos", map(meta_type::<u64>(), meta_type::<OldInfo>())),
+			entry("Removed", StorageEntryTypeIR::Plain(meta_type::<u32>())),
+		]
+	}
+
+	#[test]
+	fn compatible_changes_are_not_reported_as_incompatible() {
+		let old = metadata(old_entries(), None);
+		let new = metadata(
+			vec![
+				entry("Total", StorageEntryTypeIR::Plain(meta_type::<Balance>())),
+				entry("Infos", map(meta_type::<u64>(), meta_type::<NewInfo>())),
+				entry("Removed", StorageEntryTypeIR::Plain(meta_type::<u32>())),
+				entry("Added", StorageEntryTypeIR::Plain(meta_type::<u32>())),
+			],
+			None,
+		);
+
+		let diff = storage_diff(&old, &new);
+		assert_eq!(diff.pallets.len(), 1);
+		assert_eq!(
+			diff.pallets[0].changes,
+			vec![StorageChange {
+				entry: Some("Added".into()),
+				kind: StorageChangeKind::EntryAdded
+			}],
+		);
+		assert_eq!(diff.missing_migrations().count(), 0);
+		assert!(storage_diff(&old, &old).pallets.is_empty());
+	}
+
+	#[test]
+	fn incompatible_changes_are_reported() {
+		let old = metadata(old_entries(), Some(1));
+		let mut infos = entry("Infos", map(meta_type::<u32>(), meta_type::<OldStatus>()));
+		infos.ty = match infos.ty {
+			StorageEntryTypeIR::Map { key, value, .. } =>
+				StorageEntryTypeIR::Map { hashers: vec![StorageHasherIR::Twox64Concat], key, value },
+			ty => ty,
+		};
+		let new = metadata(
+			vec![entry("Total", StorageEntryTypeIR::Plain(meta_type::<u64>())), infos],
+			Some(1),
+		);
+
+		let diff = storage_diff(&old, &new);
+		let changes = diff.pallets[0]
+			.changes
+			.iter()
+			.map(|change| (change.entry.as_deref().unwrap(), change.kind.clone()))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			changes,
+			vec![
+				("Infos", StorageChangeKind::HashersChanged),
+				("Infos", StorageChangeKind::KeyTypeChanged),
+				("Infos", StorageChangeKind::ValueTypeChanged),
+				("Removed", StorageChangeKind::EntryRemoved),
+				("Total", StorageChangeKind::ValueTypeChanged),
+			],
+		);
+		assert!(diff.pallets[0].needs_migration());
+		assert_eq!(diff.missing_migrations().count(), 1);
+	}
+
+	#[test]
+	fn storage_version_bump_covers_incompatible_changes() {
+		let new_entries = vec![entry("Total", StorageEntryTypeIR::Plain(meta_type::<u64>()))];
+		let old = metadata(old_entries(), Some(1));
+
+		let diff = storage_diff(&old, &metadata(new_entries.clone(), Some(2)));
+		assert_eq!(diff.pallets[0].old_storage_version, Some(1));
+		assert_eq!(diff.pallets[0].new_storage_version, Some(2));
+		assert!(diff.pallets[0].is_incompatible());
+		assert_eq!(diff.missing_migrations().count(), 0);
+
+		let diff = storage_diff(&old, &metadata(new_entries.clone(), None));
+		assert_eq!(diff.missing_migrations().count(), 1);
+
+		// Without the old storage version, the bump can not be told.
+		let diff = storage_diff(&metadata(old_entries(), None), &metadata(new_entries, Some(2)));
+		assert!(!diff.pallets[0].storage_version_bumped());
+		assert_eq!(diff.missing_migrations().count(), 0);
+		assert_eq!(diff.unknown_storage_versions().count(), 1);
+	}
+
+	#[test]
+	fn storage_versions_are_read_from_custom_metadata() {
+		assert_eq!(
+			storage_versions(&metadata(vec![], Some(3))),
+			BTreeMap::from([("Example".into(), 3)]),
+		);
+		assert!(storage_versions(&metadata(vec![], None)).is_empty());
+	}
+}
@@ -33,6 +33,9 @@ pub use types::*;
 mod v14;
 mod v15;
 mod v16;
+pub use v16::STORAGE_VERSIONS_KEY;
+
+pub mod diff;
 
 /// Metadata V14.
 const V14: u32 = 14;
@@ -160,4 +163,50 @@ mod test {
 
 		assert!(matches!(metadata.1, RuntimeMetadata::V16(_)));
 	}
+
+	#[test]
+	fn child_maps_are_described_as_maps_in_storage_metadata() {
+		let entry = |name, ty| StorageEntryMetadataIR {
+			name,
+			modifier: StorageEntryModifierIR::Optional,
+			ty,
+			default: vec![0],
+			docs: vec![],
+			deprecation_info: ItemDeprecationInfoIR::NotDeprecated,
+		};
+		let storage = PalletStorageMetadataIR {
+			prefix: "Test",
+			entries: vec![
+				entry("Value", StorageEntryTypeIR::Plain(meta_type::<u32>())),
+				entry(
+					"ChildMap",
+					StorageEntryTypeIR::ChildMap {
+						hashers: vec![StorageHasherIR::Twox64Concat, StorageHasherIR::Twox64Concat],
+						key: meta_type::<(u32, u32)>(),
+						value: meta_type::<u32>(),
+					},
+				),
+			],
+		};
+
+		let v14: frame_metadata::v14::PalletStorageMetadata = storage.clone().into();
+		assert_eq!(
+			v14.entries.iter().map(|entry| entry.name).collect::<Vec<_>>(),
+			vec!["Value", "ChildMap"]
+		);
+		assert!(matches!(
+			&v14.entries[1].ty,
+			frame_metadata::v14::StorageEntryType::Map { hashers, .. } if hashers.len() == 2
+		));
+
+		let v16: frame_metadata::v16::PalletStorageMetadata = storage.into();
+		assert_eq!(
+			v16.entries.iter().map(|entry| entry.name).collect::<Vec<_>>(),
+			vec!["Value", "ChildMap"]
+		);
+		assert!(matches!(
+			&v16.entries[1].ty,
+			frame_metadata::v16::StorageEntryType::Map { hashers, .. } if hashers.len() == 2
+		));
+	}
 }
@@ -180,6 +180,8 @@ pub struct PalletMetadataIR<T: Form = MetaForm> {
 	pub name: T::String,
 	/// Pallet storage metadata.
 	pub storage: Option<PalletStorageMetadataIR<T>>,
+	/// The in-code storage version of the pallet, if it declares one.
+	pub storage_version: Option<u16>,
 	/// Pallet calls metadata.
 	pub calls: Option<PalletCallMetadataIR<T>>,
 	/// Pallet view functions metadata.
@@ -208,6 +210,7 @@ impl IntoPortable for PalletMetadataIR {
 		PalletMetadataIR {
 			name: self.name.into_portable(registry),
 			storage: self.storage.map(|storage| storage.into_portable(registry)),
+			storage_version: self.storage_version,
 			calls: self.calls.map(|calls| calls.into_portable(registry)),
 			view_functions: self
 				.view_functions
@@ -410,6 +413,16 @@ pub enum StorageEntryTypeIR<T: Form = MetaForm> {
 		/// The type of the value.
 		value: T::Type,
 	},
+	/// A storage map whose entries live in child tries, one per child trie identifier.
+	ChildMap {
+		/// The hasher of the child trie identifier, followed by the hasher of the key.
+		hashers: Vec<StorageHasherIR>,
+		/// The type of the key, a tuple of the child trie identifier and of the key within the
+		/// child trie.
+		key: T::Type,
+		/// The type of the value.
+		value: T::Type,
+	},
 }
 
 impl IntoPortable for StorageEntryTypeIR {
@@ -423,6 +436,11 @@ impl IntoPortable for StorageEntryTypeIR {
 				key: registry.register_type(&key),
 				value: registry.register_type(&value),
 			},
+			Self::ChildMap { hashers, key, value } => StorageEntryTypeIR::ChildMap {
+				hashers,
+				key: registry.register_type(&key),
+				value: registry.register_type(&value),
+			},
 		}
 	}
 }
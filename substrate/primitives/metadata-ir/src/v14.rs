@@ -83,7 +83,11 @@ impl From<StorageEntryTypeIR> for StorageEntryType {
 	fn from(ir: StorageEntryTypeIR) -> Self {
 		match ir {
 			StorageEntryTypeIR::Plain(ty) => StorageEntryType::Plain(ty),
-			StorageEntryTypeIR::Map { hashers, key, value } => StorageEntryType::Map {
+			// Child maps can not be expressed in this metadata version, so they are described as
+			// maps keyed by `(child_id, key)`, even though their entries are not found under the
+			// top trie keys such a map would use.
+			StorageEntryTypeIR::Map { hashers, key, value } |
+			StorageEntryTypeIR::ChildMap { hashers, key, value } => StorageEntryType::Map {
 				hashers: hashers.into_iter().map(Into::into).collect(),
 				key,
 				value,
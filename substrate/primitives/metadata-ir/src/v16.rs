@@ -30,26 +30,48 @@ use super::types::{
 };
 
 use frame_metadata::v16::{
-	CustomMetadata, EnumDeprecationInfo, ExtrinsicMetadata, FunctionParamMetadata,
-	ItemDeprecationInfo, PalletAssociatedTypeMetadata, PalletCallMetadata, PalletConstantMetadata,
-	PalletErrorMetadata, PalletEventMetadata, PalletMetadata, PalletStorageMetadata,
-	PalletViewFunctionMetadata, RuntimeApiMetadata, RuntimeApiMethodMetadata, RuntimeMetadataV16,
-	StorageEntryMetadata, TransactionExtensionMetadata, VariantDeprecationInfo,
+	CustomMetadata, CustomValueMetadata, EnumDeprecationInfo, ExtrinsicMetadata,
+	FunctionParamMetadata, ItemDeprecationInfo, PalletAssociatedTypeMetadata, PalletCallMetadata,
+	PalletConstantMetadata, PalletErrorMetadata, PalletEventMetadata, PalletMetadata,
+	PalletStorageMetadata, PalletViewFunctionMetadata, RuntimeApiMetadata,
+	RuntimeApiMethodMetadata, RuntimeMetadataV16, StorageEntryMetadata,
+	TransactionExtensionMetadata, VariantDeprecationInfo,
 };
 
-use codec::Compact;
-use scale_info::form::MetaForm;
+use alloc::{string::String, vec::Vec};
+use codec::{Compact, Encode};
+use scale_info::{form::MetaForm, meta_type};
+
+/// The key of the in-code storage versions of the pallets in the custom metadata.
+///
+/// The value is a `Vec<(String, u16)>` of the pallet names and their storage versions. Pallets
+/// that don't declare a storage version are omitted.
+pub const STORAGE_VERSIONS_KEY: &str = "storage_versions";
 
 impl From<MetadataIR> for RuntimeMetadataV16 {
 	fn from(ir: MetadataIR) -> Self {
+		let storage_versions = ir
+			.pallets
+			.iter()
+			.filter_map(|pallet| Some((pallet.name, pallet.storage_version?)))
+			.collect::<Vec<_>>();
+		let mut custom = CustomMetadata { map: Default::default() };
+		if !storage_versions.is_empty() {
+			custom.map.insert(
+				STORAGE_VERSIONS_KEY,
+				CustomValueMetadata {
+					ty: meta_type::<Vec<(String, u16)>>(),
+					value: storage_versions.encode(),
+				},
+			);
+		}
+
 		RuntimeMetadataV16::new(
 			ir.pallets.into_iter().map(Into::into).collect(),
 			ir.extrinsic.into_v16_with_call_ty(ir.outer_enums.call_enum_ty),
 			ir.apis.into_iter().map(Into::into).collect(),
 			ir.outer_enums.into(),
-			// Substrate does not collect yet the custom metadata fields.
-			// This allows us to extend the V16 easily.
-			CustomMetadata { map: Default::default() },
+			custom,
 		)
 	}
 }
@@ -20,6 +20,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
+
 /// Re-export of parent module scope storage prefix.
 pub use sp_core::offchain::STORAGE_PREFIX;
 
@@ -34,4 +38,16 @@ sp_api::decl_runtime_apis! {
 		/// Starts the off-chain task for given block header.
 		fn offchain_worker(header: &Block::Header);
 	}
+
+	/// The api for executing the tasks of the runtime in the background.
+	///
+	/// Tasks are declared by pallets using `#[pallet::tasks_experimental]` and aggregated into the
+	/// `RuntimeTask` of the runtime.
+	pub trait TasksApi<RuntimeTask: codec::Codec> {
+		/// Returns up to `max` tasks which are currently valid and can thus be executed.
+		fn valid_tasks(max: u32) -> Vec<RuntimeTask>;
+
+		/// Returns the unsigned extrinsic which executes `task` when included in a block.
+		fn task_extrinsic(task: RuntimeTask) -> Block::Extrinsic;
+	}
 }
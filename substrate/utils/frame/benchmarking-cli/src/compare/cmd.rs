@@ -0,0 +1,516 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`CompareCmd`] as entry point for the CLI to compare benchmark results.
+
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use clap::Parser;
+use comfy_table::{Row, Table};
+use log::info;
+use serde::Serialize;
+use thousands::Separable;
+
+use sc_cli::{CliConfiguration, Result, SharedParams};
+
+use super::formula::{load, BenchmarkKey, LinearTerm, WeightFormula};
+
+/// Compare the weights of two `benchmark pallet` runs.
+///
+/// Both runs can be given as JSON output from `--json-file`, as generated weight files or as
+/// directories containing either of them. Each benchmark is compared at the maximum of its
+/// component ranges. Returns an error if any worst-case weight grew by more than the allowed
+/// threshold or if it does more storage reads or writes.
+#[derive(Debug, Parser)]
+pub struct CompareCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	/// Results of the old run.
+	#[arg(long, value_name = "PATH")]
+	pub old: PathBuf,
+
+	/// Results of the new run.
+	#[arg(long, value_name = "PATH")]
+	pub new: PathBuf,
+
+	/// Maximal allowed increase of the worst-case ref time.
+	#[arg(long, default_value_t = 5.0, value_name = "PERCENT")]
+	pub ref_time_threshold: f64,
+
+	/// Maximal allowed increase of the worst-case proof size.
+	#[arg(long, default_value_t = 5.0, value_name = "PERCENT")]
+	pub proof_size_threshold: f64,
+
+	/// Also list the benchmarks whose weight did not change.
+	#[arg(long)]
+	pub show_unchanged: bool,
+
+	/// Write the comparison as JSON into this file.
+	#[arg(long, value_name = "PATH")]
+	pub json_file: Option<PathBuf>,
+}
+
+/// Errors that can be returned by the this command.
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum Error {
+	#[error("{0} benchmark(s) exceed the allowed weight increase")]
+	ThresholdExceeded(usize),
+}
+
+/// How a benchmark changed between the two runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+	Added,
+	Removed,
+	Changed,
+	Unchanged,
+}
+
+/// An old and a new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+struct Delta {
+	old: u128,
+	new: u128,
+}
+
+/// Which proof size of the benchmarks is compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum ProofSizeKind {
+	/// The estimated proof size, which is only part of weight files.
+	Estimated,
+	/// The proof size measured while benchmarking.
+	Measured,
+}
+
+/// The change of the slopes of a component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct SlopeDelta {
+	component: String,
+	ref_time: Delta,
+	proof_size: Delta,
+}
+
+/// The comparison of a single benchmark.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Comparison {
+	pallet: String,
+	instance: String,
+	extrinsic: String,
+	status: Status,
+	/// The worst-case ref time in picoseconds.
+	ref_time: Delta,
+	/// The worst-case proof size in bytes.
+	proof_size: Delta,
+	/// Whether the estimated or measured proof sizes are compared.
+	proof_size_kind: ProofSizeKind,
+	/// The worst-case number of storage reads.
+	reads: Delta,
+	/// The worst-case number of storage writes.
+	writes: Delta,
+	/// The components whose slopes changed.
+	slopes: Vec<SlopeDelta>,
+	/// Whether the increase is beyond the allowed threshold.
+	exceeded: bool,
+}
+
+impl CompareCmd {
+	/// Compare the two runs and print the results.
+	pub fn run(&self) -> Result<()> {
+		self.validate_args()?;
+		let old = load(&self.old)?;
+		let new = load(&self.new)?;
+		if old.is_empty() && new.is_empty() {
+			return Err("No benchmark results found".into())
+		}
+
+		let comparisons = compare(&old, &new, self.ref_time_threshold, self.proof_size_threshold);
+		self.print_summary(&comparisons);
+
+		if let Some(path) = &self.json_file {
+			let json = serde_json::to_string_pretty(&comparisons)
+				.map_err(|e| format!("Serializing into JSON: {:?}", e))?;
+			fs::write(path, json)?;
+		}
+
+		let exceeded = comparisons.iter().filter(|c| c.exceeded).count();
+		if exceeded != 0 {
+			return Err(sc_cli::Error::Application(Box::new(Error::ThresholdExceeded(exceeded))))
+		}
+		Ok(())
+	}
+
+	/// Prints a human-readable summary.
+	fn print_summary(&self, comparisons: &[Comparison]) {
+		let mut table = Table::new();
+		table.set_header([
+			"Pallet",
+			"Extrinsic",
+			"Ref Time [ps]",
+			"Change",
+			"Proof Size [B]",
+			"Change",
+			"Reads / Writes",
+			"Slopes",
+			"Result",
+		]);
+		let mut unchanged = 0;
+		for comparison in comparisons {
+			if comparison.status == Status::Unchanged && !self.show_unchanged {
+				unchanged += 1;
+				continue
+			}
+			table.add_row(comparison.to_row());
+		}
+
+		info!(
+			"\n{}\nCompared {} benchmarks, {} exceed the thresholds ({:.1?}% ref time, {:.1?}% proof \
+			size) and {} are unchanged.",
+			table,
+			comparisons.len(),
+			comparisons.iter().filter(|c| c.exceeded).count(),
+			self.ref_time_threshold,
+			self.proof_size_threshold,
+			comparisons.iter().filter(|c| c.status == Status::Unchanged).count(),
+		);
+		if unchanged != 0 {
+			info!("Hiding {} unchanged benchmarks; use --show-unchanged to list them.", unchanged);
+		}
+	}
+
+	/// Validates the CLI arguments.
+	fn validate_args(&self) -> Result<()> {
+		if self.ref_time_threshold < 0.0 || self.proof_size_threshold < 0.0 {
+			return Err("The thresholds must not be negative".into())
+		}
+		Ok(())
+	}
+}
+
+/// Compares all benchmarks of the old and the new run.
+fn compare(
+	old: &BTreeMap<BenchmarkKey, WeightFormula>,
+	new: &BTreeMap<BenchmarkKey, WeightFormula>,
+	ref_time_threshold: f64,
+	proof_size_threshold: f64,
+) -> Vec<Comparison> {
+	let empty = WeightFormula::default();
+	let keys = old.keys().chain(new.keys()).collect::<std::collections::BTreeSet<_>>();
+
+	keys.into_iter()
+		.map(|key| {
+			let (old, new) = (old.get(key), new.get(key));
+			let status = match (old, new) {
+				(None, _) => Status::Added,
+				(_, None) => Status::Removed,
+				(Some(old), Some(new)) if old == new => Status::Unchanged,
+				_ => Status::Changed,
+			};
+			// Weight files are only comparable with JSON results by their measured proof size.
+			let proof_size_kind =
+				if old.iter().chain(new.iter()).all(|formula| formula.proof_size.is_some()) {
+					ProofSizeKind::Estimated
+				} else {
+					ProofSizeKind::Measured
+				};
+			let (old, new) = (old.unwrap_or(&empty), new.unwrap_or(&empty));
+
+			// Both formulas are evaluated at the same, highest known, component values.
+			let mut components = BTreeMap::<String, u32>::new();
+			for (name, (_, max)) in old.ranges.iter().chain(new.ranges.iter()) {
+				let value = components.entry(name.clone()).or_default();
+				*value = (*value).max(*max);
+			}
+			let ref_time =
+				Delta { old: old.ref_time.eval(&components), new: new.ref_time.eval(&components) };
+			let proof_size = Delta {
+				old: proof_size_kind.term(old).eval(&components),
+				new: proof_size_kind.term(new).eval(&components),
+			};
+			let reads =
+				Delta { old: old.reads.eval(&components), new: new.reads.eval(&components) };
+			let writes =
+				Delta { old: old.writes.eval(&components), new: new.writes.eval(&components) };
+
+			let names = old
+				.ref_time
+				.slopes
+				.keys()
+				.chain(proof_size_kind.term(old).slopes.keys())
+				.chain(new.ref_time.slopes.keys())
+				.chain(proof_size_kind.term(new).slopes.keys())
+				.collect::<std::collections::BTreeSet<_>>();
+			let slopes = names
+				.into_iter()
+				.map(|name| {
+					let slope = |formula: &WeightFormula, ref_time: bool| {
+						let term = if ref_time {
+							&formula.ref_time
+						} else {
+							proof_size_kind.term(formula)
+						};
+						term.slopes.get(name).copied().unwrap_or_default()
+					};
+					SlopeDelta {
+						component: name.clone(),
+						ref_time: Delta { old: slope(old, true), new: slope(new, true) },
+						proof_size: Delta { old: slope(old, false), new: slope(new, false) },
+					}
+				})
+				.filter(|s| {
+					s.ref_time.old != s.ref_time.new || s.proof_size.old != s.proof_size.new
+				})
+				.collect();
+
+			// Storage accesses are counted exactly, any additional one is a regression.
+			let exceeded = status == Status::Changed &&
+				(ref_time.exceeds(ref_time_threshold) ||
+					proof_size.exceeds(proof_size_threshold) ||
+					reads.new > reads.old ||
+					writes.new > writes.old);
+
+			Comparison {
+				pallet: key.0.clone(),
+				instance: key.1.clone(),
+				extrinsic: key.2.clone(),
+				status,
+				ref_time,
+				proof_size,
+				proof_size_kind,
+				reads,
+				writes,
+				slopes,
+				exceeded,
+			}
+		})
+		.collect()
+}
+
+impl ProofSizeKind {
+	/// The proof size of this kind of `formula`.
+	fn term(self, formula: &WeightFormula) -> &LinearTerm {
+		static NOT_ESTIMATED: LinearTerm = LinearTerm { base: 0, slopes: BTreeMap::new() };
+		match self {
+			ProofSizeKind::Estimated => formula.proof_size.as_ref().unwrap_or(&NOT_ESTIMATED),
+			ProofSizeKind::Measured => &formula.measured_proof_size,
+		}
+	}
+}
+
+impl Delta {
+	/// The relative change in percent. `None` if the old value is zero but the new one is not.
+	fn percent(&self) -> Option<f64> {
+		match (self.old, self.new) {
+			(0, 0) => Some(0.0),
+			(0, _) => None,
+			(old, new) => Some((new as f64 - old as f64) / old as f64 * 100.0),
+		}
+	}
+
+	/// Whether the value increased by more than `threshold` percent.
+	fn exceeds(&self, threshold: f64) -> bool {
+		self.new > self.old && self.percent().map_or(true, |p| p > threshold)
+	}
+
+	fn format_values(&self) -> String {
+		if self.old == self.new {
+			self.new.separate_with_underscores()
+		} else {
+			format!(
+				"{} → {}",
+				self.old.separate_with_underscores(),
+				self.new.separate_with_underscores()
+			)
+		}
+	}
+
+	fn format_percent(&self) -> String {
+		match self.percent() {
+			Some(p) => format!("{:+.2} %", p),
+			None => "+∞".into(),
+		}
+	}
+}
+
+impl Comparison {
+	/// Format [`Self`] as row that can be printed in a table.
+	fn to_row(&self) -> Row {
+		let result = match self.status {
+			Status::Added => "🆕 Added",
+			Status::Removed => "🗑️ Removed",
+			_ if self.exceeded => "❌ Fail",
+			_ => "✅ Pass",
+		};
+		let (ref_time_change, proof_size_change) = match self.status {
+			Status::Changed | Status::Unchanged =>
+				(self.ref_time.format_percent(), self.proof_size.format_percent()),
+			_ => (String::new(), String::new()),
+		};
+		let slopes = self
+			.slopes
+			.iter()
+			.map(|s| {
+				format!(
+					"{}: {} ps, {} B",
+					s.component,
+					s.ref_time.format_values(),
+					s.proof_size.format_values()
+				)
+			})
+			.collect::<Vec<_>>()
+			.join("\n");
+
+		let pallet = if self.instance.is_empty() {
+			self.pallet.clone()
+		} else {
+			format!("{} ({})", self.pallet, self.instance)
+		};
+		let proof_size = match self.proof_size_kind {
+			ProofSizeKind::Estimated => self.proof_size.format_values(),
+			ProofSizeKind::Measured => format!("{} (measured)", self.proof_size.format_values()),
+		};
+
+		vec![
+			pallet,
+			self.extrinsic.clone(),
+			self.ref_time.format_values(),
+			ref_time_change,
+			proof_size,
+			proof_size_change,
+			format!("{} / {}", self.reads.format_values(), self.writes.format_values()),
+			slopes,
+			result.into(),
+		]
+		.into()
+	}
+}
+
+// Boilerplate
+impl CliConfiguration for CompareCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn formula(ref_time: u128, ref_time_slope: u128, proof_size: u128, max: u32) -> WeightFormula {
+		WeightFormula {
+			ref_time: LinearTerm { base: ref_time, slopes: [("c".into(), ref_time_slope)].into() },
+			proof_size: Some(LinearTerm { base: proof_size, slopes: Default::default() }),
+			ranges: [("c".into(), (0, max))].into(),
+			..Default::default()
+		}
+	}
+
+	fn single(formula: WeightFormula) -> BTreeMap<BenchmarkKey, WeightFormula> {
+		[(("pallet".into(), "".into(), "call".into()), formula)].into()
+	}
+
+	#[test]
+	fn worst_case_is_compared_against_thresholds() {
+		let old = single(formula(1_000, 10, 100, 100));
+
+		// 2_000 -> 2_100 is an increase of 5%.
+		let result = compare(&old, &single(formula(1_100, 10, 100, 100)), 5.0, 5.0);
+		assert_eq!(result[0].status, Status::Changed);
+		assert_eq!(result[0].ref_time, Delta { old: 2_000, new: 2_100 });
+		assert!(result[0].slopes.is_empty());
+		assert!(!result[0].exceeded);
+
+		let result = compare(&old, &single(formula(1_000, 12, 100, 100)), 5.0, 5.0);
+		assert_eq!(result[0].ref_time, Delta { old: 2_000, new: 2_200 });
+		assert_eq!(
+			result[0].slopes,
+			vec![SlopeDelta {
+				component: "c".into(),
+				ref_time: Delta { old: 10, new: 12 },
+				proof_size: Delta { old: 0, new: 0 },
+			}]
+		);
+		assert!(result[0].exceeded);
+
+		let result = compare(&old, &single(formula(1_000, 10, 106, 100)), 5.0, 5.0);
+		assert!(result[0].exceeded);
+		let result = compare(&old, &single(formula(500, 10, 50, 100)), 5.0, 5.0);
+		assert!(!result[0].exceeded);
+	}
+
+	#[test]
+	fn highest_component_range_is_used() {
+		let result =
+			compare(&single(formula(0, 10, 0, 100)), &single(formula(0, 10, 0, 200)), 5.0, 5.0);
+
+		assert_eq!(result[0].ref_time, Delta { old: 2_000, new: 2_000 });
+		assert_eq!(result[0].status, Status::Changed);
+		assert!(!result[0].exceeded);
+	}
+
+	#[test]
+	fn added_and_removed_benchmarks_do_not_fail() {
+		let result = compare(&single(formula(1, 0, 0, 0)), &Default::default(), 5.0, 5.0);
+		assert_eq!(result[0].status, Status::Removed);
+		assert!(!result[0].exceeded);
+
+		let result = compare(&Default::default(), &single(formula(1, 0, 0, 0)), 5.0, 5.0);
+		assert_eq!(result[0].status, Status::Added);
+		assert!(!result[0].exceeded);
+	}
+
+	#[test]
+	fn growth_from_zero_exceeds_any_threshold() {
+		let result =
+			compare(&single(formula(1, 0, 0, 0)), &single(formula(1, 0, 1, 0)), 5.0, 1_000.0);
+
+		assert_eq!(result[0].proof_size.percent(), None);
+		assert!(result[0].exceeded);
+	}
+
+	#[test]
+	fn additional_storage_accesses_exceed_the_threshold() {
+		let old = formula(1_000, 0, 100, 0);
+		let mut new = old.clone();
+		new.writes.base = 1;
+
+		let result = compare(&single(old), &single(new), 5.0, 5.0);
+		assert_eq!(result[0].writes, Delta { old: 0, new: 1 });
+		assert!(result[0].exceeded);
+	}
+
+	#[test]
+	fn measured_proof_size_is_compared_without_estimation() {
+		let mut old = formula(1_000, 0, 100, 0);
+		old.measured_proof_size.base = 50;
+		// JSON results have no estimated proof size.
+		let new = WeightFormula { proof_size: None, ..old.clone() };
+
+		let result = compare(&single(old.clone()), &single(new), 5.0, 5.0);
+		assert_eq!(result[0].proof_size_kind, ProofSizeKind::Measured);
+		assert_eq!(result[0].proof_size, Delta { old: 50, new: 50 });
+		assert!(!result[0].exceeded);
+
+		let result = compare(&single(old.clone()), &single(old), 5.0, 5.0);
+		assert_eq!(result[0].proof_size_kind, ProofSizeKind::Estimated);
+		assert_eq!(result[0].proof_size, Delta { old: 100, new: 100 });
+	}
+}
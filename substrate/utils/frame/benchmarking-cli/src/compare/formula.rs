@@ -0,0 +1,468 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reads the weight formulas of benchmarks from JSON results or generated weight files.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use frame_benchmarking::{Analysis, BenchmarkBatchSplitResults, BenchmarkSelector};
+use inflector::Inflector;
+use sc_cli::Result;
+
+/// Identifies a benchmark by its pallet, instance and extrinsic name.
+///
+/// The instance is the suffix that `benchmark pallet` appends to the name of the weight file of a
+/// pallet with multiple instances, e.g. `foreign` for `pallet_assets_foreign.rs`. It is empty for
+/// pallets with a single instance.
+pub(crate) type BenchmarkKey = (String, String, String);
+
+/// A linear function of the components of a benchmark.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct LinearTerm {
+	/// The constant part.
+	pub base: u128,
+	/// The non-zero slopes by component name.
+	pub slopes: BTreeMap<String, u128>,
+}
+
+/// The weight of a benchmark as function of its components.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct WeightFormula {
+	/// Ref time in picoseconds, without the storage accesses.
+	pub ref_time: LinearTerm,
+	/// Estimated proof size in bytes. `None` if it was not estimated, as for JSON results.
+	pub proof_size: Option<LinearTerm>,
+	/// Measured proof size in bytes.
+	pub measured_proof_size: LinearTerm,
+	/// Number of storage reads.
+	pub reads: LinearTerm,
+	/// Number of storage writes.
+	pub writes: LinearTerm,
+	/// The `[min, max]` range of each component.
+	pub ranges: BTreeMap<String, (u32, u32)>,
+}
+
+impl LinearTerm {
+	/// Evaluates the term. Components without a value count as zero.
+	pub fn eval(&self, components: &BTreeMap<String, u32>) -> u128 {
+		self.slopes.iter().fold(self.base, |acc, (name, slope)| {
+			let value = components.get(name).copied().unwrap_or_default();
+			acc.saturating_add(slope.saturating_mul(value.into()))
+		})
+	}
+
+	/// Adds `slope` to the slope of `component`.
+	fn add_slope(&mut self, component: &str, slope: u128) {
+		if slope != 0 {
+			let entry = self.slopes.entry(component.into()).or_default();
+			*entry = entry.saturating_add(slope);
+		}
+	}
+}
+
+/// Loads the formulas of all benchmarks in `path`.
+///
+/// `path` is either a JSON file as written by `benchmark pallet --json-file`, a generated weight
+/// file or a directory whose `.json` and `.rs` files are loaded.
+pub(crate) fn load(path: &Path) -> Result<BTreeMap<BenchmarkKey, WeightFormula>> {
+	let files = if path.is_dir() {
+		let mut files = fs::read_dir(path)?
+			.map(|entry| entry.map(|entry| entry.path()))
+			.collect::<std::io::Result<Vec<_>>>()?;
+		files.retain(|file| matches!(extension(file), Some("json" | "rs")));
+		files.sort();
+		files
+	} else {
+		vec![path.to_path_buf()]
+	};
+
+	let mut formulas = BTreeMap::new();
+	for file in files {
+		let content = fs::read_to_string(&file)?;
+		let parsed = if extension(&file) == Some("json") {
+			let batches: Vec<BenchmarkBatchSplitResults> = serde_json::from_str(&content)
+				.map_err(|e| format!("Parsing {}: {:?}", file.display(), e))?;
+			from_json(&batches)?
+		} else {
+			let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+			from_weight_file(&content, stem)
+		};
+
+		for ((pallet, instance, extrinsic), formula) in parsed {
+			if formulas
+				.insert((pallet.clone(), instance.clone(), extrinsic.clone()), formula)
+				.is_some()
+			{
+				return Err(format!(
+					"Benchmark {}::{} of instance `{}` is contained more than once in {}",
+					pallet,
+					extrinsic,
+					instance,
+					path.display()
+				)
+				.into())
+			}
+		}
+	}
+
+	Ok(formulas)
+}
+
+/// Analyzes the raw results of `benchmark pallet --json-file`.
+///
+/// Uses the same min-squares analysis as the weight file writer. Only the measured proof size is
+/// known, since the JSON output does not contain the storage info needed for the estimation.
+pub(crate) fn from_json(
+	batches: &[BenchmarkBatchSplitResults],
+) -> Result<Vec<(BenchmarkKey, WeightFormula)>> {
+	batches
+		.iter()
+		.map(|batch| {
+			let pallet = String::from_utf8(batch.pallet.clone())
+				.map_err(|_| "Pallet name is not valid UTF-8")?;
+			let benchmark = String::from_utf8(batch.benchmark.clone())
+				.map_err(|_| "Benchmark name is not valid UTF-8")?;
+			// Same as the weight file name suffix of the writer.
+			let instance = if batches
+				.iter()
+				.any(|other| other.pallet == batch.pallet && other.instance != batch.instance)
+			{
+				String::from_utf8_lossy(&batch.instance).to_snake_case()
+			} else {
+				String::new()
+			};
+			if batch.time_results.is_empty() || batch.db_results.is_empty() {
+				return Err(format!("Benchmark {}::{} has no results", pallet, benchmark).into())
+			}
+
+			let ref_time =
+				Analysis::min_squares_iqr(&batch.time_results, BenchmarkSelector::ExtrinsicTime)
+					.ok_or_else(|| format!("Analyzing the time of {}::{}", pallet, benchmark))?;
+			let analyze = |selector, name| {
+				Analysis::min_squares_iqr(&batch.db_results, selector)
+					.ok_or_else(|| format!("Analyzing the {} of {}::{}", name, pallet, benchmark))
+			};
+			let proof_size = analyze(BenchmarkSelector::ProofSize, "proof")?;
+			let reads = analyze(BenchmarkSelector::Reads, "reads")?;
+			let writes = analyze(BenchmarkSelector::Writes, "writes")?;
+
+			let mut ranges = BTreeMap::<String, (u32, u32)>::new();
+			for result in batch.time_results.iter().chain(batch.db_results.iter()) {
+				for (param, value) in &result.components {
+					let range = ranges.entry(param.to_string()).or_insert((*value, *value));
+					range.0 = range.0.min(*value);
+					range.1 = range.1.max(*value);
+				}
+			}
+
+			let formula = WeightFormula {
+				ref_time: linear_term(ref_time),
+				proof_size: None,
+				measured_proof_size: linear_term(proof_size),
+				reads: linear_term(reads),
+				writes: linear_term(writes),
+				ranges,
+			};
+			Ok(((pallet, instance, benchmark), formula))
+		})
+		.collect()
+}
+
+/// Parses a weight file that was generated by `benchmark pallet`.
+///
+/// The ref time and estimated proof size are read from the `Weight::from_parts` terms, the storage
+/// accesses from the `DbWeight` terms and the measured proof size from the proof size summary. The
+/// pallet name is taken from the file header, or `file_stem` if there is none. The instance is the
+/// rest of the `file_stem` after the pallet name. When a file implements the weight functions more
+/// than once, like the `()` fallback in pallet weight files, the first implementation is used.
+pub(crate) fn from_weight_file(
+	content: &str,
+	file_stem: &str,
+) -> Vec<(BenchmarkKey, WeightFormula)> {
+	let pallet = content
+		.lines()
+		.find_map(|line| line.strip_prefix("//! Autogenerated weights for "))
+		.map(|pallet| pallet.trim().trim_matches('`').to_string())
+		.unwrap_or_else(|| file_stem.to_string());
+	let instance = file_stem
+		.strip_prefix(&pallet)
+		.and_then(|rest| rest.strip_prefix('_'))
+		.unwrap_or_default()
+		.to_string();
+
+	let mut formulas = Vec::<(BenchmarkKey, WeightFormula)>::new();
+	let mut ranges = BTreeMap::new();
+	let mut current: Option<(String, WeightFormula)> = None;
+	for line in content.lines().map(str::trim) {
+		if let Some((name, formula)) = current.as_mut() {
+			if line == "}" {
+				let (name, formula) = (core::mem::take(name), core::mem::take(formula));
+				if !formulas.iter().any(|((_, _, other), _)| *other == name) {
+					formulas.push(((pallet.clone(), instance.clone(), name), formula));
+				}
+				current = None;
+			} else if let Some(measured) = line.strip_prefix("//  Measured:") {
+				if let Some(term) = parse_proof_size_summary(measured) {
+					formula.measured_proof_size = term;
+				}
+			} else if !parse_db_term(line, formula) {
+				parse_weight_term(line, formula);
+			}
+		} else if let Some((name, range)) = parse_component_range(line) {
+			ranges.insert(name, range);
+		} else if let Some(signature) = line.strip_prefix("fn ") {
+			let ranges = core::mem::take(&mut ranges);
+			if line.ends_with('{') {
+				let name = signature.split('(').next().unwrap_or_default().trim().to_string();
+				current = Some((name, WeightFormula { ranges, ..Default::default() }));
+			}
+		}
+	}
+
+	formulas
+}
+
+/// Parses a ``/// The range of component `c` is `[1, 100]`.`` doc comment.
+fn parse_component_range(line: &str) -> Option<(String, (u32, u32))> {
+	let rest = line.strip_prefix("/// The range of component `")?;
+	let (name, rest) = rest.split_once('`')?;
+	let (_, rest) = rest.split_once("`[")?;
+	let (range, _) = rest.split_once("]`")?;
+	let (min, max) = range.split_once(',')?;
+	Some((name.to_string(), (min.trim().parse().ok()?, max.trim().parse().ok()?)))
+}
+
+/// Adds the `Weight::from_parts` term of a line to `formula`.
+fn parse_weight_term(line: &str, formula: &mut WeightFormula) {
+	let Some((_, rest)) = line.split_once("Weight::from_parts(") else { return };
+	let Some((parts, rest)) = rest.split_once(')') else { return };
+	let Some((ref_time, proof_size)) = parts.split_once(',') else { return };
+	let (Some(ref_time), Some(proof_size)) = (parse_number(ref_time), parse_number(proof_size))
+	else {
+		return
+	};
+
+	match rest
+		.strip_prefix(".saturating_mul(")
+		.and_then(|rest| rest.split_once(".into()"))
+	{
+		Some((component, _)) => {
+			formula.ref_time.add_slope(component.trim(), ref_time);
+			formula
+				.proof_size
+				.get_or_insert_default()
+				.add_slope(component.trim(), proof_size);
+		},
+		None => {
+			formula.ref_time.base = formula.ref_time.base.saturating_add(ref_time);
+			let estimated = formula.proof_size.get_or_insert_default();
+			estimated.base = estimated.base.saturating_add(proof_size);
+		},
+	}
+}
+
+/// Adds the `DbWeight` reads or writes term of a line to `formula`.
+///
+/// Returns whether the line contains such a term.
+fn parse_db_term(line: &str, formula: &mut WeightFormula) -> bool {
+	let Some((_, rest)) = line.split_once("DbWeight::get().") else { return false };
+	let (term, rest) = if let Some(rest) = rest.strip_prefix("reads(") {
+		(&mut formula.reads, rest)
+	} else if let Some(rest) = rest.strip_prefix("writes(") {
+		(&mut formula.writes, rest)
+	} else {
+		return false
+	};
+
+	// Either `(1_u64).saturating_mul(c.into())` or `1_u64`.
+	match rest.strip_prefix('(').and_then(|rest| rest.split_once(").saturating_mul(")) {
+		Some((slope, rest)) => {
+			let component = rest.split_once(".into()").map(|(component, _)| component.trim());
+			if let (Some(slope), Some(component)) = (parse_number(slope), component) {
+				term.add_slope(component, slope);
+			}
+		},
+		None =>
+			if let Some(base) = rest.split_once(')').and_then(|(base, _)| parse_number(base)) {
+				term.base = term.base.saturating_add(base);
+			},
+	}
+	true
+}
+
+/// Parses a proof size summary like `` `103 + c * (1 ±0)` ``.
+fn parse_proof_size_summary(summary: &str) -> Option<LinearTerm> {
+	let mut parts = summary.trim().trim_matches('`').split(" + ");
+	let mut term = LinearTerm { base: parse_number(parts.next()?)?, slopes: Default::default() };
+	for part in parts {
+		let (component, slope) = part.split_once(" * (")?;
+		let (slope, _) = slope.split_once(' ')?;
+		term.add_slope(component.trim(), parse_number(slope)?);
+	}
+	Some(term)
+}
+
+/// Parses a number that can contain `_` separators and a `u64` suffix.
+fn parse_number(s: &str) -> Option<u128> {
+	let s = s.trim();
+	let s = s.strip_suffix(" as u64").or_else(|| s.strip_suffix("_u64")).unwrap_or(s);
+	s.replace('_', "").parse().ok()
+}
+
+fn linear_term(analysis: Analysis) -> LinearTerm {
+	let mut term = LinearTerm { base: analysis.base, slopes: Default::default() };
+	for (name, slope) in analysis.names.iter().zip(analysis.slopes) {
+		term.add_slope(name, slope);
+	}
+	term
+}
+
+fn extension(path: &Path) -> Option<&str> {
+	path.extension().and_then(|e| e.to_str())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_benchmarking::{BenchmarkParameter, BenchmarkResult};
+
+	const WEIGHT_FILE: &str = r#"
+//! Autogenerated weights for `pallet_example`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+
+/// Weight functions for `pallet_example`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_example::WeightInfo for WeightInfo<T> {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 1000]`.
+	/// The range of component `d` is `[0, 10]`.
+	fn transfer(c: u32, _d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `103 + c * (1 ±0)`
+		//  Estimated: `3593 + c * (2603 ±0)`
+		// Minimum execution time: 46_000_000 picoseconds.
+		Weight::from_parts(47_298_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			// Standard Error: 2_131
+			.saturating_add(Weight::from_parts(1_060_520, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+	}
+	fn remark() -> Weight {
+		Weight::from_parts(1_000, 0)
+	}
+}
+
+impl WeightInfo for () {
+	fn transfer(c: u32, ) -> Weight {
+		Weight::from_parts(1, 0)
+	}
+}
+"#;
+
+	#[test]
+	fn weight_files_are_parsed() {
+		let formulas = from_weight_file(WEIGHT_FILE, "fallback");
+
+		assert_eq!(formulas.len(), 2);
+		let ((pallet, instance, extrinsic), transfer) = &formulas[0];
+		assert_eq!(
+			(pallet.as_str(), instance.as_str(), extrinsic.as_str()),
+			("pallet_example", "", "transfer")
+		);
+		assert_eq!(transfer.ref_time.base, 47_298_000);
+		assert_eq!(transfer.ref_time.slopes, [("c".into(), 1_060_520)].into());
+		assert_eq!(
+			transfer.proof_size,
+			Some(LinearTerm { base: 3593, slopes: [("c".into(), 2603)].into() })
+		);
+		assert_eq!(transfer.measured_proof_size.base, 103);
+		assert_eq!(transfer.measured_proof_size.slopes, [("c".into(), 1)].into());
+		assert_eq!(transfer.reads, LinearTerm { base: 1, slopes: [("c".into(), 1)].into() });
+		assert_eq!(transfer.writes, LinearTerm { base: 1, slopes: Default::default() });
+		assert_eq!(transfer.ranges, [("c".into(), (1, 1000)), ("d".into(), (0, 10))].into());
+
+		let ((_, _, extrinsic), remark) = &formulas[1];
+		assert_eq!(extrinsic, "remark");
+		assert_eq!(remark.ref_time.base, 1_000);
+		assert!(remark.ranges.is_empty());
+	}
+
+	#[test]
+	fn file_stem_is_used_without_header() {
+		let formulas =
+			from_weight_file("fn remark() -> Weight {\n\tWeight::from_parts(1, 2)\n}", "p");
+
+		assert_eq!(formulas[0].0, ("p".into(), "".into(), "remark".into()));
+		assert_eq!(
+			formulas[0].1.proof_size,
+			Some(LinearTerm { base: 2, slopes: Default::default() })
+		);
+	}
+
+	#[test]
+	fn instance_is_taken_from_file_stem() {
+		let formulas = from_weight_file(WEIGHT_FILE, "pallet_example_foreign");
+		assert_eq!(formulas[0].0, ("pallet_example".into(), "foreign".into(), "transfer".into()));
+
+		let formulas = from_weight_file(WEIGHT_FILE, "weights");
+		assert_eq!(formulas[0].0, ("pallet_example".into(), "".into(), "transfer".into()));
+	}
+
+	#[test]
+	fn json_results_are_analyzed() {
+		let results = (1..=10)
+			.map(|c| BenchmarkResult {
+				components: vec![(BenchmarkParameter::c, c)],
+				extrinsic_time: 10 + 2 * c as u128,
+				reads: 2 + c,
+				writes: 1,
+				proof_size: 100 + 5 * c,
+				..Default::default()
+			})
+			.collect::<Vec<_>>();
+		let batch = |instance: &[u8]| BenchmarkBatchSplitResults {
+			pallet: b"pallet_example".to_vec(),
+			instance: instance.to_vec(),
+			benchmark: b"transfer".to_vec(),
+			time_results: results.clone(),
+			db_results: results.clone(),
+		};
+
+		let formulas = from_json(&[batch(b"Example")]).unwrap();
+
+		assert_eq!(formulas[0].0, ("pallet_example".into(), "".into(), "transfer".into()));
+		let formula = &formulas[0].1;
+		assert_eq!(formula.ref_time.base, 10_000);
+		assert_eq!(formula.ref_time.slopes, [("c".into(), 2_000)].into());
+		assert_eq!(formula.proof_size, None);
+		assert_eq!(formula.measured_proof_size.base, 100);
+		assert_eq!(formula.measured_proof_size.slopes, [("c".into(), 5)].into());
+		assert_eq!(formula.reads, LinearTerm { base: 2, slopes: [("c".into(), 1)].into() });
+		assert_eq!(formula.writes, LinearTerm { base: 1, slopes: Default::default() });
+		assert_eq!(formula.ranges, [("c".into(), (1, 10))].into());
+
+		// Multiple instances are told apart like in the names of the weight files.
+		let formulas = from_json(&[batch(b"LocalAssets"), batch(b"ForeignAssets")]).unwrap();
+		assert_eq!(formulas[0].0 .1, "local_assets");
+		assert_eq!(formulas[1].0 .1, "foreign_assets");
+	}
+}
@@ -18,16 +18,21 @@
 //! Contains the root [`BenchmarkCmd`] command and exports its sub-commands.
 
 mod block;
+mod compare;
 mod extrinsic;
 mod machine;
+mod migrations;
 mod overhead;
 mod pallet;
 mod shared;
 mod storage;
+mod storage_diff;
 
 pub use block::BlockCmd;
+pub use compare::CompareCmd;
 pub use extrinsic::{ExtrinsicBuilder, ExtrinsicCmd, ExtrinsicFactory};
 pub use machine::{MachineCmd, SUBSTRATE_REFERENCE_HARDWARE};
+pub use migrations::MigrationsCmd;
 pub use overhead::{
 	remark_builder::{DynamicRemarkBuilder, SubstrateRemarkBuilder},
 	OpaqueBlock, OverheadCmd,
@@ -35,6 +40,7 @@ pub use overhead::{
 pub use pallet::PalletCmd;
 pub use sc_service::BasePath;
 pub use storage::StorageCmd;
+pub use storage_diff::StorageDiffCmd;
 
 use sc_cli::{CliConfiguration, DatabaseParams, ImportParams, PruningParams, Result, SharedParams};
 
@@ -49,6 +55,9 @@ pub enum BenchmarkCmd {
 	Block(BlockCmd),
 	Machine(MachineCmd),
 	Extrinsic(ExtrinsicCmd),
+	Compare(CompareCmd),
+	Migrations(MigrationsCmd),
+	StorageDiff(StorageDiffCmd),
 }
 
 /// Unwraps a [`BenchmarkCmd`] into its concrete sub-command.
@@ -65,6 +74,9 @@ macro_rules! unwrap_cmd {
 			BenchmarkCmd::Block($cmd) => $code,
 			BenchmarkCmd::Machine($cmd) => $code,
 			BenchmarkCmd::Extrinsic($cmd) => $code,
+			BenchmarkCmd::Compare($cmd) => $code,
+			BenchmarkCmd::Migrations($cmd) => $code,
+			BenchmarkCmd::StorageDiff($cmd) => $code,
 		}
 	}
 }
@@ -0,0 +1,514 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`MigrationsCmd`] as entry point for the CLI to dry-run multi-block migrations.
+
+use std::{borrow::Cow, fs, path::PathBuf};
+
+use clap::Parser;
+use codec::{Decode, Encode};
+use comfy_table::{Row, Table};
+use log::info;
+use serde::Serialize;
+use thousands::Separable;
+
+use frame_support::weights::Weight;
+use pallet_migrations::{
+	runtime_api::{DryRunStatus, DryRunStep, MigrationInfo, MigrationsApi},
+	MigrationCursor,
+};
+use remote_externalities::{Builder, Mode, OfflineConfig, SnapshotConfig};
+use sc_cli::{
+	execution_method_from_cli, CliConfiguration, Result, SharedParams, WasmExecutionMethod,
+	WasmtimeInstantiationStrategy, DEFAULT_WASMTIME_INSTANTIATION_STRATEGY,
+	DEFAULT_WASM_EXECUTION_METHOD,
+};
+use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
+use sp_api::RuntimeApiInfo;
+use sp_core::{
+	storage::{well_known_keys, StateVersion},
+	traits::{CallContext, CodeExecutor, ReadRuntimeVersionExt, RuntimeCode, WrappedRuntimeCode},
+};
+use sp_externalities::Extensions;
+use sp_runtime::traits::BlakeTwo256;
+use sp_state_machine::{
+	Backend, InMemoryBackend, OverlayedChanges, StateMachine, TrieBackendBuilder,
+};
+use sp_wasm_interface::HostFunctions;
+
+use crate::OpaqueBlock;
+
+/// Block number type of the [`OpaqueBlock`].
+type BlockNumber = u32;
+
+type SubstrateAndExtraHF<T> = (sp_io::SubstrateHostFunctions, T);
+
+/// Dry-run the multi-block migrations of a runtime against a state snapshot.
+///
+/// Starts the migrations of `pallet-migrations` like a runtime upgrade would and then progresses
+/// them one simulated block at a time through the `MigrationsApi` of the runtime. Reports the
+/// weight and storage accesses of each migration and returns an error if the migrations fail or
+/// do not finish in time.
+#[derive(Debug, Parser)]
+pub struct MigrationsCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	/// State snapshot as created by `frame-remote-externalities` to run the migrations on.
+	#[arg(long, value_name = "PATH")]
+	pub snapshot: PathBuf,
+
+	/// Runtime blob to use instead of the one in the snapshot.
+	///
+	/// This is normally the runtime that contains the migrations, while the snapshot was taken
+	/// before the upgrade.
+	#[arg(long, value_name = "PATH")]
+	pub runtime: Option<PathBuf>,
+
+	/// Ref time in picoseconds that the migrations may use per block.
+	///
+	/// Defaults to the `MaxServiceWeight` of the runtime.
+	#[arg(long, requires = "step_proof_size")]
+	pub step_ref_time: Option<u64>,
+
+	/// Proof size in bytes that the migrations may use per block.
+	///
+	/// Defaults to the `MaxServiceWeight` of the runtime.
+	#[arg(long, requires = "step_ref_time")]
+	pub step_proof_size: Option<u64>,
+
+	/// Maximal number of blocks to simulate before giving up.
+	#[arg(long, default_value_t = 1000)]
+	pub max_blocks: u32,
+
+	/// Write the report of each block as JSON into this file.
+	#[arg(long, value_name = "PATH")]
+	pub json_file: Option<PathBuf>,
+
+	/// Method for executing Wasm runtime code.
+	#[arg(
+		long = "wasm-execution",
+		value_name = "METHOD",
+		value_enum,
+		ignore_case = true,
+		default_value_t = DEFAULT_WASM_EXECUTION_METHOD,
+	)]
+	pub wasm_method: WasmExecutionMethod,
+
+	/// The WASM instantiation method to use.
+	///
+	/// Only has an effect when `wasm-execution` is set to `compiled`.
+	#[arg(
+		long = "wasm-instantiation-strategy",
+		value_name = "STRATEGY",
+		default_value_t = DEFAULT_WASMTIME_INSTANTIATION_STRATEGY,
+		value_enum,
+	)]
+	pub wasmtime_instantiation_strategy: WasmtimeInstantiationStrategy,
+}
+
+/// Errors that can be returned by the this command.
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum Error {
+	#[error("The runtime does not implement the `MigrationsApi`")]
+	MissingApi,
+	#[error("The migrations failed at migration {0:?}")]
+	Failed(Option<u32>),
+	#[error("The migrations are stuck since an earlier failure")]
+	Stuck,
+	#[error("The migrations did not finish within {0} blocks")]
+	Unfinished(u32),
+}
+
+/// The storage accesses of a single runtime call.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+struct StorageAccess {
+	/// Size of the recorded storage proof in bytes.
+	proof_size: usize,
+	/// Number of written or removed keys, including child tries.
+	keys_written: usize,
+}
+
+/// The report of a single simulated block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct BlockReport {
+	block: BlockNumber,
+	/// The migrations that were active in the block, excluding skipped ones.
+	migrations: Vec<u32>,
+	/// Consumed ref time in picoseconds.
+	ref_time: u64,
+	/// Consumed proof size weight in bytes.
+	proof_size: u64,
+	storage: StorageAccess,
+	completed: Vec<u32>,
+	skipped: Vec<u32>,
+	status: String,
+}
+
+/// The accumulated reports of a single migration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MigrationSummary {
+	index: u32,
+	id: String,
+	max_steps: Option<u32>,
+	blocks: u32,
+	ref_time: u64,
+	max_storage_proof: usize,
+	keys_written: usize,
+	result: &'static str,
+}
+
+impl MigrationsCmd {
+	/// Dry-run the migrations and print the results.
+	pub fn run<ExtraHostFunctions: HostFunctions>(&self) -> Result<()> {
+		if self.max_blocks == 0 {
+			return Err("`--max-blocks` must be positive".into())
+		}
+
+		info!("Loading snapshot from {}", self.snapshot.display());
+		let builder = Builder::<OpaqueBlock>::new().mode(Mode::Offline(OfflineConfig {
+			state_snapshot: SnapshotConfig::new(self.snapshot.clone()),
+			..Default::default()
+		}));
+		let ext = futures::executor::block_on(builder.build())
+			.map_err(|e| format!("Could not load snapshot {}: {e}", self.snapshot.display()))?;
+		let state_version = ext.inner_ext.state_version;
+		let backend = ext.inner_ext.backend;
+
+		let code = self.runtime_blob(&backend)?;
+		let heap_pages = backend
+			.storage(well_known_keys::HEAP_PAGES)
+			.map_err(|e| format!("Could not read the heap pages: {e}"))?
+			.and_then(|p| u64::decode(&mut &p[..]).ok());
+		let alloc_strategy = heap_pages.map_or(DEFAULT_HEAP_ALLOC_STRATEGY, |p| {
+			HeapAllocStrategy::Static { extra_pages: p as _ }
+		});
+		let executor = WasmExecutor::<SubstrateAndExtraHF<ExtraHostFunctions>>::builder()
+			.with_execution_method(execution_method_from_cli(
+				self.wasm_method,
+				self.wasmtime_instantiation_strategy,
+			))
+			.with_onchain_heap_alloc_strategy(alloc_strategy)
+			.with_offchain_heap_alloc_strategy(alloc_strategy)
+			.with_max_runtime_instances(2)
+			.with_runtime_cache_size(2)
+			.build();
+		let wrapped_code = WrappedRuntimeCode(Cow::Borrowed(&code));
+		let runtime_code = RuntimeCode {
+			code_fetcher: &wrapped_code,
+			heap_pages,
+			hash: sp_core::blake2_256(&code).to_vec(),
+		};
+
+		let mut state = DryRunState { backend, state_version, executor: &executor, runtime_code };
+		let (version, _) = state.call::<sp_version::RuntimeVersion>("Core_version", &[])?;
+		let api_id = <dyn MigrationsApi<OpaqueBlock, BlockNumber>>::ID;
+		if !version.has_api_with(&api_id, |_| true) {
+			return Err(sc_cli::Error::Application(Box::new(Error::MissingApi)))
+		}
+
+		let (migrations, _) = state.call::<Vec<MigrationInfo>>("MigrationsApi_migrations", &[])?;
+		let (cursor, _) = state.call::<Option<MigrationCursor<Vec<u8>, BlockNumber>>>(
+			"MigrationsApi_dry_run_start",
+			&[],
+		)?;
+		let Some(cursor) = cursor else {
+			info!("The runtime has no migrations to run");
+			return Ok(())
+		};
+		info!("Dry-running {} migrations starting at {:?}", migrations.len(), cursor);
+
+		let weight_limit = self.step_weight().encode();
+		let mut migration = active_migration(&Some(cursor));
+		let mut reports = Vec::new();
+		let mut status = DryRunStatus::Ongoing;
+		while status == DryRunStatus::Ongoing && reports.len() < self.max_blocks as usize {
+			let (step, storage) = state
+				.call::<DryRunStep<BlockNumber>>("MigrationsApi_dry_run_step", &weight_limit)?;
+			log::debug!("Block {}: {:?}", step.block, step.status);
+
+			let next = active_migration(&step.cursor);
+			let mut active = migration
+				.into_iter()
+				.chain(step.completed.clone())
+				.chain(next)
+				.collect::<Vec<_>>();
+			active.sort();
+			active.dedup();
+			active.retain(|m| !step.skipped.contains(m));
+
+			reports.push(BlockReport {
+				block: step.block,
+				migrations: active,
+				ref_time: step.weight_used.ref_time(),
+				proof_size: step.weight_used.proof_size(),
+				storage,
+				completed: step.completed,
+				skipped: step.skipped,
+				status: format!("{:?}", step.status),
+			});
+			migration = next;
+			status = step.status;
+		}
+
+		self.print_summary(&summarize(&migrations, &reports, &status));
+		if let Some(path) = &self.json_file {
+			let json = serde_json::to_string_pretty(&reports)
+				.map_err(|e| format!("Serializing into JSON: {:?}", e))?;
+			fs::write(path, json)?;
+		}
+
+		let error = match status {
+			DryRunStatus::Completed | DryRunStatus::Idle => {
+				info!("Migrations completed after {} blocks", reports.len());
+				return Ok(())
+			},
+			DryRunStatus::Failed { migration } => Error::Failed(migration),
+			DryRunStatus::Stuck => Error::Stuck,
+			DryRunStatus::Ongoing => Error::Unfinished(self.max_blocks),
+		};
+		Err(sc_cli::Error::Application(Box::new(error)))
+	}
+
+	/// The weight limit per block, if one was passed.
+	fn step_weight(&self) -> Option<Weight> {
+		self.step_ref_time
+			.zip(self.step_proof_size)
+			.map(|(ref_time, proof_size)| Weight::from_parts(ref_time, proof_size))
+	}
+
+	/// Load the runtime blob from `--runtime` or the snapshot.
+	fn runtime_blob(&self, backend: &InMemoryBackend<BlakeTwo256>) -> Result<Vec<u8>> {
+		if let Some(runtime) = &self.runtime {
+			info!("Loading WASM from file");
+			return fs::read(runtime).map_err(|e| {
+				format!(
+					"Could not load runtime file from path: {}, error: {}",
+					runtime.display(),
+					e
+				)
+				.into()
+			})
+		}
+
+		info!("Loading WASM from snapshot");
+		backend
+			.storage(well_known_keys::CODE)
+			.map_err(|e| format!("Could not read the runtime from the snapshot: {e}"))?
+			.ok_or_else(|| "The snapshot contains no runtime; pass one with `--runtime`".into())
+	}
+
+	/// Prints a human-readable summary.
+	fn print_summary(&self, summaries: &[MigrationSummary]) {
+		let mut table = Table::new();
+		table.set_header([
+			"#",
+			"Migration",
+			"Blocks",
+			"Ref Time [ps]",
+			"Max Storage Proof [B]",
+			"Keys Written",
+			"Result",
+		]);
+		for summary in summaries {
+			table.add_row(summary.to_row());
+		}
+		info!("\n{}", table);
+	}
+}
+
+/// The state on which the migrations are dry-run.
+struct DryRunState<'a, E> {
+	backend: InMemoryBackend<BlakeTwo256>,
+	state_version: StateVersion,
+	executor: &'a E,
+	runtime_code: RuntimeCode<'a>,
+}
+
+impl<E: CodeExecutor + Clone> DryRunState<'_, E> {
+	/// Call a runtime API, commit its changes and record its storage accesses.
+	fn call<R: Decode>(&mut self, method: &str, data: &[u8]) -> Result<(R, StorageAccess)> {
+		let mut overlay = OverlayedChanges::default();
+		let mut extensions = Extensions::default();
+		extensions.register(ReadRuntimeVersionExt::new(self.executor.clone()));
+
+		let proving_backend = TrieBackendBuilder::wrap(&self.backend)
+			.with_recorder(Default::default())
+			.build();
+		let encoded = StateMachine::new(
+			&proving_backend,
+			&mut overlay,
+			self.executor,
+			method,
+			data,
+			&mut extensions,
+			&self.runtime_code,
+			CallContext::Onchain,
+		)
+		.execute()
+		.map_err(|e| format!("Could not call runtime API {method}: {e}"))?;
+		let proof_size = proving_backend.extract_proof().map_or(0, |p| p.encoded_size());
+
+		let keys_written = overlay.changes().count() +
+			overlay.children().map(|(changes, _)| changes.count()).sum::<usize>();
+		let changes = overlay
+			.drain_storage_changes(&self.backend, self.state_version)
+			.map_err(|e| format!("Could not commit the changes of {method}: {e}"))?;
+		self.backend
+			.apply_transaction(changes.transaction_storage_root, changes.transaction);
+
+		let result = R::decode(&mut &encoded[..])
+			.map_err(|e| format!("Failed to decode the result of {method}: {e}"))?;
+		Ok((result, StorageAccess { proof_size, keys_written }))
+	}
+}
+
+/// The index of the migration that the cursor points to.
+fn active_migration(cursor: &Option<MigrationCursor<Vec<u8>, BlockNumber>>) -> Option<u32> {
+	cursor.as_ref().and_then(|c| c.as_active()).map(|c| c.index)
+}
+
+/// Accumulate the block reports per migration.
+fn summarize(
+	migrations: &[MigrationInfo],
+	reports: &[BlockReport],
+	status: &DryRunStatus,
+) -> Vec<MigrationSummary> {
+	migrations
+		.iter()
+		.map(|info| {
+			let blocks = reports.iter().filter(|r| r.migrations.contains(&info.index));
+			let result = if reports.iter().any(|r| r.skipped.contains(&info.index)) {
+				"⏭️ Skipped"
+			} else if reports.iter().any(|r| r.completed.contains(&info.index)) {
+				"✅ Completed"
+			} else if matches!(status, DryRunStatus::Failed { migration: Some(m) } if *m == info.index)
+			{
+				"❌ Failed"
+			} else if blocks.clone().next().is_some() {
+				"⏳ Unfinished"
+			} else {
+				"Not started"
+			};
+
+			MigrationSummary {
+				index: info.index,
+				id: format_id(&info.id),
+				max_steps: info.max_steps,
+				blocks: blocks.clone().count() as u32,
+				ref_time: blocks.clone().map(|r| r.ref_time).sum(),
+				max_storage_proof: blocks.clone().map(|r| r.storage.proof_size).max().unwrap_or(0),
+				keys_written: blocks.map(|r| r.storage.keys_written).sum(),
+				result,
+			}
+		})
+		.collect()
+}
+
+/// Format a migration identifier as string if it is printable, otherwise as hex.
+fn format_id(id: &[u8]) -> String {
+	match std::str::from_utf8(id) {
+		Ok(s) if !s.chars().any(char::is_control) => s.into(),
+		_ => array_bytes::bytes2hex("0x", id),
+	}
+}
+
+impl MigrationSummary {
+	/// Format [`Self`] as row that can be printed in a table.
+	fn to_row(&self) -> Row {
+		let blocks = match self.max_steps {
+			Some(max) => format!("{} / {}", self.blocks, max),
+			None => self.blocks.to_string(),
+		};
+		vec![
+			self.index.to_string(),
+			self.id.clone(),
+			blocks,
+			self.ref_time.separate_with_underscores(),
+			self.max_storage_proof.separate_with_underscores(),
+			self.keys_written.separate_with_underscores(),
+			self.result.to_string(),
+		]
+		.into()
+	}
+}
+
+// Boilerplate
+impl CliConfiguration for MigrationsCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn report(migrations: Vec<u32>, completed: Vec<u32>, skipped: Vec<u32>) -> BlockReport {
+		BlockReport {
+			block: 0,
+			storage: StorageAccess { proof_size: 100 * migrations.len(), keys_written: 2 },
+			migrations,
+			ref_time: 10,
+			proof_size: 0,
+			completed,
+			skipped,
+			status: String::new(),
+		}
+	}
+
+	fn info(index: u32) -> MigrationInfo {
+		MigrationInfo {
+			index,
+			id: format!("m{index}").into_bytes(),
+			max_steps: Some(5),
+			historic: false,
+		}
+	}
+
+	#[test]
+	fn summarize_works() {
+		let migrations = vec![info(0), info(1), info(2), info(3)];
+		// Migration 0 is skipped and 1 completes in the same block, 2 fails after three blocks.
+		let reports = vec![
+			report(vec![1, 2], vec![1], vec![0]),
+			report(vec![2], vec![], vec![]),
+			report(vec![2], vec![], vec![]),
+		];
+		let summaries =
+			summarize(&migrations, &reports, &DryRunStatus::Failed { migration: Some(2) });
+
+		let results = summaries.iter().map(|s| s.result).collect::<Vec<_>>();
+		assert_eq!(results, vec!["⏭️ Skipped", "✅ Completed", "❌ Failed", "Not started"]);
+		assert_eq!(summaries[0].blocks, 0);
+		assert_eq!(summaries[1].blocks, 1);
+		assert_eq!(summaries[2].blocks, 3);
+		assert_eq!(summaries[2].ref_time, 30);
+		assert_eq!(summaries[2].max_storage_proof, 200);
+		assert_eq!(summaries[2].keys_written, 6);
+		assert_eq!(summaries[2].id, "m2");
+		assert_eq!(summaries[3].blocks, 0);
+	}
+
+	#[test]
+	fn format_id_works() {
+		assert_eq!(format_id(b"pallet-foo-v2"), "pallet-foo-v2");
+		assert_eq!(format_id(&[0, 1, 255]), "0x0001ff");
+	}
+}
@@ -490,6 +490,7 @@ impl OverheadCmd {
 			state_pruning: None,
 			blocks_pruning: BlocksPruning::KeepAll,
 			source: database_source,
+			event_topics_index: false,
 			metrics_registry: None,
 		})?;
 
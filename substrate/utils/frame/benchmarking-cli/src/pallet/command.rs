@@ -17,7 +17,7 @@
 
 use super::{
 	types::{ComponentRange, ComponentRangeMap},
-	writer, ListOutput, PalletCmd,
+	worst_case, writer, ListOutput, PalletCmd,
 };
 use crate::{
 	pallet::{types::FetchedCode, GenesisBuilderPolicy},
@@ -42,13 +42,14 @@ use sp_core::{
 		testing::{TestOffchainExt, TestTransactionPoolExt},
 		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
 	},
-	traits::{CallContext, CodeExecutor, ReadRuntimeVersionExt, WrappedRuntimeCode},
+	traits::{CallContext, CodeExecutor, ReadRuntimeVersionExt, RuntimeCode, WrappedRuntimeCode},
 	Hasher,
 };
 use sp_externalities::Extensions;
 use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use sp_runtime::traits::Hash;
 use sp_state_machine::StateMachine;
+use sp_storage::Storage;
 use sp_trie::{proof_size_extension::ProofSizeExt, recorder::Recorder};
 use sp_wasm_interface::HostFunctions;
 use std::{
@@ -102,6 +103,24 @@ struct SelectedBenchmark {
 	pov_modes: Vec<(String, String)>,
 }
 
+// Encodes the arguments of the `Benchmark_dispatch_benchmark` runtime API.
+fn dispatch_params(
+	benchmark_api_version: u32,
+	pallet: &str,
+	instance: &str,
+	extrinsic: &str,
+	components: &[(BenchmarkParameter, u32)],
+	verify: bool,
+	repeats: u32,
+) -> Vec<u8> {
+	if benchmark_api_version >= 2 {
+		(pallet.as_bytes(), instance.as_bytes(), extrinsic.as_bytes(), components, verify, repeats)
+			.encode()
+	} else {
+		(pallet.as_bytes(), extrinsic.as_bytes(), components, verify, repeats).encode()
+	}
+}
+
 // This takes multiple benchmark batches and combines all the results where the pallet, instance,
 // and benchmark are the same.
 fn combine_batches(
@@ -258,6 +277,9 @@ impl PalletCmd {
 		let genesis_storage =
 			state_handler.build_storage::<SubstrateAndExtraHF<ExtraHostFunctions>>(None)?;
 
+		// The worst-case search runs on its own copy of the state.
+		let search_storage = self.worst_case_search.then(|| genesis_storage.clone());
+
 		let cache_size = Some(self.database_cache_size as usize);
 		let state_with_tracking = BenchmarkingState::<Hasher>::new(
 			genesis_storage.clone(),
@@ -411,26 +433,15 @@ impl PalletCmd {
 
 			for (s, selected_components) in all_components.iter().enumerate() {
 				let params = |verify: bool, repeats: u32| -> Vec<u8> {
-					if benchmark_api_version >= 2 {
-						(
-							pallet.as_bytes(),
-							instance.as_bytes(),
-							extrinsic.as_bytes(),
-							&selected_components.clone(),
-							verify,
-							repeats,
-						)
-							.encode()
-					} else {
-						(
-							pallet.as_bytes(),
-							extrinsic.as_bytes(),
-							&selected_components.clone(),
-							verify,
-							repeats,
-						)
-							.encode()
-					}
+					dispatch_params(
+						benchmark_api_version,
+						&pallet,
+						&instance,
+						&extrinsic,
+						selected_components,
+						verify,
+						repeats,
+					)
 				};
 
 				// First we run a verification
@@ -573,7 +584,136 @@ impl PalletCmd {
 		// Combine all of the benchmark results, so that benchmarks of the same pallet/function
 		// are together.
 		let batches = combine_batches(batches, batches_db);
-		self.output(&batches, &storage_info, &component_ranges, pov_modes)
+		self.output(&batches, &storage_info, &component_ranges, pov_modes.clone())?;
+
+		if let Some(storage) = search_storage {
+			self.search_worst_case::<Hasher, _>(
+				storage,
+				&benchmarks_to_run,
+				&batches,
+				&storage_info,
+				&component_ranges,
+				pov_modes,
+				&executor,
+				&runtime_code,
+				benchmark_api_version,
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Runs the benchmarks at the corners of their component ranges on a state with a deeper trie
+	/// and checks the measured weights against the weight formulas.
+	fn search_worst_case<H, E>(
+		&self,
+		mut storage: Storage,
+		benchmarks: &[SelectedBenchmark],
+		batches: &[BenchmarkBatchSplitResults],
+		storage_info: &[StorageInfo],
+		component_ranges: &ComponentRangeMap,
+		pov_modes: PovModesMap,
+		executor: &E,
+		runtime_code: &RuntimeCode,
+		benchmark_api_version: u32,
+	) -> Result<()>
+	where
+		H: Hash,
+		<H as Hash>::Output: DecodeWithMemTracking,
+		E: CodeExecutor + Clone,
+	{
+		worst_case::fill_trie(&mut storage, storage_info, self.worst_case_trie_fill);
+		let cache_size = Some(self.database_cache_size as usize);
+		let state_with_tracking =
+			BenchmarkingState::<H>::new(storage.clone(), cache_size, true, true)?;
+		let state_without_tracking =
+			BenchmarkingState::<H>::new(storage, cache_size, !self.disable_proof_recording, false)?;
+
+		let mut samples = HashMap::<worst_case::BenchmarkKey, Vec<worst_case::Sample>>::new();
+		for SelectedBenchmark { pallet, instance, extrinsic, components, .. } in benchmarks {
+			// Use the same ranges as the regular run, which respect `--low` and `--high`.
+			let ranges = component_ranges.get(&(pallet.clone(), extrinsic.clone()));
+			let components = components
+				.iter()
+				.enumerate()
+				.map(|(idx, (name, low, high))| match ranges.and_then(|r| r.get(idx)) {
+					Some(range) => (*name, range.min, range.max),
+					None => (*name, *low, *high),
+				})
+				.collect::<Vec<_>>();
+			let combinations =
+				worst_case::component_combinations(&components, self.worst_case_combinations);
+			log::info!(
+				target: LOG_TARGET,
+				"Searching the worst case of {pallet}::{extrinsic} in {} combinations",
+				combinations.len(),
+			);
+
+			for selected_components in combinations {
+				let dispatch =
+					|state: &BenchmarkingState<H>, repeats: u32| -> Result<Vec<BenchmarkResult>> {
+						let params = dispatch_params(
+							benchmark_api_version,
+							pallet,
+							instance,
+							extrinsic,
+							&selected_components,
+							false,
+							repeats,
+						);
+						let batches = Self::exec_state_machine::<
+							std::result::Result<Vec<BenchmarkBatch>, String>,
+							_,
+							_,
+						>(
+							StateMachine::new(
+								state,
+								&mut Default::default(),
+								executor,
+								"Benchmark_dispatch_benchmark",
+								&params,
+								&mut Self::build_extensions(executor.clone(), state.recorder()),
+								runtime_code,
+								CallContext::Offchain,
+							),
+							"dispatch a benchmark",
+						)??;
+						Ok(batches.into_iter().flat_map(|batch| batch.results).collect())
+					};
+
+				let results = dispatch(&state_with_tracking, 1)
+					.and_then(|db| Ok((db, dispatch(&state_without_tracking, self.repeat)?)));
+				match results {
+					Ok((db_results, time_results)) => {
+						if let Some(sample) = worst_case::Sample::new(
+							selected_components.clone(),
+							&time_results,
+							&db_results,
+						) {
+							samples
+								.entry((pallet.clone(), instance.clone(), extrinsic.clone()))
+								.or_default()
+								.push(sample);
+						}
+					},
+					Err(e) => log::warn!(
+						target: LOG_TARGET,
+						"Worst-case search of {pallet}::{extrinsic} failed for {selected_components:?}: {e}",
+					),
+				}
+			}
+		}
+
+		let formulas =
+			writer::weight_formulas(batches, storage_info, component_ranges, pov_modes, self)?;
+		let reports = worst_case::check(&samples, &formulas, self.worst_case_tolerance);
+		let exceeding = worst_case::print_reports(&reports, self.worst_case_tolerance);
+		if exceeding != 0 {
+			return Err(format!(
+				"The weight formulas of {exceeding} benchmarks underestimate the worst case"
+			)
+			.into())
+		}
+		Ok(())
 	}
 
 	fn select_benchmarks_to_run(&self, list: Vec<BenchmarkList>) -> Result<Vec<SelectedBenchmark>> {
@@ -17,6 +17,7 @@
 
 mod command;
 mod types;
+mod worst_case;
 mod writer;
 
 use crate::shared::HostInfoParams;
@@ -275,6 +276,31 @@ pub struct PalletCmd {
 	/// solo-chains) can disable proof recording to get more accurate results.
 	#[arg(long)]
 	disable_proof_recording: bool,
+
+	/// Search for the worst case of each benchmark after running it.
+	///
+	/// Runs every benchmark again at the corners and the center of its component ranges, on a
+	/// state with a deeper storage trie. Reports the maximal measured weight and fails if any
+	/// sample exceeds the weight formula by more than `--worst-case-tolerance`.
+	#[arg(long, conflicts_with = "json_input")]
+	pub worst_case_search: bool,
+
+	/// The maximal number of component combinations that the worst-case search tries per
+	/// benchmark.
+	#[arg(long, default_value_t = 64, requires = "worst_case_search")]
+	pub worst_case_combinations: usize,
+
+	/// The number of dummy entries that the worst-case search inserts below the storage prefix of
+	/// each pallet.
+	///
+	/// This deepens the storage trie and thereby checks the PoV estimation of the `#[pov_mode]`
+	/// attributes against a larger state.
+	#[arg(long, default_value_t = 1000, requires = "worst_case_search")]
+	pub worst_case_trie_fill: u32,
+
+	/// How much a sample of the worst-case search may exceed the weight formula.
+	#[arg(long, default_value_t = 10.0, value_name = "PERCENT", requires = "worst_case_search")]
+	pub worst_case_tolerance: f64,
 }
 
 /// How the genesis state for benchmarking should be built.
@@ -0,0 +1,408 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Searches the worst case of benchmarks and checks it against their weight formulas.
+
+use std::collections::{BTreeMap, HashMap};
+
+use comfy_table::Table;
+use frame_benchmarking::{BenchmarkParameter, BenchmarkResult};
+use frame_support::traits::StorageInfo;
+use rand::Rng;
+use sp_storage::Storage;
+use thousands::Separable;
+
+use crate::{
+	compare::formula::{LinearTerm, WeightFormula},
+	shared::new_rng,
+};
+
+/// Seed of the dummy trie entries, so that all runs use the same state.
+const TRIE_FILL_SEED: u64 = 0;
+
+/// Up to this number of components all corners of the component space are tried.
+const MAX_ENUMERATED_COMPONENTS: usize = 16;
+
+/// Identifies a benchmark by its pallet, instance and name.
+pub(crate) type BenchmarkKey = (String, String, String);
+
+/// The measured weight of a benchmark for one combination of its components.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Sample {
+	pub components: Vec<(BenchmarkParameter, u32)>,
+	/// The median ref time in picoseconds.
+	pub ref_time: u128,
+	/// The recorded proof size in bytes.
+	pub proof_size: u128,
+}
+
+/// The worst case that was found for a benchmark.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Report {
+	pub key: BenchmarkKey,
+	/// The sample with the highest ref time.
+	pub max_ref_time: Sample,
+	/// The sample with the highest proof size.
+	pub max_proof_size: Sample,
+	/// The samples that exceed the weight formula, with the predicted ref time and proof size.
+	pub exceeding: Vec<(Sample, u128, u128)>,
+}
+
+impl Sample {
+	/// Combines the results of a timing and a proof recording run with the same components.
+	///
+	/// Returns `None` if either of them has no results.
+	pub(crate) fn new(
+		components: Vec<(BenchmarkParameter, u32)>,
+		time_results: &[BenchmarkResult],
+		db_results: &[BenchmarkResult],
+	) -> Option<Self> {
+		let mut times = time_results.iter().map(|r| r.extrinsic_time).collect::<Vec<_>>();
+		times.sort();
+		// The benchmarks measure nanoseconds while weights are in picoseconds.
+		let ref_time = times.get(times.len() / 2)?.saturating_mul(1000);
+		let proof_size = db_results.iter().map(|r| r.proof_size).max()?.into();
+
+		Some(Self { components, ref_time, proof_size })
+	}
+
+	fn component_values(&self) -> BTreeMap<String, u32> {
+		self.components.iter().map(|(name, value)| (name.to_string(), *value)).collect()
+	}
+
+	fn format_components(&self) -> String {
+		self.components
+			.iter()
+			.map(|(name, value)| format!("{} = {}", name, value))
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+}
+
+/// Returns the component combinations that the worst-case search tries.
+///
+/// These are the corners of the component space, starting with those that have the most
+/// components at their maximum, followed by its center. At most `limit` combinations are returned.
+pub(crate) fn component_combinations(
+	components: &[(BenchmarkParameter, u32, u32)],
+	limit: usize,
+) -> Vec<Vec<(BenchmarkParameter, u32)>> {
+	let corner = |is_high: &dyn Fn(usize) -> bool| {
+		components
+			.iter()
+			.enumerate()
+			.map(|(idx, (name, low, high))| (*name, if is_high(idx) { *high } else { *low }))
+			.collect::<Vec<_>>()
+	};
+
+	let mut candidates = Vec::new();
+	if components.len() <= MAX_ENUMERATED_COMPONENTS {
+		let mut masks = (0..1u32 << components.len()).collect::<Vec<_>>();
+		masks.sort_by_key(|mask| core::cmp::Reverse(mask.count_ones()));
+		candidates.extend(masks.into_iter().map(|mask| corner(&|idx| mask & (1 << idx) != 0)));
+	} else {
+		candidates.push(corner(&|_| true));
+		candidates.push(corner(&|_| false));
+	}
+	candidates.truncate(limit.saturating_sub(1));
+	candidates.push(
+		components
+			.iter()
+			.map(|(name, low, high)| (*name, low + (high.saturating_sub(*low)) / 2))
+			.collect(),
+	);
+
+	let mut combinations = Vec::new();
+	for candidate in candidates.into_iter().take(limit) {
+		if !combinations.contains(&candidate) {
+			combinations.push(candidate);
+		}
+	}
+	combinations
+}
+
+/// Inserts `entries` dummy values below the storage prefix of each pallet in `storage_info`.
+///
+/// The dummy keys deepen the trie that has to be traversed to reach the storage items of the
+/// pallets. Keys below the prefix of a storage item or already in `storage` are skipped, so that
+/// the storage items themselves are left as they are. Pallets with a storage item at the prefix
+/// of the pallet are not filled, since every key below it would be part of that item.
+pub(crate) fn fill_trie(storage: &mut Storage, storage_info: &[StorageInfo], entries: u32) {
+	let mut items = BTreeMap::<&[u8], Vec<&[u8]>>::new();
+	for info in storage_info {
+		if let Some(pallet) = info.prefix.get(..16) {
+			items.entry(pallet).or_default().push(&info.prefix);
+		}
+	}
+	let (mut rng, _) = new_rng(Some(TRIE_FILL_SEED));
+
+	for (pallet, item_prefixes) in items {
+		if item_prefixes.iter().any(|prefix| prefix.len() <= pallet.len()) {
+			continue
+		}
+		let mut inserted = 0;
+		while inserted < entries {
+			let mut key = pallet.to_vec();
+			key.extend(rng.gen::<[u8; 32]>());
+			if storage.top.contains_key(&key) ||
+				item_prefixes.iter().any(|prefix| key.starts_with(prefix))
+			{
+				continue
+			}
+			storage.top.insert(key, rng.gen::<[u8; 32]>().to_vec());
+			inserted += 1;
+		}
+	}
+}
+
+/// Finds the worst case of each benchmark and the samples that exceed its weight formula by more
+/// than `tolerance` percent.
+///
+/// The proof size is only checked for benchmarks with an estimated proof size.
+pub(crate) fn check(
+	samples: &HashMap<BenchmarkKey, Vec<Sample>>,
+	formulas: &HashMap<BenchmarkKey, WeightFormula>,
+	tolerance: f64,
+) -> Vec<Report> {
+	let exceeds = |observed: u128, predicted: u128| {
+		observed as f64 > predicted as f64 * (1.0 + tolerance / 100.0)
+	};
+
+	let mut reports = samples
+		.iter()
+		.filter_map(|(key, samples)| {
+			let formula = formulas.get(key)?;
+			let max_ref_time = samples.iter().max_by_key(|s| s.ref_time)?.clone();
+			let max_proof_size = samples.iter().max_by_key(|s| s.proof_size)?.clone();
+			let estimated_proof_size =
+				formula.proof_size.as_ref().filter(|term| **term != LinearTerm::default());
+
+			let exceeding = samples
+				.iter()
+				.filter_map(|sample| {
+					let values = sample.component_values();
+					let ref_time = formula.ref_time.eval(&values);
+					let proof_size = estimated_proof_size.map_or(0, |term| term.eval(&values));
+					(exceeds(sample.ref_time, ref_time) ||
+						(estimated_proof_size.is_some() &&
+							exceeds(sample.proof_size, proof_size)))
+					.then(|| (sample.clone(), ref_time, proof_size))
+				})
+				.collect();
+
+			Some(Report { key: key.clone(), max_ref_time, max_proof_size, exceeding })
+		})
+		.collect::<Vec<_>>();
+	reports.sort_by(|a, b| a.key.cmp(&b.key));
+	reports
+}
+
+/// Prints the reports and returns the number of benchmarks with exceeding samples.
+pub(crate) fn print_reports(reports: &[Report], tolerance: f64) -> usize {
+	let mut table = Table::new();
+	table.set_header(["Pallet", "Extrinsic", "Max Ref Time [ps]", "Max Proof Size [B]", "Result"]);
+	for Report { key: (pallet, _, extrinsic), max_ref_time, max_proof_size, exceeding } in reports {
+		table.add_row([
+			pallet.clone(),
+			extrinsic.clone(),
+			format!(
+				"{} ({})",
+				max_ref_time.ref_time.separate_with_underscores(),
+				max_ref_time.format_components()
+			),
+			format!(
+				"{} ({})",
+				max_proof_size.proof_size.separate_with_underscores(),
+				max_proof_size.format_components()
+			),
+			if exceeding.is_empty() {
+				"✅ Pass".into()
+			} else {
+				format!("❌ {} samples exceed the weight", exceeding.len())
+			},
+		]);
+	}
+	println!("Worst Case Search\n========\n{}", table);
+
+	for Report { key: (pallet, _, extrinsic), exceeding, .. } in reports {
+		for (sample, ref_time, proof_size) in exceeding {
+			println!(
+				"{}::{} with {}: measured {} ps and {} B, but the weight formula gives {} ps and {} B \
+				({:.1?}% tolerance)",
+				pallet,
+				extrinsic,
+				sample.format_components(),
+				sample.ref_time.separate_with_underscores(),
+				sample.proof_size.separate_with_underscores(),
+				ref_time.separate_with_underscores(),
+				proof_size.separate_with_underscores(),
+				tolerance,
+			);
+		}
+	}
+
+	reports.iter().filter(|r| !r.exceeding.is_empty()).count()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use BenchmarkParameter::{a, b};
+
+	fn sample(components: Vec<(BenchmarkParameter, u32)>, ref_time: u128, proof: u128) -> Sample {
+		Sample { components, ref_time, proof_size: proof }
+	}
+
+	#[test]
+	fn combinations_start_with_the_highest_corner() {
+		let combinations = component_combinations(&[(a, 0, 10), (b, 1, 3)], 64);
+
+		assert_eq!(
+			combinations,
+			vec![
+				vec![(a, 10), (b, 3)],
+				vec![(a, 10), (b, 1)],
+				vec![(a, 0), (b, 3)],
+				vec![(a, 0), (b, 1)],
+				vec![(a, 5), (b, 2)],
+			]
+		);
+	}
+
+	#[test]
+	fn combinations_are_limited_and_unique() {
+		let combinations = component_combinations(&[(a, 0, 10), (b, 1, 3)], 2);
+		assert_eq!(combinations, vec![vec![(a, 10), (b, 3)], vec![(a, 5), (b, 2)]]);
+
+		let combinations = component_combinations(&[(a, 4, 4)], 64);
+		assert_eq!(combinations, vec![vec![(a, 4)]]);
+
+		assert_eq!(component_combinations(&[], 64), vec![vec![]]);
+	}
+
+	#[test]
+	fn samples_use_the_median_time_and_max_proof() {
+		let result = |extrinsic_time, proof_size| BenchmarkResult {
+			extrinsic_time,
+			proof_size,
+			..Default::default()
+		};
+		let sample = Sample::new(
+			vec![(a, 1)],
+			&[result(30, 0), result(10, 0), result(20, 0)],
+			&[result(0, 5), result(0, 7)],
+		);
+
+		assert_eq!(sample, Some(self::sample(vec![(a, 1)], 20_000, 7)));
+		assert_eq!(Sample::new(vec![], &[], &[result(0, 5)]), None);
+	}
+
+	#[test]
+	fn samples_exceeding_the_formula_are_reported() {
+		let key: BenchmarkKey = ("pallet".into(), "Pallet".into(), "call".into());
+		let formula = WeightFormula {
+			ref_time: LinearTerm { base: 1_000, slopes: [("a".into(), 100)].into() },
+			proof_size: Some(LinearTerm { base: 100, slopes: Default::default() }),
+			..Default::default()
+		};
+		let samples = vec![
+			sample(vec![(a, 10)], 2_000, 100),
+			// Within the tolerance of 10%.
+			sample(vec![(a, 0)], 1_100, 110),
+			sample(vec![(a, 0)], 1_200, 50),
+			sample(vec![(a, 5)], 1_000, 200),
+		];
+
+		let reports =
+			check(&[(key.clone(), samples.clone())].into(), &[(key.clone(), formula)].into(), 10.0);
+
+		assert_eq!(reports.len(), 1);
+		assert_eq!(reports[0].max_ref_time, samples[0]);
+		assert_eq!(reports[0].max_proof_size, samples[3]);
+		assert_eq!(
+			reports[0].exceeding,
+			vec![(samples[2].clone(), 1_000, 100), (samples[3].clone(), 1_500, 100)]
+		);
+	}
+
+	#[test]
+	fn proof_size_is_not_checked_without_estimation() {
+		let key: BenchmarkKey = ("pallet".into(), "Pallet".into(), "call".into());
+		let formula = WeightFormula {
+			ref_time: LinearTerm { base: 1_000, slopes: Default::default() },
+			..Default::default()
+		};
+
+		let reports = check(
+			&[(key.clone(), vec![sample(vec![], 1_000, 1_000)])].into(),
+			&[(key, formula)].into(),
+			0.0,
+		);
+
+		assert!(reports[0].exceeding.is_empty());
+	}
+
+	#[test]
+	fn trie_is_filled_below_pallet_prefixes() {
+		let info = |prefix: Vec<u8>| StorageInfo {
+			pallet_name: b"Pallet".to_vec(),
+			storage_name: b"Item".to_vec(),
+			prefix,
+			max_values: None,
+			max_size: None,
+		};
+		let pallet = [1u8; 16];
+		let item = |n: u8| [&pallet[..], &[n; 16][..]].concat();
+		let mut storage = Storage::default();
+
+		fill_trie(&mut storage, &[info(item(2)), info(item(3))], 10);
+
+		assert_eq!(storage.top.len(), 10);
+		assert!(storage.top.keys().all(|key| key.len() == 48 && key.starts_with(&pallet)));
+
+		let mut other = Storage::default();
+		fill_trie(&mut other, &[info(item(2))], 10);
+		assert_eq!(storage.top, other.top);
+	}
+
+	#[test]
+	fn trie_is_not_filled_below_storage_items() {
+		let info = |prefix: Vec<u8>| StorageInfo {
+			pallet_name: b"Pallet".to_vec(),
+			storage_name: b"Item".to_vec(),
+			prefix,
+			max_values: None,
+			max_size: None,
+		};
+		let pallet = [1u8; 16];
+		// The prefix of this item covers a 256th of the keys below the pallet.
+		let item = [&pallet[..], &[7u8][..]].concat();
+		let mut storage = Storage::default();
+		storage.top.insert(item.clone(), vec![1]);
+
+		fill_trie(&mut storage, &[info(item.clone())], 1000);
+
+		assert_eq!(storage.top.len(), 1001);
+		assert_eq!(storage.top.get(&item), Some(&vec![1]));
+		assert_eq!(storage.top.keys().filter(|key| key.starts_with(&item)).count(), 1);
+
+		// Every key below the prefix of the pallet would be part of the item.
+		let mut storage = Storage::default();
+		fill_trie(&mut storage, &[info(pallet.to_vec())], 10);
+		assert!(storage.top.is_empty());
+	}
+}
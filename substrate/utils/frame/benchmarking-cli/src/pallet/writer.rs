@@ -28,6 +28,7 @@ use itertools::Itertools;
 use serde::Serialize;
 
 use crate::{
+	compare::formula::{LinearTerm, WeightFormula},
 	pallet::{
 		command::{PovEstimationMode, PovModesMap},
 		types::{ComponentRange, ComponentRangeMap},
@@ -513,6 +514,64 @@ pub(crate) fn write_results(
 	Ok(())
 }
 
+/// Analyze the batches like [`write_results`] and return the weight formula of each benchmark.
+///
+/// The formulas are keyed by pallet, instance and benchmark name.
+pub(crate) fn weight_formulas(
+	batches: &[BenchmarkBatchSplitResults],
+	storage_info: &[StorageInfo],
+	component_ranges: &ComponentRangeMap,
+	pov_modes: PovModesMap,
+	cmd: &PalletCmd,
+) -> Result<HashMap<(String, String, String), WeightFormula>, sc_cli::Error> {
+	let analysis_choice: AnalysisChoice =
+		cmd.output_analysis.clone().try_into().map_err(io_error)?;
+	let pov_analysis_choice: AnalysisChoice =
+		cmd.output_pov_analysis.clone().try_into().map_err(io_error)?;
+
+	let all_results = map_results(
+		batches,
+		storage_info,
+		component_ranges,
+		pov_modes,
+		cmd.default_pov_mode,
+		&analysis_choice,
+		&pov_analysis_choice,
+		cmd.worst_case_map_values,
+		cmd.additional_trie_layers,
+	)?;
+
+	let mut formulas = HashMap::new();
+	for ((pallet, instance), results) in all_results {
+		for benchmark in results {
+			let term = |base: u128, slopes: &[ComponentSlope]| LinearTerm {
+				base,
+				slopes: slopes.iter().map(|s| (s.name.clone(), s.slope)).collect(),
+			};
+			let formula = WeightFormula {
+				ref_time: term(benchmark.base_weight, &benchmark.component_weight),
+				proof_size: Some(term(
+					benchmark.base_calculated_proof_size,
+					&benchmark.component_calculated_proof_size,
+				)),
+				measured_proof_size: term(
+					benchmark.base_recorded_proof_size,
+					&benchmark.component_recorded_proof_size,
+				),
+				reads: term(benchmark.base_reads, &benchmark.component_reads),
+				writes: term(benchmark.base_writes, &benchmark.component_writes),
+				ranges: benchmark
+					.component_ranges
+					.iter()
+					.map(|r| (r.name.clone(), (r.min, r.max)))
+					.collect(),
+			};
+			formulas.insert((pallet.clone(), instance.clone(), benchmark.name), formula);
+		}
+	}
+	Ok(formulas)
+}
+
 /// This function looks at the keys touched during the benchmark, and the storage info we collected
 /// from the pallets, and creates comments with information about the storage keys touched during
 /// each benchmark.
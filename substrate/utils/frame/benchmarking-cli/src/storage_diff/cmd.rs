@@ -0,0 +1,321 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`StorageDiffCmd`] as entry point for the CLI to compare storage layouts.
+
+use std::{borrow::Cow, fs, path::PathBuf};
+
+use clap::Parser;
+use codec::Decode;
+use comfy_table::{Row, Table};
+use frame_metadata::{v16::RuntimeMetadataV16, RuntimeMetadata, RuntimeMetadataPrefixed};
+use log::{info, warn};
+use serde::Serialize;
+
+use sc_cli::{
+	execution_method_from_cli, CliConfiguration, Result, SharedParams, WasmExecutionMethod,
+	WasmtimeInstantiationStrategy, DEFAULT_WASMTIME_INSTANTIATION_STRATEGY,
+	DEFAULT_WASM_EXECUTION_METHOD,
+};
+use sc_executor::WasmExecutor;
+use sc_runtime_utilities::RuntimeCaller;
+use sp_core::OpaqueMetadata;
+use sp_metadata_ir::diff::{storage_diff, PalletStorageDiff, StorageDiff};
+use sp_wasm_interface::HostFunctions;
+
+type SubstrateAndExtraHF<T> = (sp_io::SubstrateHostFunctions, T);
+
+/// The metadata version that contains the storage versions of the pallets.
+const METADATA_VERSION: u32 = 16;
+
+/// Compare the storage layout of two runtimes.
+///
+/// Reports every change to the storage entries of each pallet between the metadata of the old
+/// and the new runtime. Returns an error if a pallet changed its storage in an incompatible way
+/// without bumping its `#[pallet::storage_version]`, which usually means a migration is missing.
+#[derive(Debug, Parser)]
+pub struct StorageDiffCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	/// The old runtime blob, usually the one currently on-chain.
+	#[arg(long, value_name = "PATH")]
+	pub old: PathBuf,
+
+	/// The new runtime blob.
+	#[arg(long, value_name = "PATH")]
+	pub new: PathBuf,
+
+	/// Also list the compatible changes.
+	#[arg(long)]
+	pub show_compatible: bool,
+
+	/// Write the changes as JSON into this file.
+	#[arg(long, value_name = "PATH")]
+	pub json_file: Option<PathBuf>,
+
+	/// Method for executing Wasm runtime code.
+	#[arg(
+		long = "wasm-execution",
+		value_name = "METHOD",
+		value_enum,
+		ignore_case = true,
+		default_value_t = DEFAULT_WASM_EXECUTION_METHOD,
+	)]
+	pub wasm_method: WasmExecutionMethod,
+
+	/// The WASM instantiation method to use.
+	///
+	/// Only has an effect when `wasm-execution` is set to `compiled`.
+	#[arg(
+		long = "wasm-instantiation-strategy",
+		value_name = "STRATEGY",
+		default_value_t = DEFAULT_WASMTIME_INSTANTIATION_STRATEGY,
+		value_enum,
+	)]
+	pub wasmtime_instantiation_strategy: WasmtimeInstantiationStrategy,
+}
+
+/// Errors that can be returned by the this command.
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum Error {
+	#[error("{0} pallet(s) changed their storage without bumping the storage version: {1}")]
+	MissingMigrations(usize, String),
+}
+
+/// A change to the storage of a pallet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct ChangeReport {
+	pallet: String,
+	entry: Option<String>,
+	change: String,
+	incompatible: bool,
+	old_storage_version: Option<u16>,
+	new_storage_version: Option<u16>,
+	/// Whether the change is incompatible and not covered by a storage version bump.
+	missing_migration: bool,
+	/// Whether the change is incompatible and the old storage version is unknown.
+	unknown_storage_version: bool,
+}
+
+impl StorageDiffCmd {
+	/// Compare the storage layouts and print the results.
+	pub fn run<ExtraHostFunctions: HostFunctions>(&self) -> Result<()> {
+		let executor = WasmExecutor::<SubstrateAndExtraHF<ExtraHostFunctions>>::builder()
+			.with_execution_method(execution_method_from_cli(
+				self.wasm_method,
+				self.wasmtime_instantiation_strategy,
+			))
+			.with_max_runtime_instances(2)
+			.with_runtime_cache_size(2)
+			.build();
+		let old = load_metadata(&executor, &self.old)?;
+		let new = load_metadata(&executor, &self.new)?;
+
+		let diff = storage_diff(&old, &new);
+		let reports = reports(&diff);
+		self.print_summary(&reports);
+		if let Some(path) = &self.json_file {
+			let json = serde_json::to_string_pretty(&reports)
+				.map_err(|e| format!("Serializing into JSON: {:?}", e))?;
+			fs::write(path, json)?;
+		}
+
+		let unknown =
+			diff.unknown_storage_versions().map(|p| p.pallet.as_str()).collect::<Vec<_>>();
+		if !unknown.is_empty() {
+			warn!(
+				"The old runtime does not declare the storage version of {} pallet(s) with \
+				incompatible changes, check their migrations manually: {}",
+				unknown.len(),
+				unknown.join(", "),
+			);
+		}
+
+		let missing = diff.missing_migrations().map(|p| p.pallet.as_str()).collect::<Vec<_>>();
+		if !missing.is_empty() {
+			let error = Error::MissingMigrations(missing.len(), missing.join(", "));
+			return Err(sc_cli::Error::Application(Box::new(error)))
+		}
+		Ok(())
+	}
+
+	/// Prints a human-readable summary.
+	fn print_summary(&self, reports: &[ChangeReport]) {
+		let mut table = Table::new();
+		table.set_header(["Pallet", "Entry", "Change", "Storage Version", "Result"]);
+		for report in reports.iter().filter(|r| r.incompatible || self.show_compatible) {
+			table.add_row(report.to_row());
+		}
+
+		let compatible = reports.iter().filter(|r| !r.incompatible).count();
+		info!(
+			"\n{}\nFound {} storage changes, {} are incompatible and {} are not covered by a storage \
+			version bump.",
+			table,
+			reports.len(),
+			reports.len() - compatible,
+			reports.iter().filter(|r| r.missing_migration).count(),
+		);
+		if compatible != 0 && !self.show_compatible {
+			info!("Hiding {} compatible changes; use --show-compatible to list them.", compatible);
+		}
+	}
+}
+
+/// Load the V16 metadata of the runtime blob at `path`.
+fn load_metadata<HF: HostFunctions>(
+	executor: &WasmExecutor<HF>,
+	path: &PathBuf,
+) -> Result<RuntimeMetadataV16> {
+	info!("Loading metadata of {}", path.display());
+	let code = fs::read(path).map_err(|e| {
+		format!("Could not load runtime file from path: {}, error: {}", path.display(), e)
+	})?;
+	let encoded = RuntimeCaller::new(executor, Cow::Owned(code))
+		.call("Metadata_metadata_at_version", METADATA_VERSION)
+		.map_err(|e| format!("Could not fetch the metadata of {}: {e}", path.display()))?;
+	let metadata = Option::<OpaqueMetadata>::decode(&mut &encoded[..])
+		.map_err(|e| format!("Could not decode the metadata of {}: {e}", path.display()))?
+		.ok_or_else(|| {
+			format!("The runtime {} does not support metadata V{METADATA_VERSION}", path.display())
+		})?;
+
+	match RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+		.map_err(|e| format!("Could not decode the metadata of {}: {e}", path.display()))?
+		.1
+	{
+		RuntimeMetadata::V16(metadata) => Ok(metadata),
+		_ => Err(format!("The runtime {} returned unexpected metadata", path.display()).into()),
+	}
+}
+
+/// Flatten the diff into one report per change.
+fn reports(diff: &StorageDiff) -> Vec<ChangeReport> {
+	diff.pallets
+		.iter()
+		.flat_map(|pallet: &PalletStorageDiff| {
+			pallet.changes.iter().map(move |change| ChangeReport {
+				pallet: pallet.pallet.clone(),
+				entry: change.entry.clone(),
+				change: change.kind.to_string(),
+				incompatible: change.kind.is_incompatible(),
+				old_storage_version: pallet.old_storage_version,
+				new_storage_version: pallet.new_storage_version,
+				missing_migration: change.kind.is_incompatible() && pallet.needs_migration(),
+				unknown_storage_version: change.kind.is_incompatible() &&
+					pallet.storage_version_unknown(),
+			})
+		})
+		.collect()
+}
+
+impl ChangeReport {
+	/// Format [`Self`] as row that can be printed in a table.
+	fn to_row(&self) -> Row {
+		let version = |v: Option<u16>| v.map_or_else(|| "-".into(), |v| v.to_string());
+		let result = if self.missing_migration {
+			"❌ Missing migration"
+		} else if self.unknown_storage_version {
+			"⚠️ Unknown old version"
+		} else if self.incompatible {
+			"✅ Version bumped"
+		} else {
+			"✅ Compatible"
+		};
+		vec![
+			self.pallet.clone(),
+			self.entry.clone().unwrap_or_default(),
+			self.change.clone(),
+			format!(
+				"{} → {}",
+				version(self.old_storage_version),
+				version(self.new_storage_version)
+			),
+			result.into(),
+		]
+		.into()
+	}
+}
+
+// Boilerplate
+impl CliConfiguration for StorageDiffCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_metadata_ir::diff::{StorageChange, StorageChangeKind};
+
+	fn change(entry: &str, kind: StorageChangeKind) -> StorageChange {
+		StorageChange { entry: Some(entry.into()), kind }
+	}
+
+	#[test]
+	fn reports_works() {
+		let diff = StorageDiff {
+			pallets: vec![
+				PalletStorageDiff {
+					pallet: "Balances".into(),
+					old_storage_version: Some(1),
+					new_storage_version: Some(1),
+					changes: vec![
+						change("Account", StorageChangeKind::ValueTypeChanged),
+						change("Holds", StorageChangeKind::EntryAdded),
+					],
+				},
+				PalletStorageDiff {
+					pallet: "Staking".into(),
+					old_storage_version: Some(14),
+					new_storage_version: Some(15),
+					changes: vec![change("Ledger", StorageChangeKind::HashersChanged)],
+				},
+				PalletStorageDiff {
+					pallet: "Vesting".into(),
+					old_storage_version: None,
+					new_storage_version: Some(1),
+					changes: vec![change("Vesting", StorageChangeKind::ValueTypeChanged)],
+				},
+			],
+		};
+
+		let reports = reports(&diff);
+		assert_eq!(
+			reports
+				.iter()
+				.map(|r| (
+					r.pallet.as_str(),
+					r.incompatible,
+					r.missing_migration,
+					r.unknown_storage_version
+				))
+				.collect::<Vec<_>>(),
+			vec![
+				("Balances", true, true, false),
+				("Balances", false, false, false),
+				("Staking", true, false, false),
+				("Vesting", true, false, true),
+			],
+		);
+		assert_eq!(reports[0].change, "value type changed");
+	}
+}
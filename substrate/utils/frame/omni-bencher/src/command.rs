@@ -138,9 +138,12 @@ impl V1SubCommand {
 				},
 				BenchmarkCmd::Overhead(overhead_cmd) =>
 					overhead_cmd.run_with_default_builder_and_spec::<OpaqueBlock, HostFunctions>(None),
+				BenchmarkCmd::Compare(compare_cmd) => compare_cmd.run(),
+				BenchmarkCmd::Migrations(migrations_cmd) => migrations_cmd.run::<HostFunctions>(),
+				BenchmarkCmd::StorageDiff(diff_cmd) => diff_cmd.run::<HostFunctions>(),
 				_ =>
 					return Err(
-						"Only the `v1 benchmark pallet` and `v1 benchmark overhead` command is currently supported".into()
+						"Only the `v1 benchmark pallet`, `v1 benchmark overhead`, `v1 benchmark compare`, `v1 benchmark migrations` and `v1 benchmark storage-diff` commands are currently supported".into()
 					),
 			},
 		}
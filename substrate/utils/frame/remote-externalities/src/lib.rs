@@ -19,14 +19,19 @@
 //!
 //! An equivalent of `sp_io::TestExternalities` that can load its state from a remote substrate
 //! based chain, or a local state snapshot file.
+//!
+//! A state snapshot records the block it was taken at and the keys that were scraped for every
+//! hashed prefix. It can be loaded partially by prefix, see [`OfflineConfig`], and refreshed to a
+//! newer block by only downloading the storage diff, see [`Mode::Refresh`].
 
 mod logging;
+mod snapshot;
 
-use codec::{Compact, Decode, Encode};
 use indicatif::{ProgressBar, ProgressStyle};
 use jsonrpsee::{core::params::ArrayParams, http_client::HttpClient};
 use log::*;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use snapshot::{RawKeyValues, Snapshot, TopChanges};
 use sp_core::{
 	hexdisplay::HexDisplay,
 	storage::{
@@ -41,13 +46,16 @@ use sp_runtime::{
 use sp_state_machine::TestExternalities;
 use std::{
 	cmp::{max, min},
-	fs,
+	collections::BTreeSet,
 	ops::{Deref, DerefMut},
 	path::{Path, PathBuf},
 	sync::Arc,
 	time::{Duration, Instant},
 };
-use substrate_rpc_client::{rpc_params, BatchRequestBuilder, ChainApi, ClientT, StateApi};
+use substrate_rpc_client::{
+	rpc_params, ws_client, BatchRequestBuilder, ChainApi, ClientT, StateApi, Subscription,
+	SubscriptionClientT,
+};
 use tokio_retry::{strategy::FixedInterval, Retry};
 
 type Result<T, E = &'static str> = std::result::Result<T, E>;
@@ -55,56 +63,43 @@ type Result<T, E = &'static str> = std::result::Result<T, E>;
 type KeyValue = (StorageKey, StorageData);
 type TopKeyValues = Vec<KeyValue>;
 type ChildKeyValues = Vec<(ChildInfo, Vec<KeyValue>)>;
-type SnapshotVersion = Compact<u16>;
 
-const LOG_TARGET: &str = "remote-ext";
-const DEFAULT_HTTP_ENDPOINT: &str = "https://try-runtime.polkadot.io:443";
-const SNAPSHOT_VERSION: SnapshotVersion = Compact(4);
-
-/// The snapshot that we store on disk.
-#[derive(Decode, Encode)]
-struct Snapshot<B: BlockT> {
-	snapshot_version: SnapshotVersion,
-	state_version: StateVersion,
-	// <Vec<Key, (Value, MemoryDbRefCount)>>
-	raw_storage: Vec<(Vec<u8>, (Vec<u8>, i32))>,
-	// The storage root of the state. This may vary from the storage root in the header, if not the
-	// entire state was fetched.
-	storage_root: B::Hash,
-	header: B::Header,
+/// An item of an `archive_v1_storageDiff` subscription.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchiveStorageDiffItem {
+	key: StorageKey,
+	return_type: &'static str,
 }
 
-impl<B: BlockT> Snapshot<B> {
-	pub fn new(
-		state_version: StateVersion,
-		raw_storage: Vec<(Vec<u8>, (Vec<u8>, i32))>,
-		storage_root: B::Hash,
-		header: B::Header,
-	) -> Self {
-		Self {
-			snapshot_version: SNAPSHOT_VERSION,
-			state_version,
-			raw_storage,
-			storage_root,
-			header,
-		}
-	}
-
-	fn load(path: &PathBuf) -> Result<Snapshot<B>> {
-		let bytes = fs::read(path).map_err(|_| "fs::read failed.")?;
-		// The first item in the SCALE encoded struct bytes is the snapshot version. We decode and
-		// check that first, before proceeding to decode the rest of the snapshot.
-		let snapshot_version = SnapshotVersion::decode(&mut &*bytes)
-			.map_err(|_| "Failed to decode snapshot version")?;
-
-		if snapshot_version != SNAPSHOT_VERSION {
-			return Err("Unsupported snapshot version detected. Please create a new snapshot.")
-		}
+/// The kind of change reported by an `archive_v1_storageDiff` subscription.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+enum ArchiveStorageDiffType {
+	Added,
+	Modified,
+	Deleted,
+}
 
-		Decode::decode(&mut &*bytes).map_err(|_| "Decode failed")
-	}
+/// An event of an `archive_v1_storageDiff` subscription.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", tag = "event")]
+enum ArchiveStorageDiffEvent {
+	StorageDiff {
+		key: StorageKey,
+		value: Option<StorageData>,
+		#[serde(rename = "type")]
+		diff_type: ArchiveStorageDiffType,
+	},
+	StorageDiffError {
+		error: String,
+	},
+	StorageDiffDone,
 }
 
+const LOG_TARGET: &str = "remote-ext";
+const DEFAULT_HTTP_ENDPOINT: &str = "https://try-runtime.polkadot.io:443";
+
 /// An externalities that acts exactly the same as [`sp_io::TestExternalities`] but has a few extra
 /// bits and pieces to it, and can be loaded remotely.
 pub struct RemoteExternalities<B: BlockT> {
@@ -136,6 +131,17 @@ pub enum Mode<H> {
 	Offline(OfflineConfig),
 	/// Prefer using a snapshot file if it exists, else use a remote server.
 	OfflineOrElseOnline(OfflineConfig, OnlineConfig<H>),
+	/// Refresh an existing snapshot file to the block `at` of the online config by only
+	/// downloading the storage diff, then use it.
+	///
+	/// The storage diff is fetched with `archive_v1_storageDiff` if the transport is a URI and the
+	/// node supports it, else with `state_queryStorage`. Both need the state of the block of the
+	/// snapshot to be available on the node. Changed child tries are downloaded again entirely.
+	///
+	/// The prefixes and keys that are refreshed are the ones of the snapshot, the ones of the
+	/// online config are ignored. The refreshed snapshot is written to the `state_snapshot` of the
+	/// online config if set, else the snapshot file is overwritten.
+	Refresh(OfflineConfig, OnlineConfig<H>),
 }
 
 impl<H> Default for Mode<H> {
@@ -147,10 +153,35 @@ impl<H> Default for Mode<H> {
 /// Configuration of the offline execution.
 ///
 /// A state snapshot config must be present.
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub struct OfflineConfig {
 	/// The configuration of the state snapshot file to use. It must be present.
 	pub state_snapshot: SnapshotConfig,
+	/// The pallets to load from the snapshot. These values are hashed and added to
+	/// `hashed_prefixes`.
+	pub pallets: Vec<String>,
+	/// Storage entry key prefixes to load from the snapshot. The *hashed* prefix must be given.
+	///
+	/// Everything is loaded if this and `pallets` are empty. Keys that were scraped individually
+	/// are always loaded.
+	pub hashed_prefixes: Vec<Vec<u8>>,
+}
+
+impl OfflineConfig {
+	/// All hashed prefixes to load, including the ones of `pallets`.
+	fn prefixes(&self) -> Vec<Vec<u8>> {
+		self.pallets
+			.iter()
+			.map(|p| sp_crypto_hashing::twox_128(p.as_bytes()).to_vec())
+			.chain(self.hashed_prefixes.iter().cloned())
+			.collect()
+	}
+}
+
+impl From<SnapshotConfig> for OfflineConfig {
+	fn from(state_snapshot: SnapshotConfig) -> Self {
+		Self { state_snapshot, ..Default::default() }
+	}
 }
 
 /// Description of the transport protocol (for online execution).
@@ -170,6 +201,18 @@ impl Transport {
 		}
 	}
 
+	// The WebSocket URI of an uninitialized transport, needed for subscriptions.
+	fn as_ws_uri(&self) -> Option<String> {
+		match self {
+			Self::Uri(uri) if uri.starts_with("http://") =>
+				Some(uri.replacen("http://", "ws://", 1)),
+			Self::Uri(uri) if uri.starts_with("https://") =>
+				Some(uri.replacen("https://", "wss://", 1)),
+			Self::Uri(uri) => Some(uri.clone()),
+			Self::RemoteClient(_) => None,
+		}
+	}
+
 	// Build an HttpClient from a URI.
 	async fn init(&mut self) -> Result<()> {
 		if let Self::Uri(uri) = self {
@@ -334,6 +377,7 @@ impl<B: BlockT> Builder<B> {
 		match &self.mode {
 			Mode::Online(config) => config,
 			Mode::OfflineOrElseOnline(_, config) => config,
+			Mode::Refresh(_, config) => config,
 			_ => panic!("Unexpected mode: Online"),
 		}
 	}
@@ -342,6 +386,7 @@ impl<B: BlockT> Builder<B> {
 		match &mut self.mode {
 			Mode::Online(config) => config,
 			Mode::OfflineOrElseOnline(_, config) => config,
+			Mode::Refresh(_, config) => config,
 			_ => panic!("Unexpected mode: Online"),
 		}
 	}
@@ -882,6 +927,164 @@ where
 
 		Ok(all_child_keys)
 	}
+
+	/// Get the state version of the runtime at `at`.
+	async fn rpc_get_state_version(&self, at: Option<B::Hash>) -> Result<StateVersion> {
+		StateApi::<B::Hash>::runtime_version(self.as_online().rpc_client(), at)
+			.await
+			.map_err(|e| {
+				error!(target: LOG_TARGET, "Error = {e:?}");
+				"rpc runtime_version failed."
+			})
+			.map(|v| v.state_version())
+	}
+
+	/// Get the changes of the top keys of `snapshot` up to the block `at` via
+	/// `archive_v1_storageDiff`.
+	async fn rpc_archive_storage_diff(
+		&self,
+		uri: &str,
+		snapshot: &Snapshot<B>,
+		at: B::Hash,
+	) -> Result<TopChanges> {
+		let client = ws_client(uri).await.map_err(|e| {
+			error!(target: LOG_TARGET, "Error = {e:?}");
+			"failed to build ws client"
+		})?;
+
+		let items = snapshot
+			.prefixes
+			.iter()
+			.map(|p| &p.prefix)
+			.chain(snapshot.keys.iter().map(|(k, _)| k))
+			.map(|key| ArchiveStorageDiffItem {
+				key: StorageKey(key.clone()),
+				return_type: "value",
+			})
+			.collect::<Vec<_>>();
+		let mut subscription: Subscription<ArchiveStorageDiffEvent> = client
+			.subscribe(
+				"archive_v1_storageDiff",
+				rpc_params![at, items, snapshot.block_hash],
+				"archive_v1_storageDiff_stopStorageDiff",
+			)
+			.await
+			.map_err(|e| {
+				error!(target: LOG_TARGET, "Error = {e:?}");
+				"rpc archive_v1_storageDiff failed."
+			})?;
+
+		// The items are treated as prefixes, so keys below the individually scraped keys are
+		// reported as well.
+		let is_covered = |key: &[u8]| {
+			snapshot.prefixes.iter().any(|p| key.starts_with(&p.prefix)) ||
+				snapshot.keys.iter().any(|(k, _)| k == key)
+		};
+
+		let mut changes = TopChanges::new();
+		while let Some(event) = subscription.next().await {
+			let event = event.map_err(|e| {
+				error!(target: LOG_TARGET, "Error = {e:?}");
+				"invalid archive_v1_storageDiff event."
+			})?;
+			match event {
+				ArchiveStorageDiffEvent::StorageDiff { key, value, diff_type } => {
+					if !is_covered(&key.0) {
+						continue
+					}
+					let value = match diff_type {
+						ArchiveStorageDiffType::Deleted => None,
+						_ => Some(value.ok_or("archive_v1_storageDiff returned no value.")?.0),
+					};
+					changes.insert(key.0, value);
+				},
+				ArchiveStorageDiffEvent::StorageDiffError { error } => {
+					error!(target: LOG_TARGET, "Error = {error:?}");
+					return Err("rpc archive_v1_storageDiff failed.")
+				},
+				ArchiveStorageDiffEvent::StorageDiffDone => return Ok(changes),
+			}
+		}
+
+		Err("archive_v1_storageDiff subscription closed before it was done.")
+	}
+
+	/// Get the changes of the top keys of `snapshot` up to the block `at` via
+	/// `state_queryStorage`.
+	///
+	/// `state_queryStorage` only reports the changes of known keys, so the keys of each prefix are
+	/// listed again to find the added and removed ones.
+	async fn rpc_query_storage_diff(
+		&self,
+		snapshot: &Snapshot<B>,
+		at: B::Hash,
+	) -> Result<TopChanges> {
+		let client = self.as_online().rpc_client();
+		let known = snapshot.top_keys();
+		let mut changes = TopChanges::new();
+
+		let mut listed = BTreeSet::new();
+		for prefix in &snapshot.prefixes {
+			let keys = self
+				.rpc_get_keys_parallel(
+					&StorageKey(prefix.prefix.clone()),
+					at,
+					Self::PARALLEL_REQUESTS,
+				)
+				.await?
+				.into_iter()
+				.map(|k| k.0)
+				.collect::<BTreeSet<_>>();
+			for (key, _) in prefix.key_values.iter().filter(|(k, _)| !keys.contains(k)) {
+				changes.insert(key.clone(), None);
+			}
+			listed.extend(keys);
+		}
+
+		let added = listed.difference(&known).cloned().collect::<Vec<_>>();
+		info!(target: LOG_TARGET, "fetching {} added keys", added.len());
+		let payloads = added
+			.iter()
+			.map(|key| ("state_getStorage".to_string(), rpc_params!(StorageKey(key.clone()), at)))
+			.collect::<Vec<_>>();
+		let bar = ProgressBar::new(payloads.len() as u64);
+		let values = Self::get_storage_data_dynamic_batch_size(client, payloads, &bar)
+			.await
+			.map_err(|e| {
+				error!(target: LOG_TARGET, "batch processing failed: {e:?}");
+				"batch processing failed"
+			})?;
+		changes.extend(added.into_iter().zip(values.into_iter().map(|v| v.map(|v| v.0))));
+
+		let existing = listed
+			.intersection(&known)
+			.cloned()
+			.chain(snapshot.keys.iter().map(|(k, _)| k.clone()))
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.map(StorageKey)
+			.collect::<Vec<_>>();
+		info!(target: LOG_TARGET, "querying changes of {} existing keys", existing.len());
+		for keys in existing.chunks(Self::DEFAULT_KEY_DOWNLOAD_PAGE as usize) {
+			let change_sets = StateApi::<B::Hash>::query_storage(
+				client,
+				keys.to_vec(),
+				snapshot.block_hash,
+				Some(at),
+			)
+			.await
+			.map_err(|e| {
+				error!(target: LOG_TARGET, "Error = {e:?}");
+				"rpc query_storage failed."
+			})?;
+			// The first change set contains the values at the block of the snapshot.
+			for change_set in change_sets.into_iter().filter(|c| c.block != snapshot.block_hash) {
+				changes.extend(change_set.changes.into_iter().map(|(k, v)| (k.0, v.map(|v| v.0))));
+			}
+		}
+
+		Ok(changes)
+	}
 }
 
 impl<B: BlockT> Builder<B>
@@ -1011,18 +1214,7 @@ where
 	///
 	/// initializes the remote client in `transport`, and sets the `at` field, if not specified.
 	async fn init_remote_client(&mut self) -> Result<()> {
-		// First, initialize the http client.
-		self.as_online_mut().transport.init().await?;
-
-		// Then, if `at` is not set, set it.
-		if self.as_online().at.is_none() {
-			let at = self.rpc_get_head().await?;
-			info!(
-				target: LOG_TARGET,
-				"since no at is provided, setting it to latest finalized head, {at:?}",
-			);
-			self.as_online_mut().at = Some(at);
-		}
+		self.init_remote_transport().await?;
 
 		// Then, a few transformation that we want to perform in the online config:
 		let online_config = self.as_online_mut();
@@ -1054,6 +1246,24 @@ where
 		Ok(())
 	}
 
+	/// Initialize the remote client in `transport`, and set the `at` field, if not specified.
+	async fn init_remote_transport(&mut self) -> Result<()> {
+		// First, initialize the http client.
+		self.as_online_mut().transport.init().await?;
+
+		// Then, if `at` is not set, set it.
+		if self.as_online().at.is_none() {
+			let at = self.rpc_get_head().await?;
+			info!(
+				target: LOG_TARGET,
+				"since no at is provided, setting it to latest finalized head, {at:?}",
+			);
+			self.as_online_mut().at = Some(at);
+		}
+
+		Ok(())
+	}
+
 	async fn load_header(&self) -> Result<B::Header> {
 		let retry_strategy =
 			FixedInterval::new(Self::KEYS_PAGE_RETRY_INTERVAL).take(Self::MAX_RETRIES);
@@ -1074,14 +1284,7 @@ where
 	///
 	/// Must be called after `init_remote_client`.
 	async fn load_remote_and_maybe_save(&mut self) -> Result<TestExternalities<HashingFor<B>>> {
-		let state_version =
-			StateApi::<B::Hash>::runtime_version(self.as_online().rpc_client(), None)
-				.await
-				.map_err(|e| {
-					error!(target: LOG_TARGET, "Error = {e:?}");
-					"rpc runtime_version failed."
-				})
-				.map(|v| v.state_version())?;
+		let state_version = self.rpc_get_state_version(None).await?;
 		let mut pending_ext = TestExternalities::new_with_code_and_state(
 			Default::default(),
 			Default::default(),
@@ -1090,31 +1293,36 @@ where
 
 		// Load data from the remote into `pending_ext`.
 		let top_kv = self.load_top_remote(&mut pending_ext).await?;
-		self.load_child_remote(&top_kv, &mut pending_ext).await?;
+		let child_kv = self.load_child_remote(&top_kv, &mut pending_ext).await?;
 
-		// If we need to save a snapshot, save the raw storage and root hash to the snapshot.
+		// If we need to save a snapshot, save the key-values of each prefix to the snapshot.
 		if let Some(path) = self.as_online().state_snapshot.clone().map(|c| c.path) {
-			let (raw_storage, storage_root) = pending_ext.into_raw_snapshot();
-			let snapshot = Snapshot::<B>::new(
-				state_version,
-				raw_storage.clone(),
-				storage_root,
-				self.load_header().await?,
-			);
-			let encoded = snapshot.encode();
-			info!(
-				target: LOG_TARGET,
-				"writing snapshot of {} bytes to {path:?}",
-				encoded.len(),
-			);
-			std::fs::write(path, encoded).map_err(|_| "fs::write failed")?;
-
-			// pending_ext was consumed when creating the snapshot, need to reinitailize it
-			return Ok(TestExternalities::from_raw_snapshot(
-				raw_storage,
-				storage_root,
-				self.overwrite_state_version.unwrap_or(state_version),
-			))
+			let config = self.as_online();
+			let header = self.load_header().await?;
+			let mut snapshot = Snapshot::<B>::new(state_version, config.at_expected(), header);
+			for prefix in &config.hashed_prefixes {
+				let key_values = top_kv
+					.iter()
+					.filter(|(k, _)| k.0.starts_with(prefix))
+					.map(|(k, v)| (k.0.clone(), v.0.clone()))
+					.collect::<RawKeyValues>();
+				snapshot.add_prefix(prefix.clone(), key_values);
+			}
+			snapshot.keys = config
+				.hashed_keys
+				.iter()
+				.map(|key| {
+					let value = top_kv.iter().find(|(k, _)| &k.0 == key).map(|(_, v)| v.0.clone());
+					(key.clone(), value)
+				})
+				.collect();
+			for (info, key_values) in child_kv {
+				snapshot.set_child_trie(
+					info.prefixed_storage_key().into_inner(),
+					Some(key_values.into_iter().map(|(k, v)| (k.0, v.0)).collect()),
+				);
+			}
+			snapshot.save(&path)?;
 		}
 
 		Ok(pending_ext)
@@ -1131,13 +1339,10 @@ where
 			|| {
 				info!(target: LOG_TARGET, "Loading snapshot from {:?}", &config.state_snapshot.path);
 
-				let Snapshot { header, state_version, raw_storage, storage_root, .. } =
-					Snapshot::<B>::load(&config.state_snapshot.path)?;
-				let inner_ext = TestExternalities::from_raw_snapshot(
-					raw_storage,
-					storage_root,
-					self.overwrite_state_version.unwrap_or(state_version),
-				);
+				let snapshot = Snapshot::<B>::load(&config.state_snapshot.path)?;
+				let header = snapshot.header.clone();
+				let state_version = self.overwrite_state_version.unwrap_or(snapshot.state_version);
+				let inner_ext = snapshot.into_ext(&config.prefixes(), state_version)?;
 
 				Ok((header, inner_ext))
 			},
@@ -1148,6 +1353,82 @@ where
 		Ok(RemoteExternalities { inner_ext, header })
 	}
 
+	/// Refresh the snapshot of `config` to the block `at` of the online config, save and load it.
+	async fn do_load_refreshed(&mut self, config: OfflineConfig) -> Result<RemoteExternalities<B>> {
+		info!(target: LOG_TARGET, "Refreshing snapshot {:?}", &config.state_snapshot.path);
+		let mut snapshot = Snapshot::<B>::load(&config.state_snapshot.path)?;
+
+		let ws_uri = self.as_online().transport.as_ws_uri();
+		self.init_remote_transport().await?;
+		let at = self.as_online().at_expected();
+
+		if snapshot.block_hash == at {
+			info!(target: LOG_TARGET, "snapshot is already at block {at:?}");
+		} else {
+			let changes = match ws_uri {
+				Some(uri) => match self.rpc_archive_storage_diff(&uri, &snapshot, at).await {
+					Ok(changes) => changes,
+					Err(e) => {
+						warn!(
+							target: LOG_TARGET,
+							"archive_v1_storageDiff unavailable ({e}), falling back to state_queryStorage"
+						);
+						self.rpc_query_storage_diff(&snapshot, at).await?
+					},
+				},
+				None => self.rpc_query_storage_diff(&snapshot, at).await?,
+			};
+			info!(
+				target: LOG_TARGET,
+				"applying {} changes from block {:?} to {at:?}",
+				changes.len(),
+				snapshot.block_hash,
+			);
+
+			let client = self.as_online().rpc_client();
+			for prefixed_key in snapshot.apply_top_changes(&changes) {
+				let key_values = match changes.get(&prefixed_key) {
+					Some(Some(_)) => {
+						let prefixed_top_key = StorageKey(prefixed_key.clone());
+						let child_keys = Self::rpc_child_get_keys(
+							client,
+							&prefixed_top_key,
+							StorageKey(vec![]),
+							at,
+						)
+						.await?;
+						let key_values = Self::rpc_child_get_storage_paged(
+							client,
+							&prefixed_top_key,
+							child_keys,
+							at,
+						)
+						.await?;
+						Some(key_values.into_iter().map(|(k, v)| (k.0, v.0)).collect())
+					},
+					_ => None,
+				};
+				snapshot.set_child_trie(prefixed_key, key_values);
+			}
+
+			snapshot.block_hash = at;
+			snapshot.header = self.load_header().await?;
+			snapshot.state_version = self.rpc_get_state_version(Some(at)).await?;
+		}
+
+		let path = self
+			.as_online()
+			.state_snapshot
+			.clone()
+			.map_or(config.state_snapshot.path.clone(), |c| c.path);
+		snapshot.save(&path)?;
+
+		let header = snapshot.header.clone();
+		let state_version = self.overwrite_state_version.unwrap_or(snapshot.state_version);
+		let inner_ext = snapshot.into_ext(&config.prefixes(), state_version)?;
+		Ok(RemoteExternalities { inner_ext, header })
+	}
+
 	pub(crate) async fn pre_build(mut self) -> Result<RemoteExternalities<B>> {
 		let mut ext = match self.mode.clone() {
 			Mode::Offline(config) => self.do_load_offline(config)?,
@@ -1158,6 +1439,7 @@ where
 					Err(_) => self.do_load_remote().await?,
 				}
 			},
+			Mode::Refresh(offline_config, _) => self.do_load_refreshed(offline_config).await?,
 		};
 
 		// inject manual key values.
@@ -1261,6 +1543,7 @@ mod tests {
 		Builder::<Block>::new()
 			.mode(Mode::Offline(OfflineConfig {
 				state_snapshot: SnapshotConfig::new("test_data/test.snap"),
+				..Default::default()
 			}))
 			.build()
 			.await
@@ -1276,6 +1559,7 @@ mod tests {
 		let some_key = Builder::<Block>::new()
 			.mode(Mode::Offline(OfflineConfig {
 				state_snapshot: SnapshotConfig::new("test_data/test.snap"),
+				..Default::default()
 			}))
 			.build()
 			.await
@@ -1290,6 +1574,7 @@ mod tests {
 		Builder::<Block>::new()
 			.mode(Mode::Offline(OfflineConfig {
 				state_snapshot: SnapshotConfig::new("test_data/test.snap"),
+				..Default::default()
 			}))
 			.blacklist_hashed_key(&some_key)
 			.build()
@@ -1297,6 +1582,43 @@ mod tests {
 			.expect("Can't read state snapshot file")
 			.execute_with(|| assert!(sp_io::storage::get(&some_key).is_none()));
 	}
+
+	#[tokio::test]
+	async fn can_load_snapshot_partially() {
+		init_logger();
+		let system_key = |ext: &mut RemoteExternalities<Block>| {
+			ext.execute_with(|| {
+				sp_io::storage::next_key(&sp_crypto_hashing::twox_128(b"System"))
+					.filter(|k| k.starts_with(&sp_crypto_hashing::twox_128(b"System")))
+			})
+		};
+
+		let mut ext = Builder::<Block>::new()
+			.mode(Mode::Offline(OfflineConfig {
+				state_snapshot: SnapshotConfig::new("test_data/test.snap"),
+				pallets: vec!["System".to_owned()],
+				..Default::default()
+			}))
+			.build()
+			.await
+			.expect("Can't read state snapshot file");
+		assert!(system_key(&mut ext).is_some());
+
+		let mut ext = Builder::<Block>::new()
+			.mode(Mode::Offline(OfflineConfig {
+				state_snapshot: SnapshotConfig::new("test_data/test.snap"),
+				pallets: vec!["Balances".to_owned()],
+				..Default::default()
+			}))
+			.build()
+			.await
+			.expect("Can't read state snapshot file");
+		assert!(system_key(&mut ext).is_none());
+		// individually scraped keys are always loaded.
+		ext.execute_with(|| {
+			assert!(sp_io::storage::get(sp_core::storage::well_known_keys::CODE).is_some())
+		});
+	}
 }
 
 #[cfg(all(test, feature = "remote-test"))]
@@ -1328,7 +1650,10 @@ mod remote_tests {
 
 		// now re-create the same snapshot.
 		let cached_ext = Builder::<Block>::new()
-			.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(CACHE) }))
+			.mode(Mode::Offline(OfflineConfig {
+				state_snapshot: SnapshotConfig::new(CACHE),
+				..Default::default()
+			}))
 			.build()
 			.await
 			.unwrap();
@@ -1341,7 +1666,10 @@ mod remote_tests {
 			StateVersion::V1 => StateVersion::V0,
 		};
 		let cached_ext = Builder::<Block>::new()
-			.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(CACHE) }))
+			.mode(Mode::Offline(OfflineConfig {
+				state_snapshot: SnapshotConfig::new(CACHE),
+				..Default::default()
+			}))
 			.overwrite_state_version(other)
 			.build()
 			.await
@@ -1370,7 +1698,10 @@ mod remote_tests {
 
 		// now re-create the same snapshot.
 		let cached_ext = Builder::<Block>::new()
-			.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(CACHE) }))
+			.mode(Mode::Offline(OfflineConfig {
+				state_snapshot: SnapshotConfig::new(CACHE),
+				..Default::default()
+			}))
 			.build()
 			.await
 			.unwrap();
@@ -1378,6 +1709,64 @@ mod remote_tests {
 		assert_eq!(ext.header.hash(), cached_ext.header.hash());
 	}
 
+	#[tokio::test(flavor = "multi_thread")]
+	async fn can_refresh_snapshot() {
+		const CACHE: &'static str = "can_refresh_snapshot";
+		init_logger();
+
+		let mut builder = Builder::<Block>::new().mode(Mode::Online(OnlineConfig {
+			transport: endpoint().clone().into(),
+			..Default::default()
+		}));
+		builder.init_remote_client().await.unwrap();
+		let head = builder.as_online().at_expected();
+		let parent = builder.load_header().await.unwrap().parent_hash;
+
+		// first, build a snapshot of the parent block.
+		Builder::<Block>::new()
+			.mode(Mode::Online(OnlineConfig {
+				transport: endpoint().clone().into(),
+				at: Some(parent),
+				pallets: vec!["Proxy".to_owned()],
+				child_trie: false,
+				state_snapshot: Some(SnapshotConfig::new(CACHE)),
+				..Default::default()
+			}))
+			.build()
+			.await
+			.unwrap();
+
+		// then refresh it to the head.
+		let mut refreshed = Builder::<Block>::new()
+			.mode(Mode::Refresh(
+				SnapshotConfig::new(CACHE).into(),
+				OnlineConfig {
+					transport: endpoint().clone().into(),
+					at: Some(head),
+					..Default::default()
+				},
+			))
+			.build()
+			.await
+			.unwrap();
+
+		let mut fresh = Builder::<Block>::new()
+			.mode(Mode::Online(OnlineConfig {
+				transport: endpoint().clone().into(),
+				at: Some(head),
+				pallets: vec!["Proxy".to_owned()],
+				child_trie: false,
+				..Default::default()
+			}))
+			.build()
+			.await
+			.unwrap();
+
+		assert_eq!(refreshed.header.hash(), head);
+		assert_eq!(refreshed.as_backend().root(), fresh.as_backend().root());
+		std::fs::remove_file(CACHE).unwrap();
+	}
+
 	#[tokio::test]
 	async fn child_keys_are_loaded() {
 		const CACHE: &'static str = "snapshot_retains_storage";
@@ -1423,7 +1812,7 @@ mod remote_tests {
 		// this shows that in the second run, we use the remote and create a snapshot.
 		Builder::<Block>::new()
 			.mode(Mode::OfflineOrElseOnline(
-				OfflineConfig { state_snapshot: SnapshotConfig::new(CACHE) },
+				OfflineConfig { state_snapshot: SnapshotConfig::new(CACHE), ..Default::default() },
 				OnlineConfig {
 					transport: endpoint().clone().into(),
 					pallets: vec!["Proxy".to_owned()],
@@ -1440,7 +1829,7 @@ mod remote_tests {
 		// this shows that in the second run, we are not using the remote
 		Builder::<Block>::new()
 			.mode(Mode::OfflineOrElseOnline(
-				OfflineConfig { state_snapshot: SnapshotConfig::new(CACHE) },
+				OfflineConfig { state_snapshot: SnapshotConfig::new(CACHE), ..Default::default() },
 				OnlineConfig {
 					transport: "ws://non-existent:666".to_owned().into(),
 					..Default::default()
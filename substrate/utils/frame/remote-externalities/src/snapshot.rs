@@ -0,0 +1,352 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The state snapshot that is stored on disk.
+//!
+//! The storage is kept as plain key-values, grouped by the hashed prefix that they were scraped
+//! with. This allows to refresh a snapshot by only applying the storage diff to a newer block, and
+//! to load only some of its prefixes.
+
+use super::{Result, LOG_TARGET};
+use codec::{Compact, Decode, Encode};
+use log::*;
+use sp_core::storage::{
+	well_known_keys::is_default_child_storage_key, ChildInfo, ChildType, PrefixedStorageKey,
+};
+use sp_runtime::{
+	traits::{Block as BlockT, HashingFor},
+	StateVersion,
+};
+use sp_state_machine::TestExternalities;
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	fs,
+	path::Path,
+};
+
+pub(crate) type RawKeyValues = Vec<(Vec<u8>, Vec<u8>)>;
+type SnapshotVersion = Compact<u16>;
+
+pub(crate) const SNAPSHOT_VERSION: SnapshotVersion = Compact(5);
+
+/// The changes of the top trie between two blocks.
+///
+/// A value of `None` means that the key was removed.
+pub(crate) type TopChanges = BTreeMap<Vec<u8>, Option<Vec<u8>>>;
+
+/// The key-values of the top trie that were scraped with one hashed prefix.
+#[derive(Decode, Encode, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PrefixSnapshot {
+	pub prefix: Vec<u8>,
+	// Sorted by key.
+	pub key_values: RawKeyValues,
+}
+
+/// The key-values of one child trie.
+#[derive(Decode, Encode, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ChildSnapshot {
+	// The prefixed storage key of the child trie, as found in the top trie.
+	pub prefixed_key: Vec<u8>,
+	pub key_values: RawKeyValues,
+}
+
+/// The snapshot that we store on disk.
+#[derive(Decode, Encode, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Snapshot<B: BlockT> {
+	pub snapshot_version: SnapshotVersion,
+	pub state_version: StateVersion,
+	// The hash of the block that the state belongs to.
+	pub block_hash: B::Hash,
+	pub header: B::Header,
+	pub prefixes: Vec<PrefixSnapshot>,
+	// Individually scraped keys. A value of `None` means that the key did not exist.
+	pub keys: Vec<(Vec<u8>, Option<Vec<u8>>)>,
+	pub child_tries: Vec<ChildSnapshot>,
+}
+
+impl<B: BlockT> Snapshot<B> {
+	pub fn new(state_version: StateVersion, block_hash: B::Hash, header: B::Header) -> Self {
+		Self {
+			snapshot_version: SNAPSHOT_VERSION,
+			state_version,
+			block_hash,
+			header,
+			prefixes: Default::default(),
+			keys: Default::default(),
+			child_tries: Default::default(),
+		}
+	}
+
+	pub fn load(path: &Path) -> Result<Snapshot<B>> {
+		let bytes = fs::read(path).map_err(|_| "fs::read failed.")?;
+		// The first item in the SCALE encoded struct bytes is the snapshot version. We decode and
+		// check that first, before proceeding to decode the rest of the snapshot.
+		let snapshot_version = SnapshotVersion::decode(&mut &*bytes)
+			.map_err(|_| "Failed to decode snapshot version")?;
+
+		if snapshot_version != SNAPSHOT_VERSION {
+			return Err("Unsupported snapshot version detected. Please create a new snapshot.")
+		}
+
+		Decode::decode(&mut &*bytes).map_err(|_| "Decode failed")
+	}
+
+	pub fn save(&self, path: &Path) -> Result<()> {
+		let encoded = self.encode();
+		info!(target: LOG_TARGET, "writing snapshot of {} bytes to {path:?}", encoded.len());
+		fs::write(path, encoded).map_err(|_| "fs::write failed")
+	}
+
+	/// Add the key-values that were scraped with `prefix`.
+	pub fn add_prefix(&mut self, prefix: Vec<u8>, mut key_values: RawKeyValues) {
+		key_values.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+		self.prefixes.push(PrefixSnapshot { prefix, key_values });
+	}
+
+	/// All keys of the top trie that are part of the snapshot.
+	pub fn top_keys(&self) -> BTreeSet<Vec<u8>> {
+		self.prefixes
+			.iter()
+			.flat_map(|p| p.key_values.iter().map(|(k, _)| k.clone()))
+			.chain(self.keys.iter().filter(|(_, v)| v.is_some()).map(|(k, _)| k.clone()))
+			.collect()
+	}
+
+	/// Apply the `changes` of the top trie to all prefixes and keys that contain them.
+	///
+	/// Changes of keys which are not covered by the snapshot are ignored. Returns the prefixed
+	/// storage keys of the child tries whose root changed.
+	pub fn apply_top_changes(&mut self, changes: &TopChanges) -> Vec<Vec<u8>> {
+		for entry in self.prefixes.iter_mut() {
+			let mut key_values =
+				core::mem::take(&mut entry.key_values).into_iter().collect::<BTreeMap<_, _>>();
+			for (key, value) in changes.iter().filter(|(k, _)| k.starts_with(&entry.prefix)) {
+				match value {
+					Some(value) => key_values.insert(key.clone(), value.clone()),
+					None => key_values.remove(key),
+				};
+			}
+			entry.key_values = key_values.into_iter().collect();
+		}
+
+		for (key, value) in self.keys.iter_mut() {
+			if let Some(change) = changes.get(key) {
+				*value = change.clone();
+			}
+		}
+
+		let covered = self.top_keys();
+		changes
+			.keys()
+			.filter(|k| is_default_child_storage_key(k))
+			.filter(|k| {
+				covered.contains(*k) || self.child_tries.iter().any(|c| &c.prefixed_key == *k)
+			})
+			.cloned()
+			.collect()
+	}
+
+	/// Replace the key-values of the child trie at `prefixed_key`, or remove it if `None`.
+	pub fn set_child_trie(&mut self, prefixed_key: Vec<u8>, key_values: Option<RawKeyValues>) {
+		self.child_tries.retain(|c| c.prefixed_key != prefixed_key);
+		if let Some(key_values) = key_values {
+			self.child_tries.push(ChildSnapshot { prefixed_key, key_values });
+		}
+	}
+
+	/// Build the externalities from the snapshot.
+	///
+	/// If `prefixes` is not empty, only the top keys starting with one of them are loaded. The
+	/// individually scraped keys are always loaded. A child trie is loaded whenever its root key in
+	/// the top trie is.
+	pub fn into_ext(
+		self,
+		prefixes: &[Vec<u8>],
+		state_version: StateVersion,
+	) -> Result<TestExternalities<HashingFor<B>>> {
+		let included =
+			|key: &[u8]| prefixes.is_empty() || prefixes.iter().any(|p| key.starts_with(p));
+		let mut ext = TestExternalities::new_with_code_and_state(
+			Default::default(),
+			Default::default(),
+			state_version,
+		);
+
+		let scraped_keys =
+			self.keys.into_iter().filter_map(|(k, v)| v.map(|v| (k, v))).collect::<Vec<_>>();
+		let scraped_child_keys = scraped_keys
+			.iter()
+			.map(|(k, _)| k)
+			.filter(|k| is_default_child_storage_key(k))
+			.cloned()
+			.collect::<BTreeSet<_>>();
+
+		// The roots of the child tries are set when inserting the child tries.
+		let top = self
+			.prefixes
+			.into_iter()
+			.flat_map(|p| p.key_values)
+			.filter(|(k, _)| included(k))
+			.chain(scraped_keys)
+			.filter(|(k, _)| !is_default_child_storage_key(k))
+			.collect::<Vec<_>>();
+		debug!(target: LOG_TARGET, "loading {} top keys from snapshot", top.len());
+		ext.batch_insert(top);
+
+		for child in self
+			.child_tries
+			.into_iter()
+			.filter(|c| included(&c.prefixed_key) || scraped_child_keys.contains(&c.prefixed_key))
+		{
+			let info = match ChildType::from_prefixed_key(PrefixedStorageKey::new_ref(
+				&child.prefixed_key,
+			)) {
+				Some((ChildType::ParentKeyId, storage_key)) => ChildInfo::new_default(storage_key),
+				None => {
+					error!(target: LOG_TARGET, "invalid child key: {:?}", child.prefixed_key);
+					return Err("Invalid child key")
+				},
+			};
+			for (k, v) in child.key_values {
+				ext.insert_child(info.clone(), k, v);
+			}
+		}
+
+		Ok(ext)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_prelude::Block;
+	use sp_core::storage::well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX;
+	use sp_runtime::traits::Header as _;
+
+	fn child_key(name: &[u8]) -> Vec<u8> {
+		[DEFAULT_CHILD_STORAGE_KEY_PREFIX, name].concat()
+	}
+
+	fn snapshot() -> Snapshot<Block> {
+		let header = <Block as BlockT>::Header::new(
+			1,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+		);
+		let mut snapshot = Snapshot::<Block>::new(StateVersion::V1, header.hash(), header);
+		snapshot.add_prefix(vec![1], vec![(vec![1, 2], vec![2]), (vec![1, 1], vec![1])]);
+		snapshot.add_prefix(vec![2], vec![(vec![2, 1], vec![3])]);
+		snapshot.add_prefix(
+			DEFAULT_CHILD_STORAGE_KEY_PREFIX.to_vec(),
+			vec![(child_key(b"a"), vec![0; 32])],
+		);
+		snapshot.keys = vec![(vec![3], Some(vec![4])), (vec![4], None)];
+		snapshot.set_child_trie(child_key(b"a"), Some(vec![(vec![5], vec![5])]));
+		snapshot
+	}
+
+	#[test]
+	fn prefixes_are_sorted() {
+		let snapshot = snapshot();
+		assert_eq!(
+			snapshot.prefixes[0].key_values,
+			vec![(vec![1, 1], vec![1]), (vec![1, 2], vec![2])]
+		);
+	}
+
+	#[test]
+	fn apply_top_changes_works() {
+		let mut snapshot = snapshot();
+		let changes = TopChanges::from([
+			// modified
+			(vec![1, 1], Some(vec![10])),
+			// removed
+			(vec![1, 2], None),
+			// added
+			(vec![2, 0], Some(vec![11])),
+			// not covered by the snapshot
+			(vec![9], Some(vec![12])),
+			// individually scraped keys
+			(vec![3], None),
+			(vec![4], Some(vec![13])),
+			// child roots
+			(child_key(b"a"), Some(vec![1; 32])),
+			(child_key(b"b"), Some(vec![2; 32])),
+		]);
+
+		let changed_children = snapshot.apply_top_changes(&changes);
+
+		assert_eq!(changed_children, vec![child_key(b"a"), child_key(b"b")]);
+		assert_eq!(snapshot.prefixes[0].key_values, vec![(vec![1, 1], vec![10])]);
+		assert_eq!(
+			snapshot.prefixes[1].key_values,
+			vec![(vec![2, 0], vec![11]), (vec![2, 1], vec![3])]
+		);
+		assert_eq!(snapshot.keys, vec![(vec![3], None), (vec![4], Some(vec![13]))]);
+		assert!(!snapshot.top_keys().contains(&vec![9]));
+	}
+
+	#[test]
+	fn child_tries_can_be_replaced_and_removed() {
+		let mut snapshot = snapshot();
+		snapshot.set_child_trie(child_key(b"a"), Some(vec![(vec![6], vec![6])]));
+		assert_eq!(snapshot.child_tries.len(), 1);
+		assert_eq!(snapshot.child_tries[0].key_values, vec![(vec![6], vec![6])]);
+
+		snapshot.set_child_trie(child_key(b"a"), None);
+		assert!(snapshot.child_tries.is_empty());
+	}
+
+	#[test]
+	fn can_load_partially() {
+		let mut ext = snapshot().into_ext(&[vec![2]], StateVersion::V1).unwrap();
+		ext.execute_with(|| {
+			assert_eq!(sp_io::storage::get(&[1, 1]), None);
+			assert_eq!(sp_io::storage::get(&[2, 1]).unwrap().to_vec(), vec![3]);
+			// individually scraped keys are always loaded.
+			assert_eq!(sp_io::storage::get(&[3]).unwrap().to_vec(), vec![4]);
+			assert_eq!(sp_io::default_child_storage::get(b"a", &[5]), None);
+		});
+
+		let mut scraped_root = snapshot();
+		// The root key of the child trie is individually scraped.
+		scraped_root.keys.push((child_key(b"b"), Some(vec![0; 32])));
+		scraped_root.set_child_trie(child_key(b"b"), Some(vec![(vec![6], vec![6])]));
+		let mut ext = scraped_root.into_ext(&[vec![2]], StateVersion::V1).unwrap();
+		ext.execute_with(|| {
+			assert_eq!(sp_io::default_child_storage::get(b"a", &[5]), None);
+			assert_eq!(sp_io::default_child_storage::get(b"b", &[6]).unwrap().to_vec(), vec![6]);
+		});
+
+		let mut ext = snapshot().into_ext(&[], StateVersion::V1).unwrap();
+		ext.execute_with(|| {
+			assert_eq!(sp_io::storage::get(&[1, 1]).unwrap().to_vec(), vec![1]);
+			assert_eq!(sp_io::default_child_storage::get(b"a", &[5]).unwrap().to_vec(), vec![5]);
+		});
+	}
+
+	#[test]
+	fn snapshot_roundtrip_works() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("snapshot");
+		let snapshot = snapshot();
+		snapshot.save(&path).unwrap();
+		assert_eq!(Snapshot::<Block>::load(&path).unwrap(), snapshot);
+	}
+}
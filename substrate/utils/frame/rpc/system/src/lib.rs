@@ -27,7 +27,9 @@ use jsonrpsee::{
 	Extensions,
 };
 
+use sc_client_api::backend::EventTopicsIndex;
 use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
+use serde::{Deserialize, Serialize};
 use sp_api::ApiExt;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::HeaderBackend;
@@ -53,12 +55,50 @@ pub trait SystemApi<BlockHash, AccountId, Nonce> {
 	async fn dry_run(&self, extrinsic: Bytes, at: Option<BlockHash>) -> RpcResult<Bytes>;
 }
 
+/// The maximum number of blocks `system_eventsByTopic` scans in one call.
+pub const MAX_EVENTS_BY_TOPIC_RANGE: u32 = 10_000;
+
+/// Event topics RPC methods.
+#[rpc(client, server)]
+pub trait EventTopicsApi<BlockHash, BlockNumber> {
+	/// Returns the events with `topic` in the canonical blocks `from..=to`.
+	///
+	/// `to` defaults to the best block and the range can span at most
+	/// [`MAX_EVENTS_BY_TOPIC_RANGE`] blocks. Requires the node to run with the event topics index
+	/// enabled, only blocks imported while it was enabled are returned.
+	///
+	/// This method is unsafe, as it reads the index of every block of the range.
+	#[method(name = "system_eventsByTopic", blocking, with_extensions)]
+	fn events_by_topic(
+		&self,
+		topic: Bytes,
+		from: BlockNumber,
+		to: Option<BlockNumber>,
+	) -> RpcResult<Vec<TopicEvent<BlockHash, BlockNumber>>>;
+}
+
+/// An event deposited with a given topic.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicEvent<BlockHash, BlockNumber> {
+	/// The number of the block the event was deposited in.
+	pub block_number: BlockNumber,
+	/// The hash of the block the event was deposited in.
+	pub block_hash: BlockHash,
+	/// The index of the event in the events of the block.
+	pub event_index: u32,
+}
+
 /// Error type of this RPC api.
 pub enum Error {
 	/// The transaction was not decodable.
 	DecodeError,
 	/// The call to runtime failed.
 	RuntimeError,
+	/// The requested block range is invalid.
+	InvalidRange,
+	/// The call to the backend failed.
+	BackendError,
 }
 
 impl From<Error> for i32 {
@@ -66,6 +106,8 @@ impl From<Error> for i32 {
 		match e {
 			Error::RuntimeError => 1,
 			Error::DecodeError => 2,
+			Error::InvalidRange => 3,
+			Error::BackendError => 4,
 		}
 	}
 }
@@ -176,6 +218,67 @@ where
 	}
 }
 
+/// An implementation of the event topics RPC methods on full client.
+pub struct EventTopics<C, I, B> {
+	client: Arc<C>,
+	index: Arc<I>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, I, B> EventTopics<C, I, B> {
+	/// Create new `EventTopics` given client and the backend providing the index.
+	pub fn new(client: Arc<C>, index: Arc<I>) -> Self {
+		Self { client, index, _marker: Default::default() }
+	}
+}
+
+impl<C, I, Block> EventTopicsApiServer<<Block as traits::Block>::Hash, traits::NumberFor<Block>>
+	for EventTopics<C, I, Block>
+where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+	I: EventTopicsIndex<Block> + 'static,
+	Block: traits::Block,
+{
+	fn events_by_topic(
+		&self,
+		ext: &Extensions,
+		topic: Bytes,
+		from: traits::NumberFor<Block>,
+		to: Option<traits::NumberFor<Block>>,
+	) -> RpcResult<Vec<TopicEvent<<Block as traits::Block>::Hash, traits::NumberFor<Block>>>> {
+		sc_rpc_api::check_if_safe(ext)?;
+
+		let best_number = self.client.info().best_number;
+		let to = to.unwrap_or(best_number).min(best_number);
+		if from > to {
+			return Ok(Vec::new())
+		}
+		if to - from >= MAX_EVENTS_BY_TOPIC_RANGE.into() {
+			return Err(ErrorObject::owned(
+				Error::InvalidRange.into(),
+				"Block range too large.",
+				Some(format!("At most {} blocks can be queried.", MAX_EVENTS_BY_TOPIC_RANGE)),
+			))
+		}
+
+		let events = self.index.events_by_topic(&topic, from, to).map_err(|e| {
+			ErrorObject::owned(
+				Error::BackendError.into(),
+				"Unable to query events by topic.",
+				Some(e.to_string()),
+			)
+		})?;
+		Ok(events
+			.into_iter()
+			.map(|(block_number, block_hash, event_index)| TopicEvent {
+				block_number,
+				block_hash,
+				event_index,
+			})
+			.collect())
+	}
+}
+
 /// Adjust account nonce from state, so that tx with the nonce will be
 /// placed after all ready txpool transactions.
 fn adjust_nonce<P, AccountId, Nonce>(pool: &P, account: AccountId, nonce: Nonce) -> Nonce
@@ -224,7 +327,10 @@ mod tests {
 		transaction_validity::{InvalidTransaction, TransactionValidityError},
 		ApplyExtrinsicResult,
 	};
-	use substrate_test_runtime_client::{runtime::Transfer, Sr25519Keyring};
+	use substrate_test_runtime_client::{
+		runtime::{Block, Transfer},
+		Sr25519Keyring,
+	};
 
 	fn deny_unsafe() -> Extensions {
 		let mut ext = Extensions::new();
@@ -374,4 +480,55 @@ mod tests {
 		let apply_res: ApplyExtrinsicResult = Decode::decode(&mut bytes.as_ref()).unwrap();
 		assert_eq!(apply_res, Err(TransactionValidityError::Invalid(InvalidTransaction::Future)));
 	}
+
+	struct TestIndex;
+
+	impl EventTopicsIndex<Block> for TestIndex {
+		fn events_by_topic(
+			&self,
+			topic: &[u8],
+			from: u64,
+			to: u64,
+		) -> sp_blockchain::Result<Vec<(u64, <Block as traits::Block>::Hash, u32)>> {
+			assert_eq!(topic, b"topic");
+			Ok((from..=to).map(|number| (number, Default::default(), 3)).collect())
+		}
+	}
+
+	#[test]
+	fn events_by_topic_should_be_capped_at_best_block() {
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let topics = EventTopics::new(client, Arc::new(TestIndex));
+
+		// when
+		let events = topics
+			.events_by_topic(&allow_unsafe(), b"topic".to_vec().into(), 0, Some(100))
+			.unwrap();
+
+		// then
+		assert_eq!(
+			events,
+			vec![TopicEvent { block_number: 0, block_hash: Default::default(), event_index: 3 }],
+		);
+		assert!(topics
+			.events_by_topic(&allow_unsafe(), b"topic".to_vec().into(), 1, None)
+			.unwrap()
+			.is_empty());
+	}
+
+	#[test]
+	fn events_by_topic_should_be_unsafe() {
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let topics = EventTopics::new(client, Arc::new(TestIndex));
+
+		// when
+		let res = topics.events_by_topic(&deny_unsafe(), b"topic".to_vec().into(), 0, None);
+
+		// then
+		assert_matches!(res, Err(e) => {
+			assert!(e.message().contains("RPC call is unsafe to be called externally"));
+		});
+	}
 }
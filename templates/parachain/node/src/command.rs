@@ -214,6 +214,9 @@ pub fn run() -> Result<()> {
 				}),
 				BenchmarkCmd::Machine(cmd) =>
 					runner.sync_run(|config| cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone())),
+				BenchmarkCmd::Compare(cmd) => cmd.run(),
+				BenchmarkCmd::Migrations(cmd) => cmd.run::<ReclaimHostFunctions>(),
+				BenchmarkCmd::StorageDiff(cmd) => cmd.run::<ReclaimHostFunctions>(),
 				// NOTE: this allows the Client to leniently implement
 				// new benchmark commands without requiring a companion MR.
 				#[allow(unreachable_patterns)]
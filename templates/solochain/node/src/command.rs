@@ -174,6 +174,9 @@ pub fn run() -> sc_cli::Result<()> {
 					},
 					BenchmarkCmd::Machine(cmd) =>
 						cmd.run(&config, SUBSTRATE_REFERENCE_HARDWARE.clone()),
+					BenchmarkCmd::Compare(cmd) => cmd.run(),
+					BenchmarkCmd::Migrations(cmd) => cmd.run::<()>(),
+					BenchmarkCmd::StorageDiff(cmd) => cmd.run::<()>(),
 				}
 			})
 		},